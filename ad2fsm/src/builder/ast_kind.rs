@@ -1,24 +1,66 @@
+use common::cfg::cfg_node_kind::{JoinKind, StateKind};
+use common::diag::source_span::SourceSpan;
+
 #[derive(Clone, Debug)]
 pub struct CondBody {
     pub cond: String,
+    /// the branch label, `yes` in `if (cond) then (yes)`
+    pub label: Option<String>,
     pub body: Vec<ASTKind>,
 }
 
 #[derive(Clone, Debug)]
 pub struct IfElseBlock {
     pub if_elif: Vec<CondBody>,
+    /// the label of `else (label)`
+    pub else_label: Option<String>,
     pub else_: Vec<ASTKind>,
 }
 
+/// `while (cond) is (label) ... endwhile (exit_label)`
+#[derive(Clone, Debug)]
+pub struct WhileBlock {
+    pub cond: CondBody,
+    /// the label of the edge leaving the loop
+    pub exit_label: Option<String>,
+}
+
+/// `fork ... fork again ... end fork`, `split` alike
+#[derive(Clone, Debug)]
+pub struct ForkBlock {
+    /// the concurrent branches, in diagram order
+    pub branches: Vec<Vec<ASTKind>>,
+    pub join: JoinKind,
+}
+
+/// `partition`, `group`, `package`, `rectangle` or `card` around statements
+#[derive(Clone, Debug)]
+pub struct GroupBlock {
+    /// the group's name, its type when it has none
+    pub name: String,
+    pub body: Vec<ASTKind>,
+}
+
 #[derive(Clone, Debug)]
 pub enum ASTKind {
-    SimpleStmt(String),
+    /// an action, with the kind its SDL stereotype gives it
+    SimpleStmt(String, Option<StateKind>),
     ActivityRef(String),
-    While(CondBody),
+    While(WhileBlock),
+    /// post-tested loop: `repeat ... repeat while (cond)`
+    RepeatWhile(CondBody),
     Case(Vec<CondBody>),
     IfElse(IfElseBlock),
+    Fork(ForkBlock),
+    Group(GroupBlock),
+    /// the label of a `-> label;` arrow, put on the edge that follows
+    Arrow(String),
+    /// the text of a note, documents the statement before it
+    Note(String),
     Label(String),
-    Goto(String),
-    Break,
+    /// a jump to the label, with the span of the statement
+    Goto(String, SourceSpan),
+    /// with the span of the statement
+    Break(SourceSpan),
     Stop,
 }
\ No newline at end of file
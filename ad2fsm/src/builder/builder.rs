@@ -1,13 +1,18 @@
 use crate::builder::lower::build_cfg;
 use crate::builder::parser::parse_with_tree_sitter;
+use common::diag::diagnostic::Diagnostic;
 use common::fsm::fs_machine::{cfg_to_fsm, FSMachine};
 
-pub fn build_fsm_from_plantuml(text: &str) -> anyhow::Result<FSMachine> {
+/// Builds a state machine from PlantUML activity diagram text.
+///
+/// Every problem found while parsing, lowering to a CFG or converting to
+/// the FSM is returned as a list of diagnostics instead of aborting.
+pub fn build_fsm_from_plantuml(text: &str) -> Result<FSMachine, Vec<Diagnostic>> {
     let ast = parse_with_tree_sitter(text)?;
 
     let cfg = build_cfg(&ast)?;
 
-    let fsm = cfg_to_fsm(&cfg);
+    let fsm = cfg_to_fsm(&cfg).map_err(|d| vec![d])?;
 
     Ok(fsm)
 }
@@ -15,19 +20,39 @@ pub fn build_fsm_from_plantuml(text: &str) -> anyhow::Result<FSMachine> {
 #[cfg(test)]
 mod tests {
     use super::build_fsm_from_plantuml;
-    use common::fsm::fs_machine::fsm_to_dot;
+    use crate::builder::lower::{build_cfg, BREAK_OUTSIDE_LOOP, UNDEFINED_LABEL};
+    use crate::builder::parser::parse_with_tree_sitter;
+    use common::cfg::cf_graph::cfg_to_dot;
+    use common::csharp::csharp_gen::generate_csharp_fsm;
+    use common::rust::rust_gen::generate_rust_fsm;
+    use common::fsm::analysis::{analyze_fsm, FindingKind};
+    use common::fsm::fs_machine::{fsm_to_dot, FSMachine};
+    use common::fsm::fsm_json::{fsm_from_json, fsm_to_json};
+    use common::cfg::cfg_node_kind::{JoinKind, StateKind};
+    use common::fsm::partition::cross_partition_transitions;
+    use common::fsm::state_id::StateId;
 
     #[test]
     fn test_builder() {
         for text in [
             include_str!("test_data/break-in-while.puml"),
+            include_str!("test_data/events.puml"),
+            include_str!("test_data/fork.puml"),
             include_str!("test_data/goto-label.puml"),
             include_str!("test_data/goto-loop.puml"),
             include_str!("test_data/if-else.puml"),
             include_str!("test_data/if-elseif-else.puml"),
+            include_str!("test_data/labels.puml"),
+            include_str!("test_data/multi-line.puml"),
+            include_str!("test_data/nested.puml"),
+            include_str!("test_data/notes.puml"),
+            include_str!("test_data/partition.puml"),
+            include_str!("test_data/repeat-retry.puml"),
             include_str!("test_data/repeat-while.puml"),
             include_str!("test_data/simple.puml"),
             include_str!("test_data/stop.puml"),
+            include_str!("test_data/switch-empty-case.puml"),
+            include_str!("test_data/switch.puml"),
             include_str!("test_data/while.puml"),
         ] {
             let fsm = build_fsm_from_plantuml(text).unwrap();
@@ -35,5 +60,478 @@ mod tests {
             println!("{}", dot);
         }
     }
-}
 
+    #[test]
+    fn test_csharp_gen() {
+        let out_dir = std::env::temp_dir().join("ad2fsm_csharp");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        for text in [
+            include_str!("test_data/events.puml"),
+            include_str!("test_data/fork.puml"),
+            include_str!("test_data/if-elseif-else.puml"),
+            include_str!("test_data/labels.puml"),
+            include_str!("test_data/multi-line.puml"),
+            include_str!("test_data/nested.puml"),
+            include_str!("test_data/repeat-retry.puml"),
+            include_str!("test_data/switch.puml"),
+            include_str!("test_data/while.puml"),
+        ] {
+            let fsm = build_fsm_from_plantuml(text).unwrap();
+            generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        }
+        assert!(out_dir.join("State.cs").exists());
+    }
+
+    #[test]
+    fn test_rust_gen() {
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        for (name, text) in [
+            ("activity", include_str!("test_data/activity.puml")),
+            ("events", include_str!("test_data/events.puml")),
+            ("goto_loop", include_str!("test_data/goto-loop.puml")),
+            ("if_elseif_else", include_str!("test_data/if-elseif-else.puml")),
+            ("labels", include_str!("test_data/labels.puml")),
+            ("multi_line", include_str!("test_data/multi-line.puml")),
+            ("nested", include_str!("test_data/nested.puml")),
+            ("notes", include_str!("test_data/notes.puml")),
+            ("repeat_retry", include_str!("test_data/repeat-retry.puml")),
+            ("switch", include_str!("test_data/switch.puml")),
+            ("while", include_str!("test_data/while.puml")),
+        ] {
+            let out_dir = std::env::temp_dir().join(format!("ad2fsm_rust_{}", name));
+            let _ = std::fs::remove_dir_all(&out_dir);
+            std::fs::create_dir_all(&out_dir).unwrap();
+            let fsm = build_fsm_from_plantuml(text).unwrap();
+            generate_rust_fsm(&fsm, &out_dir).unwrap();
+
+            let output = std::process::Command::new(&rustc)
+                .args(["--crate-type", "lib", "--edition", "2021", "-D", "warnings"])
+                .args(["--crate-name", name])
+                .arg("--out-dir")
+                .arg(&out_dir)
+                .arg(out_dir.join("mod.rs"))
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        for text in [
+            include_str!("test_data/activity.puml"),
+            include_str!("test_data/break-in-while.puml"),
+            include_str!("test_data/events.puml"),
+            include_str!("test_data/fork.puml"),
+            include_str!("test_data/goto-label.puml"),
+            include_str!("test_data/goto-loop.puml"),
+            include_str!("test_data/if-else.puml"),
+            include_str!("test_data/if-elseif-else.puml"),
+            include_str!("test_data/labels.puml"),
+            include_str!("test_data/multi-line.puml"),
+            include_str!("test_data/nested.puml"),
+            include_str!("test_data/notes.puml"),
+            include_str!("test_data/partition.puml"),
+            include_str!("test_data/repeat-retry.puml"),
+            include_str!("test_data/repeat-while.puml"),
+            include_str!("test_data/simple.puml"),
+            include_str!("test_data/stop.puml"),
+            include_str!("test_data/switch.puml"),
+            include_str!("test_data/while.puml"),
+        ] {
+            let fsm = build_fsm_from_plantuml(text).unwrap();
+            let json = fsm_to_json(&fsm);
+            let loaded = fsm_from_json(&json).unwrap();
+            assert_eq!(loaded, fsm);
+            assert_eq!(fsm_to_json(&loaded), json);
+        }
+    }
+
+    #[test]
+    fn test_deterministic_output() {
+        fn read_dir(dir: &std::path::Path) -> Vec<(String, String)> {
+            let mut files: Vec<_> = std::fs::read_dir(dir)
+                .unwrap()
+                .map(|e| e.unwrap().path())
+                .map(|p| {
+                    let name = p.file_name().unwrap().to_string_lossy().to_string();
+                    (name, std::fs::read_to_string(&p).unwrap())
+                })
+                .collect();
+            files.sort();
+            files
+        }
+
+        let text = include_str!("test_data/if-elseif-else.puml");
+        let dirs: Vec<_> = (0..2)
+            .map(|i| std::env::temp_dir().join(format!("ad2fsm_deterministic_{}", i)))
+            .collect();
+        let mut outputs = vec![];
+        for dir in &dirs {
+            let _ = std::fs::remove_dir_all(dir);
+            std::fs::create_dir_all(dir).unwrap();
+            let ast = parse_with_tree_sitter(text).unwrap();
+            let cfg = build_cfg(&ast).unwrap();
+            let fsm = build_fsm_from_plantuml(text).unwrap();
+            generate_csharp_fsm(&fsm, dir).unwrap();
+            outputs.push((cfg_to_dot(&cfg), fsm_to_dot(&fsm), read_dir(dir)));
+        }
+        // every run hashes with a fresh seed, unordered storage would differ
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    fn state_id(fsm: &FSMachine, name: &str) -> StateId {
+        fsm.state_map()
+            .iter()
+            .find(|(_, n)| n.as_str() == name)
+            .map(|(id, _)| *id)
+            .unwrap_or_else(|| panic!("no state named {}", name))
+    }
+
+    fn has_transition(fsm: &FSMachine, from: &str, to: &str, cond: Option<&str>) -> bool {
+        let from = state_id(fsm, from);
+        let to = state_id(fsm, to);
+        fsm.transitions().iter().any(|t| {
+            t.from() == from && t.to() == to && t.condition().as_deref() == cond
+        })
+    }
+
+    #[test]
+    fn test_switch() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/switch.puml")).unwrap();
+        assert!(has_transition(&fsm, "START", "Handle A", Some(" 0 A")));
+        assert!(has_transition(&fsm, "START", "Handle B1", Some(" 1 B")));
+        assert!(has_transition(&fsm, "START", "Handle C", Some(" 2 C")));
+        assert!(has_transition(&fsm, "START", "case_end", Some(" 3 default")));
+        // the second statement of a case body is only reachable through the first
+        let b1 = state_id(&fsm, "Handle B1");
+        let b2 = state_id(&fsm, "Handle B2");
+        assert!(fsm.transitions().iter().all(|t| t.to() != b2 || t.from() == b1));
+        // only the implicit default is taken when no case matches
+        let fallbacks: Vec<_> = fsm
+            .transitions()
+            .iter()
+            .filter(|t| t.cond().as_ref().is_some_and(|c| c.is_fallback()))
+            .map(|t| fsm.state_map()[&t.to()].as_str())
+            .collect();
+        assert_eq!(fallbacks, vec!["case_end"]);
+    }
+
+    #[test]
+    fn test_switch_empty_case() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/switch-empty-case.puml")).unwrap();
+        assert!(has_transition(&fsm, "START", "Handle A", Some(" 0 A")));
+        assert!(has_transition(&fsm, "START", "case_end", Some(" 1 B")));
+        // the default cannot share the empty case's edge
+        assert!(has_transition(&fsm, "START", "default", Some(" 2 default")));
+        assert!(has_transition(&fsm, "default", "case_end", None));
+    }
+
+    #[test]
+    fn test_repeat_while() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/repeat-retry.puml")).unwrap();
+        assert!(has_transition(&fsm, "START", "Connect", None));
+        assert!(has_transition(&fsm, "Connect", "Send", None));
+        // the condition is tested after the body and loops back to its head
+        assert!(has_transition(&fsm, "Send", "Connect", Some(" 0 true")));
+        assert!(has_transition(&fsm, "Send", "repeat_end", Some(" 1 false")));
+        let exit = fsm.transitions().iter().find(|t| t.condition().as_deref() == Some(" 1 false"));
+        assert!(exit.unwrap().cond().as_ref().unwrap().is_fallback());
+        assert!(has_transition(&fsm, "repeat_end", "Close", None));
+    }
+
+    #[test]
+    fn test_nested_decisions() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/nested.puml")).unwrap();
+        // a repeat whose body starts with an if loops back into the if
+        assert!(has_transition(&fsm, "START", "Work", Some(" 0 ready?")));
+        assert!(has_transition(&fsm, "if_end", "Work", Some(" 0 true and ready?")));
+        assert!(has_transition(&fsm, "if_end", "Wait", Some(" 0 true and else")));
+        assert!(has_transition(&fsm, "if_end", "repeat_end", Some(" 1 false")));
+        // a while in a branch is entered through the branch
+        assert!(has_transition(&fsm, "repeat_end", "Poll", Some(" 0 check? and true")));
+        assert!(has_transition(&fsm, "repeat_end", "while_end", Some(" 0 check? and false")));
+        // so is a switch, and an if in one of its cases
+        assert!(has_transition(&fsm, "repeat_end", "Handle A", Some(" 2 else and A")));
+        assert!(has_transition(&fsm, "repeat_end", "Quick", Some(" 2 else and B and fast?")));
+        assert!(has_transition(&fsm, "repeat_end", "case_end", Some(" 2 else and default")));
+        // only a branch made of fallbacks alone is a fallback
+        let fallback = |to: &str, cond: &str| {
+            fsm.transitions()
+                .iter()
+                .find(|t| fsm.state_map()[&t.to()] == to && t.condition().as_deref() == Some(cond))
+                .is_some_and(|t| t.cond().as_ref().unwrap().is_fallback())
+        };
+        assert!(!fallback("Wait", " 0 true and else"));
+        assert!(fallback("case_end", " 2 else and default"));
+        // every state is reachable
+        assert!(analyze_fsm(&fsm).iter().all(|f| f.kind() != FindingKind::Unreachable));
+    }
+
+    #[test]
+    fn test_goto_label() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/goto-label.puml")).unwrap();
+        assert!(has_transition(&fsm, "A", "goto L1", None));
+        assert!(has_transition(&fsm, "goto L1", "C", None));
+        assert!(has_transition(&fsm, "B", "C", None));
+        assert!(has_transition(&fsm, "C", "END", None));
+        // B is skipped by the jump
+        let b = state_id(&fsm, "B");
+        assert!(fsm.transitions().iter().all(|t| t.to() != b));
+    }
+
+    #[test]
+    fn test_goto_loop() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/goto-loop.puml")).unwrap();
+        assert!(has_transition(&fsm, "START", "A", None));
+        assert!(has_transition(&fsm, "A", "goto LOOP", None));
+        assert!(has_transition(&fsm, "goto LOOP", "A", None));
+        // labels are no states of their own
+        assert!(fsm.state_map().values().all(|name| !name.starts_with("label")));
+    }
+
+    #[test]
+    fn test_label_targets() {
+        // a label before a decision jumps into the decision, one at the end
+        // of the diagram ends it
+        let text = "@startuml\nstart\nlabel TOP\nif (more?) then\n:B;\ngoto TOP\nendif\n\
+                    :C;\ngoto FIN\n:D;\nlabel FIN\n@enduml\n";
+        let fsm = build_fsm_from_plantuml(text).unwrap();
+        assert!(has_transition(&fsm, "START", "B", Some(" 0 more?")));
+        assert!(has_transition(&fsm, "goto TOP", "B", Some(" 0 more?")));
+        assert!(has_transition(&fsm, "goto FIN", "END", None));
+        assert!(has_transition(&fsm, "D", "END", None));
+    }
+
+    #[test]
+    fn test_undefined_label() {
+        let text = "@startuml\nstart\n:A;\ngoto MISSING;\ngoto ALSO_MISSING;\nstop\n@enduml\n";
+        let diagnostics = build_fsm_from_plantuml(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| d.code() == UNDEFINED_LABEL));
+        assert!(diagnostics[0].message().contains("MISSING"));
+        let spans: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.span().map(|s| (s.start.line, s.start.column)))
+            .collect();
+        assert_eq!(spans, [Some((4, 1)), Some((5, 1))]);
+    }
+
+    #[test]
+    fn test_break_outside_loop() {
+        let text = "@startuml\nstart\n:A;\nbreak;\nstop\n@enduml\n";
+        let diagnostics = build_fsm_from_plantuml(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), BREAK_OUTSIDE_LOOP);
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (4, 1));
+    }
+
+    #[test]
+    fn test_break_in_while() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/break-in-while.puml")).unwrap();
+        assert!(has_transition(&fsm, "START", "A", Some(" 0 true")));
+        assert!(has_transition(&fsm, "A", "break", None));
+        assert!(has_transition(&fsm, "break", "while_end", None));
+        assert!(has_transition(&fsm, "while_end", "B", None));
+        assert!(has_transition(&fsm, "B", "END", None));
+    }
+
+    #[test]
+    fn test_stop() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/stop.puml")).unwrap();
+        assert!(has_transition(&fsm, "A", "END", None));
+        assert_eq!(fsm.terminals(), &vec![state_id(&fsm, "END")]);
+        // statements after stop are never entered
+        let b = state_id(&fsm, "B");
+        assert!(fsm.transitions().iter().all(|t| t.from() != b && t.to() != b));
+    }
+
+    #[test]
+    fn test_fork() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/fork.puml")).unwrap();
+        // each fork is one state of the outer machine
+        let forks: Vec<_> = fsm.orthogonals().keys().copied().collect();
+        assert_eq!(forks.len(), 2);
+        assert!(forks.iter().all(|id| fsm.state_map()[id] == "fork"));
+        let (pay, ship) = (forks[0], state_id(&fsm, "Ship order"));
+        assert!(has_transition(&fsm, "Receive order", "fork", None));
+        assert!(fsm.transitions().iter().any(|t| t.from() == pay && t.to() == ship));
+        assert!(fsm.transitions().iter().any(|t| t.from() == ship && t.to() == forks[1]));
+
+        let fulfil = fsm.orthogonal(pay).unwrap();
+        assert_eq!(fulfil.join(), JoinKind::All);
+        assert_eq!(fulfil.regions().len(), 2);
+        assert!(has_transition(&fulfil.regions()[0], "START", "Take payment", None));
+        assert!(has_transition(&fulfil.regions()[0], "Take payment", "END", None));
+        let stock = &fulfil.regions()[1];
+        assert!(has_transition(stock, "Reserve stock", "Hold items", Some(" 0 in stock?")));
+        assert!(has_transition(stock, "if_end", "END", None));
+
+        // `end merge` continues once either branch ended
+        let notify = fsm.orthogonal(forks[1]).unwrap();
+        assert_eq!(notify.join(), JoinKind::Any);
+        assert!(has_transition(&notify.regions()[1], "START", "Text customer", None));
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains(&format!("subgraph cluster_S{} {{", pay.0)));
+        assert!(dot.contains("label=\"fork (join any)\";"));
+        assert!(dot.contains(&format!("S{0} -> S{0}_R1_S0 [style=dashed];", pay.0)));
+        let cfg = build_cfg(&parse_with_tree_sitter(include_str!("test_data/fork.puml")).unwrap()).unwrap();
+        assert!(cfg_to_dot(&cfg).contains("label=\"join all\", shape=invhouse"));
+    }
+
+    #[test]
+    fn test_jump_out_of_fork() {
+        let text = "@startuml\nstart\nlabel L\nwhile (more?)\nfork\n:A;\nfork again\nbreak\nend fork\nendwhile\n\
+                    fork\n:B;\nfork again\ngoto L\nend fork\nstop\n@enduml\n";
+        let diagnostics = build_fsm_from_plantuml(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].code(), BREAK_OUTSIDE_LOOP);
+        assert_eq!(diagnostics[1].code(), UNDEFINED_LABEL);
+        assert!(diagnostics.iter().all(|d| d.notes() == &vec!["in branch 2 of a fork".to_string()]));
+    }
+
+    #[test]
+    fn test_partitions() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/partition.puml")).unwrap();
+        let partition = |name| fsm.partition(state_id(&fsm, name)).join("/");
+        assert_eq!(partition("Receive order"), "Order Service");
+        assert_eq!(partition("Take payment"), "Payment");
+        assert_eq!(partition("Check card"), "Payment/Fraud");
+        assert_eq!(partition("Cancel order"), "Order Service");
+        assert_eq!(partition("END"), "");
+
+        let handoffs: Vec<_> = cross_partition_transitions(&fsm)
+            .iter()
+            .map(|t| (fsm.state_map()[&t.from()].as_str(), fsm.state_map()[&t.to()].as_str()))
+            .collect();
+        assert_eq!(
+            handoffs,
+            vec![
+                ("Receive order", "Take payment"),
+                ("Take payment", "Check card"),
+                ("Check card", "Confirm order"),
+                ("Check card", "Cancel order"),
+            ]
+        );
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains("  subgraph cluster_P1 {\n    label=\"Payment\";\n"));
+        assert!(dot.contains("    subgraph cluster_P2 {\n      label=\"Fraud\";\n"));
+    }
+
+    #[test]
+    fn test_labels() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/labels.puml")).unwrap();
+        let label = |from, to| {
+            let (from, to) = (state_id(&fsm, from), state_id(&fsm, to));
+            let t = fsm.transitions().iter().find(|t| t.from() == from && t.to() == to).unwrap();
+            t.label().clone()
+        };
+        // the guard is the condition, the label only names the branch
+        assert!(has_transition(&fsm, "Receive order", "Ship order", Some(" 0 paid?")));
+        assert_eq!(label("Receive order", "Ship order").as_deref(), Some("yes"));
+        assert_eq!(label("Receive order", "Hold order").as_deref(), Some("no"));
+        // an arrow into a decision labels the branches without a label
+        assert_eq!(label("Review order", "Archive order").as_deref(), Some("checked"));
+        assert_eq!(label("Pack item", "Pack item").as_deref(), Some("next"));
+        assert_eq!(label("Pack item", "while_end").as_deref(), Some("done"));
+        assert_eq!(label("START", "Receive order"), None);
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains("[label=\"yes [paid?]\"];"));
+        assert!(dot.contains("[label=\"checked [approved?]\"];"));
+
+        let out_dir = std::env::temp_dir().join("ad2fsm_csharp_labels");
+        let _ = std::fs::remove_dir_all(&out_dir);
+        std::fs::create_dir_all(&out_dir).unwrap();
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        let ship = std::fs::read_to_string(
+            out_dir.join("Transition_receive_order_ship_order_yes.cs"),
+        ).unwrap();
+        assert!(ship.contains("// yes [paid?]\n"));
+        assert!(ship.contains("Condition check for transition receive_order_1 -> ship_order_3 (yes)\n"));
+    }
+
+    #[test]
+    fn test_notes() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/notes.puml")).unwrap();
+        let check = state_id(&fsm, "Check card");
+        assert_eq!(
+            fsm.doc(check),
+            Some(
+                "Verifies the card with the issuer (3-D Secure),\n\
+                 declines when the amount is > the limit.\n\
+                 Retried once on timeout."
+            )
+        );
+        assert_eq!(fsm.doc(state_id(&fsm, "Capture payment")), Some("Money is only moved here"));
+        assert_eq!(fsm.doc(state_id(&fsm, "Send receipt")), None);
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains(&format!(
+            "S{} [label=\"Check card\", shape=circle, tooltip=\"Verifies the card",
+            check.0
+        )));
+
+        let out_dir = std::env::temp_dir().join("ad2fsm_csharp_notes");
+        let _ = std::fs::remove_dir_all(&out_dir);
+        std::fs::create_dir_all(&out_dir).unwrap();
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        let states = std::fs::read_to_string(out_dir.join("State.cs")).unwrap();
+        assert!(states.contains(
+            "        /// <summary>\n\
+             \x20       /// Money is only moved here\n\
+             \x20       /// </summary>\n\
+             \x20       capture_payment_2,\n"
+        ));
+        let check = std::fs::read_to_string(out_dir.join("Transition_start_check_card.cs")).unwrap();
+        assert!(check.contains(
+            "        /// declines when the amount is &gt; the limit.\n\
+             \x20       /// Retried once on timeout.\n\
+             \x20       /// </summary>\n\
+             \x20       public static State Execute(Context ctx)\n"
+        ));
+    }
+
+    #[test]
+    fn test_stereotypes() {
+        let fsm = build_fsm_from_plantuml(include_str!("test_data/events.puml")).unwrap();
+        let kind = |name| fsm.kind(state_id(&fsm, name));
+        assert_eq!(kind("Order placed"), Some(StateKind::AcceptEvent));
+        assert_eq!(kind("Load order"), Some(StateKind::Load));
+        assert_eq!(kind("Notify warehouse"), Some(StateKind::SendSignal));
+        assert_eq!(kind("1 hour"), Some(StateKind::TimeEvent));
+        assert_eq!(kind("Save order"), Some(StateKind::Save));
+        assert_eq!(kind("Payment\nreceived"), Some(StateKind::AcceptEvent));
+        // other stereotypes only change how the action is drawn
+        assert_eq!(kind("Archive order"), None);
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains("[label=\"<<acceptEvent>>\\nOrder placed\", shape=circle];"));
+
+        let out_dir = std::env::temp_dir().join("ad2fsm_rust_events_hooks");
+        let _ = std::fs::remove_dir_all(&out_dir);
+        std::fs::create_dir_all(&out_dir).unwrap();
+        generate_rust_fsm(&fsm, &out_dir).unwrap();
+        let dispatcher = std::fs::read_to_string(out_dir.join("dispatcher.rs")).unwrap();
+        assert!(dispatcher.contains("if !accept_order_placed(ctx) {\n                return None;"));
+        assert!(dispatcher.contains(
+            "action_order_placed_to_load_order(ctx);\n                load_load_order(ctx);"
+        ));
+        let events = std::fs::read_to_string(out_dir.join("events.rs")).unwrap();
+        assert!(events.contains("/// Whether the timeout `1 hour` elapsed\npub fn elapsed_s1_hour"));
+        // a multi-line name stays inside the doc comment
+        assert!(events.contains("/// Whether the event `Payment received` arrived\npub fn accept_payment_received"));
+
+        let out_dir = std::env::temp_dir().join("ad2fsm_csharp_events_hooks");
+        let _ = std::fs::remove_dir_all(&out_dir);
+        std::fs::create_dir_all(&out_dir).unwrap();
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        let events = std::fs::read_to_string(out_dir.join("Events.cs")).unwrap();
+        assert!(events.contains(
+            "/// Whether the event \"Payment received\" arrived; the machine waits in payment_received_"
+        ));
+    }
+}
@@ -1,3 +0,0 @@
-mod csharp_gen;
-mod template;
-mod test_csharp_gen;
\ No newline at end of file
@@ -1,9 +1,15 @@
-use crate::builder::ast_kind::{ASTKind, CondBody, IfElseBlock};
-use anyhow::{anyhow, Result};
+use crate::builder::ast_kind::{ASTKind, CondBody, ForkBlock, GroupBlock, IfElseBlock, WhileBlock};
 use common::cfg::cf_graph::CFGraph;
 use common::cfg::cfg_builder::CfgBuilder;
 use common::cfg::cfg_cond::{CfgCond, CfgEdge};
 use common::cfg::cfg_node_kind::{CfgNodeKind, NodeId};
+use common::diag::diagnostic::{has_errors, Diagnostic};
+use std::collections::HashSet;
+
+/// Diagnostic code: `break` used outside of any loop
+pub const BREAK_OUTSIDE_LOOP: &str = "AD0101";
+/// Diagnostic code: `goto` refers to a label that is never defined
+pub const UNDEFINED_LABEL: &str = "AD0102";
 
 pub fn lower_block(
     builder: &mut CfgBuilder,
@@ -25,50 +31,75 @@ pub fn lower_stmt(
     entry: NodeId,
 ) -> NodeId {
     match stmt {
-        ASTKind::SimpleStmt(text) => {
+        ASTKind::SimpleStmt(text, kind) => {
             let n = builder.new_node(CfgNodeKind::Action(text.clone()));
+            if let Some(kind) = kind {
+                builder.cfg.kinds.insert(n, *kind);
+            }
             builder.edge(CfgEdge::new(entry, n, None, None));
             n
         }
 
         ASTKind::ActivityRef(name) => {
-            let n = builder.new_node(CfgNodeKind::Action(format!("call {}", name)));
+            let n = builder.new_node(CfgNodeKind::Activity(name.clone()));
             builder.edge(CfgEdge::new(entry, n, None, None));
             n
         }
 
         // ---------------------------
-        // LABEL (no node created)
+        // ARROW (label of the next edge)
+        // ---------------------------
+        ASTKind::Arrow(label) => {
+            builder.arrow_labels.insert(entry, label.clone());
+            entry
+        }
+
+        // ---------------------------
+        // NOTE (documents the node before it)
+        // ---------------------------
+        ASTKind::Note(text) => {
+            builder.note(entry, text.clone());
+            entry
+        }
+
+        // ---------------------------
+        // LABEL (jump target)
         // ---------------------------
         ASTKind::Label(name) => {
-            // Label points to the current entry node
-            builder.labels.insert(name.clone(), entry);
+            // A jump lands on the statement following the label rather
+            // than on the one preceding it, the label is no node itself
+            builder.label(entry, name.clone());
             entry
         }
 
         // ---------------------------
         // GOTO (unconditional jump)
         // ---------------------------
-        ASTKind::Goto(label) => {
+        ASTKind::Goto(label, span) => {
             let n = builder.new_node(CfgNodeKind::Action(format!("goto {}", label)));
             builder.edge(CfgEdge::new(entry, n, None, None));
 
             // resolve later
-            builder.pending_gotos.push((n, label.clone()));
-            n
+            builder.pending_gotos.push((n, label.clone(), *span));
+            unreachable_node(builder)
         }
 
         // ---------------------------
         // BREAK (jump to loop exit)
         // ---------------------------
-        ASTKind::Break => {
+        ASTKind::Break(span) => {
             let Some(&exit) = builder.loop_exit_stack.last()
-            else { panic!("break outside loop"); };
+            else {
+                let d = Diagnostic::error(BREAK_OUTSIDE_LOOP, "break outside loop".to_string())
+                    .with_span(*span);
+                builder.diagnostics.push(d);
+                return entry;
+            };
 
             let n = builder.new_node(CfgNodeKind::Action("break".into()));
             builder.edge(CfgEdge::new(entry, n, None, None));
             builder.edge(CfgEdge::new(n, exit, None, None));
-            n
+            unreachable_node(builder)
         }
 
         // ---------------------------
@@ -77,7 +108,7 @@ pub fn lower_stmt(
         ASTKind::Stop => {
             let n = builder.new_node(CfgNodeKind::End);
             builder.edge(CfgEdge::new(entry, n, None, None));
-            n
+            unreachable_node(builder)
         }
 
         // ---------------------------
@@ -90,8 +121,15 @@ pub fn lower_stmt(
         // ---------------------------
         // WHILE LOOP
         // ---------------------------
-        ASTKind::While(cond) => {
-            lower_while(builder, cond, entry)
+        ASTKind::While(block) => {
+            lower_while(builder, block, entry)
+        }
+
+        // ---------------------------
+        // REPEAT ... REPEAT WHILE
+        // ---------------------------
+        ASTKind::RepeatWhile(cond) => {
+            lower_repeat_while(builder, cond, entry)
         }
 
         // ---------------------------
@@ -100,6 +138,20 @@ pub fn lower_stmt(
         ASTKind::Case(cases) => {
             lower_case(builder, cases, entry)
         }
+
+        // ---------------------------
+        // FORK / SPLIT (concurrent branches)
+        // ---------------------------
+        ASTKind::Fork(block) => {
+            lower_fork(builder, block, entry)
+        }
+
+        // ---------------------------
+        // GROUP / PARTITION (ownership of the statements)
+        // ---------------------------
+        ASTKind::Group(block) => {
+            lower_group(builder, block, entry)
+        }
     }
 }
 
@@ -114,23 +166,19 @@ fn lower_ifelse(
     builder.edge(CfgEdge::new(entry, decision, None, None));
 
     for (i, cond) in block.if_elif.iter().enumerate() {
-        let body_entry = lower_block(builder, &cond.body, decision);
         let cfg_cond = CfgCond::new(
             i as _,
             cond.cond.clone(),
         );
-        builder.edge(CfgEdge::new(decision, body_entry, Some(cond.cond.clone()), Some(cfg_cond)));
-        builder.edge(CfgEdge::new(body_entry, merge, None, None));
+        lower_branch(builder, &cond.body, decision, merge, cond.label.clone(), cfg_cond);
     }
 
-    if !block.else_.is_empty() {
-        let else_entry = lower_block(builder, &block.else_, decision);
+    if !block.else_.is_empty() || block.else_label.is_some() {
         let cfg_cond = CfgCond::new(
             (block.if_elif.len() + 1usize) as _,
             "else".to_string(),
-        );
-        builder.edge(CfgEdge::new(decision, else_entry, None, Some(cfg_cond)));
-        builder.edge(CfgEdge::new(else_entry, merge, None, None));
+        ).with_fallback(true);
+        lower_branch(builder, &block.else_, decision, merge, block.else_label.clone(), cfg_cond);
     }
 
     merge
@@ -139,16 +187,16 @@ fn lower_ifelse(
 
 fn lower_while(
     builder: &mut CfgBuilder,
-    cond: &CondBody,
+    block: &WhileBlock,
     entry: NodeId,
 ) -> NodeId {
+    let cond = &block.cond;
     let decision = builder.new_node(CfgNodeKind::Decision(cond.cond.clone()));
     let merge = builder.new_node(CfgNodeKind::Action("while_end".into()));
 
     builder.edge(CfgEdge::new(entry, decision, None, None));
     builder.loop_exit_stack.push(merge);
 
-    let body_exit = lower_block(builder, &cond.body, decision);
     let cfg_cond_true = CfgCond::new(
         0 as _,
         "true".to_string(),
@@ -156,16 +204,51 @@ fn lower_while(
     let cfg_cond_false = CfgCond::new(
         1 as _,
         "false".to_string(),
+    ).with_fallback(true);
+    // the body flows back into the decision node
+    lower_branch(builder, &cond.body, decision, decision, cond.label.clone(), cfg_cond_true);
+    builder.edge(CfgEdge::new(decision, merge, block.exit_label.clone(), Some(cfg_cond_false)));
+
+    builder.loop_exit_stack.pop();
+    merge
+}
+
+fn lower_repeat_while(
+    builder: &mut CfgBuilder,
+    cond: &CondBody,
+    entry: NodeId,
+) -> NodeId {
+    let decision = builder.new_node(CfgNodeKind::Decision(cond.cond.clone()));
+    let merge = builder.new_node(CfgNodeKind::Action("repeat_end".into()));
+
+    builder.loop_exit_stack.push(merge);
+
+    // the body runs once before the condition is tested
+    let existing = successors(builder, entry);
+    let body_exit = lower_block(builder, &cond.body, entry);
+    let body_heads: Vec<NodeId> = successors(builder, entry)
+        .into_iter()
+        .filter(|n| !existing.contains(n))
+        .collect();
+
+    let cfg_cond_true = CfgCond::new(
+        0 as _,
+        "true".to_string(),
     );
-    builder.edge(CfgEdge::new(decision, body_exit, None, Some(cfg_cond_true)));
+    let cfg_cond_false = CfgCond::new(
+        1 as _,
+        "false".to_string(),
+    ).with_fallback(true);
     builder.edge(CfgEdge::new(body_exit, decision, None, None));
+    for head in body_heads {
+        builder.edge(CfgEdge::new(decision, head, None, Some(cfg_cond_true.clone())));
+    }
     builder.edge(CfgEdge::new(decision, merge, None, Some(cfg_cond_false)));
 
     builder.loop_exit_stack.pop();
     merge
 }
 
-
 fn lower_case(
     builder: &mut CfgBuilder,
     cases: &[CondBody],
@@ -176,33 +259,155 @@ fn lower_case(
     builder.edge(CfgEdge::new(entry, decision, None, None));
 
     for (i, c) in cases.iter().enumerate() {
-        let body_exit = lower_block(builder, &c.body, decision);
         let cfg_cond = CfgCond::new(
             i as _,
             c.cond.clone(),
         );
-        builder.edge(CfgEdge::new(decision, body_exit, None, Some(cfg_cond)));
-        builder.edge(CfgEdge::new(body_exit, merge, None, None));
+        lower_branch(builder, &c.body, decision, merge, None, cfg_cond);
     }
 
+    // implicit path taken when no case matches
+    let cfg_cond = CfgCond::new(
+        cases.len() as _,
+        "default".to_string(),
+    ).with_fallback(true);
+    lower_branch(builder, &[], decision, merge, None, cfg_cond);
+
     merge
 }
 
-pub fn resolve_gotos_step2(builder: &mut CfgBuilder) -> Result<()> {
-    for (from, label) in builder.pending_gotos.clone() {
+fn lower_fork(
+    builder: &mut CfgBuilder,
+    block: &ForkBlock,
+    entry: NodeId,
+) -> NodeId {
+    let branches = block.branches
+        .iter()
+        .enumerate()
+        .map(|(i, body)| lower_fork_branch(builder, body, i))
+        .collect();
+    let fork = builder.new_node(CfgNodeKind::Fork(branches));
+    let join = builder.new_node(CfgNodeKind::Join(block.join));
+    builder.edge(CfgEdge::new(entry, fork, None, None));
+    builder.edge(CfgEdge::new(fork, join, None, None));
+    join
+}
+
+/// Lowers one branch of a fork into a graph of its own, which ends where
+/// the branch ends. Labels and loops of the enclosing diagram are out of
+/// reach: a branch cannot `goto` or `break` out of the fork.
+fn lower_fork_branch(
+    builder: &mut CfgBuilder,
+    body: &[ASTKind],
+    index: usize,
+) -> CFGraph {
+    let (mut branch, start) = CfgBuilder::new();
+    // the branch is still inside the groups around the fork
+    branch.group_stack = builder.group_stack.clone();
+    let exit = lower_block(&mut branch, body, start);
+    let end = branch.new_node(CfgNodeKind::End);
+    branch.edge(CfgEdge::new(exit, end, None, None));
+    resolve_gotos_step2(&mut branch);
+    builder.diagnostics.extend(
+        branch.diagnostics
+            .into_iter()
+            .map(|d| d.with_note(format!("in branch {} of a fork", index + 1))),
+    );
+    branch.cfg
+}
+
+/// The body is lowered in place, its nodes record the group's name
+fn lower_group(
+    builder: &mut CfgBuilder,
+    block: &GroupBlock,
+    entry: NodeId,
+) -> NodeId {
+    builder.group_stack.push(block.name.clone());
+    let exit = lower_block(builder, &block.body, entry);
+    builder.group_stack.pop();
+    exit
+}
+
+/// Lowers one guarded branch of a decision node.
+///
+/// The condition is attached to the edge(s) leaving `decision` that were
+/// created for the first statement of `body`, so a multi-statement body
+/// is entered through its head rather than its tail. An empty body jumps
+/// straight to `merge` under the condition, unless an empty branch before
+/// it already does: the edges are keyed by their ends, so the later branch
+/// passes through a node of its own, named after its condition.
+fn lower_branch(
+    builder: &mut CfgBuilder,
+    body: &[ASTKind],
+    decision: NodeId,
+    merge: NodeId,
+    label: Option<String>,
+    cfg_cond: CfgCond,
+) {
+    let existing = successors(builder, decision);
+
+    let body_exit = lower_block(builder, body, decision);
+    if body_exit == decision {
+        if builder.cfg.edges.contains_key(&(decision, merge)) {
+            let n = builder.new_node(CfgNodeKind::Action(cfg_cond.name().clone()));
+            builder.edge(CfgEdge::new(decision, n, label, Some(cfg_cond)));
+            builder.edge(CfgEdge::new(n, merge, None, None));
+        } else {
+            builder.edge(CfgEdge::new(decision, merge, label, Some(cfg_cond)));
+        }
+        return;
+    }
+
+    for ((from, to), e) in builder.cfg.edges.iter_mut() {
+        if *from == decision && !existing.contains(to) && e.cond.is_none() {
+            // an arrow at the head of the body labels the branch too
+            if label.is_some() {
+                e.label = label.clone();
+            }
+            e.cond = Some(cfg_cond.clone());
+        }
+    }
+    builder.edge(CfgEdge::new(body_exit, merge, None, None));
+}
+
+/// Creates a node without predecessors to continue from after an
+/// unconditional jump; statements lowered after it are unreachable.
+fn unreachable_node(builder: &mut CfgBuilder) -> NodeId {
+    builder.new_node(CfgNodeKind::Merge)
+}
+
+/// Targets of all edges currently leaving `node`.
+fn successors(builder: &CfgBuilder, node: NodeId) -> HashSet<NodeId> {
+    builder.cfg.edges.keys()
+        .filter(|(from, _)| *from == node)
+        .map(|(_, to)| *to)
+        .collect()
+}
+
+pub fn resolve_gotos_step2(builder: &mut CfgBuilder) {
+    for (from, label, span) in builder.pending_gotos.clone() {
         if let Some(&target) = builder.labels.get(&label) {
             builder.edge(CfgEdge::new(from, target, None, None));
         } else {
-            return Err(anyhow!(format!("Undefined label: {}", label)));
+            let d = Diagnostic::error(UNDEFINED_LABEL, format!("undefined label: {}", label))
+                .with_span(span);
+            builder.diagnostics.push(d);
         }
     }
-    Ok(())
 }
 
 
-pub fn build_cfg(ast: &[ASTKind]) -> Result<CFGraph> {
+pub fn build_cfg(ast: &[ASTKind]) -> Result<CFGraph, Vec<Diagnostic>> {
     let (mut builder, start) = CfgBuilder::new();
-    let _exit = lower_block(&mut builder, ast, start);
-    resolve_gotos_step2(&mut builder)?;
+    let exit = lower_block(&mut builder, ast, start);
+    // nothing follows a label at the end of the diagram, jumping there ends it
+    if !builder.pending_labels.is_empty() {
+        let end = builder.new_node(CfgNodeKind::End);
+        builder.edge(CfgEdge::new(exit, end, None, None));
+    }
+    resolve_gotos_step2(&mut builder);
+    if has_errors(&builder.diagnostics) {
+        return Err(builder.diagnostics);
+    }
     Ok(builder.cfg)
-}
\ No newline at end of file
+}
@@ -1,11 +1,10 @@
 pub mod parser;
-mod parse_context;
 
-mod ast_kind;
-mod parse_error;
+pub mod ast_kind;
 
 
-mod lower;
+pub mod lower;
+pub mod resolver;
 
-mod builder;
-mod csharp;
+#[allow(clippy::module_inception)]
+pub mod builder;
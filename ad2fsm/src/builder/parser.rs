@@ -1,7 +1,9 @@
-use crate::builder::ast_kind::{ASTKind, CondBody, IfElseBlock};
-use crate::builder::parse_context::ParseContext;
+use crate::builder::ast_kind::{ASTKind, CondBody, ForkBlock, GroupBlock, IfElseBlock, WhileBlock};
 use crate::ts_const;
 use anyhow::{Error, Result};
+use common::cfg::cfg_node_kind::{JoinKind, StateKind};
+use common::diag::diagnostic::{has_errors, Diagnostic};
+use common::diag::parse_context::ParseContext;
 use regex::Regex;
 use tree_sitter::{Node, Parser};
 use tree_sitter_ad::LANGUAGE;
@@ -14,15 +16,26 @@ pub struct ADParser {
     parser: Parser,
 }
 
+/// Diagnostic code: the source contains text the grammar cannot parse
+pub const SYNTAX_ERROR: &str = "AD0001";
+/// Diagnostic code: the parser had to assume a token that is not there
+pub const MISSING_TOKEN: &str = "AD0002";
+/// Diagnostic code: the tree-sitter grammar could not be loaded
+pub const PARSER_INIT: &str = "AD0003";
+/// Diagnostic code: a syntax node lacks a part the AST needs
+pub const MALFORMED_NODE: &str = "AD0004";
+
 impl ADParser {
     /// Creates a new parser instance with the PlantUML grammar
-    pub fn new() -> Self {
+    pub fn new() -> std::result::Result<Self, Diagnostic> {
         let mut parser = Parser::new();
 
         parser.set_language(&LANGUAGE.into())
-            .expect("Failed to set language");
+            .map_err(|e| {
+                Diagnostic::error(PARSER_INIT, format!("failed to set language: {}", e))
+            })?;
 
-        ADParser { parser }
+        Ok(ADParser { parser })
     }
 
     /// Parses PlantUML source code and builds an AST
@@ -31,34 +44,120 @@ impl ADParser {
     /// * `source_code` - The PlantUML source code to parse
     ///
     /// # Returns
-    /// * `Result<ParseContext>` - return parse context
-    pub fn parse(&mut self, source_code: &str) -> Result<Vec<ASTKind>> {
+    /// * `Ok(Vec<ASTKind>)` - the AST if the source parsed without errors
+    /// * `Err(Vec<Diagnostic>)` - every syntax error found otherwise
+    pub fn parse(&mut self, source_code: &str) -> std::result::Result<Vec<ASTKind>, Vec<Diagnostic>> {
         // Parse the source code into a Tree-sitter tree
         let source_pre_processed = Self::pre_process(source_code);
         let opt_tree = self.parser
-            .parse(source_pre_processed, None);
+            .parse(&source_pre_processed, None);
 
-        let tree = opt_tree.expect("Failed to parse source code");
+        let Some(tree) = opt_tree else {
+            let d = Diagnostic::error(PARSER_INIT, "failed to parse source code".to_string());
+            return Err(vec![d]);
+        };
 
         let root_node = tree.root_node();
-        let mut context = ParseContext::new(source_code.to_string());
+        // node byte ranges refer to the pre-processed text, spans to the source
+        let mut context = ParseContext::new(source_pre_processed, SYNTAX_ERROR, MISSING_TOKEN)
+            .with_source(source_code.to_string());
+
+        if root_node.has_error() {
+            Self::report_syntax_errors(root_node, &mut context);
+        }
 
         // Build our custom AST from the Tree-sitter tree
-        let ast_list = self.traverse_node(root_node, &mut context)?;
-        Ok(ast_list)
+        let result = self.traverse_node(root_node, &mut context);
+        let has_syntax_error = has_errors(context.diagnostics());
+        match result {
+            Ok(ast_list) if !has_syntax_error => Ok(ast_list),
+            Ok(_) => Err(context.into_diagnostics()),
+            Err(e) => {
+                // a malformed node is expected once a syntax error was reported
+                if !has_syntax_error {
+                    let d = match e.downcast::<Diagnostic>() {
+                        Ok(d) => d,
+                        Err(e) => Diagnostic::error(MALFORMED_NODE, e.to_string()),
+                    };
+                    context.report(d);
+                }
+                Err(context.into_diagnostics())
+            }
+        }
+    }
+
+    /// Reports every `ERROR` and missing node below `node`
+    fn report_syntax_errors(node: Node, context: &mut ParseContext) {
+        if node.is_error() || node.is_missing() {
+            if let Err(e) = context.report_syntax_error(node) {
+                let d = Diagnostic::error(SYNTAX_ERROR, e.to_string())
+                    .with_span(context.span_of(&node));
+                context.report(d);
+            }
+            return;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.has_error() {
+                Self::report_syntax_errors(child, context);
+            }
+        }
     }
 
+    /// Rewrites the PlantUML forms the grammar does not know; a rewrite
+    /// keeps the lines and the columns of the text before its end, so
+    /// spans still point into the source
     fn pre_process(text: &str) -> String {
-        Self::preprocess_repeat_while(text)
+        let text = Self::preprocess_repeat_while(text);
+        let text = Self::preprocess_fork_again(&text);
+        let text = Self::preprocess_note_line(&text);
+        Self::preprocess_group_end(&text)
     }
 
     /// Normalize `repeat while (...)` into `repeatwhile (...)`
     pub fn preprocess_repeat_while(input: &str) -> String {
-        //  repeat while -> repeatwhile
-        let re = Regex::new(r"(?m)^(\s*)repeat\s+while\b").unwrap();
+        //  repeat while -> repeatwhile, the blanks move behind it
+        let re = Regex::new(r"(?m)^([ \t]*)repeat([ \t]+)while\b").unwrap();
 
         re.replace_all(input, |caps: &regex::Captures| {
-            format!("{}repeatwhile", &caps[1])
+            format!("{}repeatwhile{}", &caps[1], &caps[2])
+        })
+            .to_string()
+    }
+
+    /// Normalize `fork again` and `split again` into `forkagain` and `splitagain`
+    pub fn preprocess_fork_again(input: &str) -> String {
+        let re = Regex::new(r"(?m)^([ \t]*)(fork|split)([ \t]+)again\b").unwrap();
+
+        re.replace_all(input, |caps: &regex::Captures| {
+            format!("{}{}again{}", &caps[1], &caps[2], &caps[3])
+        })
+            .to_string()
+    }
+
+    /// Normalize the one-line `note right: text` into `note right  text end note`,
+    /// the colon would stick to the position otherwise
+    pub fn preprocess_note_line(input: &str) -> String {
+        let re = Regex::new(r"(?m)^([ \t]*(?:floating[ \t]+)?note(?:[ \t]+(?:left|right|top|bottom))?[ \t]*):(.*)$").unwrap();
+
+        re.replace_all(input, |caps: &regex::Captures| {
+            format!("{} {} end note", &caps[1], caps[2].trim_end())
+        })
+            .to_string()
+    }
+
+    /// Normalize `partition X` ... `end partition` into `partition X {` ... `}`,
+    /// a group name without braces would run into the next line; the
+    /// same for `group`, `package`, `rectangle` and `card`
+    pub fn preprocess_group_end(input: &str) -> String {
+        let open = Regex::new(r"(?m)^([ \t]*(?:group|partition|package|rectangle|card)\b[^{\n]*?)[ \t]*$").unwrap();
+        let close = Regex::new(r"(?m)^([ \t]*)end[ \t]+(?:group|partition|package|rectangle|card)\b[ \t]*$").unwrap();
+
+        let text = open.replace_all(input, |caps: &regex::Captures| {
+            format!("{} {{", &caps[1])
+        });
+        close.replace_all(&text, |caps: &regex::Captures| {
+            format!("{}}}", &caps[1])
         })
             .to_string()
     }
@@ -70,11 +169,6 @@ impl ADParser {
     fn traverse_node(&self, node: Node, context: &mut ParseContext) -> Result<Vec<ASTKind>> {
         let node_type = node.kind();
         let mut vec_ast = vec![];
-        if node.has_error() {
-            let mut str = String::new();
-            context.print_error_line(node, &mut str)?;
-            println!("{}", str);
-        }
         match node_type {
             ts_const::ts_kind_name::S_IF_STATEMENT => {
                 let ast = self.visit_if_statement(node, context)?;
@@ -84,10 +178,49 @@ impl ADParser {
                 let ast = self.visit_while_statement(node, context)?;
                 vec_ast.push(ast)
             }
+            ts_const::ts_kind_name::S_REPEAT_STATEMENT => {
+                let ast = self.visit_repeat_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_SWITCH_STATEMENT => {
+                let ast = self.visit_switch_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_FORK_STATEMENT => {
+                let ast = self.visit_fork_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_GROUP_STATEMENT => {
+                let ast = self.visit_group_statement(node, context)?;
+                vec_ast.push(ast)
+            }
             ts_const::ts_kind_name::S_ACTION_STATEMENT => {
                 let ast = self.visit_action_statement(node, context)?;
                 vec_ast.push(ast)
             }
+            ts_const::ts_kind_name::S_ARROW => {
+                if let Some(label) = self.visit_label(node, ts_const::ts_field_name::LABEL, context)? {
+                    vec_ast.push(ASTKind::Arrow(label))
+                }
+            }
+            ts_const::ts_kind_name::S_NOTE_STATEMENT => {
+                let ast = self.visit_note_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_GOTO_STATEMENT => {
+                let ast = self.visit_goto_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_ACTIVITY_STATEMENT => {
+                let ast = self.visit_activity_statement(node, context)?;
+                vec_ast.push(ast)
+            }
+            ts_const::ts_kind_name::S_BREAK_STATEMENT => {
+                vec_ast.push(ASTKind::Break(context.span_of(&node)))
+            }
+            ts_const::ts_kind_name::S_STOP => {
+                vec_ast.push(ASTKind::Stop)
+            }
             _ => {
                 // Recursively process child nodes
                 let mut cursor = node.walk();
@@ -101,10 +234,16 @@ impl ADParser {
         Ok(vec_ast)
     }
 
-    fn get_named_field<'a>(&self, node: &Node<'a>, field_name: &str) -> Result<Node<'a>> {
+    fn get_named_field<'a>(&self, node: &Node<'a>, field_name: &str, context: &ParseContext) -> Result<Node<'a>> {
         let child = node.child_by_field_name(field_name)
             .map_or_else(
-                || { Err(Error::msg(format!("expected get field {}", field_name))) },
+                || {
+                    let d = Diagnostic::error(
+                        MALFORMED_NODE,
+                        format!("expected field {} in {}", field_name, node.kind()),
+                    ).with_span(context.span_of(node));
+                    Err(Error::new(d))
+                },
                 |c| { Ok(c) })?;
         Ok(child)
     }
@@ -113,17 +252,17 @@ impl ADParser {
         let mut cursor = node.walk();
         let mut block = IfElseBlock {
             if_elif: vec![],
+            else_label: None,
             else_: vec![],
         };
         for child in node.children(&mut cursor) {
             let child_kind = child.kind();
-            if child_kind == ts_const::ts_kind_name::S_IF_CONDITION {
-                let cond_body = self.visit_if_condition(child, context)?;
-                block.if_elif.push(cond_body);
-            } else if child_kind == ts_const::ts_kind_name::S_ELSEIF_CONDITION {
+            if child_kind == ts_const::ts_kind_name::S_IF_CONDITION
+                || child_kind == ts_const::ts_kind_name::S_ELSEIF_CONDITION {
                 let cond_body = self.visit_if_condition(child, context)?;
                 block.if_elif.push(cond_body);
             } else if child_kind == ts_const::ts_kind_name::S_ELSE_CONDITION {
+                block.else_label = self.visit_label(child, ts_const::ts_field_name::LABEL, context)?;
                 let else_ = self.visit_else_condition(child, context)?;
                 block.else_.extend(else_);
             }
@@ -132,22 +271,35 @@ impl ADParser {
     }
 
     fn visit_if_condition(&self, node: Node, context: &mut ParseContext) -> Result<CondBody> {
-        let expression = self.get_named_field(&node, ts_const::ts_field_name::EXPRESSION)?;
+        let expression = self.get_named_field(&node, ts_const::ts_field_name::EXPRESSION, context)?;
         let cond = self.visit_expression(expression, context)?;
-        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST)?;
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
         let body = self.visit_block_statement_list(node_body, context)?;
+        let label = self.visit_label(node, ts_const::ts_field_name::LABEL, context)?;
         Ok(CondBody {
             cond,
+            label,
             body,
         })
     }
 
     fn visit_else_condition(&self, node: Node, context: &mut ParseContext) -> Result<Vec<ASTKind>> {
-        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST)?;
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
         let body = self.visit_block_statement_list(node_body, context)?;
         Ok(body)
     }
 
+    /// The text of an optional label field, unquoted and on one line; an
+    /// empty label counts as none
+    fn visit_label(&self, node: Node, field_name: &str, context: &mut ParseContext) -> Result<Option<String>> {
+        let Some(node_label) = node.child_by_field_name(field_name) else {
+            return Ok(None);
+        };
+        let text = context.text_of_node(&node_label)?;
+        let label = text.trim().trim_matches('"').split_whitespace().collect::<Vec<_>>().join(" ");
+        Ok((!label.is_empty()).then_some(label))
+    }
+
     fn visit_expression(&self, node: Node, context: &mut ParseContext) -> Result<String> {
         let content = context.text_of_node(&node)?;
         Ok(content)
@@ -164,39 +316,216 @@ impl ADParser {
     }
 
     fn visit_while_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
-        let node_expr = self.get_named_field(&node, ts_const::ts_field_name::EXPRESSION)?;
+        let node_expr = self.get_named_field(&node, ts_const::ts_field_name::EXPRESSION, context)?;
         let cond = self.visit_expression(node_expr, context)?;
-        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST)?;
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
         let body = self.visit_block_statement_list(node_body, context)?;
-        Ok(ASTKind::While(CondBody {
+        let label = self.visit_label(node, ts_const::ts_field_name::LABEL, context)?;
+        let exit_label = self.visit_label(node, ts_const::ts_field_name::EXIT_LABEL, context)?;
+        Ok(ASTKind::While(WhileBlock {
+            cond: CondBody {
+                cond,
+                label,
+                body,
+            },
+            exit_label,
+        }))
+    }
+
+    fn visit_repeat_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
+        let body = self.visit_block_statement_list(node_body, context)?;
+        let mut cursor = node.walk();
+        let node_end = node.children(&mut cursor)
+            .find(|c| c.kind() == ts_const::ts_kind_name::S_REPEAT_STATEMENT_END)
+            .ok_or_else(|| Error::msg("expected repeat while condition"))?;
+        let node_expr = self.get_named_field(&node_end, ts_const::ts_field_name::EXPRESSION, context)?;
+        let cond = self.visit_expression(node_expr, context)?;
+        Ok(ASTKind::RepeatWhile(CondBody {
             cond,
+            label: None,
             body,
         }))
     }
 
+    fn visit_switch_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let mut cursor = node.walk();
+        let mut cases = vec![];
+        for child in node.children(&mut cursor) {
+            if child.kind() == ts_const::ts_kind_name::S_CASE_CLAUSE {
+                let cond_body = self.visit_case_clause(child, context)?;
+                cases.push(cond_body);
+            }
+        }
+        Ok(ASTKind::Case(cases))
+    }
+
+    fn visit_case_clause(&self, node: Node, context: &mut ParseContext) -> Result<CondBody> {
+        let node_expr = self.get_named_field(&node, ts_const::ts_field_name::EXPRESSION, context)?;
+        let cond = self.visit_expression(node_expr, context)?;
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
+        let body = self.visit_block_statement_list(node_body, context)?;
+        Ok(CondBody {
+            cond,
+            label: None,
+            body,
+        })
+    }
+
+    /// The first branch is the statement's own body, every `fork again`
+    /// adds one; the end directive tells how the branches join
+    fn visit_fork_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
+        let mut branches = vec![self.visit_block_statement_list(node_body, context)?];
+        let mut join = JoinKind::All;
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                ts_const::ts_kind_name::S_FORK_AGAIN => {
+                    let node_body = self.get_named_field(&child, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
+                    branches.push(self.visit_block_statement_list(node_body, context)?);
+                }
+                ts_const::ts_kind_name::S_END_FORK_DIRECTIVE => {
+                    if let Some(node_join) = child.child_by_field_name(ts_const::ts_field_name::JOIN)
+                        && matches!(node_join.kind(), "or" | "merge") {
+                        join = JoinKind::Any;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(ASTKind::Fork(ForkBlock {
+            branches,
+            join,
+        }))
+    }
+
+    /// A quoted name is unquoted, an unnamed group is named by its type
+    fn visit_group_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let name = match node.child_by_field_name(ts_const::ts_field_name::NAME) {
+            Some(node_name) => context.text_of_node(&node_name)?,
+            None => {
+                let node_type = self.get_named_field(&node, ts_const::ts_field_name::TYPE, context)?;
+                context.text_of_node(&node_type)?
+            }
+        };
+        let node_body = self.get_named_field(&node, ts_const::ts_kind_name::S_BLOCK_STATEMENT_LIST, context)?;
+        let body = self.visit_block_statement_list(node_body, context)?;
+        Ok(ASTKind::Group(GroupBlock {
+            name: name.trim().trim_matches('"').to_string(),
+            body,
+        }))
+    }
+
+    /// `label <identifier>` and `goto <identifier>` share one grammar rule,
+    /// the leading keyword tells them apart
+    fn visit_goto_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let keyword = node.child(0)
+            .ok_or_else(|| Error::msg("expected label or goto keyword"))?;
+        let mut cursor = node.walk();
+        let identifier = node.children(&mut cursor)
+            .find(|c| c.kind() == ts_const::ts_kind_name::S_IDENTIFIER)
+            .ok_or_else(|| Error::msg("expected label name"))?;
+        let name = context.text_of_node(&identifier)?;
+        match keyword.kind() {
+            "label" => Ok(ASTKind::Label(name)),
+            "goto" => Ok(ASTKind::Goto(name, context.span_of(&node))),
+            kind => Err(Error::msg(format!("unexpected keyword {} in goto statement", kind))),
+        }
+    }
+
+    /// Every line of the note trimmed, its position does not matter
+    fn visit_note_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let content = self.get_named_field(&node, ts_const::ts_field_name::CONTENT, context)?;
+        let text = context.text_of_node(&content)?;
+        let lines: Vec<&str> = text.lines().map(str::trim).collect();
+        Ok(ASTKind::Note(lines.join("\n").trim().to_string()))
+    }
+
+    fn visit_activity_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
+        let identifier = self.get_named_field(&node, ts_const::ts_field_name::ACTIVITY_IDENTIFIER, context)?;
+        let name = context.text_of_node(&identifier)?;
+        Ok(ASTKind::ActivityRef(name))
+    }
+
     fn visit_action_statement(&self, node: Node, context: &mut ParseContext) -> Result<ASTKind> {
-        let simple = self.get_named_field(&node, ts_const::ts_field_name::ACTION)?;
+        let simple = self.get_named_field(&node, ts_const::ts_field_name::ACTION, context)?;
         let content = context.text_of_node(&simple)?;
-        Ok(ASTKind::SimpleStmt(content))
+        let kind = match node.child_by_field_name(ts_const::ts_field_name::SHAPE) {
+            Some(shape) => {
+                let stereotype = self.get_named_field(&shape, ts_const::ts_field_name::STEREOTYPE, context)?;
+                state_kind(&context.text_of_node(&stereotype)?)
+            }
+            None => None,
+        };
+        Ok(ASTKind::SimpleStmt(content, kind))
+    }
+}
+
+/// The kind of state an SDL stereotype stands for; the other stereotypes
+/// only change how the action is drawn
+fn state_kind(stereotype: &str) -> Option<StateKind> {
+    match stereotype {
+        "acceptEvent" | "accept-event" | "input" => Some(StateKind::AcceptEvent),
+        "sendSignal" | "send-signal" | "output" => Some(StateKind::SendSignal),
+        "timeEvent" | "time-event" => Some(StateKind::TimeEvent),
+        "load" => Some(StateKind::Load),
+        "save" => Some(StateKind::Save),
+        _ => None,
     }
 }
 
 
-pub fn parse_with_tree_sitter(source: &str) -> Result<Vec<ASTKind>> {
-    let mut parser = ADParser::new();
-    let context = parser.parse(source)?;
-    Ok(context)
+pub fn parse_with_tree_sitter(source: &str) -> std::result::Result<Vec<ASTKind>, Vec<Diagnostic>> {
+    let mut parser = ADParser::new().map_err(|d| vec![d])?;
+    parser.parse(source)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::builder::parser::ADParser;
-    use anyhow::Result;
+    use crate::builder::parser::{ADParser, MISSING_TOKEN, SYNTAX_ERROR};
     #[test]
-    fn test_parser() -> Result<()> {
+    fn test_parser() {
         let text = include_str!("test_data/activity.puml");
-        let mut parser = ADParser::new();
-        let _context = parser.parse(text)?;
-        Ok(())
+        let mut parser = ADParser::new().unwrap();
+        let _context = parser.parse(text).unwrap();
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let text = "@startuml\nstart\n:A;\nendwhile\n:B;\n@enduml\n";
+        let mut parser = ADParser::new().unwrap();
+        let diagnostics = parser.parse(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        let d = &diagnostics[0];
+        assert_eq!(d.code(), SYNTAX_ERROR);
+        assert_eq!(d.message(), "unexpected `endwhile`");
+        let span = d.span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (4, 1));
+        // the notes are for the reader, not a dump of the syntax tree
+        assert!(d.notes().iter().all(|note| !note.starts_with("s-expr")));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_span_after_rewrite() {
+        // `partition` and `repeat while` are rewritten before parsing, the
+        // span still points into the source
+        let text = "@startuml\nstart\npartition P\nrepeat\n  :A;\nrepeat   while (done?) ]\nend partition\n@enduml\n";
+        let mut parser = ADParser::new().unwrap();
+        let diagnostics = parser.parse(text).unwrap_err();
+        let d = &diagnostics[0];
+        assert_eq!(d.code(), SYNTAX_ERROR);
+        assert_eq!(d.message(), "unexpected `]`");
+        let span = d.span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (6, 24));
+        assert_eq!(span.start_byte, text.find(']').unwrap());
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let text = "@startuml\nstart\n:A;\nif (x) then\n:B;\n@enduml\n";
+        let mut parser = ADParser::new().unwrap();
+        let diagnostics = parser.parse(text).unwrap_err();
+        assert!(diagnostics.iter().any(|d| d.code() == MISSING_TOKEN && d.span().is_some()));
+    }
+}
@@ -0,0 +1,181 @@
+use crate::builder::builder::build_fsm_from_plantuml;
+use common::diag::diagnostic::Diagnostic;
+use common::fsm::fs_machine::FSMachine;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Diagnostic code: no diagram file exists for a referenced activity
+pub const UNRESOLVED_ACTIVITY: &str = "AD0201";
+/// Diagnostic code: diagrams reference each other in a cycle
+pub const ACTIVITY_CYCLE: &str = "AD0202";
+
+/// Resolves `activity X` references to the diagram `X.puml` of a project
+/// directory and builds hierarchical state machines from them
+pub struct ActivityResolver {
+    project_dir: PathBuf,
+    /// machines already built, by activity name
+    cache: HashMap<String, FSMachine>,
+}
+
+impl ActivityResolver {
+    pub fn new(project_dir: impl Into<PathBuf>) -> ActivityResolver {
+        Self {
+            project_dir: project_dir.into(),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The file the diagram of `activity` is read from
+    pub fn path_of(&self, activity: &str) -> PathBuf {
+        self.project_dir.join(format!("{}.puml", activity))
+    }
+
+    /// Builds the machine of the diagram `text`, known as activity `name`,
+    /// and attaches the machine of every activity it references, recursively
+    pub fn build(&mut self, name: &str, text: &str) -> Result<FSMachine, Vec<Diagnostic>> {
+        let mut stack = vec![name.to_string()];
+        self.build_nested(text, &mut stack)
+    }
+
+    /// Builds the machine of the activity `name` from its diagram file
+    pub fn build_activity(&mut self, name: &str) -> Result<FSMachine, Vec<Diagnostic>> {
+        self.resolve(name, &mut vec![])
+    }
+
+    /// `stack` holds the activities being built, outermost first
+    fn build_nested(&mut self, text: &str, stack: &mut Vec<String>) -> Result<FSMachine, Vec<Diagnostic>> {
+        let mut fsm = build_fsm_from_plantuml(text)?;
+        let mut diagnostics = vec![];
+        self.resolve_composites(&mut fsm, stack, &mut diagnostics);
+        if !diagnostics.is_empty() {
+            return Err(diagnostics);
+        }
+        Ok(fsm)
+    }
+
+    /// Attaches the machines of the composite states of `fsm`, and of the
+    /// composite states in the regions of its orthogonal states
+    fn resolve_composites(
+        &mut self,
+        fsm: &mut FSMachine,
+        stack: &mut Vec<String>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let ids: Vec<_> = fsm.composites().keys().copied().collect();
+        for id in ids {
+            let Some(composite) = fsm.composite_mut(id) else {
+                continue;
+            };
+            let activity = composite.activity().clone();
+            match self.resolve(&activity, stack) {
+                Ok(machine) => composite.set_machine(machine),
+                Err(d) => diagnostics.extend(d),
+            }
+        }
+        let ids: Vec<_> = fsm.orthogonals().keys().copied().collect();
+        for id in ids {
+            let Some(orthogonal) = fsm.orthogonal_mut(id) else {
+                continue;
+            };
+            for region in orthogonal.regions_mut() {
+                self.resolve_composites(region, stack, diagnostics);
+            }
+        }
+    }
+
+    fn resolve(&mut self, name: &str, stack: &mut Vec<String>) -> Result<FSMachine, Vec<Diagnostic>> {
+        if let Some(pos) = stack.iter().position(|n| n == name) {
+            let mut cycle = stack[pos..].to_vec();
+            cycle.push(name.to_string());
+            let d = Diagnostic::error(
+                ACTIVITY_CYCLE,
+                format!("activity `{}` contains itself", name),
+            )
+            .with_note(format!("cycle: {}", cycle.join(" -> ")));
+            return Err(vec![d]);
+        }
+        if let Some(machine) = self.cache.get(name) {
+            return Ok(machine.clone());
+        }
+
+        let path = self.path_of(name);
+        let text = fs::read_to_string(&path).map_err(|e| {
+            let d = Diagnostic::error(
+                UNRESOLVED_ACTIVITY,
+                format!("cannot load activity `{}`: {}", name, e),
+            )
+            .with_note(format!("looked for {}", path.display()));
+            vec![d]
+        })?;
+
+        stack.push(name.to_string());
+        let result = self.build_nested(&text, stack);
+        stack.pop();
+        let machine = result.map_err(|diagnostics| {
+            // problems of nested diagrams point into another file
+            diagnostics
+                .into_iter()
+                .map(|d| d.with_note(format!("in activity `{}` ({})", name, path.display())))
+                .collect::<Vec<_>>()
+        })?;
+        self.cache.insert(name.to_string(), machine.clone());
+        Ok(machine)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::fsm::fs_machine::fsm_to_dot;
+
+    fn project() -> ActivityResolver {
+        let dir = format!("{}/src/builder/test_data/project", env!("CARGO_MANIFEST_DIR"));
+        ActivityResolver::new(dir)
+    }
+
+    #[test]
+    fn test_nested_machines() {
+        let fsm = project().build_activity("order").unwrap();
+        assert_eq!(fsm.composites().len(), 1);
+        let (id, payment) = fsm.composites().iter().next().unwrap();
+        assert_eq!(fsm.state_map()[id], "payment");
+        assert_eq!(payment.activity(), "payment");
+
+        // payment itself nests the card check
+        let payment = payment.machine().unwrap();
+        let card = payment.composites().values().next().unwrap();
+        assert_eq!(card.activity(), "card_check");
+        assert!(card.machine().is_some());
+
+        let dot = fsm_to_dot(&fsm);
+        assert!(dot.contains("subgraph cluster_S2 {"));
+        assert!(dot.contains("subgraph cluster_S2_S2 {"));
+        assert!(dot.contains("S2 -> S2_S0 [style=dashed];"));
+    }
+
+    #[test]
+    fn test_activity_in_fork() {
+        let fsm = project().build_activity("fulfilment").unwrap();
+        let fork = fsm.orthogonals().values().next().unwrap();
+        let payment = fork.regions()[0].composites().values().next().unwrap();
+        assert_eq!(payment.activity(), "payment");
+        assert!(payment.machine().is_some());
+    }
+
+    #[test]
+    fn test_unresolved_activity() {
+        let text = "@startuml\nstart\nactivity missing\nstop\n@enduml\n";
+        let diagnostics = project().build("main", text).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), UNRESOLVED_ACTIVITY);
+    }
+
+    #[test]
+    fn test_activity_cycle() {
+        let diagnostics = project().build_activity("ping").unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), ACTIVITY_CYCLE);
+        assert_eq!(diagnostics[0].notes()[0], "cycle: ping -> pong -> ping");
+    }
+}
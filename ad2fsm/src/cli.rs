@@ -0,0 +1,385 @@
+use common::csharp::csharp_gen::generate_csharp_fsm;
+use common::rust::rust_gen::generate_rust_fsm;
+use common::tla::tla_model::generate_tla_fsm;
+use crate::builder::lower::build_cfg;
+use crate::builder::parser::parse_with_tree_sitter;
+use crate::builder::resolver::ActivityResolver;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::cfg::cf_graph::cfg_to_dot;
+use common::cli::exit_code::{EXIT_IO, EXIT_OK};
+use common::cli::report::report;
+use common::cli::source::Source;
+use common::codegen::naming::camel_name;
+use common::diag::diagnostic::Diagnostic;
+use common::fsm::context_schema::parse_context_schema;
+use common::fsm::analysis::analyze_fsm;
+use common::fsm::fs_machine::{fsm_to_dot, FSMachine};
+use common::fsm::fsm_json::fsm_to_json;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Converts PlantUML activity diagrams into finite state machines
+///
+/// Exit codes: 0 success, 1 the diagram has errors, 2 invalid command
+/// line, 3 input/output failure.
+#[derive(Parser, Debug)]
+#[command(name = "ad2fsm", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Parse the diagram and dump its AST
+    Parse(InputArgs),
+    /// Lower the diagram and dump its control flow graph as DOT
+    Cfg(InputArgs),
+    /// Build the state machine and emit it
+    Fsm {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = FsmFormat::Dot)]
+        format: FsmFormat,
+    },
+    /// Generate code for the state machine
+    Gen {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Target language
+        #[arg(long, value_enum)]
+        target: Target,
+        /// Directory the generated files are written to
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Report diagnostics only
+    Check(InputArgs),
+    /// Report unreachable states, deadlocks, states that cannot terminate
+    /// and decisions without a fallback, as warnings
+    Analyze(InputArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// PlantUML file to read, stdin if omitted or `-`
+    pub input: Option<PathBuf>,
+    /// Directory `activity X` references are resolved in as `X.puml`,
+    /// defaults to the directory of the input
+    #[arg(long)]
+    pub project: Option<PathBuf>,
+    /// Context schema of the machine, one `name: type` per line, defaults
+    /// to the `.context` file next to the input if there is one
+    #[arg(long)]
+    pub context: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FsmFormat {
+    Dot,
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    Csharp,
+    Rust,
+    /// A TLA+ module and its TLC configuration
+    Tla,
+}
+
+/// Runs a parsed command line, returns the process exit code
+pub fn run<O: Write, E: Write>(cli: Cli, out: &mut O, err: &mut E) -> u8 {
+    let result = match &cli.command {
+        Command::Parse(input) => cmd_parse(input, out, err),
+        Command::Cfg(input) => cmd_cfg(input, out, err),
+        Command::Fsm { input, format } => cmd_fsm(input, *format, out, err),
+        Command::Gen { input, target, out: out_dir } => cmd_gen(input, *target, out_dir, err),
+        Command::Check(input) => cmd_check(input, err),
+        Command::Analyze(input) => cmd_analyze(input, err),
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            let _ = writeln!(err, "error: {}", e);
+            EXIT_IO
+        }
+    }
+}
+
+/// Builds the machine of the input with the machines of every activity
+/// it references
+fn build_fsm(input: &InputArgs, source: &Source) -> Result<FSMachine, Vec<Diagnostic>> {
+    let file = input.input.as_ref().filter(|path| path.as_os_str() != "-");
+    let project = match (&input.project, file) {
+        (Some(dir), _) => dir.clone(),
+        (None, Some(path)) => path.parent().map(Path::to_path_buf).unwrap_or_default(),
+        (None, None) => PathBuf::from("."),
+    };
+    let name = file
+        .and_then(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| source.origin.clone());
+    ActivityResolver::new(project).build(&name, &source.text)
+}
+
+/// Reads the context schema given with `--context` or found next to the
+/// input
+fn read_context(input: &InputArgs) -> io::Result<Option<Source>> {
+    Source::read_sidecar(input.context.as_deref(), input.input.as_deref(), "context")
+}
+
+/// Builds the machine of the input and attaches its context schema,
+/// diagnostics are reported and turned into the exit code
+fn build_fsm_with_context<E: Write>(
+    input: &InputArgs,
+    source: &Source,
+    err: &mut E,
+) -> io::Result<Result<FSMachine, u8>> {
+    let mut fsm = match build_fsm(input, source) {
+        Ok(fsm) => fsm,
+        Err(diagnostics) => return report(err, &source.origin, &diagnostics).map(Err),
+    };
+    if let Some(schema) = read_context(input)? {
+        match parse_context_schema(&schema.text) {
+            Ok(fields) => fsm.set_context(fields),
+            Err(diagnostics) => return report(err, &schema.origin, &diagnostics).map(Err),
+        }
+    }
+    Ok(Ok(fsm))
+}
+
+fn cmd_parse<O: Write, E: Write>(input: &InputArgs, out: &mut O, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    match parse_with_tree_sitter(&source.text) {
+        Ok(ast) => {
+            writeln!(out, "{:#?}", ast)?;
+            Ok(EXIT_OK)
+        }
+        Err(diagnostics) => report(err, &source.origin, &diagnostics),
+    }
+}
+
+fn cmd_cfg<O: Write, E: Write>(input: &InputArgs, out: &mut O, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let cfg = parse_with_tree_sitter(&source.text)
+        .and_then(|ast| build_cfg(&ast));
+    match cfg {
+        Ok(cfg) => {
+            write!(out, "{}", cfg_to_dot(&cfg))?;
+            Ok(EXIT_OK)
+        }
+        Err(diagnostics) => report(err, &source.origin, &diagnostics),
+    }
+}
+
+fn cmd_fsm<O: Write, E: Write>(
+    input: &InputArgs,
+    format: FsmFormat,
+    out: &mut O,
+    err: &mut E,
+) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    match build_fsm_with_context(input, &source, err)? {
+        Ok(fsm) => {
+            match format {
+                FsmFormat::Dot => write!(out, "{}", fsm_to_dot(&fsm))?,
+                FsmFormat::Json => writeln!(out, "{}", fsm_to_json(&fsm))?,
+            }
+            Ok(EXIT_OK)
+        }
+        Err(code) => Ok(code),
+    }
+}
+
+fn cmd_gen<E: Write>(
+    input: &InputArgs,
+    target: Target,
+    out_dir: &Path,
+    err: &mut E,
+) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let fsm = match build_fsm_with_context(input, &source, err)? {
+        Ok(fsm) => fsm,
+        Err(code) => return Ok(code),
+    };
+    fs::create_dir_all(out_dir)?;
+    let result = match target {
+        Target::Csharp => generate_csharp_fsm(&fsm, out_dir),
+        Target::Rust => generate_rust_fsm(&fsm, out_dir),
+        Target::Tla => generate_tla_fsm(&fsm, &module_name(input), out_dir),
+    };
+    match result {
+        // orphaned user code is reported against the output directory
+        Ok(diagnostics) => report(err, &out_dir.display().to_string(), &diagnostics),
+        Err(e) => {
+            writeln!(err, "error: code generation failed: {}", e)?;
+            Ok(EXIT_IO)
+        }
+    }
+}
+
+/// Name of the TLA+ module generated for `input`, after the input file
+fn module_name(input: &InputArgs) -> String {
+    match input.input.as_ref().and_then(|p| p.file_stem()) {
+        Some(stem) if stem != "-" => camel_name(&stem.to_string_lossy()),
+        _ => "Machine".to_string(),
+    }
+}
+
+fn cmd_check<E: Write>(input: &InputArgs, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    match build_fsm_with_context(input, &source, err)? {
+        Ok(_) => Ok(EXIT_OK),
+        Err(code) => Ok(code),
+    }
+}
+
+fn cmd_analyze<E: Write>(input: &InputArgs, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let fsm = match build_fsm_with_context(input, &source, err)? {
+        Ok(fsm) => fsm,
+        Err(code) => return Ok(code),
+    };
+    let diagnostics: Vec<_> = analyze_fsm(&fsm).iter().map(|f| f.to_diagnostic()).collect();
+    report(err, &source.origin, &diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::cli::exit_code::EXIT_DIAGNOSTICS;
+
+    fn test_data(name: &str) -> String {
+        format!("{}/src/builder/test_data/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn run_args(args: &[&str]) -> (u8, String, String) {
+        let cli = Cli::try_parse_from(args).unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run(cli, &mut out, &mut err);
+        (code, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    #[test]
+    fn test_commands() {
+        let input = test_data("if-else.puml");
+        let (code, out, _) = run_args(&["ad2fsm", "parse", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.contains("IfElse"));
+
+        let (code, out, _) = run_args(&["ad2fsm", "cfg", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.starts_with("digraph CFG"));
+
+        let (code, out, _) = run_args(&["ad2fsm", "fsm", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.starts_with("digraph FSM"));
+
+        let (code, out, _) = run_args(&["ad2fsm", "fsm", "--format", "json", &input]);
+        assert_eq!(code, EXIT_OK);
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["states"][0]["name"], "START");
+
+        let (code, out, err) = run_args(&["ad2fsm", "check", &input]);
+        assert_eq!((code, out.as_str(), err.as_str()), (EXIT_OK, "", ""));
+
+        let out_dir = std::env::temp_dir().join("ad2fsm_cli_gen");
+        let (code, _, _) = run_args(&[
+            "ad2fsm", "gen", "--target", "csharp", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out_dir.join("State.cs").exists());
+
+        let (code, _, _) = run_args(&[
+            "ad2fsm", "gen", "--target", "rust", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out_dir.join("dispatcher.rs").exists());
+
+        let (code, _, _) = run_args(&[
+            "ad2fsm", "gen", "--target", "tla", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!(code, EXIT_OK);
+        let module = fs::read_to_string(out_dir.join("IfElse.tla")).unwrap();
+        assert!(module.starts_with("---------------------------- MODULE IfElse "));
+        assert!(out_dir.join("IfElse.cfg").exists());
+    }
+
+    #[test]
+    fn test_nested_activities() {
+        let input = test_data("project/order.puml");
+        let (code, out, _) = run_args(&["ad2fsm", "fsm", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.contains("subgraph cluster_S2 {"));
+
+        let input = test_data("project/ping.puml");
+        let (code, _, err) = run_args(&["ad2fsm", "check", &input]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.contains("error[AD0202]"));
+
+        let project = std::env::temp_dir();
+        let input = test_data("project/order.puml");
+        let (code, _, err) = run_args(&["ad2fsm", "check", "--project", project.to_str().unwrap(), &input]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.contains("error[AD0201]"));
+    }
+
+    #[test]
+    fn test_context_schema() {
+        let dir = std::env::temp_dir().join("ad2fsm_cli_context");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("order.puml");
+        fs::copy(test_data("if-else.puml"), &input).unwrap();
+        fs::write(dir.join("order.context"), "# order\nqty: int\n").unwrap();
+
+        // the schema next to the input is picked up
+        let out_dir = dir.join("out");
+        let (code, _, _) = run_args(&[
+            "ad2fsm", "gen", "--target", "csharp", "--out", out_dir.to_str().unwrap(),
+            input.to_str().unwrap(),
+        ]);
+        assert_eq!(code, EXIT_OK);
+        let context = fs::read_to_string(out_dir.join("Context.cs")).unwrap();
+        assert!(context.contains("public long qty { get; set; }"));
+
+        let schema = dir.join("bad.context");
+        fs::write(&schema, "qty int\n").unwrap();
+        let (code, _, err) = run_args(&[
+            "ad2fsm", "check", "--context", schema.to_str().unwrap(), input.to_str().unwrap(),
+        ]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.contains("error[FSM0201]"));
+        assert!(err.contains("bad.context:1:1"));
+    }
+
+    #[test]
+    fn test_analyze() {
+        let (code, _, err) = run_args(&["ad2fsm", "analyze", &test_data("if-else.puml")]);
+        assert_eq!((code, err.as_str()), (EXIT_OK, ""));
+
+        let (code, _, err) = run_args(&["ad2fsm", "analyze", &test_data("stop.puml")]);
+        assert_eq!(code, EXIT_OK);
+        assert!(err.contains("warning[FSM0301]: state `B` (id 3) is unreachable"));
+
+        let (_, _, err) = run_args(&["ad2fsm", "analyze", &test_data("goto-loop.puml")]);
+        assert!(err.contains("warning[FSM0303]"));
+    }
+
+    #[test]
+    fn test_check_reports_errors() {
+        let path = std::env::temp_dir().join("ad2fsm_cli_bad.puml");
+        fs::write(&path, "@startuml\nstart\n:A;\nendwhile\n:B;\n@enduml\n").unwrap();
+        let (code, _, err) = run_args(&["ad2fsm", "check", path.to_str().unwrap()]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.contains("error[AD0001]"));
+        assert!(err.contains("ad2fsm_cli_bad.puml:4:1"));
+
+        let (code, _, _) = run_args(&["ad2fsm", "check", "/nonexistent/diagram.puml"]);
+        assert_eq!(code, EXIT_IO);
+    }
+}
@@ -1,55 +1,43 @@
-use anyhow::Result;
-use std::error::Error;
-use std::fs;
-use tracing::info;
+use clap::Parser;
+use common::cli::exit_code::{EXIT_OK, EXIT_USAGE};
+use std::io;
+use std::process::ExitCode;
 
 #[allow(unused)]
 mod ts_const;
 #[allow(unused)]
 mod builder;
+mod cli;
 
 /// Main entry point for the PlantUML to State Machine converter
 ///
-/// This function:
-/// 1. Reads a PlantUML file
-/// 2. Parses it using Tree-sitter
-/// 3. Converts to state machine model
-/// 4. Generates C# code using Askama templates
-/// 5. Saves the generated code to a file
+/// The subcommands:
+/// 1. `parse` dumps the AST of a PlantUML activity diagram
+/// 2. `cfg` dumps its control flow graph
+/// 3. `fsm` emits the state machine as DOT or JSON
+/// 4. `gen` generates code for the state machine
+/// 5. `check` only reports diagnostics
+/// 6. `analyze` reports structural problems of the state machine
 ///
-fn main() -> Result<(), Box<dyn Error>> {
-    main_inner(std::env::args_os())?;
-    Ok(())
+/// Input is read from a file or stdin; the exit code is non-zero on errors.
+fn main() -> ExitCode {
+    main_inner(std::env::args_os())
 }
 
-pub fn main_inner<I, T>(_args: I) -> Result<(), Box<dyn Error>>
+pub fn main_inner<I, T>(args: I) -> ExitCode
 where
     I: IntoIterator<Item=T>,
     T: Into<std::ffi::OsString> + Clone,
 {
-
-    // Execute the logic
-    if let Err(e) = execute() {
-        eprintln!("Error: {}", e);
-        //process::exit(1);
-    }
-    Ok(())
+    let cli = match cli::Cli::try_parse_from(args) {
+        Ok(cli) => cli,
+        Err(e) => {
+            // prints help/version to stdout and usage errors to stderr
+            let _ = e.print();
+            let code = if e.use_stderr() { EXIT_USAGE } else { EXIT_OK };
+            return ExitCode::from(code);
+        }
+    };
+    let code = cli::run(cli, &mut io::stdout(), &mut io::stderr());
+    ExitCode::from(code)
 }
-
-fn execute() -> Result<()> {
-    // Initialize logging
-
-    // Read the PlantUML file
-    let plantuml_content = fs::read_to_string("example.puml")?;
-
-    info!("Parsing PlantUML activity diagram...");
-
-    // Create parser and parse the content
-    let mut parser = builder::parser::ADParser::new();
-    let _ast = parser.parse(&plantuml_content)?;
-
-
-    info!("C# state machine generated successfully!");
-
-    Ok(())
-}
\ No newline at end of file
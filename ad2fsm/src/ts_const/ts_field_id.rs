@@ -11,13 +11,18 @@ pub const FI_BLOCK_STATEMENT_LIST: u16 = 4;
 pub const FI_COLOR: u16 = 5;
 pub const FI_CONTENT: u16 = 6;
 pub const FI_ELEMENT: u16 = 7;
-pub const FI_EXPRESSION: u16 = 8;
-pub const FI_EXPRESSION_CONTENT: u16 = 9;
-pub const FI_NAME: u16 = 10;
-pub const FI_OPERATOR: u16 = 11;
-pub const FI_POSITION: u16 = 12;
-pub const FI_PROPERTY: u16 = 13;
-pub const FI_SELECTOR: u16 = 14;
-pub const FI_TEXT: u16 = 15;
-pub const FI_TYPE: u16 = 16;
-pub const FI_VALUE: u16 = 17;
+pub const FI_EXIT_LABEL: u16 = 8;
+pub const FI_EXPRESSION: u16 = 9;
+pub const FI_EXPRESSION_CONTENT: u16 = 10;
+pub const FI_JOIN: u16 = 11;
+pub const FI_LABEL: u16 = 12;
+pub const FI_NAME: u16 = 13;
+pub const FI_OPERATOR: u16 = 14;
+pub const FI_POSITION: u16 = 15;
+pub const FI_PROPERTY: u16 = 16;
+pub const FI_SELECTOR: u16 = 17;
+pub const FI_SHAPE: u16 = 18;
+pub const FI_STEREOTYPE: u16 = 19;
+pub const FI_TEXT: u16 = 20;
+pub const FI_TYPE: u16 = 21;
+pub const FI_VALUE: u16 = 22;
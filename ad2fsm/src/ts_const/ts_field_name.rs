@@ -4,20 +4,25 @@
 //
 // field name
 
-pub const ACTION: &'static str = "action";
-pub const ACTIVITY_IDENTIFIER: &'static str = "activity_identifier";
-pub const ARROW: &'static str = "arrow";
-pub const BLOCK_STATEMENT_LIST: &'static str = "block_statement_list";
-pub const COLOR: &'static str = "color";
-pub const CONTENT: &'static str = "content";
-pub const ELEMENT: &'static str = "element";
-pub const EXPRESSION: &'static str = "expression";
-pub const EXPRESSION_CONTENT: &'static str = "expression_content";
-pub const NAME: &'static str = "name";
-pub const OPERATOR: &'static str = "operator";
-pub const POSITION: &'static str = "position";
-pub const PROPERTY: &'static str = "property";
-pub const SELECTOR: &'static str = "selector";
-pub const TEXT: &'static str = "text";
-pub const TYPE: &'static str = "type";
-pub const VALUE: &'static str = "value";
+pub const ACTION: &str = "action";
+pub const ACTIVITY_IDENTIFIER: &str = "activity_identifier";
+pub const ARROW: &str = "arrow";
+pub const BLOCK_STATEMENT_LIST: &str = "block_statement_list";
+pub const COLOR: &str = "color";
+pub const CONTENT: &str = "content";
+pub const ELEMENT: &str = "element";
+pub const EXIT_LABEL: &str = "exit_label";
+pub const EXPRESSION: &str = "expression";
+pub const EXPRESSION_CONTENT: &str = "expression_content";
+pub const JOIN: &str = "join";
+pub const LABEL: &str = "label";
+pub const NAME: &str = "name";
+pub const OPERATOR: &str = "operator";
+pub const POSITION: &str = "position";
+pub const PROPERTY: &str = "property";
+pub const SELECTOR: &str = "selector";
+pub const SHAPE: &str = "shape";
+pub const STEREOTYPE: &str = "stereotype";
+pub const TEXT: &str = "text";
+pub const TYPE: &str = "type";
+pub const VALUE: &str = "value";
@@ -4,53 +4,58 @@
 //
 // kind id of Node
 
-pub const NUMBER: u16 = 0;
 pub const BOOLEAN: u16 = 0;
-pub const STEREOTYPE: u16 = 0;
+pub const COLOR_EXPRESSION: u16 = 0;
 pub const _TOP_STATEMENT: u16 = 0;
-pub const EMOJI: u16 = 0;
 pub const _BLOCK_STATEMENT: u16 = 0;
-pub const SDL_SHAPE: u16 = 0;
-pub const COLOR_EXPRESSION: u16 = 0;
+pub const EMOJI: u16 = 0;
+pub const NUMBER: u16 = 0;
 pub const ENDUML_DIRECTIVE: u16 = 4;
-pub const BREAK_STATEMENT: u16 = 22;
-pub const IDENTIFIER: u16 = 60;
-pub const DOCUMENT: u16 = 65;
-pub const STARTUML_DIRECTIVE: u16 = 67;
-pub const DEFINE_STATEMENT: u16 = 68;
-pub const TERMINAL_STATEMENT: u16 = 69;
-pub const STOP: u16 = 70;
-pub const IF_STATEMENT: u16 = 71;
-pub const ENDIF_DIRECTIVE: u16 = 72;
-pub const IF_CONDITION: u16 = 73;
-pub const ELSEIF_CONDITION: u16 = 74;
-pub const ELSE_CONDITION: u16 = 75;
-pub const BLOCK_STATEMENT_LIST: u16 = 76;
-pub const SWITCH_STATEMENT: u16 = 78;
-pub const ENDSWITCH_DIRECTIVE: u16 = 79;
-pub const CASE_CLAUSE: u16 = 80;
-pub const REPEAT_STATEMENT: u16 = 81;
-pub const REPEAT_STATEMENT_END: u16 = 82;
-pub const WHILE_STATEMENT: u16 = 83;
-pub const ENDWHILE_DIRECTIVE: u16 = 84;
-pub const GOTO_STATEMENT: u16 = 85;
-pub const GROUP_TYPE: u16 = 86;
-pub const GROUP_STATEMENT: u16 = 87;
-pub const NOTE_STATEMENT: u16 = 88;
-pub const ARROW: u16 = 89;
-pub const ARROW_STYLE: u16 = 90;
-pub const ARROW_PROPERTIES: u16 = 91;
-pub const ARROW_PROPERTY_ELEMENT: u16 = 92;
-pub const TITLE_STATEMENT: u16 = 93;
-pub const SKINPARAM: u16 = 94;
-pub const SKINPARAM_VALUE: u16 = 95;
-pub const STYLE_BLOCK: u16 = 96;
-pub const STYLE_RULE: u16 = 97;
-pub const STYLE_PROPERTY: u16 = 98;
-pub const PRAGMA: u16 = 99;
-pub const EXPRESSION: u16 = 100;
-pub const TEXT_CONTENT: u16 = 101;
-pub const TEXT_WORD: u16 = 102;
-pub const ACTION_STATEMENT: u16 = 103;
-pub const COLOR_VALUE: u16 = 104;
-pub const COLOR_VALUE_ITEM: u16 = 105;
+pub const IDENTIFIER: u16 = 87;
+pub const DOCUMENT: u16 = 92;
+pub const STARTUML_DIRECTIVE: u16 = 94;
+pub const DEFINE_STATEMENT: u16 = 95;
+pub const TERMINAL_STATEMENT: u16 = 96;
+pub const STOP: u16 = 97;
+pub const IF_STATEMENT: u16 = 98;
+pub const ENDIF_DIRECTIVE: u16 = 99;
+pub const IF_CONDITION: u16 = 100;
+pub const ELSEIF_CONDITION: u16 = 101;
+pub const ELSE_CONDITION: u16 = 102;
+pub const BLOCK_STATEMENT_LIST: u16 = 103;
+pub const SWITCH_STATEMENT: u16 = 105;
+pub const ENDSWITCH_DIRECTIVE: u16 = 106;
+pub const CASE_CLAUSE: u16 = 107;
+pub const REPEAT_STATEMENT: u16 = 108;
+pub const REPEAT_STATEMENT_END: u16 = 109;
+pub const WHILE_STATEMENT: u16 = 110;
+pub const ENDWHILE_DIRECTIVE: u16 = 111;
+pub const FORK_STATEMENT: u16 = 112;
+pub const FORK_AGAIN: u16 = 113;
+pub const END_FORK_DIRECTIVE: u16 = 114;
+pub const BREAK_STATEMENT: u16 = 115;
+pub const GOTO_STATEMENT: u16 = 116;
+pub const ACTIVITY_STATEMENT: u16 = 117;
+pub const GROUP_TYPE: u16 = 118;
+pub const GROUP_STATEMENT: u16 = 119;
+pub const NOTE_STATEMENT: u16 = 120;
+pub const NOTE_TEXT: u16 = 121;
+pub const ARROW: u16 = 122;
+pub const ARROW_STYLE: u16 = 123;
+pub const ARROW_PROPERTIES: u16 = 124;
+pub const ARROW_PROPERTY_ELEMENT: u16 = 125;
+pub const TITLE_STATEMENT: u16 = 126;
+pub const SKINPARAM: u16 = 127;
+pub const SKINPARAM_VALUE: u16 = 128;
+pub const STYLE_BLOCK: u16 = 129;
+pub const STYLE_RULE: u16 = 130;
+pub const STYLE_PROPERTY: u16 = 131;
+pub const PRAGMA: u16 = 132;
+pub const SDL_SHAPE: u16 = 133;
+pub const STEREOTYPE: u16 = 134;
+pub const EXPRESSION: u16 = 135;
+pub const TEXT_CONTENT: u16 = 136;
+pub const TEXT_WORD: u16 = 137;
+pub const ACTION_STATEMENT: u16 = 138;
+pub const COLOR_VALUE: u16 = 139;
+pub const COLOR_VALUE_ITEM: u16 = 140;
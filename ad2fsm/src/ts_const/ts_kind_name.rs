@@ -4,16 +4,13 @@
 //
 // kind name of Node
 
-pub const S_NUMBER: &str = "number";
 pub const S_BOOLEAN: &str = "boolean";
-pub const S_STEREOTYPE: &str = "stereotype";
+pub const S_COLOR_EXPRESSION: &str = "color_expression";
 pub const S__TOP_STATEMENT: &str = "_top_statement";
-pub const S_EMOJI: &str = "emoji";
 pub const S__BLOCK_STATEMENT: &str = "_block_statement";
-pub const S_SDL_SHAPE: &str = "sdl_shape";
-pub const S_COLOR_EXPRESSION: &str = "color_expression";
+pub const S_EMOJI: &str = "emoji";
+pub const S_NUMBER: &str = "number";
 pub const S_ENDUML_DIRECTIVE: &str = "enduml_directive";
-pub const S_BREAK_STATEMENT: &str = "break_statement";
 pub const S_IDENTIFIER: &str = "identifier";
 pub const S_DOCUMENT: &str = "document";
 pub const S_STARTUML_DIRECTIVE: &str = "startuml_directive";
@@ -33,10 +30,16 @@ pub const S_REPEAT_STATEMENT: &str = "repeat_statement";
 pub const S_REPEAT_STATEMENT_END: &str = "repeat_statement_end";
 pub const S_WHILE_STATEMENT: &str = "while_statement";
 pub const S_ENDWHILE_DIRECTIVE: &str = "endwhile_directive";
+pub const S_FORK_STATEMENT: &str = "fork_statement";
+pub const S_FORK_AGAIN: &str = "fork_again";
+pub const S_END_FORK_DIRECTIVE: &str = "end_fork_directive";
+pub const S_BREAK_STATEMENT: &str = "break_statement";
 pub const S_GOTO_STATEMENT: &str = "goto_statement";
+pub const S_ACTIVITY_STATEMENT: &str = "activity_statement";
 pub const S_GROUP_TYPE: &str = "group_type";
 pub const S_GROUP_STATEMENT: &str = "group_statement";
 pub const S_NOTE_STATEMENT: &str = "note_statement";
+pub const S_NOTE_TEXT: &str = "note_text";
 pub const S_ARROW: &str = "arrow";
 pub const S_ARROW_STYLE: &str = "arrow_style";
 pub const S_ARROW_PROPERTIES: &str = "arrow_properties";
@@ -48,6 +51,8 @@ pub const S_STYLE_BLOCK: &str = "style_block";
 pub const S_STYLE_RULE: &str = "style_rule";
 pub const S_STYLE_PROPERTY: &str = "style_property";
 pub const S_PRAGMA: &str = "pragma";
+pub const S_SDL_SHAPE: &str = "sdl_shape";
+pub const S_STEREOTYPE: &str = "stereotype";
 pub const S_EXPRESSION: &str = "expression";
 pub const S_TEXT_CONTENT: &str = "text_content";
 pub const S_TEXT_WORD: &str = "text_word";
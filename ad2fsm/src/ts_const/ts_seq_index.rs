@@ -4,11 +4,16 @@
 //
 // sequence index in array of SEQ type
 
+pub const ACTION_STATEMENT_SEQ_CHOICE: usize = 3;
 pub const ACTION_STATEMENT_SEQ_FIELD: usize = 1;
 pub const ACTION_STATEMENT_SEQ_STRING_0: usize = 0;
 pub const ACTION_STATEMENT_SEQ_STRING_2: usize = 2;
+pub const ACTIVITY_STATEMENT_PREC_RIGHT_SEQ_CHOICE: usize = 2;
+pub const ACTIVITY_STATEMENT_PREC_RIGHT_SEQ_FIELD: usize = 1;
+pub const ACTIVITY_STATEMENT_PREC_RIGHT_SEQ_STRING: usize = 0;
+pub const ARROW_SEQ_CHOICE: usize = 1;
 pub const ARROW_SEQ_FIELD: usize = 0;
-pub const ARROW_SEQ_SYMBOL: usize = 1;
+pub const ARROW_SEQ_STRING: usize = 2;
 pub const ARROW_PROPERTIES_SEQ_CHOICE: usize = 2;
 pub const ARROW_PROPERTIES_SEQ_REPEAT: usize = 1;
 pub const ARROW_PROPERTIES_SEQ_REPEAT_SEQ_STRING: usize = 0;
@@ -20,6 +25,8 @@ pub const ARROW_STYLE_CHOICE_SEQ_STRING_3: usize = 3;
 pub const ARROW_STYLE_CHOICE_SEQ_STRING_4: usize = 4;
 pub const ARROW_STYLE_CHOICE_SEQ_STRING_2: usize = 2;
 pub const ARROW_STYLE_CHOICE_SEQ_SYMBOL: usize = 2;
+pub const BREAK_STATEMENT_PREC_RIGHT_SEQ_CHOICE: usize = 1;
+pub const BREAK_STATEMENT_PREC_RIGHT_SEQ_STRING: usize = 0;
 pub const CASE_CLAUSE_SEQ_CHOICE_1: usize = 1;
 pub const CASE_CLAUSE_SEQ_CHOICE_3: usize = 3;
 pub const CASE_CLAUSE_SEQ_FIELD_2: usize = 2;
@@ -38,21 +45,28 @@ pub const DOCUMENT_SEQ_CHOICE_3: usize = 3;
 pub const DOCUMENT_SEQ_CHOICE_4: usize = 4;
 pub const DOCUMENT_SEQ_REPEAT1: usize = 2;
 pub const ELSE_CONDITION_SEQ_CHOICE: usize = 1;
+pub const ELSE_CONDITION_SEQ_CHOICE_SEQ_FIELD: usize = 1;
 pub const ELSE_CONDITION_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
 pub const ELSE_CONDITION_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
-pub const ELSE_CONDITION_SEQ_CHOICE_SEQ_SYMBOL: usize = 1;
 pub const ELSE_CONDITION_SEQ_FIELD: usize = 2;
 pub const ELSE_CONDITION_SEQ_STRING: usize = 0;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_1: usize = 1;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_3: usize = 3;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_4: usize = 4;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_5: usize = 5;
+pub const ELSEIF_CONDITION_SEQ_CHOICE_SEQ_FIELD: usize = 1;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
 pub const ELSEIF_CONDITION_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
-pub const ELSEIF_CONDITION_SEQ_CHOICE_SEQ_SYMBOL: usize = 1;
 pub const ELSEIF_CONDITION_SEQ_FIELD_2: usize = 2;
 pub const ELSEIF_CONDITION_SEQ_FIELD_6: usize = 6;
 pub const ELSEIF_CONDITION_SEQ_STRING: usize = 0;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE: usize = 1;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE_SEQ_CHOICE_0: usize = 0;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE_SEQ_CHOICE_1: usize = 1;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE_SEQ_CHOICE_SEQ_FIELD: usize = 1;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
+pub const END_FORK_DIRECTIVE_SEQ_CHOICE_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
+pub const END_FORK_DIRECTIVE_SEQ_STRING: usize = 0;
 pub const ENDIF_DIRECTIVE_CHOICE_SEQ_STRING_0: usize = 0;
 pub const ENDIF_DIRECTIVE_CHOICE_SEQ_STRING_1: usize = 1;
 pub const ENDSWITCH_DIRECTIVE_CHOICE_SEQ_STRING_0: usize = 0;
@@ -61,6 +75,12 @@ pub const ENDWHILE_DIRECTIVE_CHOICE_SEQ_STRING_0: usize = 0;
 pub const ENDWHILE_DIRECTIVE_CHOICE_SEQ_STRING_1: usize = 1;
 pub const EXPRESSION_CHOICE_SEQ_FIELD: usize = 1;
 pub const EXPRESSION_CHOICE_SEQ_STRING: usize = 0;
+pub const FORK_AGAIN_SEQ_CHOICE: usize = 0;
+pub const FORK_AGAIN_SEQ_FIELD: usize = 1;
+pub const FORK_STATEMENT_SEQ_CHOICE: usize = 0;
+pub const FORK_STATEMENT_SEQ_FIELD: usize = 1;
+pub const FORK_STATEMENT_SEQ_REPEAT: usize = 2;
+pub const FORK_STATEMENT_SEQ_SYMBOL: usize = 3;
 pub const GOTO_STATEMENT_PREC_RIGHT_SEQ_CHOICE_0: usize = 0;
 pub const GOTO_STATEMENT_PREC_RIGHT_SEQ_CHOICE_1: usize = 1;
 pub const GOTO_STATEMENT_PREC_RIGHT_SEQ_CHOICE_SEQ_STRING: usize = 0;
@@ -79,9 +99,9 @@ pub const IF_CONDITION_SEQ_CHOICE_1: usize = 1;
 pub const IF_CONDITION_SEQ_CHOICE_3: usize = 3;
 pub const IF_CONDITION_SEQ_CHOICE_4: usize = 4;
 pub const IF_CONDITION_SEQ_CHOICE_5: usize = 5;
+pub const IF_CONDITION_SEQ_CHOICE_SEQ_FIELD: usize = 1;
 pub const IF_CONDITION_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
 pub const IF_CONDITION_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
-pub const IF_CONDITION_SEQ_CHOICE_SEQ_SYMBOL: usize = 1;
 pub const IF_CONDITION_SEQ_FIELD_2: usize = 2;
 pub const IF_CONDITION_SEQ_FIELD_6: usize = 6;
 pub const IF_CONDITION_SEQ_STRING: usize = 0;
@@ -109,12 +129,9 @@ pub const REPEAT_STATEMENT_END_SEQ_CHOICE_1: usize = 1;
 pub const REPEAT_STATEMENT_END_SEQ_CHOICE_3: usize = 3;
 pub const REPEAT_STATEMENT_END_SEQ_FIELD: usize = 2;
 pub const REPEAT_STATEMENT_END_SEQ_STRING: usize = 0;
-pub const SDL_SHAPE_SEQ_CHOICE: usize = 2;
-pub const SDL_SHAPE_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
-pub const SDL_SHAPE_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
-pub const SDL_SHAPE_SEQ_CHOICE_SEQ_SYMBOL: usize = 1;
-pub const SDL_SHAPE_SEQ_STRING: usize = 1;
-pub const SDL_SHAPE_SEQ_SYMBOL: usize = 0;
+pub const SDL_SHAPE_SEQ_FIELD: usize = 1;
+pub const SDL_SHAPE_SEQ_STRING_0: usize = 0;
+pub const SDL_SHAPE_SEQ_STRING_2: usize = 2;
 pub const SKINPARAM_SEQ_CHOICE: usize = 4;
 pub const SKINPARAM_SEQ_FIELD_1: usize = 1;
 pub const SKINPARAM_SEQ_FIELD_2: usize = 2;
@@ -150,12 +167,12 @@ pub const WHILE_STATEMENT_SEQ_CHOICE_3: usize = 3;
 pub const WHILE_STATEMENT_SEQ_CHOICE_4: usize = 4;
 pub const WHILE_STATEMENT_SEQ_CHOICE_7: usize = 7;
 pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_CHOICE: usize = 0;
+pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_FIELD_2: usize = 2;
+pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_FIELD_1: usize = 1;
 pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_STRING_1: usize = 1;
 pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_STRING_3: usize = 3;
 pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_STRING_0: usize = 0;
 pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_STRING_2: usize = 2;
-pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_SYMBOL_2: usize = 2;
-pub const WHILE_STATEMENT_SEQ_CHOICE_SEQ_SYMBOL_1: usize = 1;
 pub const WHILE_STATEMENT_SEQ_FIELD_2: usize = 2;
 pub const WHILE_STATEMENT_SEQ_FIELD_5: usize = 5;
 pub const WHILE_STATEMENT_SEQ_STRING: usize = 0;
@@ -1,10 +1,110 @@
 use crate::cfg::cfg_cond::CfgEdge;
-use crate::cfg::cfg_node_kind::{CfgNodeKind, NodeId};
-use std::collections::HashMap;
+use crate::cfg::cfg_node_kind::{CfgNodeKind, NodeId, StateKind};
+use crate::dot::escape;
+use std::collections::BTreeMap;
+use std::fmt::Write;
 
+#[derive(Debug)]
 pub struct CFGraph {
     pub nodes: Vec<CfgNodeKind>,
     /// Control Flow Graph (CFG) represented as an adjacency list.
-    /// Maps each (source, to) node ID to the CfgEdge.
-    pub edges: HashMap<(NodeId, NodeId), CfgEdge>,
-}
\ No newline at end of file
+    /// Maps each (source, to) node ID to the CfgEdge, ordered by the
+    /// pair so every walk over the edges visits them in the same order.
+    pub edges: BTreeMap<(NodeId, NodeId), CfgEdge>,
+    /// Path of the diagram groups (`partition`, `group`, ...) around each
+    /// node created inside one, outermost first
+    pub partitions: BTreeMap<NodeId, Vec<String>>,
+    /// Text of the diagram's notes on each node that has some
+    pub notes: BTreeMap<NodeId, String>,
+    /// Kind of each action with an SDL stereotype
+    pub kinds: BTreeMap<NodeId, StateKind>,
+}
+
+/// Converts CFG to Graphviz DOT format for visualization
+///
+/// The branches of a fork are drawn in one cluster each, their node names
+/// are prefixed with the fork's node name and the branch number. Notes
+/// are shown as tooltips, stereotypes above the action.
+pub fn cfg_to_dot(cfg: &CFGraph) -> String {
+    let mut out = String::new();
+
+    writeln!(&mut out, "digraph CFG {{").unwrap();
+    writeln!(&mut out).unwrap();
+
+    write_dot_graph(&mut out, cfg, "", 1);
+
+    writeln!(&mut out, "}}").unwrap();
+    out
+}
+
+/// Writes the nodes and edges of one (possibly nested) graph
+fn write_dot_graph(out: &mut String, cfg: &CFGraph, prefix: &str, depth: usize) {
+    let indent = "  ".repeat(depth);
+
+    for (i, node) in cfg.nodes.iter().enumerate() {
+        let (label, shape) = match node {
+            CfgNodeKind::Start => ("START".to_string(), "circle"),
+            CfgNodeKind::Action(text) => match cfg.kinds.get(&NodeId(i)) {
+                Some(kind) => (format!("<<{}>>\n{}", kind, text), "box"),
+                None => (text.clone(), "box"),
+            },
+            CfgNodeKind::Activity(name) => (name.clone(), "box3d"),
+            CfgNodeKind::Decision(text) => (text.clone(), "diamond"),
+            CfgNodeKind::Merge => (String::new(), "point"),
+            CfgNodeKind::Stop => ("STOP".to_string(), "doublecircle"),
+            CfgNodeKind::End => ("END".to_string(), "doublecircle"),
+            CfgNodeKind::Fork(_) => ("fork".to_string(), "house"),
+            CfgNodeKind::Join(join) => (format!("join {}", join), "invhouse"),
+        };
+        let tooltip = cfg.notes
+            .get(&NodeId(i))
+            .map(|note| format!(", tooltip=\"{}\"", escape(note)))
+            .unwrap_or_default();
+        writeln!(
+            out,
+            "{}{}N{} [label=\"{}\", shape={}{}];",
+            indent,
+            prefix,
+            i,
+            escape(&label),
+            shape,
+            tooltip
+        )
+            .unwrap();
+
+        // every branch is entered from the fork node
+        if let CfgNodeKind::Fork(branches) = node {
+            for (b, branch) in branches.iter().enumerate() {
+                let nested = format!("{}N{}_B{}_", prefix, i, b);
+                writeln!(out, "{}subgraph cluster_{}N{}_B{} {{", indent, prefix, i, b).unwrap();
+                writeln!(out, "{}  label=\"branch {}\";", indent, b + 1).unwrap();
+                write_dot_graph(out, branch, &nested, depth + 1);
+                writeln!(out, "{}}}", indent).unwrap();
+                writeln!(out, "{}{}N{} -> {}N0 [style=dashed];", indent, prefix, i, nested).unwrap();
+            }
+        }
+    }
+
+    writeln!(out).unwrap();
+
+    for e in cfg.edges.values() {
+        match e.caption() {
+            Some(cond) => {
+                writeln!(
+                    out,
+                    "{}{}N{} -> {}N{} [label=\"{}\"];",
+                    indent,
+                    prefix,
+                    e.from.0,
+                    prefix,
+                    e.to.0,
+                    escape(&cond)
+                )
+                    .unwrap();
+            }
+            None => {
+                writeln!(out, "{}{}N{} -> {}N{};", indent, prefix, e.from.0, prefix, e.to.0).unwrap();
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::cfg::cf_graph::CFGraph;
 use crate::cfg::cfg_cond::CfgEdge;
 use crate::cfg::cfg_node_kind::{CfgNodeKind, NodeId};
-use std::collections::HashMap;
+use crate::diag::diagnostic::Diagnostic;
+use crate::diag::source_span::SourceSpan;
+use std::collections::{BTreeMap, HashMap};
 
 pub struct CfgBuilder {
     pub cfg: CFGraph,
@@ -9,18 +11,36 @@ pub struct CfgBuilder {
     /// label name -> node where the label points to
     pub labels: HashMap<String, NodeId>,
 
-    /// list of (from_node, label_name) for unresolved gotos
-    pub pending_gotos: Vec<(NodeId, String)>,
+    /// labels not followed by a node yet: (node before the label, label
+    /// name); the first edge leaving that node gives the label its node
+    pub pending_labels: Vec<(NodeId, String)>,
+
+    /// list of (from_node, label_name, span of the goto) for unresolved gotos
+    pub pending_gotos: Vec<(NodeId, String, SourceSpan)>,
 
     /// stack of loop-exit nodes, used for `break`
     pub loop_exit_stack: Vec<NodeId>,
+
+    /// problems reported while lowering into the graph
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// names of the groups around the statements being lowered, outermost
+    /// first; recorded for every node created
+    pub group_stack: Vec<String>,
+
+    /// node -> label of the arrow written after it, put on the next edge
+    /// leaving the node
+    pub arrow_labels: HashMap<NodeId, String>,
 }
 
 impl CfgBuilder {
     pub fn new() -> (Self, NodeId) {
         let mut cfg = CFGraph {
             nodes: Vec::new(),
-            edges: HashMap::new(),
+            edges: BTreeMap::new(),
+            partitions: BTreeMap::new(),
+            notes: BTreeMap::new(),
+            kinds: BTreeMap::new(),
         };
 
         let start = NodeId(cfg.nodes.len());
@@ -30,8 +50,12 @@ impl CfgBuilder {
             Self {
                 cfg,
                 labels: HashMap::new(),
+                pending_labels: Vec::new(),
                 pending_gotos: Vec::new(),
                 loop_exit_stack: Vec::new(),
+                diagnostics: Vec::new(),
+                group_stack: Vec::new(),
+                arrow_labels: HashMap::new(),
             },
             start,
         )
@@ -40,11 +64,42 @@ impl CfgBuilder {
     pub fn new_node(&mut self, kind: CfgNodeKind) -> NodeId {
         let id = NodeId(self.cfg.nodes.len());
         self.cfg.nodes.push(kind);
+        if !self.group_stack.is_empty() {
+            self.cfg.partitions.insert(id, self.group_stack.clone());
+        }
         id
     }
 
+    /// Documents `node` with a note, several notes on one node are kept
+    /// one after the other
+    pub fn note(&mut self, node: NodeId, text: String) {
+        self.cfg.notes
+            .entry(node)
+            .and_modify(|notes| {
+                notes.push('\n');
+                notes.push_str(&text);
+            })
+            .or_insert(text);
+    }
+
+    /// Defines label `name` after `node`, it points to the node the flow
+    /// reaches next
+    pub fn label(&mut self, node: NodeId, name: String) {
+        self.pending_labels.push((node, name));
+    }
+
     /// Inserts a CFG edge into the adjacency list
-    pub fn edge(&mut self, edge: CfgEdge) {
+    pub fn edge(&mut self, mut edge: CfgEdge) {
+        let (resolved, pending) = std::mem::take(&mut self.pending_labels)
+            .into_iter()
+            .partition(|(node, _)| *node == edge.from);
+        self.pending_labels = pending;
+        for (_, name) in resolved {
+            self.labels.insert(name, edge.to);
+        }
+        if edge.label.is_none() {
+            edge.label = self.arrow_labels.remove(&edge.from);
+        }
         // Add the edge to the edge set
         // Duplicate edges (same from/to pair) will be updated
         let opt = self.cfg.edges.get_mut(&(edge.from, edge.to));
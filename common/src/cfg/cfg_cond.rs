@@ -1,4 +1,5 @@
 use crate::cfg::cfg_node_kind::NodeId;
+use std::fmt;
 
 /// Represents a directed edge in the Control Flow Graph (CFG).
 #[derive(Debug)]
@@ -10,21 +11,29 @@ pub struct CfgEdge {
     /// Optional condition expression for conditional branches
     /// (e.g., "x > 0" for if-statements)
     pub cond: Option<CfgCond>,
-    /// Optional edge label for additional metadata
-    /// (e.g., "loop_back_edge", "exception_handler")
+    /// Optional label written on the diagram, a branch label such as the
+    /// "yes" of `then (yes)` or the text of a `-> label;` arrow
     pub label: Option<String>,
 
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CfgCond {
     seq: u64,
     name: String,
+    /// Whether the branch is taken whenever the branches before it were not
+    fallback: bool,
 }
 
 impl CfgCond {
     pub fn new(seq: u64, name: String) -> CfgCond {
-        Self { seq, name }
+        Self { seq, name, fallback: false }
+    }
+
+    /// Marks the branch as the one taken when no other branch is
+    pub fn with_fallback(mut self, fallback: bool) -> CfgCond {
+        self.fallback = fallback;
+        self
     }
 
     pub fn seq(&self) -> u64 {
@@ -35,8 +44,38 @@ impl CfgCond {
         &self.name
     }
 
-    pub fn to_string(&self) -> String {
-        format!(" {} {}", self.seq, self.name)
+    /// The branch taken through this one and then `inner`, a branch of the
+    /// decision this one leads to. It keeps this branch's place among its
+    /// siblings and is only a fallback when both are.
+    pub fn nested(&self, inner: &CfgCond) -> CfgCond {
+        Self {
+            seq: self.seq,
+            name: format!("{} and {}", self.name, inner.name),
+            fallback: self.fallback && inner.fallback,
+        }
+    }
+
+    /// Whether the branch is taken whenever the branches before it were
+    /// not: an `else`, the `default` of a switch, an `otherwise` or the
+    /// exit of a loop
+    pub fn is_fallback(&self) -> bool {
+        self.fallback
     }
 }
 
+impl fmt::Display for CfgCond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " {} {}", self.seq, self.name)
+    }
+}
+
+
+/// Text shown on an edge or transition: the label with the guard's name
+/// in brackets, `condition` alone when there is no label
+pub fn caption(label: Option<&String>, cond: Option<&CfgCond>, condition: Option<String>) -> Option<String> {
+    match (label, cond) {
+        (Some(label), Some(cond)) => Some(format!("{} [{}]", label, cond.name())),
+        (Some(label), None) => Some(label.clone()),
+        (None, _) => condition,
+    }
+}
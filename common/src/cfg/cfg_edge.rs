@@ -1,4 +1,4 @@
-use crate::cfg::cfg_cond::{CfgCond, CfgEdge};
+use crate::cfg::cfg_cond::{caption, CfgCond, CfgEdge};
 use crate::cfg::cfg_node_kind::NodeId;
 
 impl CfgEdge {
@@ -11,13 +11,14 @@ impl CfgEdge {
         }
     }
 
+    /// The guard of the edge, the branch label is not part of it
     pub fn condition(&self) -> Option<String> {
-        let condition = self.label.clone();
-        let condition = if let Some(_cond) = condition {
-            self.cond.as_ref().map(|cond| { _cond + &cond.to_string() })
-        } else {
-            self.cond.as_ref().map(|cond| cond.to_string())
-        };
-        condition
+        self.cond.as_ref().map(|cond| cond.to_string())
+    }
+
+    /// Text shown on the edge: the branch label with the guard's name in
+    /// brackets, the guard alone when the edge has no label
+    pub fn caption(&self) -> Option<String> {
+        caption(self.label.as_ref(), self.cond.as_ref(), self.condition())
     }
 }
\ No newline at end of file
@@ -1,12 +1,75 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use crate::cfg::cf_graph::CFGraph;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NodeId(pub usize);
 
 #[derive(Debug)]
 pub enum CfgNodeKind {
     Start,
     Action(String),
+    /// runs the activity diagram of the given name to its end
+    Activity(String),
     Decision(String),
     Merge,
     Stop,
     End,
+    /// starts the graph of every branch at once, each graph runs from its
+    /// own start to its own end; the flow continues at the join after it
+    Fork(Vec<CFGraph>),
+    /// waits for the branches of the fork before it
+    Join(JoinKind),
+}
+
+/// When the flow leaves a join
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinKind {
+    /// once every branch ended: `end fork`, `end fork {and}`
+    All,
+    /// once any branch ended: `end fork {or}`, `end merge`
+    Any,
+}
+
+impl fmt::Display for JoinKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinKind::All => write!(f, "all"),
+            JoinKind::Any => write!(f, "any"),
+        }
+    }
+}
+
+/// What an action does besides its own work, from its SDL stereotype
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateKind {
+    /// waits for the external event the action names: `<<acceptEvent>>`,
+    /// `<<input>>`
+    AcceptEvent,
+    /// emits the signal the action names: `<<sendSignal>>`, `<<output>>`
+    SendSignal,
+    /// waits for the timeout the action describes: `<<timeEvent>>`
+    TimeEvent,
+    /// reads from the database: `<<load>>`
+    Load,
+    /// writes to the database: `<<save>>`
+    Save,
+}
+
+impl StateKind {
+    /// Whether the state is only left once its event occurred
+    pub fn waits(self) -> bool {
+        matches!(self, StateKind::AcceptEvent | StateKind::TimeEvent)
+    }
+}
+
+impl fmt::Display for StateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateKind::AcceptEvent => write!(f, "acceptEvent"),
+            StateKind::SendSignal => write!(f, "sendSignal"),
+            StateKind::TimeEvent => write!(f, "timeEvent"),
+            StateKind::Load => write!(f, "load"),
+            StateKind::Save => write!(f, "save"),
+        }
+    }
 }
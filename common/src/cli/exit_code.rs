@@ -0,0 +1,8 @@
+/// The command succeeded
+pub const EXIT_OK: u8 = 0;
+/// The input has errors, diagnostics were written to stderr
+pub const EXIT_DIAGNOSTICS: u8 = 1;
+/// The command line could not be parsed (reported by clap)
+pub const EXIT_USAGE: u8 = 2;
+/// Reading the input or writing the output failed
+pub const EXIT_IO: u8 = 3;
@@ -0,0 +1,3 @@
+pub mod exit_code;
+pub mod report;
+pub mod source;
@@ -0,0 +1,16 @@
+use crate::cli::exit_code::{EXIT_DIAGNOSTICS, EXIT_OK};
+use crate::diag::diagnostic::{has_errors, Diagnostic};
+use std::io::{self, Write};
+
+/// Writes the diagnostics found in `origin`, returns the exit code they
+/// lead to
+pub fn report<E: Write>(err: &mut E, origin: &str, diagnostics: &[Diagnostic]) -> io::Result<u8> {
+    for d in diagnostics {
+        writeln!(err, "{}", d.located(origin))?;
+    }
+    if has_errors(diagnostics) {
+        Ok(EXIT_DIAGNOSTICS)
+    } else {
+        Ok(EXIT_OK)
+    }
+}
@@ -0,0 +1,57 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Source text and a name to refer to it in diagnostics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    pub origin: String,
+    pub text: String,
+}
+
+impl Source {
+    /// Reads the file at `path`, or stdin if there is none or it is `-`
+    pub fn read(path: Option<&Path>) -> io::Result<Source> {
+        match path {
+            Some(path) if path.as_os_str() != "-" => Self::read_file(path),
+            _ => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(Source {
+                    origin: "<stdin>".to_string(),
+                    text,
+                })
+            }
+        }
+    }
+
+    pub fn read_file(path: &Path) -> io::Result<Source> {
+        let text = fs::read_to_string(path)?;
+        Ok(Source {
+            origin: path.display().to_string(),
+            text,
+        })
+    }
+
+    /// Reads a file that accompanies the input: the one given on the
+    /// command line, or else the file next to the input with the same
+    /// stem and `extension`, if there is one
+    pub fn read_sidecar(
+        given: Option<&Path>,
+        input: Option<&Path>,
+        extension: &str,
+    ) -> io::Result<Option<Source>> {
+        let path = match (given, input) {
+            (Some(path), _) => path.to_path_buf(),
+            (None, Some(path)) if path.as_os_str() != "-" => {
+                let path = path.with_extension(extension);
+                if !path.exists() {
+                    return Ok(None);
+                }
+                path
+            }
+            (None, _) => return Ok(None),
+        };
+        Self::read_file(&path).map(Some)
+    }
+}
@@ -0,0 +1,25 @@
+//! Diagram text the generators write into comments
+
+/// `text` with its lines joined by spaces, so a multi-line name or guard
+/// cannot end the comment it is written into; one-line text is kept as is
+pub fn one_line(text: &str) -> String {
+    let mut lines = text.lines();
+    let mut joined = lines.next().unwrap_or_default().trim_end().to_string();
+    for line in lines.map(str::trim).filter(|line| !line.is_empty()) {
+        joined.push(' ');
+        joined.push_str(line);
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::one_line;
+
+    #[test]
+    fn test_one_line() {
+        assert_eq!(one_line("Load\nConfig"), "Load Config");
+        assert_eq!(one_line("a \r\n\n b  "), "a b");
+        assert_eq!(one_line(" 0 ok?"), " 0 ok?");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod comment;
+pub mod naming;
+pub mod unsupported;
+pub mod user_code;
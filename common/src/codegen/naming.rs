@@ -0,0 +1,106 @@
+//! Identifiers the generators derive from state names
+
+use crate::cfg::cfg_node_kind::StateKind;
+use crate::fsm::state_id::StateId;
+use std::collections::HashMap;
+
+/// CamelCase enum variant of a state, the id keeps equally named states
+/// apart
+pub fn variant_name(name: &str, id: &StateId) -> String {
+    format!("{}{}", camel_name(name), id.0)
+}
+
+/// CamelCase identifier made of the alphanumeric words of `name`
+pub fn camel_name(name: &str) -> String {
+    let mut camel: String = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap_or_default();
+            // an all-caps word such as START is written Start
+            let rest = if word.chars().any(char::is_lowercase) {
+                chars.as_str().to_string()
+            } else {
+                chars.as_str().to_lowercase()
+            };
+            first.to_uppercase().chain(rest.chars()).collect::<String>()
+        })
+        .collect();
+    if !camel.starts_with(|c: char| c.is_alphabetic()) {
+        camel.insert(0, 'S');
+    }
+    camel
+}
+
+/// snake_case of a CamelCase variant
+pub fn snake_name(variant: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in variant.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// snake_case of the alphanumeric words of a diagram label, `None` when
+/// it has none
+pub fn label_name(label: &str) -> Option<String> {
+    let words: Vec<String> = label
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    (!words.is_empty()).then(|| words.join("_"))
+}
+
+/// `base` on its first use, `base` with the number of its use appended
+/// from the second on; `used` counts the uses of every base
+pub fn unique_name(used: &mut HashMap<String, usize>, base: String) -> String {
+    let count = used.entry(base.clone()).or_default();
+    *count += 1;
+    if *count > 1 {
+        format!("{}_{}", base, count)
+    } else {
+        base
+    }
+}
+
+/// Verb naming the event hook of a state of `kind`
+pub fn hook_verb(kind: StateKind) -> &'static str {
+    match kind {
+        StateKind::AcceptEvent => "accept",
+        StateKind::SendSignal => "send",
+        StateKind::TimeEvent => "elapsed",
+        StateKind::Load => "load",
+        StateKind::Save => "save",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(variant_name("Check Stock", &StateId(1)), "CheckStock1");
+        assert_eq!(variant_name("START", &StateId(0)), "Start0");
+        assert_eq!(variant_name("if_end", &StateId(4)), "IfEnd4");
+        assert_eq!(variant_name("LoadUser", &StateId(0)), "LoadUser0");
+        assert_eq!(variant_name("2nd try", &StateId(5)), "S2ndTry5");
+        assert_eq!(camel_name("if-else"), "IfElse");
+        assert_eq!(snake_name("CheckStock1"), "check_stock1");
+        assert_eq!(label_name("More data?").as_deref(), Some("more_data"));
+        assert_eq!(label_name("?"), None);
+        let mut used = HashMap::new();
+        assert_eq!(unique_name(&mut used, "if_end".to_string()), "if_end");
+        assert_eq!(unique_name(&mut used, "if_end".to_string()), "if_end_2");
+        assert_eq!(unique_name(&mut used, "done".to_string()), "done");
+    }
+}
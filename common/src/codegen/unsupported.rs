@@ -0,0 +1,31 @@
+//! Parts of a machine some targets cannot express
+//!
+//! A generator whose target has no counterpart for a part of the machine
+//! reports it as an error instead of leaving it out of the output.
+
+use crate::diag::diagnostic::Diagnostic;
+use crate::fsm::fs_machine::FSMachine;
+
+/// Diagnostic code: the target cannot express the concurrent regions of
+/// an orthogonal state
+pub const UNSUPPORTED_ORTHOGONAL_STATE: &str = "GEN0002";
+
+/// An error for every orthogonal state of `fsm`, none when it has none;
+/// `target` names the output, `TLA+` or `Rust`
+pub fn unsupported_orthogonals(fsm: &FSMachine, target: &str) -> Vec<Diagnostic> {
+    fsm.orthogonals()
+        .keys()
+        .map(|id| {
+            Diagnostic::error(
+                UNSUPPORTED_ORTHOGONAL_STATE,
+                format!(
+                    "state `{}` (id {}) runs concurrent regions, which {} output does not support",
+                    fsm.state_map()[id],
+                    id.0,
+                    target
+                ),
+            )
+            .with_note("the C# generator supports fork and join".to_string())
+        })
+        .collect()
+}
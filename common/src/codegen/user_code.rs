@@ -0,0 +1,336 @@
+//! Hand-written code inside generated files
+//!
+//! Generators mark every stub the user is expected to fill in:
+//!
+//! ```text
+//! // <user-code NAME>
+//! default body
+//! // </user-code NAME 1f2e3d4c>
+//! ```
+//!
+//! The closing marker carries a digest of the default body, so a body
+//! that differs from it is known to be hand-written. Regenerating keeps
+//! hand-written bodies, found by `NAME` in any file of the output
+//! directory, and refreshes untouched ones. Hand-written code whose
+//! region is not generated anymore is reported and kept in
+//! [`ORPHANED_USER_CODE_FILE`] until a region of its name comes back.
+
+use crate::diag::diagnostic::Diagnostic;
+use std::collections::{btree_map, BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Diagnostic code: hand-written code belongs to a region that is not
+/// generated anymore
+pub const ORPHANED_USER_CODE: &str = "GEN0001";
+
+/// File the orphaned user code of an output directory is kept in
+pub const ORPHANED_USER_CODE_FILE: &str = "orphaned-user-code.txt";
+
+/// Opening marker of the region `name`, without indentation
+pub fn begin_marker(name: &str) -> String {
+    format!("// <user-code {}>", name)
+}
+
+/// Closing marker of the region `name`, without indentation
+pub fn end_marker(name: &str) -> String {
+    format!("// </user-code {}>", name)
+}
+
+/// Name of the region a line opens
+fn begin_of(line: &str) -> Option<&str> {
+    line.trim().strip_prefix("// <user-code ")?.strip_suffix('>')
+}
+
+/// Name and default body digest of the region a line closes
+fn end_of(line: &str) -> Option<(&str, Option<&str>)> {
+    let inner = line.trim().strip_prefix("// </user-code ")?.strip_suffix('>')?;
+    match inner.split_once(' ') {
+        Some((name, digest)) => Some((name, Some(digest))),
+        None => Some((inner, None)),
+    }
+}
+
+/// FNV-1a, stable across runs and toolchains
+fn digest(body: &str) -> String {
+    let mut hash: u32 = 0x811c_9dc5;
+    for b in body.bytes() {
+        hash ^= b as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    format!("{:08x}", hash)
+}
+
+/// Returns the hand-written body of every region, by region name; bodies
+/// still equal to the generated default are left out
+pub fn extract_user_code(text: &str) -> BTreeMap<String, String> {
+    let mut regions = BTreeMap::new();
+    let mut lines = text.split_inclusive('\n');
+    while let Some(line) = lines.next() {
+        let Some(name) = begin_of(line) else {
+            continue;
+        };
+        let mut body = String::new();
+        let mut default_digest = None;
+        let mut closed = false;
+        for line in lines.by_ref() {
+            if let Some((end, d)) = end_of(line)
+                && end == name
+            {
+                default_digest = d;
+                closed = true;
+                break;
+            }
+            body.push_str(line);
+        }
+        // an unterminated region is no region, nothing is kept from it
+        if closed && default_digest != Some(digest(&body).as_str()) {
+            regions.insert(name.to_string(), body);
+        }
+    }
+    regions
+}
+
+/// Adds the digest of the default body to every closing marker
+fn seal(generated: &str) -> String {
+    let mut out = String::new();
+    let mut body = String::new();
+    let mut open = None;
+    for line in generated.split_inclusive('\n') {
+        if let Some(name) = &open
+            && let Some((end, None)) = end_of(line)
+            && end == name
+        {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            out.push_str(&body);
+            out.push_str(&format!("{}// </user-code {} {}>\n", indent, name, digest(&body)));
+            open = None;
+            body.clear();
+        } else if open.is_some() {
+            body.push_str(line);
+        } else {
+            if let Some(name) = begin_of(line) {
+                open = Some(name.to_string());
+            }
+            out.push_str(line);
+        }
+    }
+    out.push_str(&body);
+    out
+}
+
+/// Replaces the body of every region of `generated` that has hand-written
+/// code in `user_code`, the closing markers get the digest of the
+/// generated default
+pub fn merge_user_code(generated: &str, user_code: &BTreeMap<String, String>) -> String {
+    let mut out = String::new();
+    let mut skip_to = None;
+    for line in seal(generated).split_inclusive('\n') {
+        if let Some(end) = &skip_to {
+            if end_of(line).is_none_or(|(name, _)| name != end) {
+                continue;
+            }
+            skip_to = None;
+        } else if let Some(name) = begin_of(line)
+            && let Some(body) = user_code.get(name)
+        {
+            out.push_str(line);
+            out.push_str(body);
+            skip_to = Some(name.to_string());
+            continue;
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// Regions used by the generated files of a file set
+fn regions_of(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().filter_map(begin_of)
+}
+
+/// Regenerates the files of one output directory without losing the
+/// hand-written code in them
+///
+/// All files with the generator's extension are read first, then every
+/// generated file is written through [`UserCodeMerge::write`], and
+/// [`UserCodeMerge::finish`] reports what was left over.
+pub struct UserCodeMerge {
+    dir: PathBuf,
+    /// hand-written bodies by region name
+    found: BTreeMap<String, String>,
+    /// the file each hand-written body came from
+    origins: BTreeMap<String, PathBuf>,
+    /// regions of the files written so far
+    generated: BTreeSet<String>,
+    /// names of the files written so far
+    written: BTreeSet<String>,
+}
+
+impl UserCodeMerge {
+    /// Collects the hand-written code of the files ending in `extension`
+    /// and of the orphaned user code in `dir`
+    pub fn load(dir: &Path, extension: &str) -> io::Result<UserCodeMerge> {
+        let mut found = BTreeMap::new();
+        let mut origins = BTreeMap::new();
+        let mut paths = vec![];
+        match fs::read_dir(dir) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    if name.ends_with(extension) || name == ORPHANED_USER_CODE_FILE {
+                        paths.push(path);
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        // the order files are read in decides between equally named regions
+        paths.sort();
+        for path in paths {
+            let text = fs::read_to_string(&path)?;
+            for (name, body) in extract_user_code(&text) {
+                if let btree_map::Entry::Vacant(entry) = found.entry(name.clone()) {
+                    entry.insert(body);
+                    origins.insert(name, path.clone());
+                }
+            }
+        }
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            found,
+            origins,
+            generated: BTreeSet::new(),
+            written: BTreeSet::new(),
+        })
+    }
+
+    /// Writes a generated file, with the hand-written code of its regions
+    pub fn write(&mut self, file_name: &str, generated: &str) -> io::Result<()> {
+        self.generated.extend(regions_of(generated).map(str::to_string));
+        self.written.insert(file_name.to_string());
+        fs::write(self.dir.join(file_name), merge_user_code(generated, &self.found))
+    }
+
+    /// Removes the files of an earlier run that were not written this time,
+    /// `is_generated` tells the generator's files from the user's; their
+    /// hand-written code was read by [`UserCodeMerge::load`]
+    pub fn remove_stale(&self, is_generated: impl Fn(&str) -> bool) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            if is_generated(&name) && !self.written.contains(&name) {
+                fs::remove_file(&path)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Keeps the hand-written code no written file has a region for in
+    /// [`ORPHANED_USER_CODE_FILE`] and reports one warning per region
+    pub fn finish(self) -> io::Result<Vec<Diagnostic>> {
+        let orphans: Vec<_> = self
+            .found
+            .iter()
+            .filter(|(name, _)| !self.generated.contains(*name))
+            .collect();
+        let orphan_path = self.dir.join(ORPHANED_USER_CODE_FILE);
+        if orphans.is_empty() {
+            match fs::remove_file(&orphan_path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => return Ok(vec![]),
+            }
+        }
+
+        let mut text = String::from(
+            "Hand-written code whose region is not generated anymore, it is moved\n\
+             back when a region of the same name is generated again.\n",
+        );
+        let mut diagnostics = vec![];
+        for (name, body) in orphans {
+            let path = &self.origins[name];
+            let origin = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            text.push_str(&format!(
+                "\n// from {}\n{}\n{}{}\n",
+                origin,
+                begin_marker(name),
+                body,
+                end_marker(name)
+            ));
+            let d = Diagnostic::warning(
+                ORPHANED_USER_CODE,
+                format!("user code `{}` from {} has no place in the generated code", name, origin),
+            )
+            .with_note(format!("it is kept in {}", orphan_path.display()));
+            diagnostics.push(d);
+        }
+        fs::write(&orphan_path, text)?;
+        Ok(diagnostics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_user_code() {
+        let old = "fn a() {\n    // <user-code a>\n    done();\n    // </user-code a>\n}\n";
+        let user_code = extract_user_code(old);
+        assert_eq!(user_code["a"], "    done();\n");
+
+        let generated = "// v2\nfn a() {\n    // <user-code a>\n    todo!()\n    // </user-code a>\n}\n\
+                         fn b() {\n    // <user-code b>\n    // </user-code b>\n}\n";
+        let merged = merge_user_code(generated, &user_code);
+        let todo = digest("    todo!()\n");
+        let empty = digest("");
+        assert_eq!(
+            merged,
+            format!(
+                "// v2\nfn a() {{\n    // <user-code a>\n    done();\n    // </user-code a {}>\n}}\n\
+                 fn b() {{\n    // <user-code b>\n    // </user-code b {}>\n}}\n",
+                todo, empty
+            )
+        );
+
+        // untouched defaults are not user code, edited ones are
+        let user_code = extract_user_code(&merge_user_code(generated, &BTreeMap::new()));
+        assert!(user_code.is_empty());
+        let user_code = extract_user_code(&merged);
+        assert_eq!(user_code.keys().collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn test_orphaned_user_code() {
+        let dir = std::env::temp_dir().join("common_user_code_orphans");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let stub = |name: &str| format!("// <user-code {}>\nreturn false;\n// </user-code {}>\n", name, name);
+
+        let mut merge = UserCodeMerge::load(&dir, ".cs").unwrap();
+        merge.write("A.cs", &stub("a")).unwrap();
+        assert!(merge.finish().unwrap().is_empty());
+        let text = fs::read_to_string(dir.join("A.cs")).unwrap();
+        fs::write(dir.join("A.cs"), text.replace("return false;", "return ctx.Ready;")).unwrap();
+
+        // `a` was renamed to `b`, its code survives the rewrite of A.cs
+        let mut merge = UserCodeMerge::load(&dir, ".cs").unwrap();
+        merge.write("A.cs", &stub("b")).unwrap();
+        let diagnostics = merge.finish().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), ORPHANED_USER_CODE);
+        assert!(!diagnostics[0].is_error());
+        let orphans = fs::read_to_string(dir.join(ORPHANED_USER_CODE_FILE)).unwrap();
+        assert!(orphans.contains("return ctx.Ready;"));
+
+        // once `a` is generated again its code moves back
+        let mut merge = UserCodeMerge::load(&dir, ".cs").unwrap();
+        merge.write("A.cs", &format!("{}{}", stub("a"), stub("b"))).unwrap();
+        assert!(merge.finish().unwrap().is_empty());
+        assert!(fs::read_to_string(dir.join("A.cs")).unwrap().contains("return ctx.Ready;"));
+        assert!(!dir.join(ORPHANED_USER_CODE_FILE).exists());
+    }
+}
@@ -0,0 +1,731 @@
+use crate::codegen::comment::one_line;
+use crate::codegen::naming::{camel_name, hook_verb, label_name, unique_name};
+use crate::codegen::user_code::UserCodeMerge;
+use crate::csharp::template::{
+    ContextTemplate, DispatcherTemplate, EventsTemplate, FieldView, HookView, OrthogonalView,
+    RecordView, StateEnumTemplate, TransitionTemplate, TransitionView,
+};
+use crate::cfg::cfg_node_kind::{JoinKind, StateKind};
+use crate::diag::diagnostic::Diagnostic;
+use crate::fsm::context_field::{ContextField, ContextType};
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::state_id::StateId;
+use anyhow::Result;
+use askama::Template;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+// Entry point: generate full C# FSM
+
+pub fn generate_csharp_fsm<P: AsRef<Path>>(fsm: &FSMachine, out_dir: P) -> Result<Vec<Diagnostic>> {
+    generate_csharp_fsm_bundle(fsm, out_dir.as_ref())
+}
+
+fn sanitize_enum_name(s:&str, id:&StateId) -> String {
+    let name = sanitize_fn_name(&format!("{}_{}", s, id.0));
+    // an identifier cannot start with a digit
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("s_{}", name)
+    } else {
+        name
+    }
+}
+
+/// The enum name of a state of a machine whose state names get `prefix`
+fn enum_name(prefix: &str, fsm: &FSMachine, id: &StateId) -> String {
+    format!("{}{}", prefix, sanitize_enum_name(&fsm.state_map()[id], id))
+}
+
+/// The name of a state without its id, for the names of the classes and
+/// hooks holding user code: they stay the same when states are added or
+/// removed elsewhere in the machine
+fn stable_name(prefix: &str, fsm: &FSMachine, id: &StateId) -> String {
+    let name = sanitize_fn_name(&fsm.state_map()[id]);
+    // an identifier cannot start with a digit
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}s_{}", prefix, name)
+    } else {
+        format!("{}{}", prefix, name)
+    }
+}
+
+/// Prefix of the state names of region `r` of the orthogonal state named
+/// `state`
+fn region_prefix(state: &str, r: usize) -> String {
+    format!("{}_r{}_", state, r)
+}
+
+/// Lines of the XML documentation of a state, its doc with `&`, `<` and
+/// `>` escaped; empty for an undocumented state
+fn doc_lines(fsm: &FSMachine, id: StateId) -> Vec<String> {
+    fsm.doc(id)
+        .map(|doc| doc.lines().map(xml_escape).collect())
+        .unwrap_or_default()
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// The `Events` hook of a typed state named `state` in the enum, named
+/// after its stable name `stable`; `None` for a plain state
+fn hook_view(fsm: &FSMachine, id: StateId, state: &str, stable: &str) -> Option<HookView> {
+    let kind = fsm.kind(id)?;
+    // the summary is a doc comment
+    let name = xml_escape(&one_line(&fsm.state_map()[&id]));
+    let summary = match kind {
+        StateKind::AcceptEvent => {
+            format!("Whether the event \"{}\" arrived; the machine waits in {} until it did", name, state)
+        }
+        StateKind::TimeEvent => {
+            format!("Whether the timeout \"{}\" elapsed; the machine waits in {} until it did", name, state)
+        }
+        StateKind::SendSignal => format!("Emits the signal \"{}\" on entering {}", name, state),
+        StateKind::Load => format!("Loads \"{}\" from the database on entering {}", name, state),
+        StateKind::Save => format!("Saves \"{}\" to the database on entering {}", name, state),
+    };
+    Some(HookView {
+        name: format!("{}_{}", camel_name(hook_verb(kind)), stable),
+        summary,
+        waits: kind.waits(),
+    })
+}
+
+/// Namespace of the transitions leaving a state of the partition `path`,
+/// relative to `GeneratedFSM`; empty outside of any partition
+fn lane_namespace(path: &[String]) -> String {
+    path.iter().map(|name| camel_name(name)).collect::<Vec<_>>().join(".")
+}
+
+/// The machine and, depth first, the machines of the regions of its
+/// orthogonal states, each with the prefix of its state names in the enum
+/// and the prefix of their stable names
+fn collect_machines<'a>(
+    fsm: &'a FSMachine,
+    prefix: String,
+    stable: String,
+    out: &mut Vec<(&'a FSMachine, String, String)>,
+) {
+    out.push((fsm, prefix.clone(), stable.clone()));
+    for (id, orthogonal) in fsm.orthogonals() {
+        let state = enum_name(&prefix, fsm, id);
+        let stable_state = stable_name(&stable, fsm, id);
+        for (r, region) in orthogonal.regions().iter().enumerate() {
+            collect_machines(region, region_prefix(&state, r), region_prefix(&stable_state, r), out);
+        }
+    }
+}
+
+/// Generate a full C# FSM bundle:
+/// - State.cs: the `State` enum and `IsTerminal()`
+/// - Context.cs: the `Context` class with the machine's context fields
+/// - Dispatcher.cs: `Step()` and `Run()`, which try the conditions of the
+///   current state in branch order and take the first that holds
+/// - Transition_X_Y.cs (one file per transition), named after the names
+///   of the states X and Y rather than their ids, numbered when several
+///   transitions connect equally named states
+///
+/// The notes documenting a state are written as XML documentation of its
+/// enum member and of the `Execute` of every transition into it.
+///
+/// A typed state gets a hook in the `Events` class of Events.cs, written
+/// only when there are typed states: an accept-event or time-event state
+/// is left only once its hook tells the event occurred, the hook of a
+/// send-signal, load or save state runs at the end of the `Execute` of
+/// every transition into it.
+///
+/// A transition leaving a state inside diagram groups is put into the
+/// namespace of its partition below `GeneratedFSM`, `Shop.Payment` for
+/// `partition Shop` around `partition Payment`, and written to
+/// `Shop.Payment.Transition_X_Y.cs`; every lane gets its own set of files.
+///
+/// The states of the regions of an orthogonal state are part of the
+/// `State` enum, prefixed with the orthogonal state's name and the region.
+/// While the machine is in an orthogonal state every step advances each
+/// region that has not terminated, the state's own transitions are only
+/// tried once its join condition holds.
+///
+/// The bodies of `CheckCondition` and `Execute` are user-code regions,
+/// code written there survives regenerating into the same directory.
+/// Hand-written code of transitions that are gone is kept aside and
+/// reported in the returned warnings.
+pub fn generate_csharp_fsm_bundle(
+    fsm: &FSMachine,
+    out_dir: &Path,
+) -> Result<Vec<Diagnostic>> {
+    let mut merge = UserCodeMerge::load(out_dir, ".cs")?;
+
+    // --------------------------------------------------
+    // 1. Collect states, the state map iterates in id order and the
+    //    states of the regions follow the machine they are in
+    // --------------------------------------------------
+
+    let mut machines = vec![];
+    collect_machines(fsm, String::new(), String::new(), &mut machines);
+
+    // the hooks of the typed states of every machine, in enum order;
+    // equally named states get numbered hooks
+    let mut used_hooks: HashMap<String, usize> = HashMap::new();
+    let hooks: Vec<(String, HookView)> = machines
+        .iter()
+        .flat_map(|(m, prefix, stable)| {
+            m.state_map().keys().filter_map(|id| {
+                let state = enum_name(prefix, m, id);
+                hook_view(m, *id, &state, &stable_name(stable, m, id)).map(|hook| (state, hook))
+            })
+        })
+        .map(|(state, mut hook)| {
+            hook.name = unique_name(&mut used_hooks, hook.name);
+            (state, hook)
+        })
+        .collect();
+
+    let state_names: Vec<String> = machines
+        .iter()
+        .flat_map(|(m, prefix, _)| m.state_map().keys().map(|id| enum_name(prefix, m, id)))
+        .collect();
+
+    // --------------------------------------------------
+    // 2. Generate State enum (State.cs)
+    // --------------------------------------------------
+
+    let state_enum = StateEnumTemplate {
+        states: state_names.clone(),
+        terminals: machines
+            .iter()
+            .flat_map(|(m, prefix, _)| m.terminals().iter().map(|id| enum_name(prefix, m, id)))
+            .collect(),
+        docs: machines
+            .iter()
+            .flat_map(|(m, prefix, _)| {
+                m.state_map()
+                    .keys()
+                    .filter(|id| m.doc(**id).is_some())
+                    .map(|id| (enum_name(prefix, m, id), doc_lines(m, *id)))
+            })
+            .collect(),
+    };
+
+    merge.write("State.cs", &state_enum.render()?)?;
+
+    let mut records = vec![];
+    let fields = fsm.context().iter().map(|f| field_view(f, &mut records)).collect();
+    merge.write("Context.cs", &ContextTemplate { fields, records }.render()?)?;
+
+    // --------------------------------------------------
+    // 3. Build transition views and dispatch map
+    // --------------------------------------------------
+
+    let mut transitions: Vec<TransitionView> = Vec::new();
+
+    // from_state -> list of transition class names with their branch order
+    let mut dispatch_map: BTreeMap<String, Vec<(u64, String)>> = BTreeMap::new();
+    let mut used_names: HashMap<String, usize> = HashMap::new();
+
+    // orthogonal state -> the start states of its regions and its join
+    let mut orthogonals: BTreeMap<String, OrthogonalView> = BTreeMap::new();
+
+    for (m, prefix, stable) in &machines {
+        for (id, orthogonal) in m.orthogonals() {
+            let state = enum_name(prefix, m, id);
+            let starts = orthogonal
+                .regions()
+                .iter()
+                .enumerate()
+                .map(|(r, region)| enum_name(&region_prefix(&state, r), region, &region.start_id()))
+                .collect();
+            // the regions are stepped even if the state has no way out
+            dispatch_map.entry(state.clone()).or_default();
+            orthogonals.insert(
+                state,
+                OrthogonalView { starts, join_all: orthogonal.join() == JoinKind::All },
+            );
+        }
+
+        for t in m.transitions() {
+            let from = enum_name(prefix, m, &t.from());
+            let to = enum_name(prefix, m, &t.to());
+            // the class holds user code, it is named after the states
+            // rather than their ids
+            let mut class_name = format!(
+                "Transition_{}_{}",
+                stable_name(stable, m, &t.from()),
+                stable_name(stable, m, &t.to())
+            );
+            // a labelled branch or arrow names the transition too
+            if let Some(label) = t.label().as_deref().and_then(label_name) {
+                class_name = format!("{}_{}", class_name, label);
+            }
+            // several transitions may connect equally named states
+            let class_name = unique_name(&mut used_names, class_name);
+
+            let lane = lane_namespace(m.partition(t.from()));
+            let (namespace, qualified) = if lane.is_empty() {
+                ("GeneratedFSM".to_string(), class_name.clone())
+            } else {
+                (format!("GeneratedFSM.{}", lane), format!("{}.{}", lane, class_name))
+            };
+
+            // the caption and label go into comments
+            let comment = match t.caption() {
+                Some(caption) => one_line(&caption),
+                None => format!("{} -> {}", from, to),
+            };
+
+            transitions.push(TransitionView {
+                from:from.clone(),
+                to:to.clone(),
+                class_name: class_name.clone(),
+                qualified_name: qualified.clone(),
+                namespace,
+                func_name: "".to_string(),
+                comment,
+                condition: t.condition().as_ref()
+                    .map(|cond| sanitize_fn_name(cond.as_str())),
+                fallback: t.cond().as_ref().is_some_and(|c| c.is_fallback()),
+                label: t.label().as_deref().map(one_line),
+                // the transition's action is the work of its target state
+                doc: doc_lines(m, t.to()),
+                enter_hook: hooks
+                    .iter()
+                    .find(|(state, hook)| *state == to && !hook.waits)
+                    .map(|(_, hook)| hook.name.clone()),
+            });
+
+            let seq = t.cond().as_ref().map_or(0, |c| c.seq());
+            dispatch_map
+                .entry(from)
+                .or_default()
+                .push((seq, qualified));
+        }
+    }
+    // the conditions of one decision are tried in branch order
+    let dispatch_map: BTreeMap<String, Vec<String>> = dispatch_map
+        .into_iter()
+        .map(|(from, mut classes)| {
+            classes.sort_by_key(|(seq, _)| *seq);
+            (from, classes.into_iter().map(|(_, class)| class).collect())
+        })
+        .collect();
+
+    // --------------------------------------------------
+    // 4. Generate Transition_*.cs files
+    // --------------------------------------------------
+
+    for tr in &transitions {
+        let tpl = TransitionTemplate {
+            t: tr.clone(),
+        };
+
+        merge.write(&format!("{}.cs", tr.qualified_name), &tpl.render()?)?;
+    }
+    // transitions of an earlier run that are gone, their hand-written
+    // code ends up with the orphans
+    merge.remove_stale(|name| {
+        (name.starts_with("Transition_") || name.contains(".Transition_")) && name.ends_with(".cs")
+    })?;
+
+    // --------------------------------------------------
+    // 5. Generate Dispatcher.cs
+    // --------------------------------------------------
+
+
+    let dispatcher = DispatcherTemplate {
+        start: sanitize_enum_name(&fsm.state_map()[&fsm.start_id()], &fsm.start_id()),
+        states: state_names
+            .iter()
+            .filter(|s| dispatch_map.contains_key(*s))
+            .cloned()
+            .collect(),
+        dispatch_map,
+        orthogonals,
+        waits: hooks
+            .iter()
+            .filter(|(_, hook)| hook.waits)
+            .map(|(state, hook)| (state.clone(), hook.name.clone()))
+            .collect(),
+    };
+
+    merge.write("Dispatcher.cs", &dispatcher.render()?)?;
+
+    // --------------------------------------------------
+    // 6. Generate Events.cs
+    // --------------------------------------------------
+
+    if !hooks.is_empty() {
+        let events = EventsTemplate { hooks: hooks.into_iter().map(|(_, hook)| hook).collect() };
+        merge.write("Events.cs", &events.render()?)?;
+    }
+    merge.remove_stale(|name| name == "Events.cs")?;
+
+    Ok(merge.finish()?)
+}
+
+/// Property of a context field, a record gets a class of its own which is
+/// added to `records`
+fn field_view(field: &ContextField, records: &mut Vec<RecordView>) -> FieldView {
+    let (ty, init) = match field.ty() {
+        ContextType::Bool => ("bool".to_string(), String::new()),
+        ContextType::Int => ("long".to_string(), String::new()),
+        ContextType::Float => ("double".to_string(), String::new()),
+        ContextType::String => ("string".to_string(), " = \"\";".to_string()),
+        // nothing is known about the type, the user assigns a value
+        ContextType::Named(name) => ("object".to_string(), format!(" // {}", name)),
+        ContextType::Record(fields) => {
+            let class_name = format!("{}Data", field.name());
+            let fields = fields.iter().map(|f| field_view(f, records)).collect();
+            records.push(RecordView { class_name: class_name.clone(), fields });
+            (class_name.clone(), format!(" = new {}();", class_name))
+        }
+    };
+    FieldView { ty, name: field.name().clone(), init }
+}
+
+#[allow(unused)]
+fn sanitize_fn_name(s: &str) -> String {
+    s.to_lowercase()
+        .replace(" ", "_")
+        .replace("=", "_eq_")
+        .replace(">", "_gt_")
+        .replace("<", "_lt_")
+        .replace("==", "_eq_")
+        .replace("!=", "_ne_")
+        .replace("&&", "_and_")
+        .replace("||", "_or_")
+        .replace(|c: char| !c.is_alphanumeric() && c != '_', "_")
+        .trim_matches('_')
+        .to_string()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::user_code::{ORPHANED_USER_CODE, ORPHANED_USER_CODE_FILE};
+    use crate::cfg::cfg_cond::CfgCond;
+    use crate::fsm::orthogonal_state::OrthogonalState;
+    use crate::fsm::transition::Transition;
+    use std::collections::HashSet;
+    use std::fs;
+
+    fn machine(to_ship: bool) -> FSMachine {
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Check".to_string()),
+            (StateId(2), "Ship".to_string()),
+            (StateId(3), "END".to_string()),
+        ]);
+        let mut transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(3), Some("out of stock".to_string())),
+            Transition::new(StateId(2), StateId(3), None),
+        ];
+        if to_ship {
+            transitions.push(Transition::new(StateId(1), StateId(2), Some("in stock".to_string())));
+        }
+        FSMachine::new(states, transitions, StateId(0), vec![StateId(3)])
+    }
+
+    /// Syntax-level check of a generated bundle: delimiters are balanced
+    /// and every type, state and transition class it refers to is declared
+    fn check_csharp(dir: &Path) {
+        let mut sources = vec![];
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "cs") {
+                sources.push((path.clone(), fs::read_to_string(&path).unwrap()));
+            }
+        }
+
+        let mut declared = HashSet::new();
+        let mut states = HashSet::new();
+        let mut words = vec![];
+        for (path, text) in &sources {
+            let mut stack = vec![];
+            let mut code = String::new();
+            for line in text.lines() {
+                // drop comments and string literals
+                let line = line.split("//").next().unwrap();
+                let mut in_string = false;
+                for c in line.chars() {
+                    match c {
+                        '"' => in_string = !in_string,
+                        _ if in_string => {}
+                        '{' | '(' | '[' => {
+                            stack.push(c);
+                            code.push(' ');
+                        }
+                        '}' | ')' | ']' => {
+                            code.push(' ');
+                            let open = stack.pop();
+                            let expected = match c { '}' => '{', ')' => '(', _ => '[' };
+                            assert_eq!(open, Some(expected), "unbalanced `{}` in {}", c, path.display());
+                        }
+                        _ => code.push(c),
+                    }
+                }
+                code.push('\n');
+            }
+            assert!(stack.is_empty(), "unclosed `{:?}` in {}", stack, path.display());
+            assert!(!text.contains("&#"), "escaped text in {}", path.display());
+
+            let tokens: Vec<_> = code
+                .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '.')
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect();
+            for pair in tokens.windows(2) {
+                if pair[0] == "class" || pair[0] == "enum" {
+                    declared.insert(pair[1].clone());
+                }
+                if path.ends_with("Events.cs") && (pair[0] == "bool" || pair[0] == "void") {
+                    declared.insert(format!("Events.{}", pair[1]));
+                }
+            }
+            if path.ends_with("State.cs") {
+                let body = &text[text.find("enum State").unwrap()..];
+                let body = &body[body.find('{').unwrap() + 1..body.find('}').unwrap()];
+                states.extend(body.split(',').map(|s| s.trim().to_string()));
+            }
+            words.extend(tokens);
+        }
+
+        for word in words {
+            if let Some(state) = word.strip_prefix("State.") {
+                assert!(states.contains(state), "unknown state `{}`", state);
+            } else if word.starts_with("Events.") {
+                assert!(declared.contains(&word), "unknown hook `{}`", word);
+            } else if let Some(class) = word.strip_suffix(".CheckCondition") {
+                // a class in the namespace of a partition is qualified
+                let class = class.rsplit('.').next().unwrap();
+                assert!(declared.contains(class), "unknown class `{}`", class);
+            }
+        }
+        for ty in ["State", "StateExtensions", "Context", "Dispatcher"] {
+            assert!(declared.contains(ty), "`{}` is not declared", ty);
+        }
+    }
+
+    #[test]
+    fn test_generated_code_is_complete() {
+        let out_dir = std::env::temp_dir().join("common_csharp_gen");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = FSMachine::new(
+            BTreeMap::from([
+                (StateId(0), "START".to_string()),
+                (StateId(1), "Check".to_string()),
+                (StateId(2), "2nd try".to_string()),
+                (StateId(3), "END".to_string()),
+            ]),
+            vec![
+                Transition::new(StateId(0), StateId(1), None),
+                Transition::new(StateId(1), StateId(3), Some(" 1 else".to_string()))
+                    .with_cond(Some(CfgCond::new(1, "else".to_string()).with_fallback(true))),
+                Transition::new(StateId(1), StateId(2), Some("retry? 0 if".to_string()))
+                    .with_cond(Some(CfgCond::new(0, "if".to_string()))),
+                Transition::new(StateId(2), StateId(1), None),
+            ],
+            StateId(0),
+            vec![StateId(3)],
+        );
+        let order = vec![ContextField::new("qty".to_string(), ContextType::Int)];
+        fsm.set_context(vec![
+            ContextField::new("Order".to_string(), ContextType::Record(order)),
+            ContextField::new("note".to_string(), ContextType::String),
+            ContextField::new("items".to_string(), ContextType::Named("Set[Item]".to_string())),
+        ]);
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        check_csharp(&out_dir);
+
+        let context = fs::read_to_string(out_dir.join("Context.cs")).unwrap();
+        assert!(context.contains("public OrderData Order { get; set; } = new OrderData();"));
+        assert!(context.contains("public long qty { get; set; }"));
+        let state = fs::read_to_string(out_dir.join("State.cs")).unwrap();
+        assert!(state.contains("s_2nd_try_2"));
+        assert!(state.contains("case State.end_3:"));
+
+        let dispatcher = fs::read_to_string(out_dir.join("Dispatcher.cs")).unwrap();
+        assert!(dispatcher.contains("public const State Start = State.start_0;"));
+        // the `if` branch is tried before `else`
+        let if_branch = dispatcher.find("Transition_check_s_2nd_try.CheckCondition").unwrap();
+        let else_branch = dispatcher.find("Transition_check_end.CheckCondition").unwrap();
+        assert!(if_branch < else_branch);
+        // the `else` branch is taken whenever `if` is not
+        let if_class = fs::read_to_string(out_dir.join("Transition_check_s_2nd_try.cs")).unwrap();
+        assert!(if_class.contains("return false;"));
+        let else_class = fs::read_to_string(out_dir.join("Transition_check_end.cs")).unwrap();
+        assert!(else_class.contains("return true;"));
+        assert!(!else_class.contains("return false;"));
+    }
+
+    #[test]
+    fn test_regeneration_keeps_user_code() {
+        let out_dir = std::env::temp_dir().join("common_csharp_gen_user_code");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        assert!(generate_csharp_fsm(&machine(true), &out_dir).unwrap().is_empty());
+
+        let ship = out_dir.join("Transition_check_ship.cs");
+        let dispatcher = fs::read_to_string(out_dir.join("Dispatcher.cs")).unwrap();
+        assert!(dispatcher.contains("Transition_check_ship.CheckCondition(_ctx)"));
+        let text = fs::read_to_string(&ship).unwrap();
+        fs::write(&ship, text.replace("return false;", "return ctx.InStock;")).unwrap();
+
+        // regenerating the same machine keeps the edit
+        assert!(generate_csharp_fsm(&machine(true), &out_dir).unwrap().is_empty());
+        assert!(fs::read_to_string(&ship).unwrap().contains("return ctx.InStock;"));
+
+        // the transition is gone, its code is reported and kept aside
+        let diagnostics = generate_csharp_fsm(&machine(false), &out_dir).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), ORPHANED_USER_CODE);
+        assert!(diagnostics[0].message().contains("Transition_check_ship.CheckCondition"));
+        assert!(!ship.exists());
+        let orphans = fs::read_to_string(out_dir.join(ORPHANED_USER_CODE_FILE)).unwrap();
+        assert!(orphans.contains("return ctx.InStock;"));
+
+        // and restored when it comes back
+        assert!(generate_csharp_fsm(&machine(true), &out_dir).unwrap().is_empty());
+        assert!(fs::read_to_string(&ship).unwrap().contains("return ctx.InStock;"));
+        assert!(!out_dir.join(ORPHANED_USER_CODE_FILE).exists());
+
+        // a state inserted ahead of the others renumbers them, the code
+        // stays with the transition
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Reserve".to_string()),
+            (StateId(2), "Check".to_string()),
+            (StateId(3), "Ship".to_string()),
+            (StateId(4), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(2), None),
+            Transition::new(StateId(2), StateId(4), Some("out of stock".to_string())),
+            Transition::new(StateId(3), StateId(4), None),
+            Transition::new(StateId(2), StateId(3), Some("in stock".to_string())),
+        ];
+        let fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(4)]);
+        assert!(generate_csharp_fsm(&fsm, &out_dir).unwrap().is_empty());
+        assert!(fs::read_to_string(&ship).unwrap().contains("return ctx.InStock;"));
+    }
+
+    #[test]
+    fn test_orthogonal_regions() {
+        let out_dir = std::env::temp_dir().join("common_csharp_gen_orthogonal");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let region = |name: &str| {
+            FSMachine::new(
+                BTreeMap::from([
+                    (StateId(0), "START".to_string()),
+                    (StateId(1), name.to_string()),
+                    (StateId(2), "END".to_string()),
+                ]),
+                vec![
+                    Transition::new(StateId(0), StateId(1), None),
+                    Transition::new(StateId(1), StateId(2), None),
+                ],
+                StateId(0),
+                vec![StateId(2)],
+            )
+        };
+        let mut fsm = FSMachine::new(
+            BTreeMap::from([
+                (StateId(0), "START".to_string()),
+                (StateId(1), "fork".to_string()),
+                (StateId(2), "END".to_string()),
+            ]),
+            vec![
+                Transition::new(StateId(0), StateId(1), None),
+                Transition::new(StateId(1), StateId(2), None),
+            ],
+            StateId(0),
+            vec![StateId(2)],
+        );
+        let regions = vec![region("Pay"), region("Reserve")];
+        fsm.add_orthogonal(StateId(1), OrthogonalState::new(regions, JoinKind::All));
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        check_csharp(&out_dir);
+
+        let state = fs::read_to_string(out_dir.join("State.cs")).unwrap();
+        assert!(state.contains("fork_1_r1_reserve_1,"));
+        assert!(state.contains("case State.fork_1_r0_end_2:"));
+        assert!(out_dir.join("Transition_fork_r0_pay_fork_r0_end.cs").exists());
+
+        let dispatcher = fs::read_to_string(out_dir.join("Dispatcher.cs")).unwrap();
+        assert!(dispatcher.contains(
+            "StepRegions(State.fork_1, new State[] { State.fork_1_r0_start_0, State.fork_1_r1_start_0 });"
+        ));
+        assert!(dispatcher.contains("System.Array.TrueForAll(_regions[State.fork_1], r => r.IsTerminal())"));
+        assert!(dispatcher.contains("_regions.Remove(State.fork_1);"));
+        assert!(dispatcher.contains("case State.fork_1_r1_reserve_1:"));
+    }
+
+    #[test]
+    fn test_partition_namespaces() {
+        let out_dir = std::env::temp_dir().join("common_csharp_gen_partitions");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = machine(true);
+        fsm.set_partition(StateId(1), vec!["Warehouse".to_string()]);
+        fsm.set_partition(StateId(2), vec!["Warehouse".to_string(), "loading dock".to_string()]);
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        check_csharp(&out_dir);
+
+        let check = fs::read_to_string(out_dir.join("Warehouse.Transition_check_ship.cs")).unwrap();
+        assert!(check.contains("namespace GeneratedFSM.Warehouse\n"));
+        let ship = fs::read_to_string(out_dir.join("Warehouse.LoadingDock.Transition_ship_end.cs")).unwrap();
+        assert!(ship.contains("namespace GeneratedFSM.Warehouse.LoadingDock\n"));
+        assert!(out_dir.join("Transition_start_check.cs").exists());
+        let dispatcher = fs::read_to_string(out_dir.join("Dispatcher.cs")).unwrap();
+        assert!(dispatcher.contains("if (Warehouse.Transition_check_ship.CheckCondition(_ctx))"));
+
+        // moving a state out of its partition moves the file
+        generate_csharp_fsm(&machine(true), &out_dir).unwrap();
+        assert!(!out_dir.join("Warehouse.Transition_check_ship.cs").exists());
+        assert!(out_dir.join("Transition_check_ship.cs").exists());
+    }
+
+    #[test]
+    fn test_event_hooks() {
+        let out_dir = std::env::temp_dir().join("common_csharp_gen_events");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = FSMachine::new(
+            BTreeMap::from([
+                (StateId(0), "START".to_string()),
+                (StateId(1), "Payment received".to_string()),
+                (StateId(2), "Notify shop <email>".to_string()),
+                (StateId(3), "END".to_string()),
+            ]),
+            vec![
+                Transition::new(StateId(0), StateId(1), None),
+                Transition::new(StateId(1), StateId(2), None),
+                Transition::new(StateId(2), StateId(3), None),
+            ],
+            StateId(0),
+            vec![StateId(3)],
+        );
+        fsm.set_kind(StateId(1), Some(StateKind::AcceptEvent));
+        fsm.set_kind(StateId(2), Some(StateKind::SendSignal));
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        check_csharp(&out_dir);
+
+        let events = fs::read_to_string(out_dir.join("Events.cs")).unwrap();
+        assert!(events.contains("public static bool Accept_payment_received(Context ctx)"));
+        assert!(events.contains("/// Emits the signal \"Notify shop &lt;email&gt;\" on entering notify_shop__lt_email_gt__2\n"));
+        let dispatcher = fs::read_to_string(out_dir.join("Dispatcher.cs")).unwrap();
+        assert!(dispatcher.contains("if (!Events.Accept_payment_received(_ctx))"));
+        let notify = fs::read_to_string(out_dir.join("Transition_payment_received_notify_shop__lt_email_gt.cs")).unwrap();
+        assert!(notify.contains("Events.Send_notify_shop__lt_email_gt(ctx);\n            return State.notify_shop__lt_email_gt__2;"));
+
+        // without typed states there are no hooks
+        fsm.set_kind(StateId(1), None);
+        fsm.set_kind(StateId(2), None);
+        generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        assert!(!out_dir.join("Events.cs").exists());
+        check_csharp(&out_dir);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod csharp_gen;
+mod template;
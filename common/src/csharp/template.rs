@@ -0,0 +1,113 @@
+use askama::Template;
+use std::collections::BTreeMap;
+
+/// Askama templates
+#[derive(Template)]
+#[template(path = "csharp/states.cs.j2", escape = "none")]
+pub struct StateEnumTemplate {
+    pub states: Vec<String>,
+    pub terminals: Vec<String>,
+    /// state -> the lines of its XML documentation, for documented states
+    pub docs: BTreeMap<String, Vec<String>>,
+}
+
+/// The `Context` class and one class per record field
+#[derive(Template)]
+#[template(path = "csharp/context.cs.j2", escape = "none")]
+pub struct ContextTemplate {
+    pub fields: Vec<FieldView>,
+    pub records: Vec<RecordView>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldView {
+    pub ty: String,
+    pub name: String,
+    /// text after the property: an initializer with its `;`, a comment or
+    /// nothing
+    pub init: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordView {
+    pub class_name: String,
+    pub fields: Vec<FieldView>,
+}
+
+
+/// FSM dispatcher template
+#[derive(Template)]
+#[template(path = "csharp/dispatcher.cs.j2", escape = "none")]
+pub struct DispatcherTemplate {
+    pub start: String,
+
+    /// states with outgoing transitions, in state id order
+    pub states: Vec<String>,
+
+    /// from_state -> list of Transition class names, in the order their
+    /// conditions are evaluated
+    pub dispatch_map: BTreeMap<String, Vec<String>>,
+
+    /// orthogonal states, their transitions are tried once the regions joined
+    pub orthogonals: BTreeMap<String, OrthogonalView>,
+
+    /// waiting state -> the `Events` hook telling whether its event occurred
+    pub waits: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrthogonalView {
+    /// start state of every region, in region order
+    pub starts: Vec<String>,
+    /// whether every region has to terminate, otherwise any one does
+    pub join_all: bool,
+}
+
+
+/// One transition = one C# file
+#[derive(Template)]
+#[template(path = "csharp/transition.cs.j2", escape = "none")]
+pub struct TransitionTemplate {
+    pub t: TransitionView,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransitionView {
+    pub from: String,
+    pub to: String,
+    pub class_name: String,
+    /// the class name relative to `GeneratedFSM`, with its lane's namespace
+    pub qualified_name: String,
+    pub namespace: String,
+    #[allow(unused)]
+    pub func_name: String,
+    pub comment: String,
+    pub condition: Option<String>,
+    /// whether the branch is taken when the branches before it are not
+    pub fallback: bool,
+    /// the label of the diagram's branch or arrow
+    pub label: Option<String>,
+    /// the lines of the target state's XML documentation
+    pub doc: Vec<String>,
+    /// the `Events` hook run on entering the target state
+    pub enter_hook: Option<String>,
+}
+
+
+/// The hooks of the typed states, one C# file
+#[derive(Template)]
+#[template(path = "csharp/events.cs.j2", escape = "none")]
+pub struct EventsTemplate {
+    pub hooks: Vec<HookView>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HookView {
+    pub name: String,
+    pub summary: String,
+    /// whether the hook tells if the state's event occurred, otherwise it
+    /// runs on entering the state
+    pub waits: bool,
+}
+
+
@@ -0,0 +1,148 @@
+use crate::diag::severity::Severity;
+use crate::diag::source_span::SourceSpan;
+use std::fmt;
+
+/// A problem found while parsing, lowering or converting a specification.
+///
+/// Diagnostics are collected and handed back to the caller instead of
+/// aborting, so a single run can report every problem it finds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    severity: Severity,
+    /// Stable identifier of the kind of problem (e.g. "AD0001")
+    code: &'static str,
+    message: String,
+    span: Option<Box<SourceSpan>>,
+    notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &'static str, message: String) -> Diagnostic {
+        Self {
+            severity,
+            code,
+            message,
+            span: None,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn error(code: &'static str, message: String) -> Diagnostic {
+        Self::new(Severity::Error, code, message)
+    }
+
+    pub fn warning(code: &'static str, message: String) -> Diagnostic {
+        Self::new(Severity::Warning, code, message)
+    }
+
+    /// Attaches the source range the diagnostic refers to
+    pub fn with_span(mut self, span: SourceSpan) -> Diagnostic {
+        self.span = Some(Box::new(span));
+        self
+    }
+
+    /// Appends a free-form note giving more context
+    pub fn with_note(mut self, note: String) -> Diagnostic {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_deref()
+    }
+
+    pub fn notes(&self) -> &Vec<String> {
+        &self.notes
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Displays the diagnostic with the input it was found in
+    pub fn located<'a>(&'a self, origin: &'a str) -> Located<'a> {
+        Located { diagnostic: self, origin }
+    }
+
+    fn fmt_in(&self, f: &mut fmt::Formatter<'_>, origin: Option<&str>) -> fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.message)?;
+        match (origin, &self.span) {
+            (Some(origin), Some(span)) => write!(f, "\n  --> {}:{}", origin, span.start)?,
+            (Some(origin), None) => write!(f, "\n  --> {}", origin)?,
+            (None, Some(span)) => write!(f, "\n  --> {}", span.start)?,
+            (None, None) => {}
+        }
+        for note in &self.notes {
+            write!(f, "\n  = note: {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+/// A diagnostic displayed with the input it was found in, see
+/// [`Diagnostic::located`]
+pub struct Located<'a> {
+    diagnostic: &'a Diagnostic,
+    origin: &'a str,
+}
+
+impl fmt::Display for Located<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diagnostic.fmt_in(f, Some(self.origin))
+    }
+}
+
+/// Returns true if any diagnostic in the list is an error
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|d| d.is_error())
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_in(f, None)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diag::source_span::SourcePos;
+
+    #[test]
+    fn test_display() {
+        let span = SourceSpan::new(
+            SourcePos { line: 3, column: 5 },
+            SourcePos { line: 3, column: 9 },
+            20,
+            24,
+        );
+        let d = Diagnostic::error("AD0001", "syntax error".to_string())
+            .with_span(span)
+            .with_note("while parsing if_statement".to_string());
+        assert_eq!(
+            d.to_string(),
+            "error[AD0001]: syntax error\n  --> 3:5\n  = note: while parsing if_statement"
+        );
+        assert_eq!(
+            d.located("main.puml").to_string(),
+            "error[AD0001]: syntax error\n  --> main.puml:3:5\n  = note: while parsing if_statement"
+        );
+        assert!(has_errors(&[d]));
+        let d = Diagnostic::warning("AD0100", "unused".to_string());
+        assert_eq!(d.located("<stdin>").to_string(), "warning[AD0100]: unused\n  --> <stdin>");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod diagnostic;
+pub mod parse_context;
+pub mod severity;
+pub mod source_span;
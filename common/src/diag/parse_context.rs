@@ -0,0 +1,114 @@
+use anyhow::Result;
+use crate::diag::diagnostic::Diagnostic;
+use crate::diag::source_span::{SourcePos, SourceSpan};
+use tree_sitter::{Node, Point};
+
+/// Source text and diagnostics of one tree-sitter parse
+///
+/// Each front end passes its own codes for syntax errors and missing
+/// tokens.
+pub struct ParseContext {
+    text: String,
+    /// the text the user wrote, `text` may be a rewrite of it
+    source: String,
+    diagnostics: Vec<Diagnostic>,
+    syntax_error: &'static str,
+    missing_token: &'static str,
+}
+
+impl ParseContext {
+    pub fn new(text: String, syntax_error: &'static str, missing_token: &'static str) -> ParseContext {
+        Self {
+            source: text.clone(),
+            text,
+            diagnostics: Vec::new(),
+            syntax_error,
+            missing_token,
+        }
+    }
+
+    /// Sets the source the parsed text was rewritten from
+    ///
+    /// A rewrite must keep the lines of the source and the columns of
+    /// the text before its end, text it adds at the end of a line is
+    /// mapped to that end.
+    pub fn with_source(mut self, source: String) -> ParseContext {
+        self.source = source;
+        self
+    }
+
+    pub fn text_of_node(&self, node: &Node) -> Result<String> {
+        let text = node.utf8_text(self.text.as_bytes())?;
+        Ok(text.to_string())
+    }
+
+    pub fn report(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    pub fn diagnostics(&self) -> &Vec<Diagnostic> {
+        &self.diagnostics
+    }
+
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics
+    }
+
+    /// Source range of a node, with 1-based line and column
+    pub fn span_of(&self, node: &Node) -> SourceSpan {
+        let (start, start_byte) = self.source_pos(node.start_position());
+        let (end, end_byte) = self.source_pos(node.end_position());
+        SourceSpan::new(start, end, start_byte, end_byte)
+    }
+
+    /// Position and byte offset in the source of a point of the parsed text
+    fn source_pos(&self, point: Point) -> (SourcePos, usize) {
+        let mut line_start = 0;
+        for _ in 0..point.row {
+            match self.source[line_start..].find('\n') {
+                Some(i) => line_start += i + 1,
+                None => break,
+            }
+        }
+        let line = &self.source[line_start..];
+        let line_len = line.find(['\r', '\n']).unwrap_or(line.len());
+        let column = point.column.min(line_len);
+        (SourcePos { line: point.row + 1, column: column + 1 }, line_start + column)
+    }
+
+    /// Reports an `ERROR` or missing node produced by tree-sitter
+    pub fn report_syntax_error(&mut self, node: Node) -> Result<()> {
+        let span = self.span_of(&node);
+        let diagnostic = if node.is_missing() {
+            Diagnostic::error(self.missing_token, format!("missing `{}`", node.kind()))
+        } else {
+            // the source rather than its rewrite
+            let text = self.source.get(span.start_byte..span.end_byte).unwrap_or_default();
+            // an error node may swallow several lines, the first one is enough
+            let first_line = text.trim().lines().next().unwrap_or_default().to_string();
+            Diagnostic::error(self.syntax_error, format!("unexpected `{}`", first_line))
+        };
+        let kind = if let Some(parent) = node.parent() {
+            parent.kind()
+        } else {
+            "root"
+        };
+        let diagnostic = diagnostic
+            .with_span(span)
+            .with_note(format!("while parsing {}", kind));
+        self.report(diagnostic);
+        Ok(())
+    }
+}
+
+/// Source range of a node, with 1-based line and column
+pub fn span_of_node(node: &Node) -> SourceSpan {
+    let start = node.start_position();
+    let end = node.end_position();
+    SourceSpan::new(
+        SourcePos { line: start.row + 1, column: start.column + 1 },
+        SourcePos { line: end.row + 1, column: end.column + 1 },
+        node.start_byte(),
+        node.end_byte(),
+    )
+}
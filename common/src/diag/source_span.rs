@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// A position in source text, line and column start at 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourcePos {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A range of source text a diagnostic refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceSpan {
+    pub start: SourcePos,
+    pub end: SourcePos,
+    /// Byte offsets of `start` and `end` in the source text
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+impl SourceSpan {
+    pub fn new(start: SourcePos, end: SourcePos, start_byte: usize, end_byte: usize) -> SourceSpan {
+        Self {
+            start,
+            end,
+            start_byte,
+            end_byte,
+        }
+    }
+}
+
+impl fmt::Display for SourcePos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
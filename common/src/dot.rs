@@ -0,0 +1,8 @@
+//! Helpers shared by the Graphviz DOT writers.
+
+/// Escapes special characters for DOT format
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")  // Escape backslashes
+        .replace('"', "\\\"") // Escape quotes
+        .replace('\n', "\\n") // Escape newlines
+}
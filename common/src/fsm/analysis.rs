@@ -0,0 +1,278 @@
+//! Structural analysis of a state machine
+//!
+//! [`analyze_fsm`] looks for states a run can never get to or never get
+//! out of, without evaluating any guard: every transition is assumed to be
+//! takeable. Problems of reachable states only are reported, the states
+//! behind an unreachable one are not looked at again. The machines of
+//! composite states are analyzed as well, once per activity, and so are
+//! the regions of orthogonal states.
+
+use crate::diag::diagnostic::Diagnostic;
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::state_id::StateId;
+use crate::fsm::transition::Transition;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+/// Diagnostic code: no path leads from the start state to the state
+pub const UNREACHABLE_STATE: &str = "FSM0301";
+/// Diagnostic code: a non-terminal state has no outgoing transition
+pub const DEADLOCK_STATE: &str = "FSM0302";
+/// Diagnostic code: no path leads from the state to a terminal state
+pub const NO_TERMINAL_REACHABLE: &str = "FSM0303";
+/// Diagnostic code: every transition out of a decision is guarded and
+/// none is taken when all guards fail
+pub const NO_FALLBACK: &str = "FSM0304";
+
+/// Kind of problem found in a machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindingKind {
+    Unreachable,
+    Deadlock,
+    NoTerminalReachable,
+    NoFallback,
+}
+
+/// A problem found in one state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    kind: FindingKind,
+    state: StateId,
+    name: String,
+    /// Where the machine the state belongs to is nested, outermost first:
+    /// ``activity `X` `` for the machine of a composite state, ``region 1
+    /// of state `fork` (id 2)`` for a region; empty for the analyzed machine
+    scopes: Vec<String>,
+}
+
+impl Finding {
+    pub fn kind(&self) -> FindingKind {
+        self.kind
+    }
+
+    pub fn state(&self) -> StateId {
+        self.state
+    }
+
+    /// Name of the state
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn scopes(&self) -> &Vec<String> {
+        &self.scopes
+    }
+
+    /// The finding as a warning
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let (code, what) = match self.kind {
+            FindingKind::Unreachable => (UNREACHABLE_STATE, "is unreachable from the start state"),
+            FindingKind::Deadlock => (DEADLOCK_STATE, "is not terminal but has no outgoing transition"),
+            FindingKind::NoTerminalReachable => (NO_TERMINAL_REACHABLE, "cannot reach a terminal state"),
+            FindingKind::NoFallback => (NO_FALLBACK, "has only guarded transitions and no fallback"),
+        };
+        let mut d = Diagnostic::warning(
+            code,
+            format!("state `{}` (id {}) {}", self.name, self.state.0, what),
+        );
+        for scope in self.scopes.iter().rev() {
+            d = d.with_note(format!("in {}", scope));
+        }
+        d
+    }
+}
+
+/// Analyzes a machine and the machines of its composite states, the
+/// findings of one machine are ordered by state id and kind
+pub fn analyze_fsm(fsm: &FSMachine) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut analyzed = HashSet::new();
+    analyze_machine(fsm, &mut vec![], &mut analyzed, &mut findings);
+    findings
+}
+
+fn analyze_machine(
+    fsm: &FSMachine,
+    scopes: &mut Vec<String>,
+    analyzed: &mut HashSet<String>,
+    findings: &mut Vec<Finding>,
+) {
+    let reachable = closure(fsm.start_id(), fsm.transitions(), |t| (t.from(), t.to()));
+    let mut can_terminate = BTreeSet::new();
+    for terminal in fsm.terminals() {
+        can_terminate.extend(closure(*terminal, fsm.transitions(), |t| (t.to(), t.from())));
+    }
+
+    for id in fsm.state_map().keys() {
+        let mut found = |kind| {
+            findings.push(Finding {
+                kind,
+                state: *id,
+                name: fsm.state_map()[id].clone(),
+                scopes: scopes.clone(),
+            })
+        };
+        if !reachable.contains(id) {
+            found(FindingKind::Unreachable);
+            continue;
+        }
+        if fsm.terminals().contains(id) {
+            continue;
+        }
+        let outgoing: Vec<_> = fsm.transitions().iter().filter(|t| t.from() == *id).collect();
+        if outgoing.is_empty() {
+            found(FindingKind::Deadlock);
+            continue;
+        }
+        if !can_terminate.contains(id) {
+            found(FindingKind::NoTerminalReachable);
+        }
+        if outgoing.iter().all(|t| is_guarded(t)) {
+            found(FindingKind::NoFallback);
+        }
+    }
+
+    for composite in fsm.composites().values() {
+        let Some(machine) = composite.machine() else {
+            continue;
+        };
+        if !analyzed.insert(composite.activity().clone()) {
+            continue;
+        }
+        scopes.push(format!("activity `{}`", composite.activity()));
+        analyze_machine(machine, scopes, analyzed, findings);
+        scopes.pop();
+    }
+
+    for (id, orthogonal) in fsm.orthogonals() {
+        for (r, region) in orthogonal.regions().iter().enumerate() {
+            scopes.push(format!("region {} of state `{}` (id {})", r + 1, fsm.state_map()[id], id.0));
+            analyze_machine(region, scopes, analyzed, findings);
+            scopes.pop();
+        }
+    }
+}
+
+/// Whether a transition is only taken when its guard holds
+fn is_guarded(t: &Transition) -> bool {
+    match t.cond() {
+        Some(cond) => !cond.is_fallback(),
+        None => t.condition().is_some(),
+    }
+}
+
+/// States reachable from `start` along the edges `edge` makes of the
+/// transitions, `start` included
+fn closure(
+    start: StateId,
+    transitions: &[Transition],
+    edge: impl Fn(&Transition) -> (StateId, StateId),
+) -> BTreeSet<StateId> {
+    let mut seen = BTreeSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(id) = queue.pop_front() {
+        for t in transitions {
+            let (from, to) = edge(t);
+            if from == id && seen.insert(to) {
+                queue.push_back(to);
+            }
+        }
+    }
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg::cfg_cond::CfgCond;
+    use crate::cfg::cfg_node_kind::JoinKind;
+    use crate::fsm::orthogonal_state::OrthogonalState;
+    use std::collections::BTreeMap;
+
+    fn kinds(findings: &[Finding]) -> Vec<(usize, FindingKind)> {
+        findings.iter().map(|f| (f.state().0, f.kind())).collect()
+    }
+
+    #[test]
+    fn test_analyze_fsm() {
+        let states = (0..7).map(|i| (StateId(i), format!("S{}", i))).collect::<BTreeMap<_, _>>();
+        let guarded = |from, to, seq| {
+            Transition::new(StateId(from), StateId(to), Some(format!("x 0 x > {}", seq)))
+                .with_cond(Some(CfgCond::new(seq, format!("x > {}", seq))))
+        };
+        let transitions = vec![
+            // 1 decides without a fallback, 2 is stuck, 3 and 4 loop forever
+            Transition::new(StateId(0), StateId(1), None),
+            guarded(1, 2, 0),
+            guarded(1, 3, 1),
+            guarded(1, 5, 2),
+            Transition::new(StateId(3), StateId(4), None),
+            Transition::new(StateId(4), StateId(3), None),
+            // 6 is unreachable
+            Transition::new(StateId(6), StateId(5), None),
+        ];
+        let fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(5)]);
+        let findings = analyze_fsm(&fsm);
+        assert_eq!(
+            kinds(&findings),
+            vec![
+                (1, FindingKind::NoFallback),
+                (2, FindingKind::Deadlock),
+                (3, FindingKind::NoTerminalReachable),
+                (4, FindingKind::NoTerminalReachable),
+                (6, FindingKind::Unreachable),
+            ]
+        );
+        let d = findings[1].to_diagnostic();
+        assert_eq!(d.code(), DEADLOCK_STATE);
+        assert!(!d.is_error());
+        assert_eq!(d.message(), "state `S2` (id 2) is not terminal but has no outgoing transition");
+
+        // an `else` branch is a fallback, the nested machine is analyzed too
+        let states = (0..3).map(|i| (StateId(i), format!("T{}", i))).collect();
+        let transitions = vec![
+            guarded(0, 1, 0),
+            Transition::new(StateId(0), StateId(2), Some(" 1 else".to_string()))
+                .with_cond(Some(CfgCond::new(1, "else".to_string()).with_fallback(true))),
+            Transition::new(StateId(1), StateId(2), None),
+        ];
+        let mut outer = FSMachine::new(states, transitions, StateId(0), vec![StateId(2)]);
+        assert!(analyze_fsm(&outer).is_empty());
+        outer.add_composite(StateId(1), "inner".to_string());
+        outer.composite_mut(StateId(1)).unwrap().set_machine(fsm);
+        let findings = analyze_fsm(&outer);
+        assert_eq!(findings.len(), 5);
+        assert_eq!(findings[0].scopes(), &vec!["activity `inner`".to_string()]);
+        assert_eq!(findings[0].to_diagnostic().notes(), &vec!["in activity `inner`".to_string()]);
+    }
+
+    #[test]
+    fn test_analyze_regions() {
+        let region = |stuck: bool| {
+            let states = (0..3).map(|i| (StateId(i), format!("R{}", i))).collect();
+            let mut transitions = vec![Transition::new(StateId(0), StateId(1), None)];
+            if !stuck {
+                transitions.push(Transition::new(StateId(1), StateId(2), None));
+            }
+            FSMachine::new(states, transitions, StateId(0), vec![StateId(2)])
+        };
+        let states = (0..3).map(|i| (StateId(i), format!("S{}", i))).collect();
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(2), None),
+        ];
+        let mut fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(2)]);
+        let regions = vec![region(false), region(true)];
+        fsm.add_orthogonal(StateId(1), OrthogonalState::new(regions, JoinKind::All));
+        let findings = analyze_fsm(&fsm);
+        // the second region gets stuck in R1 and never terminates
+        assert_eq!(
+            kinds(&findings),
+            vec![
+                (0, FindingKind::NoTerminalReachable),
+                (1, FindingKind::Deadlock),
+                (2, FindingKind::Unreachable),
+            ]
+        );
+        assert_eq!(findings[0].scopes(), &vec!["region 2 of state `S1` (id 1)".to_string()]);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::fsm::fs_machine::FSMachine;
+
+/// A state that runs the machine of another activity diagram
+///
+/// Entering the state starts the nested machine, its transitions are
+/// taken once the nested machine reached a terminal state. The machine
+/// is `None` until the activity has been resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeState {
+    activity: String,
+    machine: Option<FSMachine>,
+}
+
+impl CompositeState {
+    pub fn new(activity: String) -> CompositeState {
+        Self { activity, machine: None }
+    }
+
+    /// Name of the referenced activity
+    pub fn activity(&self) -> &String {
+        &self.activity
+    }
+
+    /// The nested machine, once resolved
+    pub fn machine(&self) -> Option<&FSMachine> {
+        self.machine.as_ref()
+    }
+
+    pub fn set_machine(&mut self, machine: FSMachine) {
+        self.machine = Some(machine);
+    }
+}
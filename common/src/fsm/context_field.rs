@@ -0,0 +1,77 @@
+use std::fmt;
+
+/// A field of the data the guards and actions of a machine work on
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextField {
+    name: String,
+    ty: ContextType,
+}
+
+/// Type of a context field, independent of the target language
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextType {
+    Bool,
+    Int,
+    Float,
+    String,
+    /// A type the machine knows only by name, e.g. a relation
+    Named(String),
+    /// A group of fields, e.g. one SCL `context` declaration
+    Record(Vec<ContextField>),
+}
+
+impl ContextField {
+    pub fn new(name: String, ty: ContextType) -> ContextField {
+        Self { name, ty }
+    }
+
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &ContextType {
+        &self.ty
+    }
+}
+
+impl ContextType {
+    /// The type written as `name`: one of the primitive names, any other
+    /// name is a [`ContextType::Named`] type
+    pub fn from_name(name: &str) -> ContextType {
+        match name {
+            "bool" => ContextType::Bool,
+            "int" => ContextType::Int,
+            "float" => ContextType::Float,
+            "string" => ContextType::String,
+            _ => ContextType::Named(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ContextField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.ty)
+    }
+}
+
+impl fmt::Display for ContextType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextType::Bool => write!(f, "bool"),
+            ContextType::Int => write!(f, "int"),
+            ContextType::Float => write!(f, "float"),
+            ContextType::String => write!(f, "string"),
+            ContextType::Named(name) => write!(f, "{}", name),
+            ContextType::Record(fields) => {
+                write!(f, "{{ ")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", field)?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Sidecar schema of a machine's context
+//!
+//! A diagram has no notion of the data its guards read, the fields can be
+//! listed next to it in a text file, one `name: type` per line:
+//!
+//! ```text
+//! # order.context
+//! qty: int
+//! customer: string
+//! ```
+//!
+//! Types are `bool`, `int`, `float`, `string` or any other name, which
+//! the generators treat as opaque. Empty lines and lines starting with
+//! `#` are skipped.
+
+use crate::diag::diagnostic::Diagnostic;
+use crate::diag::source_span::{SourcePos, SourceSpan};
+use crate::fsm::context_field::{ContextField, ContextType};
+use std::collections::HashSet;
+
+/// Diagnostic code: a line of a context schema is not a `name: type` pair
+/// or declares a field twice
+pub const INVALID_CONTEXT_SCHEMA: &str = "FSM0201";
+
+/// Reads the fields of a context schema, in file order
+pub fn parse_context_schema(text: &str) -> Result<Vec<ContextField>, Vec<Diagnostic>> {
+    let mut fields = vec![];
+    let mut names = HashSet::new();
+    let mut diagnostics = vec![];
+    let mut offset = 0;
+    for (i, raw) in text.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let span = SourceSpan::new(
+            SourcePos { line: i + 1, column: 1 },
+            SourcePos { line: i + 1, column: line.len() + 1 },
+            offset,
+            offset + line.len(),
+        );
+        offset += raw.len();
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((name, ty)) = line.split_once(':') else {
+            diagnostics.push(
+                Diagnostic::error(INVALID_CONTEXT_SCHEMA, format!("expected `name: type`, found `{}`", line))
+                    .with_span(span),
+            );
+            continue;
+        };
+        let (name, ty) = (name.trim(), ty.trim());
+        if !is_identifier(name) || !is_identifier(ty) {
+            diagnostics.push(
+                Diagnostic::error(INVALID_CONTEXT_SCHEMA, format!("`{}` is not a field declaration", line))
+                    .with_span(span)
+                    .with_note("names and types are identifiers".to_string()),
+            );
+            continue;
+        }
+        if !names.insert(name.to_string()) {
+            diagnostics.push(
+                Diagnostic::error(INVALID_CONTEXT_SCHEMA, format!("field `{}` is declared twice", name))
+                    .with_span(span),
+            );
+            continue;
+        }
+        fields.push(ContextField::new(name.to_string(), ContextType::from_name(ty)));
+    }
+    if diagnostics.is_empty() {
+        Ok(fields)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_context_schema() {
+        let fields = parse_context_schema("# order\nqty: int\n\ncustomer : string\nstock: Stock\n").unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                ContextField::new("qty".to_string(), ContextType::Int),
+                ContextField::new("customer".to_string(), ContextType::String),
+                ContextField::new("stock".to_string(), ContextType::Named("Stock".to_string())),
+            ]
+        );
+
+        let diagnostics = parse_context_schema("qty: int\nqty: float\nbroken\nx: a b\n").unwrap_err();
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics.iter().all(|d| d.code() == INVALID_CONTEXT_SCHEMA));
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.span().unwrap().start.line).collect();
+        assert_eq!(lines, vec![2, 3, 4]);
+    }
+}
@@ -1,19 +1,49 @@
 use crate::cfg::cf_graph::CFGraph;
-use crate::cfg::cfg_node_kind::{CfgNodeKind, NodeId};
-use std::collections::HashMap;
+use crate::cfg::cfg_cond::CfgEdge;
+use crate::cfg::cfg_node_kind::{CfgNodeKind, JoinKind, NodeId, StateKind};
+use crate::diag::diagnostic::Diagnostic;
+use std::collections::{BTreeMap, HashMap};
 
 /// Finite State Machine (FSM) representation
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FSMachine {
-    states: HashMap<StateId, String>, // Maps StateId to state name
+    states: BTreeMap<StateId, String>, // Maps StateId to state name, by id
     transitions: Vec<Transition>,     // List of transitions between states
     start: StateId,                   // Starting state ID
     terminals: Vec<StateId>,          // Terminal/accepting state IDs
+    composites: BTreeMap<StateId, CompositeState>, // States owning a nested machine
+    orthogonals: BTreeMap<StateId, OrthogonalState>, // States with concurrent regions
+    partitions: BTreeMap<StateId, Vec<String>>, // Group path of the states inside groups
+    docs: BTreeMap<StateId, String>,  // Notes describing the states
+    kinds: BTreeMap<StateId, StateKind>, // Event and database states
+    context: Vec<ContextField>,       // Data the guards and actions work on
 }
 
 impl FSMachine {
-    /// Returns reference to the state mapping
-    pub fn state_map(&self) -> &HashMap<StateId, String> {
+    /// Creates a machine from its parts, `start` and every terminal must
+    /// be a key of `states`
+    pub fn new(
+        states: BTreeMap<StateId, String>,
+        transitions: Vec<Transition>,
+        start: StateId,
+        terminals: Vec<StateId>,
+    ) -> FSMachine {
+        Self {
+            states,
+            transitions,
+            start,
+            terminals,
+            composites: BTreeMap::new(),
+            orthogonals: BTreeMap::new(),
+            partitions: BTreeMap::new(),
+            docs: BTreeMap::new(),
+            kinds: BTreeMap::new(),
+            context: vec![],
+        }
+    }
+
+    /// Returns reference to the state mapping, iterated in id order
+    pub fn state_map(&self) -> &BTreeMap<StateId, String> {
         &self.states
     }
 
@@ -24,22 +54,121 @@ impl FSMachine {
 
     /// Returns the starting state ID
     pub fn start_id(&self) -> StateId {
-        self.start.clone()
+        self.start
     }
 
     /// Returns reference to terminal states
     pub fn terminals(&self) -> &Vec<StateId> {
         &self.terminals
     }
+
+    /// Returns the composite states, iterated in id order
+    pub fn composites(&self) -> &BTreeMap<StateId, CompositeState> {
+        &self.composites
+    }
+
+    /// Returns the composite state of `id`, if it is one
+    pub fn composite(&self, id: StateId) -> Option<&CompositeState> {
+        self.composites.get(&id)
+    }
+
+    pub fn composite_mut(&mut self, id: StateId) -> Option<&mut CompositeState> {
+        self.composites.get_mut(&id)
+    }
+
+    /// Makes the state `id` run the machine of `activity`, the machine
+    /// itself is attached with [`CompositeState::set_machine`]
+    pub fn add_composite(&mut self, id: StateId, activity: String) {
+        self.composites.insert(id, CompositeState::new(activity));
+    }
+
+    /// Returns the orthogonal states, iterated in id order
+    pub fn orthogonals(&self) -> &BTreeMap<StateId, OrthogonalState> {
+        &self.orthogonals
+    }
+
+    /// Returns the orthogonal state of `id`, if it is one
+    pub fn orthogonal(&self, id: StateId) -> Option<&OrthogonalState> {
+        self.orthogonals.get(&id)
+    }
+
+    pub fn orthogonal_mut(&mut self, id: StateId) -> Option<&mut OrthogonalState> {
+        self.orthogonals.get_mut(&id)
+    }
+
+    /// Makes the state `id` run the regions of `state` concurrently
+    pub fn add_orthogonal(&mut self, id: StateId, state: OrthogonalState) {
+        self.orthogonals.insert(id, state);
+    }
+
+    /// Returns the path of the diagram groups (`partition`, `group`, ...)
+    /// around the state, outermost first; empty outside of any group
+    pub fn partition(&self, id: StateId) -> &[String] {
+        self.partitions.get(&id).map_or(&[], |p| p.as_slice())
+    }
+
+    /// Puts the state `id` into the partition `path`, an empty path takes
+    /// it out of every partition
+    pub fn set_partition(&mut self, id: StateId, path: Vec<String>) {
+        if path.is_empty() {
+            self.partitions.remove(&id);
+        } else {
+            self.partitions.insert(id, path);
+        }
+    }
+
+    /// Returns the documentation of the state, the text of the diagram's
+    /// notes on the action it was made of
+    pub fn doc(&self, id: StateId) -> Option<&str> {
+        self.docs.get(&id).map(String::as_str)
+    }
+
+    /// Documents the state `id`, an empty text removes its documentation
+    pub fn set_doc(&mut self, id: StateId, doc: String) {
+        if doc.is_empty() {
+            self.docs.remove(&id);
+        } else {
+            self.docs.insert(id, doc);
+        }
+    }
+
+    /// Returns the kind of the state, from the SDL stereotype of the action
+    /// it was made of; `None` for a plain state
+    pub fn kind(&self, id: StateId) -> Option<StateKind> {
+        self.kinds.get(&id).copied()
+    }
+
+    /// Gives the state `id` a kind, `None` makes it a plain state
+    pub fn set_kind(&mut self, id: StateId, kind: Option<StateKind>) {
+        match kind {
+            Some(kind) => self.kinds.insert(id, kind),
+            None => self.kinds.remove(&id),
+        };
+    }
+
+    /// Returns the fields of the machine's context, in declaration order
+    pub fn context(&self) -> &Vec<ContextField> {
+        &self.context
+    }
+
+    pub fn set_context(&mut self, context: Vec<ContextField>) {
+        self.context = context;
+    }
 }
 
+/// Diagnostic code: the CFG has no START node
+pub const NO_START_STATE: &str = "FSM0001";
+
 /// Converts Control Flow Graph (CFG) to Finite State Machine (FSM)
-pub fn cfg_to_fsm(cfg: &CFGraph) -> FSMachine {
+pub fn cfg_to_fsm(cfg: &CFGraph) -> Result<FSMachine, Diagnostic> {
     // Maps CFG node IDs to FSM state IDs
     let mut state_map: HashMap<NodeId, StateId> = HashMap::new();
-    let mut states = HashMap::new();      // FSM states
+    let mut states = BTreeMap::new();     // FSM states
     let mut transitions = Vec::new();     // FSM transitions
     let mut terminals = Vec::new();       // Terminal states
+    let mut start = None;                 // State of the START node
+    let mut composites = BTreeMap::new(); // States running another activity
+    let mut orthogonals = BTreeMap::new(); // States running concurrent regions
 
     let mut next_state_id = 0;            // Counter for generating unique state IDs
 
@@ -47,17 +176,18 @@ pub fn cfg_to_fsm(cfg: &CFGraph) -> FSMachine {
     let mut new_state = |name: String| {
         let id = StateId(next_state_id);
         next_state_id += 1;
-        states.insert(id.clone(), name);
+        states.insert(id, name);
         id
     };
 
-    // Step 1: Create FSM states for Action/Start/End nodes
+    // Step 1: Create FSM states for Action/Activity/Fork/Start/End nodes
     for (i, node) in cfg.nodes.iter().enumerate() {
         let node_id = NodeId(i);
 
         match node {
             CfgNodeKind::Start => {
                 let sid = new_state("START".into());
+                start.get_or_insert(sid);
                 state_map.insert(node_id, sid);
             }
 
@@ -66,9 +196,41 @@ pub fn cfg_to_fsm(cfg: &CFGraph) -> FSMachine {
                 state_map.insert(node_id, sid);
             }
 
+            CfgNodeKind::Activity(name) => {
+                let sid = new_state(name.clone());
+                composites.insert(sid, CompositeState::new(name.clone()));
+                state_map.insert(node_id, sid);
+            }
+
+            CfgNodeKind::Fork(branches) => {
+                // every branch becomes a region with a machine of its own
+                let sid = new_state("fork".into());
+                let regions = branches.iter().map(cfg_to_fsm).collect::<Result<Vec<_>, _>>()?;
+                let join = cfg.edges.keys()
+                    .filter(|(from, _)| *from == node_id)
+                    .find_map(|(_, to)| match &cfg.nodes[to.0] {
+                        CfgNodeKind::Join(join) => Some(*join),
+                        _ => None,
+                    })
+                    .unwrap_or(JoinKind::All);
+                orthogonals.insert(sid, OrthogonalState::new(regions, join));
+                state_map.insert(node_id, sid);
+            }
+
+            CfgNodeKind::Join(_) => {
+                // the flow leaves the fork's state once the join holds, the
+                // join stands for the state of the fork before it
+                let fork = cfg.edges.keys()
+                    .find(|(from, to)| *to == node_id && matches!(cfg.nodes[from.0], CfgNodeKind::Fork(_)))
+                    .and_then(|(from, _)| state_map.get(from).copied());
+                if let Some(sid) = fork {
+                    state_map.insert(node_id, sid);
+                }
+            }
+
             CfgNodeKind::End => {
                 let sid = new_state("END".into());
-                terminals.push(sid.clone());  // End nodes are terminal states
+                terminals.push(sid);  // End nodes are terminal states
                 state_map.insert(node_id, sid);
             }
 
@@ -80,70 +242,162 @@ pub fn cfg_to_fsm(cfg: &CFGraph) -> FSMachine {
         }
     }
 
-    // Find the START state
-    let start = state_map
+    let start = start.ok_or_else(|| {
+        Diagnostic::error(NO_START_STATE, "no START state found".to_string())
+    })?;
+
+    // a state belongs to the groups around the node it was made of, a
+    // join stands for its fork's state
+    let mut partitions = BTreeMap::new();
+    for (node, path) in &cfg.partitions {
+        if let Some(sid) = state_map.get(node)
+            && !matches!(cfg.nodes[node.0], CfgNodeKind::Join(_)) {
+            partitions.insert(*sid, path.clone());
+        }
+    }
+
+    // a note after a fork is written after its join, it documents the
+    // fork's state all the same
+    let mut docs = BTreeMap::new();
+    for (node, doc) in &cfg.notes {
+        if let Some(sid) = state_map.get(node) {
+            docs.insert(*sid, doc.clone());
+        }
+    }
+    let kinds = cfg.kinds
         .iter()
-        .find(|(_, s)| states[s] == "START")
-        .map(|(_, s)| s)
-        .expect("No START state found")
-        .clone();
+        .filter_map(|(node, kind)| state_map.get(node).map(|sid| (*sid, *kind)))
+        .collect();
 
-    // Step 2: Resolve transitions between states
+    // Step 2: Resolve transitions between states, the edges are ordered
+    // by (from, to) so the transitions come out in the same order on
+    // every run
     for ((from, to), edge) in cfg.edges.iter() {
         let from_node = &cfg.nodes[from.0];
         let to_node = &cfg.nodes[to.0];
 
+        // an activity or a fork is a state like an action, it only owns
+        // machines; a join is the state of its fork
         match (from_node, to_node) {
-            // Direct transitions between Action states or Start/End
-            (CfgNodeKind::Action(_), CfgNodeKind::Action(_))
-            | (CfgNodeKind::Start, CfgNodeKind::Action(_))
-            | (CfgNodeKind::Action(_), CfgNodeKind::End)
-            | (CfgNodeKind::Start, CfgNodeKind::End) => {
-                transitions.push(Transition::new(
-                    state_map[&from].clone(),
-                    state_map[&to].clone(),
-                    edge.condition(),
-                ));
-            }
+            // The regions of the fork run in between
+            (CfgNodeKind::Fork(_), CfgNodeKind::Join(_)) => {}
 
-            // Decision node resolving to Action
-            (CfgNodeKind::Decision(_), CfgNodeKind::Action(_)) => {
-                // Find all predecessors of this decision node
-                for (_, pred) in cfg.edges.iter()
-                    .filter(|(_, e)| e.to == edge.from) {
-                    if let Some(from_state) = state_map.get(&pred.from) {
-                        // Create transition from predecessor to target action
-                        transitions.push(Transition::new(
-                            from_state.clone(),
-                            state_map[&edge.to].clone(),
-                            edge.condition(),
-                        ));
-                    }
+            // Direct transitions between Action states or Start/End
+            (f, t) if (is_state(f) || matches!(f, CfgNodeKind::Start))
+                && (is_state(t) || matches!(t, CfgNodeKind::End)) => {
+                if let (Some(from_state), Some(to_state)) = (state_map.get(from), state_map.get(to)) {
+                    transitions.push(
+                        Transition::new(*from_state, *to_state, edge.condition())
+                            .with_cond(edge.cond.clone())
+                            .with_label(edge.label.clone()),
+                    );
                 }
             }
 
-            // Action to Decision (delayed resolution)
-            (CfgNodeKind::Action(_), CfgNodeKind::Decision(_)) => {
-                // Handled when the decision node resolves to an action
+            // A state entering a decision moves to every state the
+            // decision resolves to, through the decisions nested in it
+            (f, CfgNodeKind::Decision(_)) if is_state(f) || matches!(f, CfgNodeKind::Start) => {
+                let Some(from_state) = state_map.get(from) else {
+                    continue;
+                };
+                for (target, path) in decision_paths(cfg, *to, &mut vec![]) {
+                    let Some(to_state) = state_map.get(&target) else {
+                        continue;
+                    };
+                    // nested branches are taken one after the other
+                    let cond = path
+                        .iter()
+                        .filter_map(|e| e.cond.clone())
+                        .reduce(|outer, inner| outer.nested(&inner));
+                    // the innermost label wins, an arrow into the decision
+                    // labels it unless a branch has a label of its own
+                    let label = path
+                        .iter()
+                        .rev()
+                        .find_map(|e| e.label.clone())
+                        .or_else(|| edge.label.clone());
+                    transitions.push(
+                        Transition::new(*from_state, *to_state, cond.as_ref().map(|c| c.to_string()))
+                            .with_cond(cond)
+                            .with_label(label),
+                    );
+                }
             }
 
             _ => {} // Other cases not relevant for FSM
         }
     }
 
-    FSMachine {
+    Ok(FSMachine {
         states,
         transitions,
         start,
         terminals,
+        composites,
+        orthogonals,
+        partitions,
+        docs,
+        kinds,
+        context: vec![],
+    })
+}
+
+/// Every state or end node `decision` resolves to, with the edges taken
+/// from `decision` to it, in branch order. A branch leading into another
+/// decision continues with that decision's branches; decisions already on
+/// `path` are skipped, a loop of decisions never reaches a state.
+fn decision_paths<'a>(
+    cfg: &'a CFGraph,
+    decision: NodeId,
+    path: &mut Vec<NodeId>,
+) -> Vec<(NodeId, Vec<&'a CfgEdge>)> {
+    path.push(decision);
+    let mut branches: Vec<&CfgEdge> = cfg.edges.values().filter(|e| e.from == decision).collect();
+    branches.sort_by_key(|e| e.cond.as_ref().map_or(0, |c| c.seq()));
+
+    let mut paths = vec![];
+    for e in branches {
+        match &cfg.nodes[e.to.0] {
+            CfgNodeKind::Decision(_) if !path.contains(&e.to) => {
+                for (target, mut rest) in decision_paths(cfg, e.to, path) {
+                    rest.insert(0, e);
+                    paths.push((target, rest));
+                }
+            }
+            t if is_state(t) || matches!(t, CfgNodeKind::End) => paths.push((e.to, vec![e])),
+            _ => {}
+        }
     }
+    path.pop();
+    paths
 }
 
+/// Whether a CFG node becomes a state of its own, or stands for one
+fn is_state(node: &CfgNodeKind) -> bool {
+    matches!(
+        node,
+        CfgNodeKind::Action(_) | CfgNodeKind::Activity(_) | CfgNodeKind::Fork(_) | CfgNodeKind::Join(_)
+    )
+}
+
+use crate::fsm::composite_state::CompositeState;
+use crate::fsm::context_field::ContextField;
+use crate::fsm::orthogonal_state::OrthogonalState;
 use crate::fsm::state_id::StateId;
 use crate::fsm::transition::Transition;
+use crate::dot::escape;
 use std::fmt::Write;
 
 /// Converts FSM to Graphviz DOT format for visualization
+///
+/// The nested machine of a resolved composite state is drawn inside a
+/// cluster around the composite state, its node names are prefixed with
+/// the composite state's node name. An orthogonal state gets a cluster
+/// labelled with its join condition, holding one cluster per region.
+/// The states of a partition are drawn in a cluster labelled with the
+/// group's name, nested like the groups of the diagram. A documented
+/// state shows its documentation as tooltip, a typed state its
+/// stereotype above the name.
 pub fn fsm_to_dot(fsm: &FSMachine) -> String {
     let mut out = String::new();
 
@@ -151,59 +405,146 @@ pub fn fsm_to_dot(fsm: &FSMachine) -> String {
     writeln!(&mut out, "  rankdir=LR;").unwrap(); // Left-to-right layout
     writeln!(&mut out).unwrap();
 
+    write_dot_machine(&mut out, fsm, "", 1);
+
+    writeln!(&mut out, "}}").unwrap();
+    out
+}
+
+/// Writes the nodes and transitions of one (possibly nested) machine
+fn write_dot_machine(out: &mut String, fsm: &FSMachine, prefix: &str, depth: usize) {
+    let indent = "  ".repeat(depth);
+
     // -------------------------
-    // Node definitions
+    // Node definitions, one cluster per partition
     // -------------------------
-    for (id, name) in &fsm.states {
-        // Start and terminal states get double circles
-        let shape = if *id == fsm.start_id() || fsm.terminals().contains(id) {
-            "doublecircle"
-        } else {
-            "circle"
-        };
-
-        writeln!(
-            &mut out,
-            "  S{} [label=\"{}\", shape={}];",
-            id.0,
-            escape(name),
-            shape
-        )
-            .unwrap();
+    let mut by_partition: BTreeMap<&[String], Vec<StateId>> = BTreeMap::new();
+    for id in fsm.states.keys() {
+        by_partition.entry(fsm.partition(*id)).or_default().push(*id);
+    }
+    // a partition sorts right after the partition enclosing it
+    let mut open: Vec<&String> = vec![];
+    let mut clusters = 0;
+    for (path, ids) in by_partition {
+        let common = open.iter().zip(path).take_while(|(a, b)| **a == *b).count();
+        while open.len() > common {
+            open.pop();
+            writeln!(out, "{}{}}}", indent, "  ".repeat(open.len())).unwrap();
+        }
+        for name in &path[common..] {
+            let cluster_indent = format!("{}{}", indent, "  ".repeat(open.len()));
+            writeln!(out, "{}subgraph cluster_{}P{} {{", cluster_indent, prefix, clusters).unwrap();
+            writeln!(out, "{}  label=\"{}\";", cluster_indent, escape(name)).unwrap();
+            clusters += 1;
+            open.push(name);
+        }
+        for id in ids {
+            write_dot_state(out, fsm, id, prefix, depth + open.len());
+        }
+    }
+    while !open.is_empty() {
+        open.pop();
+        writeln!(out, "{}{}}}", indent, "  ".repeat(open.len())).unwrap();
     }
 
-    writeln!(&mut out).unwrap();
+    writeln!(out).unwrap();
 
     // -------------------------
     // Transitions
     // -------------------------
     for t in &fsm.transitions {
-        match t.condition() {
+        match t.caption() {
             Some(cond) => {
-                // Transition with condition label
+                // Transition with a label or condition
                 writeln!(
-                    &mut out,
-                    "  S{} -> S{} [label=\"{}\"];",
+                    out,
+                    "{}{}S{} -> {}S{} [label=\"{}\"];",
+                    indent,
+                    prefix,
                     t.from().0,
+                    prefix,
                     t.to().0,
-                    escape(cond)
+                    escape(&cond)
                 )
                     .unwrap();
             }
             None => {
                 // Unconditional transition
-                writeln!(&mut out, "  S{} -> S{};", t.from().0, t.to().0).unwrap();
+                writeln!(out, "{}{}S{} -> {}S{};", indent, prefix, t.from().0, prefix, t.to().0)
+                    .unwrap();
             }
         }
     }
-
-    writeln!(&mut out, "}}").unwrap();
-    out
 }
 
-/// Escapes special characters for DOT format
-fn escape(s: &str) -> String {
-    s.replace('\\', "\\\\")  // Escape backslashes
-        .replace('"', "\\\"") // Escape quotes
-        .replace('\n', "\\n") // Escape newlines
-}
\ No newline at end of file
+/// Writes the node of one state, and the clusters of the machines it owns
+fn write_dot_state(out: &mut String, fsm: &FSMachine, id: StateId, prefix: &str, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let name = &fsm.states[&id];
+    // Start and terminal states get double circles
+    let shape = if id == fsm.start_id() || fsm.terminals().contains(&id) {
+        "doublecircle"
+    } else {
+        "circle"
+    };
+    let node = format!("{}S{}", prefix, id.0);
+    let label = match fsm.kind(id) {
+        Some(kind) => format!("<<{}>>\n{}", kind, name),
+        None => name.clone(),
+    };
+    let mut attrs = format!("label=\"{}\", shape={}", escape(&label), shape);
+    if let Some(doc) = fsm.doc(id) {
+        attrs.push_str(&format!(", tooltip=\"{}\"", escape(doc)));
+    }
+
+    if let Some(orthogonal) = fsm.orthogonal(id) {
+        // Orthogonal state: the regions are side by side in its cluster
+        writeln!(out, "{}subgraph cluster_{} {{", indent, node).unwrap();
+        writeln!(out, "{}  label=\"{} (join {})\";", indent, escape(name), orthogonal.join())
+            .unwrap();
+        writeln!(out, "{}  {} [{}];", indent, node, attrs)
+            .unwrap();
+        for (r, region) in orthogonal.regions().iter().enumerate() {
+            let nested = format!("{}_R{}_", node, r);
+            writeln!(out, "{}  subgraph cluster_{}_R{} {{", indent, node, r).unwrap();
+            writeln!(out, "{}    label=\"region {}\";", indent, r + 1).unwrap();
+            write_dot_machine(out, region, &nested, depth + 2);
+            writeln!(out, "{}  }}", indent).unwrap();
+            writeln!(
+                out,
+                "{}  {} -> {}S{} [style=dashed];",
+                indent,
+                node,
+                nested,
+                region.start_id().0
+            )
+                .unwrap();
+        }
+        writeln!(out, "{}}}", indent).unwrap();
+        return;
+    }
+
+    let Some(machine) = fsm.composite(id).and_then(|c| c.machine()) else {
+        writeln!(out, "{}{} [{}];", indent, node, attrs)
+            .unwrap();
+        return;
+    };
+
+    // Composite state: the state and its machine share a cluster
+    writeln!(out, "{}subgraph cluster_{} {{", indent, node).unwrap();
+    writeln!(out, "{}  label=\"{}\";", indent, escape(name)).unwrap();
+    writeln!(out, "{}  {} [{}];", indent, node, attrs)
+        .unwrap();
+    let nested = format!("{}_", node);
+    write_dot_machine(out, machine, &nested, depth + 1);
+    writeln!(
+        out,
+        "{}  {} -> {}S{} [style=dashed];",
+        indent,
+        node,
+        nested,
+        machine.start_id().0
+    )
+        .unwrap();
+    writeln!(out, "{}}}", indent).unwrap();
+}
@@ -0,0 +1,551 @@
+//! JSON interchange format for [`FSMachine`]
+//!
+//! A machine is written as one object, version 2 of the schema is:
+//!
+//! ```json
+//! {
+//!   "version": 2,
+//!   "start": 0,
+//!   "terminals": [3],
+//!   "states": [
+//!     { "id": 0, "name": "START" },
+//!     { "id": 1, "name": "Load Configuration" }
+//!   ],
+//!   "transitions": [
+//!     { "from": 0, "to": 1, "condition": " 0 ok?", "cond": { "seq": 0, "name": "ok?" }, "label": "yes" },
+//!     { "from": 1, "to": 3, "condition": null, "cond": null }
+//!   ]
+//! }
+//! ```
+//!
+//! - `version`: schema version, raised whenever the schema gains fields; a
+//!   loader reads every version up to its own and rejects newer ones.
+//!   Version 2 added `partition`, `doc`, `kind`, `label`, `fallback`,
+//!   `composites`, `orthogonals` and `context`, all of them optional; a
+//!   version 1 document that has any of them is rejected
+//! - `start`: id of the start state
+//! - `terminals`: ids of the terminal states
+//! - `states`: every state with its unique id and name, in id order;
+//!   `partition` is the optional path of the diagram groups around the
+//!   state, outermost first, left out when the state is in none; `doc`
+//!   is the optional documentation of the state, from the diagram's notes;
+//!   `kind` is optional too: `acceptEvent` and `timeEvent` states wait for
+//!   an event, `sendSignal` states emit one, `load` and `save` states
+//!   access the database
+//! - `transitions`: in the machine's order; `condition` is the guard text
+//!   shown in DOT and the generated code, `cond` the branch it was taken
+//!   from: `seq` orders the branches of one decision, `name` is the guard
+//!   text of an `if`, `elseif`, switch case or `when` branch, `true` or
+//!   `false` for the body and exit of a loop, or the kind of a fallback
+//!   branch (`else`, `default`, `otherwise`), and `fallback`, left out
+//!   when false, is true for the branch taken when no other branch is.
+//!   Both are `null` for an unconditional transition and `condition` ends
+//!   with ` {seq} {name}` when both are present. `label` is the optional
+//!   label of the diagram's branch or arrow, left out when there is none.
+//! - `composites`: optional, the states that run the machine of another
+//!   activity: `state` is the composite state's id, `activity` the name of
+//!   the activity and `machine` its nested machine in this same schema,
+//!   `null` while unresolved. Left out when the machine has none.
+//! - `orthogonals`: optional, the states whose regions run concurrently:
+//!   `state` is the orthogonal state's id, `join` is `all` or `any`, the
+//!   number of regions that must reach a terminal state before the state
+//!   is left, and `regions` the region machines in this same schema. Left
+//!   out when the machine has none.
+//! - `context`: optional, the fields the guards and actions work on, in
+//!   declaration order: `name` and `type`, which is `bool`, `int`,
+//!   `float`, `string`, `record` for a group of `fields` in this same
+//!   form, or the name of an opaque type. Left out when empty.
+//!
+//! Objects with unknown fields are rejected.
+
+use crate::cfg::cfg_cond::CfgCond;
+use crate::cfg::cfg_node_kind::{JoinKind, StateKind};
+use crate::diag::diagnostic::Diagnostic;
+use crate::diag::source_span::{SourcePos, SourceSpan};
+use crate::fsm::context_field::{ContextField, ContextType};
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::orthogonal_state::OrthogonalState;
+use crate::fsm::state_id::StateId;
+use crate::fsm::transition::Transition;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Version of the schema written by [`fsm_to_json`]
+pub const FSM_JSON_VERSION: u32 = 2;
+
+/// Diagnostic code: the text is not JSON or does not match the schema
+pub const INVALID_JSON: &str = "FSM0101";
+/// Diagnostic code: the document has a schema version this loader does not know
+pub const UNSUPPORTED_VERSION: &str = "FSM0102";
+/// Diagnostic code: two states share one id
+pub const DUPLICATE_STATE: &str = "FSM0103";
+/// Diagnostic code: an id refers to no state
+pub const UNKNOWN_STATE: &str = "FSM0104";
+/// Diagnostic code: the condition text does not match its branch
+pub const INCONSISTENT_CONDITION: &str = "FSM0105";
+/// Diagnostic code: the document has a field its schema version does not
+pub const FIELD_NOT_IN_VERSION: &str = "FSM0106";
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FsmDoc {
+    version: u32,
+    start: usize,
+    terminals: Vec<usize>,
+    states: Vec<StateDoc>,
+    transitions: Vec<TransitionDoc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    composites: Vec<CompositeDoc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    orthogonals: Vec<OrthogonalDoc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    context: Vec<ContextFieldDoc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StateDoc {
+    id: usize,
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    partition: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kind: Option<KindDoc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum KindDoc {
+    AcceptEvent,
+    SendSignal,
+    TimeEvent,
+    Load,
+    Save,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TransitionDoc {
+    from: usize,
+    to: usize,
+    condition: Option<String>,
+    cond: Option<CondDoc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompositeDoc {
+    state: usize,
+    activity: String,
+    machine: Option<Box<FsmDoc>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrthogonalDoc {
+    state: usize,
+    join: JoinDoc,
+    regions: Vec<FsmDoc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum JoinDoc {
+    All,
+    Any,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContextFieldDoc {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<ContextFieldDoc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CondDoc {
+    seq: u64,
+    name: String,
+    #[serde(default, skip_serializing_if = "is_false")]
+    fallback: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Writes a machine as pretty printed JSON
+pub fn fsm_to_json(fsm: &FSMachine) -> String {
+    // the document only holds strings and numbers, it always serializes
+    serde_json::to_string_pretty(&fsm_to_doc(fsm)).unwrap()
+}
+
+fn fsm_to_doc(fsm: &FSMachine) -> FsmDoc {
+    FsmDoc {
+        version: FSM_JSON_VERSION,
+        start: fsm.start_id().0,
+        terminals: fsm.terminals().iter().map(|id| id.0).collect(),
+        states: fsm
+            .state_map()
+            .iter()
+            .map(|(id, name)| StateDoc {
+                id: id.0,
+                name: name.clone(),
+                partition: fsm.partition(*id).to_vec(),
+                doc: fsm.doc(*id).map(str::to_string),
+                kind: fsm.kind(*id).map(|kind| match kind {
+                    StateKind::AcceptEvent => KindDoc::AcceptEvent,
+                    StateKind::SendSignal => KindDoc::SendSignal,
+                    StateKind::TimeEvent => KindDoc::TimeEvent,
+                    StateKind::Load => KindDoc::Load,
+                    StateKind::Save => KindDoc::Save,
+                }),
+            })
+            .collect(),
+        transitions: fsm
+            .transitions()
+            .iter()
+            .map(|t| TransitionDoc {
+                from: t.from().0,
+                to: t.to().0,
+                condition: t.condition().clone(),
+                cond: t.cond().as_ref().map(|c| CondDoc {
+                    seq: c.seq(),
+                    name: c.name().clone(),
+                    fallback: c.is_fallback(),
+                }),
+                label: t.label().clone(),
+            })
+            .collect(),
+        composites: fsm
+            .composites()
+            .iter()
+            .map(|(id, c)| CompositeDoc {
+                state: id.0,
+                activity: c.activity().clone(),
+                machine: c.machine().map(|m| Box::new(fsm_to_doc(m))),
+            })
+            .collect(),
+        orthogonals: fsm
+            .orthogonals()
+            .iter()
+            .map(|(id, o)| OrthogonalDoc {
+                state: id.0,
+                join: match o.join() {
+                    JoinKind::All => JoinDoc::All,
+                    JoinKind::Any => JoinDoc::Any,
+                },
+                regions: o.regions().iter().map(fsm_to_doc).collect(),
+            })
+            .collect(),
+        context: fsm.context().iter().map(field_to_doc).collect(),
+    }
+}
+
+fn field_to_doc(field: &ContextField) -> ContextFieldDoc {
+    let (ty, fields) = match field.ty() {
+        ContextType::Record(fields) => ("record".to_string(), fields.iter().map(field_to_doc).collect()),
+        ty => (ty.to_string(), vec![]),
+    };
+    ContextFieldDoc { name: field.name().clone(), ty, fields }
+}
+
+fn doc_to_field(doc: ContextFieldDoc) -> ContextField {
+    let ty = match doc.ty.as_str() {
+        "record" => ContextType::Record(doc.fields.into_iter().map(doc_to_field).collect()),
+        name => ContextType::from_name(name),
+    };
+    ContextField::new(doc.name, ty)
+}
+
+/// Reads a machine written by [`fsm_to_json`]
+///
+/// The document is validated before the machine is built, every problem
+/// found is returned as a diagnostic.
+pub fn fsm_from_json(text: &str) -> Result<FSMachine, Vec<Diagnostic>> {
+    let doc: FsmDoc = serde_json::from_str(text).map_err(|e| {
+        let pos = SourcePos { line: e.line(), column: e.column() };
+        let byte = byte_offset(text, e.line(), e.column());
+        let d = Diagnostic::error(INVALID_JSON, format!("invalid state machine JSON: {}", e))
+            .with_span(SourceSpan::new(pos, pos, byte, byte));
+        vec![d]
+    })?;
+    doc_to_fsm(doc)
+}
+
+fn doc_to_fsm(doc: FsmDoc) -> Result<FSMachine, Vec<Diagnostic>> {
+    if doc.version == 0 || doc.version > FSM_JSON_VERSION {
+        let d = Diagnostic::error(
+            UNSUPPORTED_VERSION,
+            format!("unsupported schema version {}", doc.version),
+        )
+        .with_note(format!("this loader reads versions 1 to {}", FSM_JSON_VERSION));
+        return Err(vec![d]);
+    }
+    if doc.version == 1 {
+        let fields = version_2_fields(&doc);
+        if !fields.is_empty() {
+            let d = Diagnostic::error(
+                FIELD_NOT_IN_VERSION,
+                format!("version 1 document has version 2 fields: {}", fields.join(", ")),
+            )
+            .with_note("set `version` to 2 to load them".to_string());
+            return Err(vec![d]);
+        }
+    }
+
+    let mut diagnostics = vec![];
+    let mut states = BTreeMap::new();
+    // partition, documentation and kind of every state
+    let mut annotations = vec![];
+    for state in doc.states {
+        if states.contains_key(&StateId(state.id)) {
+            diagnostics.push(Diagnostic::error(
+                DUPLICATE_STATE,
+                format!("state id {} is used more than once", state.id),
+            ));
+            continue;
+        }
+        states.insert(StateId(state.id), state.name);
+        let kind = state.kind.map(|kind| match kind {
+            KindDoc::AcceptEvent => StateKind::AcceptEvent,
+            KindDoc::SendSignal => StateKind::SendSignal,
+            KindDoc::TimeEvent => StateKind::TimeEvent,
+            KindDoc::Load => StateKind::Load,
+            KindDoc::Save => StateKind::Save,
+        });
+        annotations.push((StateId(state.id), state.partition, state.doc, kind));
+    }
+
+    let mut check_id = |id: usize, what: &str| {
+        if !states.contains_key(&StateId(id)) {
+            diagnostics.push(Diagnostic::error(
+                UNKNOWN_STATE,
+                format!("{} refers to unknown state id {}", what, id),
+            ));
+        }
+    };
+    check_id(doc.start, "start");
+    for id in &doc.terminals {
+        check_id(*id, "terminal");
+    }
+    for (i, t) in doc.transitions.iter().enumerate() {
+        check_id(t.from, &format!("transition {} source", i));
+        check_id(t.to, &format!("transition {} target", i));
+    }
+    for c in &doc.composites {
+        check_id(c.state, &format!("composite `{}`", c.activity));
+    }
+    for o in &doc.orthogonals {
+        check_id(o.state, "orthogonal state");
+    }
+
+    let mut transitions = vec![];
+    for (i, t) in doc.transitions.into_iter().enumerate() {
+        let cond = t.cond.map(|c| CfgCond::new(c.seq, c.name).with_fallback(c.fallback));
+        if let Some(cond) = &cond {
+            let suffix = cond.to_string();
+            if !t.condition.as_ref().is_some_and(|text| text.ends_with(&suffix)) {
+                diagnostics.push(Diagnostic::error(
+                    INCONSISTENT_CONDITION,
+                    format!("transition {} condition does not end with `{}`", i, suffix),
+                ));
+            }
+        }
+        transitions.push(
+            Transition::new(StateId(t.from), StateId(t.to), t.condition)
+                .with_cond(cond)
+                .with_label(t.label),
+        );
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let terminals = doc.terminals.into_iter().map(StateId).collect();
+    let mut fsm = FSMachine::new(states, transitions, StateId(doc.start), terminals);
+    fsm.set_context(doc.context.into_iter().map(doc_to_field).collect());
+    for (id, path, doc, kind) in annotations {
+        fsm.set_partition(id, path);
+        fsm.set_doc(id, doc.unwrap_or_default());
+        fsm.set_kind(id, kind);
+    }
+    for c in doc.composites {
+        let id = StateId(c.state);
+        fsm.add_composite(id, c.activity.clone());
+        let Some(machine) = c.machine else {
+            continue;
+        };
+        let machine = doc_to_fsm(*machine).map_err(|diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|d| d.with_note(format!("in the machine of activity `{}`", c.activity)))
+                .collect::<Vec<_>>()
+        })?;
+        if let Some(composite) = fsm.composite_mut(id) {
+            composite.set_machine(machine);
+        }
+    }
+    for o in doc.orthogonals {
+        let mut regions = vec![];
+        for (i, region) in o.regions.into_iter().enumerate() {
+            let machine = doc_to_fsm(region).map_err(|diagnostics| {
+                diagnostics
+                    .into_iter()
+                    .map(|d| d.with_note(format!("in region {} of state id {}", i + 1, o.state)))
+                    .collect::<Vec<_>>()
+            })?;
+            regions.push(machine);
+        }
+        let join = match o.join {
+            JoinDoc::All => JoinKind::All,
+            JoinDoc::Any => JoinKind::Any,
+        };
+        fsm.add_orthogonal(StateId(o.state), OrthogonalState::new(regions, join));
+    }
+    Ok(fsm)
+}
+
+/// Names of the fields added in version 2 that the document has
+fn version_2_fields(doc: &FsmDoc) -> Vec<&'static str> {
+    let mut fields = vec![];
+    let mut add = |present: bool, name| {
+        if present {
+            fields.push(name);
+        }
+    };
+    add(doc.states.iter().any(|s| !s.partition.is_empty()), "partition");
+    add(doc.states.iter().any(|s| s.doc.is_some()), "doc");
+    add(doc.states.iter().any(|s| s.kind.is_some()), "kind");
+    add(doc.transitions.iter().any(|t| t.label.is_some()), "label");
+    add(doc.transitions.iter().any(|t| t.cond.as_ref().is_some_and(|c| c.fallback)), "fallback");
+    add(!doc.composites.is_empty(), "composites");
+    add(!doc.orthogonals.is_empty(), "orthogonals");
+    add(!doc.context.is_empty(), "context");
+    fields
+}
+
+/// Byte offset of a 1-based line and column, as reported by serde_json
+fn byte_offset(text: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    (line_start + column.saturating_sub(1)).min(text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine() -> FSMachine {
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Work".to_string()),
+            (StateId(2), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(1), Some(" 0 while".to_string()))
+                .with_cond(Some(CfgCond::new(0, "while".to_string())))
+                .with_label(Some("again".to_string())),
+            Transition::new(StateId(1), StateId(2), Some(" 1 while".to_string()))
+                .with_cond(Some(CfgCond::new(1, "while".to_string()).with_fallback(true))),
+        ];
+        FSMachine::new(states, transitions, StateId(0), vec![StateId(2)])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let fsm = machine();
+        let json = fsm_to_json(&fsm);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], FSM_JSON_VERSION);
+        assert_eq!(value["transitions"][1]["cond"]["seq"], 0);
+        assert_eq!(value["transitions"][1]["condition"], " 0 while");
+        assert_eq!(value["transitions"][1]["label"], "again");
+        assert!(value["transitions"][2].get("label").is_none());
+        assert!(value["transitions"][1]["cond"].get("fallback").is_none());
+        assert_eq!(value["transitions"][2]["cond"]["fallback"], true);
+        assert_eq!(fsm_from_json(&json).unwrap(), fsm);
+        assert!(value.get("composites").is_none());
+        // the fields added since version 1 are optional, older documents still load
+        let old = r#"{
+            "version": 1, "start": 0, "terminals": [1],
+            "states": [{ "id": 0, "name": "START" }, { "id": 1, "name": "END" }],
+            "transitions": [{ "from": 0, "to": 1, "condition": " 0 ok?", "cond": { "seq": 0, "name": "ok?" } }]
+        }"#;
+        assert_eq!(fsm_from_json(old).unwrap().transitions().len(), 1);
+
+        let mut outer = machine();
+        outer.set_partition(StateId(1), vec!["Shop".to_string(), "Packing".to_string()]);
+        outer.set_doc(StateId(1), "Packs the order.\nWeighs the parcel.".to_string());
+        outer.set_kind(StateId(1), Some(StateKind::AcceptEvent));
+        outer.add_composite(StateId(1), "work".to_string());
+        outer.composite_mut(StateId(1)).unwrap().set_machine(machine());
+        let record = vec![ContextField::new("qty".to_string(), ContextType::Int)];
+        outer.set_context(vec![
+            ContextField::new("Order".to_string(), ContextType::Record(record)),
+            ContextField::new("items".to_string(), ContextType::Named("Set[Item]".to_string())),
+        ]);
+        let json = fsm_to_json(&outer);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["composites"][0]["machine"]["states"][1]["name"], "Work");
+        assert_eq!(value["context"][0]["fields"][0]["type"], "int");
+        assert_eq!(value["states"][1]["partition"][1], "Packing");
+        assert!(value["states"][0].get("partition").is_none());
+        assert_eq!(value["states"][1]["doc"], "Packs the order.\nWeighs the parcel.");
+        assert!(value["states"][0].get("doc").is_none());
+        assert_eq!(value["states"][1]["kind"], "acceptEvent");
+        assert_eq!(fsm_from_json(&json).unwrap(), outer);
+
+        let mut fork = machine();
+        fork.add_orthogonal(StateId(1), OrthogonalState::new(vec![machine(), machine()], JoinKind::Any));
+        let json = fsm_to_json(&fork);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["orthogonals"][0]["join"], "any");
+        assert_eq!(value["orthogonals"][0]["regions"][1]["start"], 0);
+        assert_eq!(fsm_from_json(&json).unwrap(), fork);
+    }
+
+    #[test]
+    fn test_invalid_documents() {
+        let d = fsm_from_json("{\n  \"version\": 1,\n  \"oops\": 0\n}").unwrap_err();
+        assert_eq!(d[0].code(), INVALID_JSON);
+        assert_eq!(d[0].span().unwrap().start.line, 3);
+
+        let json = fsm_to_json(&machine()).replace("\"version\": 2", "\"version\": 7");
+        let d = fsm_from_json(&json).unwrap_err();
+        assert_eq!(d[0].code(), UNSUPPORTED_VERSION);
+        let json = fsm_to_json(&machine()).replace("\"version\": 2", "\"version\": 0");
+        assert_eq!(fsm_from_json(&json).unwrap_err()[0].code(), UNSUPPORTED_VERSION);
+        // machine() has a label and a fallback branch, which version 1 lacks
+        let json = fsm_to_json(&machine()).replace("\"version\": 2", "\"version\": 1");
+        let d = fsm_from_json(&json).unwrap_err();
+        assert_eq!(d[0].code(), FIELD_NOT_IN_VERSION);
+        assert_eq!(d[0].message(), "version 1 document has version 2 fields: label, fallback");
+
+        let json = r#"{
+            "version": 1, "start": 5, "terminals": [0],
+            "states": [{ "id": 0, "name": "A" }, { "id": 0, "name": "B" }],
+            "transitions": [
+                { "from": 0, "to": 9, "condition": null, "cond": null },
+                { "from": 0, "to": 0, "condition": "x 1 if", "cond": { "seq": 0, "name": "if" } }
+            ]
+        }"#;
+        let codes: Vec<_> = fsm_from_json(json).unwrap_err().iter().map(|d| d.code()).collect();
+        assert_eq!(
+            codes,
+            vec![DUPLICATE_STATE, UNKNOWN_STATE, UNKNOWN_STATE, INCONSISTENT_CONDITION]
+        );
+    }
+}
@@ -1,4 +1,11 @@
+pub mod analysis;
+pub mod composite_state;
+pub mod context_field;
+pub mod context_schema;
 pub mod fs_machine;
+pub mod orthogonal_state;
+pub mod partition;
 pub mod state_id;
 pub mod transition;
-
+#[cfg(feature = "serde")]
+pub mod fsm_json;
@@ -0,0 +1,33 @@
+use crate::cfg::cfg_node_kind::JoinKind;
+use crate::fsm::fs_machine::FSMachine;
+
+/// A state whose regions run concurrently
+///
+/// Entering the state starts the machine of every region, each region
+/// steps on its own. The state's transitions are taken once the join
+/// condition holds: every region, or any region, reached a terminal state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthogonalState {
+    regions: Vec<FSMachine>,
+    join: JoinKind,
+}
+
+impl OrthogonalState {
+    pub fn new(regions: Vec<FSMachine>, join: JoinKind) -> OrthogonalState {
+        Self { regions, join }
+    }
+
+    /// The machines of the regions, in the order of the diagram's branches
+    pub fn regions(&self) -> &Vec<FSMachine> {
+        &self.regions
+    }
+
+    pub fn regions_mut(&mut self) -> &mut Vec<FSMachine> {
+        &mut self.regions
+    }
+
+    /// When the state's transitions are taken
+    pub fn join(&self) -> JoinKind {
+        self.join
+    }
+}
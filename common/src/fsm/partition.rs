@@ -0,0 +1,63 @@
+//! Partitions of a state machine
+//!
+//! A state built from a statement inside diagram groups (`partition`,
+//! `group`, ...) belongs to the path of their names, outermost first. The
+//! groups usually name the service or actor owning a step: a transition
+//! between two partitions is a hand-off from one owner to another.
+
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::state_id::StateId;
+use crate::fsm::transition::Transition;
+use std::collections::BTreeMap;
+
+/// The states of every partition of the machine, in id order; the states
+/// outside of any group are listed under the empty path
+pub fn partitions(fsm: &FSMachine) -> BTreeMap<Vec<String>, Vec<StateId>> {
+    let mut partitions: BTreeMap<Vec<String>, Vec<StateId>> = BTreeMap::new();
+    for id in fsm.state_map().keys() {
+        partitions.entry(fsm.partition(*id).to_vec()).or_default().push(*id);
+    }
+    partitions
+}
+
+/// The transitions whose states are in different partitions, in the
+/// machine's order
+///
+/// The start and the terminal states are in no partition, the transitions
+/// from or to them are no hand-offs and left out.
+pub fn cross_partition_transitions(fsm: &FSMachine) -> Vec<&Transition> {
+    let pseudo = |id: StateId| id == fsm.start_id() || fsm.terminals().contains(&id);
+    fsm.transitions()
+        .iter()
+        .filter(|t| !pseudo(t.from()) && !pseudo(t.to()))
+        .filter(|t| fsm.partition(t.from()) != fsm.partition(t.to()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_partition_transitions() {
+        let names = ["START", "Receive", "Pay", "Check card", "Ship", "END"];
+        let states = names.iter().enumerate().map(|(i, n)| (StateId(i), n.to_string())).collect();
+        let transitions = (0..5).map(|i| Transition::new(StateId(i), StateId(i + 1), None)).collect();
+        let mut fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(5)]);
+        fsm.set_partition(StateId(1), vec!["Shop".to_string()]);
+        fsm.set_partition(StateId(2), vec!["Payment".to_string()]);
+        fsm.set_partition(StateId(3), vec!["Payment".to_string(), "Fraud".to_string()]);
+        fsm.set_partition(StateId(4), vec!["Shop".to_string()]);
+
+        let handoffs: Vec<_> = cross_partition_transitions(&fsm)
+            .iter()
+            .map(|t| (t.from().0, t.to().0))
+            .collect();
+        assert_eq!(handoffs, vec![(1, 2), (2, 3), (3, 4)]);
+
+        let partitions = partitions(&fsm);
+        assert_eq!(partitions[&vec![]], vec![StateId(0), StateId(5)]);
+        assert_eq!(partitions[&vec!["Shop".to_string()]], vec![StateId(1), StateId(4)]);
+        assert_eq!(partitions.len(), 4);
+    }
+}
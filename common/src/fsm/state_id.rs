@@ -1,2 +1,2 @@
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub struct StateId(pub usize);
\ No newline at end of file
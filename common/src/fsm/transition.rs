@@ -1,15 +1,32 @@
+use crate::cfg::cfg_cond::{caption, CfgCond};
 use crate::fsm::state_id::StateId;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Transition {
     from: StateId,
     to: StateId,
     condition: Option<String>,
+    /// The branch the condition comes from, `condition` ends with its text
+    cond: Option<CfgCond>,
+    /// The label written on the diagram's branch or arrow, if any
+    label: Option<String>,
 }
 
 impl Transition {
     pub fn new(from: StateId, to: StateId, condition: Option<String>) -> Transition {
-        Transition { from, to, condition }
+        Transition { from, to, condition, cond: None, label: None }
+    }
+
+    /// Attaches the branch the transition was taken from
+    pub fn with_cond(mut self, cond: Option<CfgCond>) -> Transition {
+        self.cond = cond;
+        self
+    }
+
+    /// Attaches the label of the branch or arrow the transition was drawn as
+    pub fn with_label(mut self, label: Option<String>) -> Transition {
+        self.label = label;
+        self
     }
 
     pub fn from(&self) -> StateId {
@@ -23,4 +40,18 @@ impl Transition {
     pub fn condition(&self) -> &Option<String> {
         &self.condition
     }
-}
\ No newline at end of file
+
+    pub fn cond(&self) -> &Option<CfgCond> {
+        &self.cond
+    }
+
+    pub fn label(&self) -> &Option<String> {
+        &self.label
+    }
+
+    /// Text shown on the transition: the label with the guard's name in
+    /// brackets, the condition alone when the transition has no label
+    pub fn caption(&self) -> Option<String> {
+        caption(self.label.as_ref(), self.cond.as_ref(), self.condition.clone())
+    }
+}
@@ -1,5 +1,12 @@
 pub mod fsm;
 pub mod cfg;
+pub mod cli;
+pub mod diag;
+pub mod csharp;
+pub mod rust;
+pub mod codegen;
+pub mod tla;
+mod dot;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
@@ -0,0 +1,2 @@
+pub mod rust_gen;
+mod template;
@@ -0,0 +1,355 @@
+use crate::codegen::comment::one_line;
+use crate::codegen::naming::{camel_name, hook_verb, label_name, snake_name, unique_name, variant_name};
+use crate::codegen::unsupported::unsupported_orthogonals;
+use crate::codegen::user_code::UserCodeMerge;
+use crate::cfg::cfg_node_kind::StateKind;
+use crate::diag::diagnostic::Diagnostic;
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::state_id::StateId;
+use crate::rust::template::{
+    ContextTemplate, DispatchView, DispatcherTemplate, EventsTemplate, HookView, ModTemplate,
+    StateTemplate, StateView, TransitionView, TransitionsTemplate,
+};
+use anyhow::Result;
+use askama::Template;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Generate a Rust module for the FSM:
+/// - mod.rs
+/// - state.rs: the `State` enum
+/// - context.rs: the `Context` struct
+/// - transitions.rs: one guard and one action function per transition,
+///   named after the names of the states they connect
+/// - dispatcher.rs: `step` with a `match` over every state, and `run`
+/// - events.rs: one hook per typed state, only when there are some
+///
+/// `step` leaves an accept-event or time-event state only once its hook
+/// returns true, and runs the hook of a send-signal, load or save state
+/// after the action of every transition into it.
+///
+/// The guards and actions of the transitions leaving a state inside
+/// diagram groups go to a module of their partition instead,
+/// `transitions_shop_payment.rs` for `partition Shop` around
+/// `partition Payment`.
+///
+/// Orthogonal states are not supported: a machine with one is not
+/// written, every such state is reported as an error.
+///
+/// Guard, action and context bodies are user-code regions, code written
+/// there survives regenerating into the same directory, code of guards
+/// and actions that are gone is kept aside and reported in the returned
+/// warnings.
+pub fn generate_rust_fsm<P: AsRef<Path>>(fsm: &FSMachine, out_dir: P) -> Result<Vec<Diagnostic>> {
+    let out_dir = out_dir.as_ref();
+    let unsupported = unsupported_orthogonals(fsm, "Rust");
+    if !unsupported.is_empty() {
+        return Ok(unsupported);
+    }
+
+    // --------------------------------------------------
+    // 1. States, the state map iterates in id order
+    // --------------------------------------------------
+
+    let variants: BTreeMap<StateId, String> = fsm
+        .state_map()
+        .iter()
+        .map(|(id, name)| (*id, variant_name(name, id)))
+        .collect();
+    let states = fsm
+        .state_map()
+        .iter()
+        .map(|(id, name)| StateView {
+            name: one_line(name),
+            variant: variants[id].clone(),
+            doc: fsm.doc(*id).map(|doc| doc.lines().map(str::to_string).collect()).unwrap_or_default(),
+        })
+        .collect();
+    let state = StateTemplate {
+        states,
+        start: variants[&fsm.start_id()].clone(),
+        terminals: fsm.terminals().iter().map(|id| variants[id].clone()).collect(),
+    };
+    // equally named states get numbered hooks
+    let mut used_hooks: HashMap<String, usize> = HashMap::new();
+    let hooks: BTreeMap<StateId, HookView> = fsm
+        .state_map()
+        .keys()
+        .filter_map(|id| hook_view(fsm, *id).map(|hook| (*id, hook)))
+        .map(|(id, mut hook)| {
+            hook.name = unique_name(&mut used_hooks, hook.name);
+            (id, hook)
+        })
+        .collect();
+
+    // --------------------------------------------------
+    // 2. Transitions, grouped by source state
+    // --------------------------------------------------
+
+    let mut dispatch: BTreeMap<StateId, Vec<(u64, TransitionView)>> =
+        variants.keys().map(|id| (*id, vec![])).collect();
+    let mut used_names: HashMap<String, usize> = HashMap::new();
+    // module -> the guards and actions in it
+    let mut transitions: BTreeMap<String, Vec<TransitionView>> =
+        BTreeMap::from([("transitions".to_string(), vec![])]);
+    for t in fsm.transitions() {
+        // guards and actions hold user code, they are named after the
+        // states rather than their ids
+        let mut base = format!(
+            "{}_to_{}",
+            stable_name(fsm, t.from()),
+            stable_name(fsm, t.to())
+        );
+        // a labelled branch or arrow names the transition too
+        if let Some(label) = t.label().as_deref().and_then(label_name) {
+            base = format!("{}_{}", base, label);
+        }
+        // several transitions may connect equally named states
+        let base = unique_name(&mut used_names, base);
+
+        let view = TransitionView {
+            // names, guard and label go into doc comments
+            from_name: one_line(&fsm.state_map()[&t.from()]),
+            to_name: one_line(&fsm.state_map()[&t.to()]),
+            to: variants[&t.to()].clone(),
+            guard: format!("guard_{}", base),
+            action: format!("action_{}", base),
+            condition: t.condition().as_deref().map(one_line),
+            fallback: t.cond().as_ref().is_some_and(|c| c.is_fallback()),
+            label: t.label().as_deref().map(one_line),
+            enter_hook: hooks
+                .get(&t.to())
+                .filter(|hook| !hook.waits)
+                .map(|hook| hook.name.clone()),
+        };
+        // guards of one decision are tried in branch order
+        let seq = t.cond().as_ref().map_or(0, |c| c.seq());
+        dispatch.entry(t.from()).or_default().push((seq, view.clone()));
+        transitions.entry(lane_module(fsm.partition(t.from()))).or_default().push(view);
+    }
+    let lanes: Vec<String> = transitions.keys().filter(|m| *m != "transitions").cloned().collect();
+    let dispatch = dispatch
+        .into_iter()
+        .map(|(id, mut views)| {
+            views.sort_by_key(|(seq, _)| *seq);
+            DispatchView {
+                variant: variants[&id].clone(),
+                transitions: views.into_iter().map(|(_, v)| v).collect(),
+                wait_hook: hooks.get(&id).filter(|hook| hook.waits).map(|hook| hook.name.clone()),
+            }
+        })
+        .collect();
+
+    // --------------------------------------------------
+    // 3. Write the files
+    // --------------------------------------------------
+
+    let events = !hooks.is_empty();
+    let mut files = vec![
+        ("mod.rs".to_string(), ModTemplate { lanes: lanes.clone(), events }.render()?),
+        ("state.rs".to_string(), state.render()?),
+        ("context.rs".to_string(), ContextTemplate.render()?),
+        ("dispatcher.rs".to_string(), DispatcherTemplate { dispatch, lanes, events }.render()?),
+    ];
+    if events {
+        let hooks = hooks.into_values().collect();
+        files.push(("events.rs".to_string(), EventsTemplate { hooks }.render()?));
+    }
+    for (module, transitions) in transitions {
+        files.push((format!("{}.rs", module), TransitionsTemplate { transitions }.render()?));
+    }
+    let mut merge = UserCodeMerge::load(out_dir, ".rs")?;
+    for (name, text) in &files {
+        merge.write(name, &format!("{}\n", text.trim_end()))?;
+    }
+    // modules of partitions that are gone, the events of a machine that
+    // has no typed states any more
+    merge.remove_stale(|name| {
+        (name.starts_with("transitions_") && name.ends_with(".rs")) || name == "events.rs"
+    })?;
+
+    Ok(merge.finish()?)
+}
+
+/// The hook of a typed state, `None` for a plain state
+fn hook_view(fsm: &FSMachine, id: StateId) -> Option<HookView> {
+    let kind = fsm.kind(id)?;
+    // the summary is a doc comment
+    let name = one_line(&fsm.state_map()[&id]);
+    let summary = match kind {
+        StateKind::AcceptEvent => format!("Whether the event `{}` arrived", name),
+        StateKind::TimeEvent => format!("Whether the timeout `{}` elapsed", name),
+        StateKind::SendSignal => format!("Emits the signal `{}`", name),
+        StateKind::Load => format!("Reads `{}` from the database", name),
+        StateKind::Save => format!("Writes `{}` to the database", name),
+    };
+    Some(HookView {
+        name: format!("{}_{}", hook_verb(kind), stable_name(fsm, id)),
+        summary,
+        waits: kind.waits(),
+    })
+}
+
+/// snake_case name of a state without its id, it stays the same when
+/// states are added or removed elsewhere in the machine
+fn stable_name(fsm: &FSMachine, id: StateId) -> String {
+    snake_name(&camel_name(&fsm.state_map()[&id]))
+}
+
+/// Module of the guards and actions of the transitions leaving a state of
+/// the partition `path`
+fn lane_module(path: &[String]) -> String {
+    if path.is_empty() {
+        return "transitions".to_string();
+    }
+    let lane: Vec<_> = path.iter().map(|name| snake_name(&camel_name(name))).collect();
+    format!("transitions_{}", lane.join("_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg::cfg_cond::CfgCond;
+    use crate::cfg::cfg_node_kind::JoinKind;
+    use crate::codegen::unsupported::UNSUPPORTED_ORTHOGONAL_STATE;
+    use crate::fsm::orthogonal_state::OrthogonalState;
+    use crate::fsm::transition::Transition;
+    use std::fs;
+    use std::process::Command;
+
+    fn machine() -> FSMachine {
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Check Stock".to_string()),
+            (StateId(2), "Ship".to_string()),
+            (StateId(3), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(3), Some(" 1 else".to_string()))
+                .with_cond(Some(CfgCond::new(1, "else".to_string()).with_fallback(true))),
+            Transition::new(StateId(1), StateId(2), Some("in stock? 0 if".to_string()))
+                .with_cond(Some(CfgCond::new(0, "if".to_string()))),
+            Transition::new(StateId(2), StateId(3), None),
+        ];
+        FSMachine::new(states, transitions, StateId(0), vec![StateId(3)])
+    }
+
+    #[test]
+    fn test_generated_code_compiles() {
+        let out_dir = std::env::temp_dir().join("common_rust_gen");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        generate_rust_fsm(&machine(), &out_dir).unwrap();
+
+        let dispatcher = fs::read_to_string(out_dir.join("dispatcher.rs")).unwrap();
+        // the `if` branch is tried before `else`
+        let if_guard = dispatcher.find("guard_check_stock_to_ship(ctx)").unwrap();
+        let else_guard = dispatcher.find("guard_check_stock_to_end(ctx)").unwrap();
+        assert!(if_guard < else_guard);
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let output = Command::new(rustc)
+            .args(["--crate-type", "lib", "--edition", "2021", "-D", "warnings"])
+            .arg("--crate-name")
+            .arg("generated_fsm")
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .arg(out_dir.join("mod.rs"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    #[test]
+    fn test_regeneration_keeps_user_code() {
+        let out_dir = std::env::temp_dir().join("common_rust_gen_user_code");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        generate_rust_fsm(&machine(), &out_dir).unwrap();
+
+        let path = out_dir.join("transitions.rs");
+        let text = fs::read_to_string(&path).unwrap();
+        // only the `if` branch needs a guard, `else` holds whenever it is tried
+        assert_eq!(text.matches("    todo!()\n").count(), 1);
+        assert!(text.contains("// <user-code guard_check_stock_to_end>\n    true\n"));
+        let text = text.replacen("    todo!()\n", "    ctx.in_stock\n", 1);
+        fs::write(&path, text).unwrap();
+
+        generate_rust_fsm(&machine(), &out_dir).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.contains("    ctx.in_stock\n"));
+        assert!(!text.contains("todo!()"));
+
+        // a state inserted ahead of the others renumbers them, the code
+        // stays with the transition
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Reserve".to_string()),
+            (StateId(2), "Check Stock".to_string()),
+            (StateId(3), "Ship".to_string()),
+            (StateId(4), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(2), None),
+            Transition::new(StateId(2), StateId(4), Some(" 1 else".to_string()))
+                .with_cond(Some(CfgCond::new(1, "else".to_string()).with_fallback(true))),
+            Transition::new(StateId(2), StateId(3), Some("in stock? 0 if".to_string()))
+                .with_cond(Some(CfgCond::new(0, "if".to_string()))),
+            Transition::new(StateId(3), StateId(4), None),
+        ];
+        let fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(4)]);
+        assert!(generate_rust_fsm(&fsm, &out_dir).unwrap().is_empty());
+        assert!(fs::read_to_string(&path).unwrap().contains("    ctx.in_stock\n"));
+    }
+
+    #[test]
+    fn test_orthogonal_states_are_rejected() {
+        let out_dir = std::env::temp_dir().join("common_rust_gen_orthogonal");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = machine();
+        fsm.add_orthogonal(StateId(2), OrthogonalState::new(vec![machine(), machine()], JoinKind::All));
+        let diagnostics = generate_rust_fsm(&fsm, &out_dir).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), UNSUPPORTED_ORTHOGONAL_STATE);
+        assert!(diagnostics[0].is_error());
+        assert!(!out_dir.join("mod.rs").exists());
+    }
+
+    #[test]
+    fn test_partition_modules() {
+        let out_dir = std::env::temp_dir().join("common_rust_gen_partitions");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = machine();
+        fsm.set_partition(StateId(1), vec!["Warehouse".to_string()]);
+        fsm.set_partition(StateId(2), vec!["Warehouse".to_string(), "Loading Dock".to_string()]);
+        generate_rust_fsm(&fsm, &out_dir).unwrap();
+
+        let warehouse = fs::read_to_string(out_dir.join("transitions_warehouse.rs")).unwrap();
+        assert!(warehouse.contains("pub fn guard_check_stock_to_ship(ctx: &Context) -> bool {"));
+        let dock = fs::read_to_string(out_dir.join("transitions_warehouse_loading_dock.rs")).unwrap();
+        assert!(dock.contains("pub fn action_ship_to_end(ctx: &mut Context) {"));
+        let transitions = fs::read_to_string(out_dir.join("transitions.rs")).unwrap();
+        assert!(transitions.contains("guard_start_to_check_stock"));
+        assert!(!transitions.contains("guard_ship_to_end"));
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let output = Command::new(rustc)
+            .args(["--crate-type", "lib", "--edition", "2021", "-D", "warnings"])
+            .arg("--crate-name")
+            .arg("generated_fsm_partitions")
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .arg(out_dir.join("mod.rs"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+        // a partition that is gone takes its module along
+        generate_rust_fsm(&machine(), &out_dir).unwrap();
+        assert!(!out_dir.join("transitions_warehouse.rs").exists());
+    }
+}
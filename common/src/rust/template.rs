@@ -0,0 +1,94 @@
+use askama::Template;
+
+/// `mod.rs` declaring the generated modules
+#[derive(Template)]
+#[template(path = "rust/mod.rs.j2", escape = "none")]
+pub struct ModTemplate {
+    /// the guard and action modules of the partitions
+    pub lanes: Vec<String>,
+    /// whether there is an `events` module
+    pub events: bool,
+}
+
+/// `State` enum
+#[derive(Template)]
+#[template(path = "rust/state.rs.j2", escape = "none")]
+pub struct StateTemplate {
+    pub states: Vec<StateView>,
+    pub start: String,
+    pub terminals: Vec<String>,
+}
+
+/// `Context` struct, its fields are written by the user
+#[derive(Template)]
+#[template(path = "rust/context.rs.j2", escape = "none")]
+pub struct ContextTemplate;
+
+/// Guard and action stubs of every transition
+#[derive(Template)]
+#[template(path = "rust/transitions.rs.j2", escape = "none")]
+pub struct TransitionsTemplate {
+    pub transitions: Vec<TransitionView>,
+}
+
+/// `step` and `run`
+#[derive(Template)]
+#[template(path = "rust/dispatcher.rs.j2", escape = "none")]
+pub struct DispatcherTemplate {
+    pub dispatch: Vec<DispatchView>,
+    /// the guard and action modules of the partitions
+    pub lanes: Vec<String>,
+    /// whether there is an `events` module
+    pub events: bool,
+}
+
+/// The hooks of the typed states
+#[derive(Template)]
+#[template(path = "rust/events.rs.j2", escape = "none")]
+pub struct EventsTemplate {
+    pub hooks: Vec<HookView>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HookView {
+    pub name: String,
+    pub summary: String,
+    /// whether the hook tells if the state's event occurred, otherwise it
+    /// runs on entering the state
+    pub waits: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct StateView {
+    pub name: String,
+    pub variant: String,
+    /// the lines of the state's documentation
+    pub doc: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransitionView {
+    pub from_name: String,
+    pub to_name: String,
+    /// variant of the target state
+    pub to: String,
+    pub guard: String,
+    pub action: String,
+    pub condition: Option<String>,
+    /// whether the branch is taken when the branches before it are not,
+    /// its guard holds
+    pub fallback: bool,
+    /// the label of the diagram's branch or arrow
+    pub label: Option<String>,
+    /// the hook run on entering the target state
+    pub enter_hook: Option<String>,
+}
+
+/// The transitions out of one state, in the order their guards are tried
+#[derive(Debug, Clone)]
+pub struct DispatchView {
+    pub variant: String,
+    pub transitions: Vec<TransitionView>,
+    /// the hook telling whether the state's event occurred
+    pub wait_hook: Option<String>,
+}
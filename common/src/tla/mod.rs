@@ -0,0 +1,2 @@
+pub mod tla_model;
+mod template;
@@ -0,0 +1,48 @@
+use askama::Template;
+
+/// The TLA+ module
+#[derive(Template)]
+#[template(path = "tla/module.tla.j2", escape = "none")]
+pub struct ModuleTemplate {
+    pub module: String,
+    pub constants: Vec<String>,
+    /// the variables, comma separated: `pc` first, then `stage` if the
+    /// machine has entry actions
+    pub vars: String,
+    /// `pc` values
+    pub states: Vec<String>,
+    pub terminals: Vec<String>,
+    pub type_ok: Vec<String>,
+    pub init: Vec<String>,
+    pub actions: Vec<ActionView>,
+    pub invariants: Vec<InvariantView>,
+}
+
+/// The TLC configuration
+#[derive(Template)]
+#[template(path = "tla/config.cfg.j2", escape = "none")]
+pub struct ConfigTemplate {
+    pub module: String,
+    pub constants: Vec<ConstantView>,
+    pub invariants: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActionView {
+    pub name: String,
+    pub comment: String,
+    pub conjuncts: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct InvariantView {
+    pub name: String,
+    pub comment: String,
+    pub expr: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstantView {
+    pub name: String,
+    pub value: String,
+}
@@ -0,0 +1,481 @@
+//! TLA+ export of a state machine
+//!
+//! A machine becomes a module with a `pc` variable holding the current
+//! state, one action per transition, `Init`, `Next`, `Spec`, a `TypeOK`
+//! invariant and a `Termination` property, plus a TLC configuration
+//! checking them. Context fields become variables that keep the value
+//! `Init` picked from small constant domains.
+//!
+//! The guards of a machine are text, they are not translated: every
+//! transition out of the current state is enabled and TLC explores all of
+//! them. A front end that knows more, such as SCL, sets the guards and
+//! the effects of states through [`TlaModel`] before rendering. A state
+//! with effects runs them in an entry action; `stage` then tells whether
+//! the entry action of the current state ran already.
+//! Composite states are exported as plain states. Orthogonal states are
+//! not supported, a machine with one is rejected.
+
+use crate::codegen::comment::one_line;
+use crate::codegen::naming::variant_name;
+use crate::codegen::unsupported::unsupported_orthogonals;
+use crate::diag::diagnostic::Diagnostic;
+use crate::fsm::context_field::ContextType;
+use crate::fsm::fs_machine::FSMachine;
+use crate::fsm::state_id::StateId;
+use crate::fsm::transition::Transition;
+use crate::tla::template::{ActionView, ConfigTemplate, ConstantView, InvariantView, ModuleTemplate};
+use anyhow::Result;
+use askama::Template;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A rendered module and its TLC configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlaSpec {
+    /// Name of the module
+    pub name: String,
+    /// Text of `<module>.tla`
+    pub module: String,
+    /// Text of `<module>.cfg`
+    pub config: String,
+}
+
+/// A machine on its way to TLA+
+#[derive(Debug, Clone)]
+pub struct TlaModel {
+    module: String,
+    /// `pc` value of every state
+    states: BTreeMap<StateId, String>,
+    start: StateId,
+    terminals: Vec<StateId>,
+    transitions: Vec<TlaTransition>,
+    /// constant name -> domain written to the TLC configuration
+    constants: BTreeMap<String, String>,
+    variables: Vec<TlaVariable>,
+    entries: BTreeMap<StateId, TlaEntry>,
+    invariants: Vec<InvariantView>,
+}
+
+#[derive(Debug, Clone)]
+struct TlaTransition {
+    from: StateId,
+    to: StateId,
+    /// the condition without its branch, for the comment
+    condition: Option<String>,
+    guard: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct TlaVariable {
+    name: String,
+    /// predicate of `TypeOK`
+    type_ok: String,
+    /// predicate of `Init`
+    init: String,
+}
+
+#[derive(Debug, Clone)]
+struct TlaEntry {
+    conjuncts: Vec<String>,
+    changed: BTreeSet<String>,
+}
+
+impl TlaModel {
+    /// States, transitions and context variables of a machine
+    pub fn from_fsm(fsm: &FSMachine, module: &str) -> TlaModel {
+        let mut model = TlaModel {
+            module: module.to_string(),
+            states: fsm
+                .state_map()
+                .iter()
+                .map(|(id, name)| (*id, variant_name(name, id)))
+                .collect(),
+            start: fsm.start_id(),
+            terminals: fsm.terminals().clone(),
+            transitions: fsm
+                .transitions()
+                .iter()
+                .map(|t| TlaTransition {
+                    from: t.from(),
+                    to: t.to(),
+                    condition: condition_text(t),
+                    guard: None,
+                })
+                .collect(),
+            constants: BTreeMap::new(),
+            variables: vec![],
+            entries: BTreeMap::new(),
+            invariants: vec![],
+        };
+        for field in fsm.context() {
+            let (type_ok, init) = model.domains(field.ty());
+            let name = field.name();
+            model.add_variable(name, format!("{} \\in {}", name, type_ok), format!("{} \\in {}", name, init));
+        }
+        model
+    }
+
+    /// `pc` value of a state
+    pub fn pc(&self, id: StateId) -> &String {
+        &self.states[&id]
+    }
+
+    /// Declares a constant, `domain` is its value in the TLC configuration
+    pub fn add_constant(&mut self, name: &str, domain: &str) {
+        self.constants.entry(name.to_string()).or_insert_with(|| domain.to_string());
+    }
+
+    /// Declares a variable, `type_ok` and `init` are the predicates on it
+    /// in `TypeOK` and `Init`
+    pub fn add_variable(&mut self, name: &str, type_ok: String, init: String) {
+        self.variables.push(TlaVariable { name: name.to_string(), type_ok, init });
+    }
+
+    /// Makes the transition at `index` of the machine's transitions
+    /// enabled only when `guard` holds
+    pub fn set_guard(&mut self, index: usize, guard: String) {
+        self.transitions[index].guard = Some(guard);
+    }
+
+    /// Gives `state` an entry action made of `conjuncts`, the variables in
+    /// `changed` are the ones they prime
+    pub fn set_entry(&mut self, state: StateId, conjuncts: Vec<String>, changed: Vec<String>) {
+        let changed = changed.into_iter().collect();
+        self.entries.insert(state, TlaEntry { conjuncts, changed });
+    }
+
+    /// Adds an invariant checked by TLC
+    pub fn add_invariant(&mut self, name: &str, comment: &str, expr: String) {
+        self.invariants.push(InvariantView {
+            name: name.to_string(),
+            comment: one_line(comment),
+            expr,
+        });
+    }
+
+    /// Whether the states have entry actions and `stage` is needed
+    pub fn is_staged(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    /// Sets of the values of a type: the one `TypeOK` checks against and
+    /// the finite one `Init` picks from
+    fn domains(&mut self, ty: &ContextType) -> (String, String) {
+        let mut constant = |name: &str, domain: &str| {
+            self.add_constant(name, domain);
+            name.to_string()
+        };
+        match ty {
+            ContextType::Bool => ("BOOLEAN".to_string(), "BOOLEAN".to_string()),
+            ContextType::Int => ("Int".to_string(), constant("IntValue", "{0, 1}")),
+            ContextType::String => ("STRING".to_string(), constant("StringValue", "{\"a\", \"b\"}")),
+            // TLC has no real numbers
+            ContextType::Float => {
+                let c = constant("FloatValue", "{0, 1}");
+                (c.clone(), c)
+            }
+            ContextType::Named(name) => {
+                let identifier = name.chars().all(|c| c.is_alphanumeric() || c == '_');
+                let c = constant(if identifier { name } else { "Value" }, "{0, 1}");
+                (c.clone(), c)
+            }
+            ContextType::Record(fields) => {
+                let (mut type_ok, mut init) = (vec![], vec![]);
+                for field in fields {
+                    let (t, i) = self.domains(field.ty());
+                    type_ok.push(format!("{}: {}", field.name(), t));
+                    init.push(format!("{}: {}", field.name(), i));
+                }
+                (format!("[{}]", type_ok.join(", ")), format!("[{}]", init.join(", ")))
+            }
+        }
+    }
+
+    /// Writes the module and its configuration
+    pub fn render(&self) -> Result<TlaSpec> {
+        let staged = self.is_staged();
+        let mut variables = vec!["pc".to_string()];
+        if staged {
+            variables.push("stage".to_string());
+        }
+        variables.extend(self.variables.iter().map(|v| v.name.clone()));
+        // variables an action leaves alone, none for a machine without
+        // context
+        let unchanged = |changed: &BTreeSet<String>| {
+            let rest: Vec<_> = variables[1..]
+                .iter()
+                .filter(|v| *v != "stage" && !changed.contains(*v))
+                .cloned()
+                .collect();
+            (!rest.is_empty()).then(|| format!("UNCHANGED <<{}>>", rest.join(", ")))
+        };
+
+        let mut type_ok = vec!["pc \\in States".to_string()];
+        let mut init = vec![format!("pc = \"{}\"", self.pc(self.start))];
+        if staged {
+            type_ok.push("stage \\in {\"entry\", \"exit\"}".to_string());
+            init.push("stage = \"entry\"".to_string());
+        }
+        for v in &self.variables {
+            type_ok.push(v.type_ok.clone());
+            init.push(v.init.clone());
+        }
+
+        let mut actions = vec![];
+        for (id, pc) in &self.states {
+            if !staged {
+                break;
+            }
+            let (mut conjuncts, changed) = match self.entries.get(id) {
+                Some(entry) => (entry.conjuncts.clone(), entry.changed.clone()),
+                None => (vec![], BTreeSet::new()),
+            };
+            conjuncts.insert(0, format!("pc = \"{}\"", pc));
+            conjuncts.insert(1, "stage = \"entry\"".to_string());
+            conjuncts.push("stage' = \"exit\"".to_string());
+            conjuncts.push("pc' = pc".to_string());
+            conjuncts.extend(unchanged(&changed));
+            actions.push(ActionView {
+                name: format!("{}Entry", pc),
+                comment: format!("entering {}", pc),
+                conjuncts,
+            });
+        }
+
+        let mut used_names: HashMap<String, usize> = HashMap::new();
+        for t in &self.transitions {
+            let mut name = format!("{}To{}", self.pc(t.from), self.pc(t.to));
+            // several transitions may connect the same two states
+            let count = used_names.entry(name.clone()).or_default();
+            *count += 1;
+            if *count > 1 {
+                name = format!("{}_{}", name, count);
+            }
+            let mut conjuncts = vec![format!("pc = \"{}\"", self.pc(t.from))];
+            if staged {
+                conjuncts.push("stage = \"exit\"".to_string());
+            }
+            conjuncts.extend(t.guard.clone());
+            conjuncts.push(format!("pc' = \"{}\"", self.pc(t.to)));
+            if staged {
+                conjuncts.push("stage' = \"entry\"".to_string());
+            }
+            conjuncts.extend(unchanged(&BTreeSet::new()));
+            // a comment ends at the line end, the condition may span lines
+            let comment = match &t.condition {
+                Some(condition) => {
+                    format!("{} -> {}: {}", self.pc(t.from), self.pc(t.to), one_line(condition))
+                }
+                None => format!("{} -> {}", self.pc(t.from), self.pc(t.to)),
+            };
+            actions.push(ActionView { name, comment, conjuncts });
+        }
+
+        let module = ModuleTemplate {
+            module: self.module.clone(),
+            vars: variables.join(", "),
+            constants: self.constants.keys().cloned().collect(),
+            states: self.states.values().cloned().collect(),
+            terminals: self.terminals.iter().map(|id| self.pc(*id).clone()).collect(),
+            type_ok,
+            init,
+            actions,
+            invariants: self.invariants.clone(),
+        };
+        let config = ConfigTemplate {
+            module: self.module.clone(),
+            constants: self
+                .constants
+                .iter()
+                .map(|(name, value)| ConstantView { name: name.clone(), value: value.clone() })
+                .collect(),
+            invariants: self.invariants.iter().map(|i| i.name.clone()).collect(),
+        };
+        Ok(TlaSpec {
+            name: self.module.clone(),
+            module: format!("{}\n", module.render()?.trim_end()),
+            config: format!("{}\n", config.render()?.trim_end()),
+        })
+    }
+}
+
+impl TlaSpec {
+    /// Writes `<name>.tla` and `<name>.cfg` to `out_dir`
+    pub fn write(&self, out_dir: &Path) -> io::Result<()> {
+        fs::write(out_dir.join(format!("{}.tla", self.name)), &self.module)?;
+        fs::write(out_dir.join(format!("{}.cfg", self.name)), &self.config)
+    }
+}
+
+/// Text of a transition's condition without the branch suffix, the
+/// branch name when nothing else is left
+fn condition_text(t: &Transition) -> Option<String> {
+    let condition = t.condition().as_ref()?;
+    let Some(cond) = t.cond() else {
+        return Some(condition.trim().to_string());
+    };
+    let text = condition.strip_suffix(&cond.to_string()).unwrap_or(condition).trim();
+    if text.is_empty() {
+        Some(cond.name().clone())
+    } else {
+        Some(text.to_string())
+    }
+}
+
+/// Exports a machine as a TLA+ module named `module`, fails for a machine
+/// with orthogonal states
+pub fn fsm_to_tla(fsm: &FSMachine, module: &str) -> Result<TlaSpec> {
+    if let Some(d) = unsupported_orthogonals(fsm, "TLA+").first() {
+        anyhow::bail!("{}", d.message());
+    }
+    TlaModel::from_fsm(fsm, module).render()
+}
+
+/// Writes the TLA+ module of a machine and its TLC configuration to
+/// `out_dir`, as `<module>.tla` and `<module>.cfg`
+///
+/// A machine with orthogonal states is not written, every such state is
+/// reported as an error.
+pub fn generate_tla_fsm<P: AsRef<Path>>(fsm: &FSMachine, module: &str, out_dir: P) -> Result<Vec<Diagnostic>> {
+    let unsupported = unsupported_orthogonals(fsm, "TLA+");
+    if !unsupported.is_empty() {
+        return Ok(unsupported);
+    }
+    fsm_to_tla(fsm, module)?.write(out_dir.as_ref())?;
+    Ok(vec![])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg::cfg_cond::CfgCond;
+    use crate::cfg::cfg_node_kind::JoinKind;
+    use crate::codegen::unsupported::UNSUPPORTED_ORTHOGONAL_STATE;
+    use crate::fsm::context_field::ContextField;
+    use crate::fsm::orthogonal_state::OrthogonalState;
+
+    fn machine() -> FSMachine {
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "Check Stock".to_string()),
+            (StateId(2), "Ship".to_string()),
+            (StateId(3), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), None),
+            Transition::new(StateId(1), StateId(2), Some("in stock? 0 if".to_string()))
+                .with_cond(Some(CfgCond::new(0, "if".to_string()))),
+            Transition::new(StateId(1), StateId(3), Some(" 1 else".to_string()))
+                .with_cond(Some(CfgCond::new(1, "else".to_string()).with_fallback(true))),
+            Transition::new(StateId(2), StateId(3), None),
+        ];
+        let mut fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(3)]);
+        let order = vec![
+            ContextField::new("qty".to_string(), ContextType::Int),
+            ContextField::new("id".to_string(), ContextType::Named("OrderId".to_string())),
+        ];
+        fsm.set_context(vec![ContextField::new("Order".to_string(), ContextType::Record(order))]);
+        fsm
+    }
+
+    #[test]
+    fn test_fsm_to_tla() {
+        let spec = fsm_to_tla(&machine(), "Order").unwrap();
+        let module = &spec.module;
+        assert!(module.starts_with("---------------------------- MODULE Order ----"));
+        assert!(module.contains("CONSTANTS IntValue, OrderId\n"));
+        assert!(module.contains("VARIABLES pc, Order\n\nvars == <<pc, Order>>\n"));
+        assert!(module.contains("States == {\"Start0\", \"CheckStock1\", \"Ship2\", \"End3\"}"));
+        assert!(module.contains("    /\\ Order \\in [qty: Int, id: OrderId]\n"));
+        assert!(module.contains("    /\\ Order \\in [qty: IntValue, id: OrderId]\n"));
+        assert!(module.contains(
+            "\\* CheckStock1 -> Ship2: in stock?\n\
+             CheckStock1ToShip2 ==\n    \
+             /\\ pc = \"CheckStock1\"\n    \
+             /\\ pc' = \"Ship2\"\n    \
+             /\\ UNCHANGED <<Order>>\n"
+        ));
+        assert!(module.contains("    \\/ Ship2ToEnd3\n    \\/ Terminating\n"));
+        assert!(module.contains("\\* CheckStock1 -> End3: else\n"));
+        assert!(!module.contains("stage"));
+        assert!(module.trim_end().ends_with("===="));
+        assert!(spec.config.contains("    OrderId = {0, 1}\n"));
+        assert!(spec.config.contains("INVARIANT TypeOK\nPROPERTY Termination\n"));
+    }
+
+    #[test]
+    fn test_orthogonal_states_are_rejected() {
+        let out_dir = std::env::temp_dir().join("common_tla_orthogonal");
+        let _ = fs::remove_dir_all(&out_dir);
+        fs::create_dir_all(&out_dir).unwrap();
+        let mut fsm = machine();
+        fsm.add_orthogonal(StateId(2), OrthogonalState::new(vec![machine()], JoinKind::Any));
+        assert!(fsm_to_tla(&fsm, "Order").is_err());
+        let diagnostics = generate_tla_fsm(&fsm, "Order", &out_dir).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), UNSUPPORTED_ORTHOGONAL_STATE);
+        assert!(diagnostics[0].message().contains("`Ship` (id 2)"));
+        assert!(!out_dir.join("Order.tla").exists());
+    }
+
+    #[test]
+    fn test_guards_and_entries() {
+        let mut model = TlaModel::from_fsm(&machine(), "Order");
+        model.set_guard(1, "Order.qty > 0".to_string());
+        model.set_guard(2, "~(Order.qty > 0)".to_string());
+        model.add_variable("Stock", "IsFiniteSet(Stock)".to_string(), "Stock = {}".to_string());
+        model.set_entry(
+            StateId(2),
+            vec!["Stock' = {}".to_string()],
+            vec!["Stock".to_string()],
+        );
+        model.add_invariant("ShipPre", "precondition of Ship", "pc = \"Ship2\" => Order.qty > 0".to_string());
+        let spec = model.render().unwrap();
+        let module = &spec.module;
+        assert!(module.contains("VARIABLES pc, stage, Order, Stock\n"));
+        assert!(module.contains("    /\\ stage = \"entry\"\n"));
+        assert!(module.contains(
+            "Ship2Entry ==\n    \
+             /\\ pc = \"Ship2\"\n    \
+             /\\ stage = \"entry\"\n    \
+             /\\ Stock' = {}\n    \
+             /\\ stage' = \"exit\"\n    \
+             /\\ pc' = pc\n    \
+             /\\ UNCHANGED <<Order>>\n"
+        ));
+        assert!(module.contains(
+            "CheckStock1ToShip2 ==\n    \
+             /\\ pc = \"CheckStock1\"\n    \
+             /\\ stage = \"exit\"\n    \
+             /\\ Order.qty > 0\n    \
+             /\\ pc' = \"Ship2\"\n    \
+             /\\ stage' = \"entry\"\n    \
+             /\\ UNCHANGED <<Order, Stock>>\n"
+        ));
+        assert!(module.contains("ShipPre == pc = \"Ship2\" => Order.qty > 0\n"));
+        assert!(spec.config.contains("INVARIANT TypeOK\nINVARIANT ShipPre\n"));
+    }
+
+    #[test]
+    fn test_multi_line_condition_without_context() {
+        let states = BTreeMap::from([
+            (StateId(0), "START".to_string()),
+            (StateId(1), "END".to_string()),
+        ]);
+        let transitions = vec![
+            Transition::new(StateId(0), StateId(1), Some(" 0 in\nstock?".to_string()))
+                .with_cond(Some(CfgCond::new(0, "in\nstock?".to_string()))),
+        ];
+        let fsm = FSMachine::new(states, transitions, StateId(0), vec![StateId(1)]);
+        let module = fsm_to_tla(&fsm, "Order").unwrap().module;
+        assert!(module.contains(
+            "\\* Start0 -> End1: in stock?\n\
+             Start0ToEnd1 ==\n    \
+             /\\ pc = \"Start0\"\n    \
+             /\\ pc' = \"End1\"\n\n"
+        ));
+        assert!(!module.contains("UNCHANGED <<"));
+    }
+}
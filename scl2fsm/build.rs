@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    ts_build::gen_ts_const("tree-sitter-scl", &tree_sitter_scl::LANGUAGE.into())
+}
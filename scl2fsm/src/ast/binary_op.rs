@@ -21,3 +21,39 @@ pub enum BinaryOp {
     Or,        // \/
     Implies,  // =>
 }
+
+impl BinaryOp {
+    /// Binding strength, higher binds tighter (same order as the grammar)
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Implies => 1,
+            BinaryOp::Or => 2,
+            BinaryOp::And => 3,
+            BinaryOp::Eq | BinaryOp::Neq => 4,
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => 5,
+            BinaryOp::Add | BinaryOp::Sub => 6,
+            BinaryOp::Mul | BinaryOp::Div => 7,
+        }
+    }
+}
+
+impl std::fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BinaryOp::Eq => "=",
+            BinaryOp::Neq => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::And => "/\\",
+            BinaryOp::Or => "\\/",
+            BinaryOp::Implies => "=>",
+        };
+        f.write_str(s)
+    }
+}
@@ -20,6 +20,9 @@ pub enum Condition {
         rhs: Expr,
     },
 
+    /// Boolean valued expression that is not a comparison
+    Expr(Expr),
+
     /* ========= Quantifier (Relation-only) ========= */
 
     Quantifier {
@@ -29,4 +32,59 @@ pub enum Condition {
         pk_binding: Expr,
         body: Box<Condition>,
     },
-}
\ No newline at end of file
+}
+impl Condition {
+    /// Binding strength, comparisons and expressions are atoms here
+    fn precedence(&self) -> u8 {
+        match self {
+            Condition::Implies(..) => 1,
+            Condition::Or(..) => 2,
+            Condition::And(..) => 3,
+            Condition::Not(_) => 8,
+            // the body extends as far right as possible
+            Condition::Quantifier { .. } => 0,
+            Condition::Compare { .. } | Condition::Expr(_) => 9,
+        }
+    }
+
+    fn write_binary(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        lhs: &Condition,
+        op: &str,
+        rhs: &Condition,
+    ) -> std::fmt::Result {
+        let min = self.precedence();
+        write_operand(f, lhs, min)?;
+        write!(f, " {} ", op)?;
+        write_operand(f, rhs, min + 1)
+    }
+}
+
+/// Writes `condition`, in parentheses if it binds looser than `min`
+fn write_operand(f: &mut std::fmt::Formatter<'_>, condition: &Condition, min: u8) -> std::fmt::Result {
+    if condition.precedence() < min {
+        write!(f, "({})", condition)
+    } else {
+        write!(f, "{}", condition)
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Condition::And(lhs, rhs) => self.write_binary(f, lhs, "/\\", rhs),
+            Condition::Or(lhs, rhs) => self.write_binary(f, lhs, "\\/", rhs),
+            Condition::Implies(lhs, rhs) => self.write_binary(f, lhs, "=>", rhs),
+            Condition::Not(c) => {
+                write!(f, "~")?;
+                write_operand(f, c, 8)
+            }
+            Condition::Compare { lhs, op, rhs } => write!(f, "{} {} {}", lhs, op, rhs),
+            Condition::Expr(expr) => write!(f, "{}", expr),
+            Condition::Quantifier { kind, relation, var, pk_binding, body } => {
+                write!(f, "{} Relation {} {} by {} : {}", kind, relation, var, pk_binding, body)
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::ast::name::Name;
 use crate::ast::ty::Type;
 
+#[derive(Debug)]
 pub struct ContextDecl {
     name: Name,
     fields: Vec<(Name, Type)>,
@@ -13,6 +13,9 @@ pub enum Expr {
 
     Literal(Literal),
 
+    /// Struct literal: { a: expr, b: expr }
+    Struct(Vec<(Name, Expr)>),
+
     /// Variable reference (context field, let binding, use-imported name, etc.)
     Var(Name),
 
@@ -45,3 +48,61 @@ pub enum Expr {
         body: Box<Expr>,
     },
 }
+
+impl Expr {
+    /// Binding strength, atoms bind tightest
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Binary { op, .. } => op.precedence(),
+            Expr::Unary { .. } => 8,
+            // the body extends as far right as possible
+            Expr::Quantifier { .. } => 0,
+            _ => 9,
+        }
+    }
+}
+
+/// Writes `expr`, in parentheses if it binds looser than `min`
+fn write_operand(f: &mut std::fmt::Formatter<'_>, expr: &Expr, min: u8) -> std::fmt::Result {
+    if expr.precedence() < min {
+        write!(f, "({})", expr)
+    } else {
+        write!(f, "{}", expr)
+    }
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Literal(literal) => write!(f, "{}", literal),
+            Expr::Struct(fields) => {
+                write!(f, "{{ ")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, value)?;
+                }
+                write!(f, " }}")
+            }
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Field { base, field } => {
+                write_operand(f, base, 9)?;
+                write!(f, ".{}", field)
+            }
+            Expr::Binary { lhs, op, rhs } => {
+                // operators are left associative
+                write_operand(f, lhs, op.precedence())?;
+                write!(f, " {} ", op)?;
+                write_operand(f, rhs, op.precedence() + 1)
+            }
+            Expr::Unary { op, expr } => {
+                write!(f, "{}", op)?;
+                write_operand(f, expr, 9)
+            }
+            Expr::Quantifier { kind, relation, var, pk_binding, body } => {
+                write!(f, "{} Relation {} {} by {} : {}", kind, relation, var, pk_binding, body)
+            }
+        }
+    }
+}
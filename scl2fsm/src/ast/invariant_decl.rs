@@ -0,0 +1,27 @@
+use crate::ast::condition::Condition;
+use crate::ast::name::Name;
+
+/// `invariant Name { cond; ... }`, every condition holds in every state
+/// a run gets to
+#[derive(Debug)]
+pub struct InvariantDecl {
+    name: Name,
+    conditions: Vec<Condition>,
+}
+
+impl InvariantDecl {
+    pub fn new(name: Name, conditions: Vec<Condition>) -> Result<Self, String> {
+        if conditions.is_empty() {
+            return Err("invariant must have at least one condition".into());
+        }
+        Ok(Self { name, conditions })
+    }
+
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+}
@@ -8,3 +8,16 @@ pub enum Literal {
     Float(f64),
     Null,
 }
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Int(i) => write!(f, "{}", i),
+            Literal::Bool(b) => write!(f, "{}", b),
+            // escapes quotes and backslashes like the source does
+            Literal::String(s) => write!(f, "{:?}", s),
+            Literal::Float(x) => write!(f, "{:?}", x),
+            Literal::Null => f.write_str("null"),
+        }
+    }
+}
@@ -1,17 +1,18 @@
-mod name;
-mod program;
-mod type_decl;
-mod context_decl;
-mod state_decl;
-mod use_stmt;
-mod next;
-mod ty;
-mod literal;
-mod expr;
-mod binary_op;
-mod unary_op;
-mod quantifier;
-mod condition;
-mod compare_op;
-mod stmt;
-mod assignment;
\ No newline at end of file
+pub mod name;
+pub mod program;
+pub mod type_decl;
+pub mod context_decl;
+pub mod state_decl;
+pub mod invariant_decl;
+pub mod use_stmt;
+pub mod next;
+pub mod ty;
+pub mod literal;
+pub mod expr;
+pub mod binary_op;
+pub mod unary_op;
+pub mod quantifier;
+pub mod condition;
+pub mod compare_op;
+pub mod stmt;
+pub mod assignment;
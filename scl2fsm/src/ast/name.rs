@@ -1,6 +1,15 @@
 // name.rs
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct Name(String);
+use common::diag::source_span::SourceSpan;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// An identifier, the span records where it was written and takes no
+/// part in comparisons
+#[derive(Clone)]
+pub struct Name {
+    text: String,
+    span: Option<Box<SourceSpan>>,
+}
 
 impl Name {
     pub fn new(s: impl Into<String>) -> Result<Self, String> {
@@ -8,10 +17,45 @@ impl Name {
         if s.is_empty() {
             return Err("empty name".into());
         }
-        Ok(Self(s))
+        Ok(Self { text: s, span: None })
+    }
+
+    pub fn with_span(mut self, span: SourceSpan) -> Self {
+        self.span = Some(Box::new(span));
+        self
     }
 
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.text
+    }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_deref()
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Name {}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Name").field(&self.text).finish()
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
     }
 }
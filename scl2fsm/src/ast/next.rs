@@ -2,6 +2,7 @@ use crate::ast::ty::Type;
 // next.rs
 use crate::ast::{condition::Condition, name::Name};
 
+#[derive(Debug)]
 pub struct NextBlock {
     cases: Vec<NextCase>,
 }
@@ -19,6 +20,7 @@ impl NextBlock {
     }
 }
 
+#[derive(Debug)]
 pub enum NextCase {
     When {
         condition: Condition,
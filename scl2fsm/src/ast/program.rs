@@ -1,13 +1,16 @@
 // program.rs
 
 use crate::ast::context_decl::ContextDecl;
+use crate::ast::invariant_decl::InvariantDecl;
 use crate::ast::state_decl::StateDecl;
 use crate::ast::type_decl::TypeDecl;
 
+#[derive(Debug)]
 pub struct Program {
     types: Vec<TypeDecl>,
     contexts: Vec<ContextDecl>,
     states: Vec<StateDecl>,
+    invariants: Vec<InvariantDecl>,
 }
 
 impl Program {
@@ -15,8 +18,9 @@ impl Program {
         types: Vec<TypeDecl>,
         contexts: Vec<ContextDecl>,
         states: Vec<StateDecl>,
+        invariants: Vec<InvariantDecl>,
     ) -> Self {
-        Self { types, contexts, states }
+        Self { types, contexts, states, invariants }
     }
 
     pub fn types(&self) -> &[TypeDecl] {
@@ -30,4 +34,8 @@ impl Program {
     pub fn states(&self) -> &[StateDecl] {
         &self.states
     }
+
+    pub fn invariants(&self) -> &[InvariantDecl] {
+        &self.invariants
+    }
 }
@@ -3,3 +3,12 @@ pub enum QuantifierKind {
     Exists,   // \E
     ForAll,   // \A
 }
+
+impl std::fmt::Display for QuantifierKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantifierKind::Exists => f.write_str("\\E"),
+            QuantifierKind::ForAll => f.write_str("\\A"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::ast::condition::Condition;
 use crate::ast::stmt::Stmt;
 use crate::ast::use_stmt::UseStmt;
 // state.rs
@@ -6,9 +7,13 @@ use crate::ast::{
     next::NextBlock,
 };
 
+#[derive(Debug)]
 pub struct StateDecl {
     name: Name,
+    /// contexts listed after `uses`
+    contexts: Vec<Name>,
     uses: Vec<UseStmt>,
+    preconditions: Vec<Condition>,
     body: Vec<Stmt>,
     next: NextBlock,
 }
@@ -16,21 +21,35 @@ pub struct StateDecl {
 impl StateDecl {
     pub fn new(
         name: Name,
+        contexts: Vec<Name>,
         uses: Vec<UseStmt>,
+        preconditions: Vec<Condition>,
         body: Vec<Stmt>,
         next: NextBlock,
     ) -> Self {
-        Self { name, uses, body, next }
+        Self { name, contexts, uses, preconditions, body, next }
     }
 
     pub fn name(&self) -> &Name {
         &self.name
     }
 
+    pub fn contexts(&self) -> &[Name] {
+        &self.contexts
+    }
+
     pub fn uses(&self) -> &[UseStmt] {
         &self.uses
     }
 
+    pub fn preconditions(&self) -> &[Condition] {
+        &self.preconditions
+    }
+
+    pub fn body(&self) -> &[Stmt] {
+        &self.body
+    }
+
     pub fn next(&self) -> &NextBlock {
         &self.next
     }
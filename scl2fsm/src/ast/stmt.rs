@@ -28,6 +28,14 @@ pub enum Stmt {
         limit: Option<u64>,
     },
 
+    /// foreach x: T in collection { ... }
+    Foreach {
+        item: Name,
+        item_ty: Type,
+        collection: Name,
+        body: Vec<Stmt>,
+    },
+
     /* ========= Relation Write ========= */
 
     /// update Relation set a = expr, b = expr where cond;
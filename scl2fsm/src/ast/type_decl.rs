@@ -1,6 +1,7 @@
 // decl.rs
 use crate::ast::{name::Name, ty::Type};
 
+#[derive(Debug)]
 pub struct TypeDecl {
     name: Name,
     fields: Vec<(Name, Type)>,
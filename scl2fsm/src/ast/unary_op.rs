@@ -3,3 +3,12 @@ pub enum UnaryOp {
     Not,   // ~ or !
     Neg,   // -
 }
+
+impl std::fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnaryOp::Not => f.write_str("!"),
+            UnaryOp::Neg => f.write_str("-"),
+        }
+    }
+}
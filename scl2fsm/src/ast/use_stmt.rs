@@ -1,6 +1,7 @@
 // state.rs
 use crate::ast::{name::Name, ty::Type};
 
+#[derive(Debug)]
 pub enum UseStmt {
     State {
         source: QualifiedName,
@@ -16,6 +17,7 @@ pub enum UseStmt {
 
 
 // name.rs
+#[derive(Debug)]
 pub struct QualifiedName {
     path: Option<String>,
     name: Name,
@@ -0,0 +1,100 @@
+use crate::ast::program::Program;
+use crate::builder::lower::lower_program;
+use crate::parser::scl_parser::parse_scl;
+use crate::sema::checker::check_program;
+use common::diag::diagnostic::{has_errors, Diagnostic};
+use common::fsm::fs_machine::FSMachine;
+
+/// Builds a state machine from SCL source text.
+///
+/// The program is parsed, checked and lowered; every problem found on
+/// the way is returned as a list of diagnostics.
+pub fn build_fsm_from_scl(text: &str) -> Result<FSMachine, Vec<Diagnostic>> {
+    build_scl(text).map(|(_, fsm)| fsm)
+}
+
+/// Builds a state machine from SCL source text and keeps the program it
+/// was lowered from, for the back ends that look at the statements.
+pub fn build_scl(text: &str) -> Result<(Program, FSMachine), Vec<Diagnostic>> {
+    let program = parse_scl(text)?;
+
+    let diagnostics = check_program(&program);
+    if has_errors(&diagnostics) {
+        return Err(diagnostics);
+    }
+
+    let fsm = lower_program(&program)?;
+    Ok((program, fsm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::build_fsm_from_scl;
+    use common::csharp::csharp_gen::generate_csharp_fsm;
+    use common::rust::rust_gen::generate_rust_fsm;
+    use common::fsm::fs_machine::fsm_to_dot;
+
+    // balance_check.scl is empty and has no state to start from
+    const FIXTURES: [&str; 7] = [
+        include_str!("../../test_data/assertion.scl"),
+        include_str!("../../test_data/inventory.scl"),
+        include_str!("../../test_data/minimal.scl"),
+        include_str!("../../test_data/multi_guard.scl"),
+        include_str!("../../test_data/select_update.scl"),
+        include_str!("../../test_data/transfer.scl"),
+        include_str!("../../test_data/use_state.scl"),
+    ];
+
+    #[test]
+    fn test_builder() {
+        for text in FIXTURES {
+            let fsm = build_fsm_from_scl(text).unwrap();
+            let dot = fsm_to_dot(&fsm);
+            println!("{}", dot);
+        }
+    }
+
+    #[test]
+    fn test_csharp_gen() {
+        let out_dir = std::env::temp_dir().join("scl2fsm_csharp");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        for text in FIXTURES {
+            let fsm = build_fsm_from_scl(text).unwrap();
+            generate_csharp_fsm(&fsm, &out_dir).unwrap();
+        }
+        assert!(out_dir.join("State.cs").exists());
+        // the contexts of the last fixture
+        let context = std::fs::read_to_string(out_dir.join("Context.cs")).unwrap();
+        assert!(context.contains("public TransferCtxData TransferCtx { get; set; } = new TransferCtxData();"));
+    }
+
+    #[test]
+    fn test_rust_gen() {
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        for (i, text) in FIXTURES.into_iter().enumerate() {
+            let out_dir = std::env::temp_dir().join(format!("scl2fsm_rust_{}", i));
+            let _ = std::fs::remove_dir_all(&out_dir);
+            std::fs::create_dir_all(&out_dir).unwrap();
+            let fsm = build_fsm_from_scl(text).unwrap();
+            generate_rust_fsm(&fsm, &out_dir).unwrap();
+
+            let output = std::process::Command::new(&rustc)
+                .args(["--crate-type", "lib", "--edition", "2021", "-D", "warnings"])
+                .arg("--crate-name")
+                .arg(format!("scl_fixture_{}", i))
+                .arg("--out-dir")
+                .arg(&out_dir)
+                .arg(out_dir.join("mod.rs"))
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    #[test]
+    fn test_check_errors_stop_lowering() {
+        let text = "state A uses Missing {\n  next { otherwise => A; }\n}\n";
+        let diagnostics = build_fsm_from_scl(text).unwrap_err();
+        assert!(!diagnostics.is_empty());
+    }
+}
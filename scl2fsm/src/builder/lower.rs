@@ -0,0 +1,159 @@
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::ty::{PrimitiveType, Type};
+use crate::sema::checker::UNDEFINED_STATE;
+use common::cfg::cfg_cond::CfgCond;
+use common::diag::diagnostic::Diagnostic;
+use common::fsm::context_field::{ContextField, ContextType};
+use common::fsm::fs_machine::{FSMachine, NO_START_STATE};
+use common::fsm::state_id::StateId;
+use common::fsm::transition::Transition;
+use std::collections::{BTreeMap, HashMap};
+
+/// Lowers the states of a program into a state machine
+///
+/// Every `state` becomes one FSM state, numbered in declaration order,
+/// and the first one is the start state. Each `next` case becomes one
+/// transition, in source order, whose condition carries the case's
+/// position as its `CfgCond` sequence number: the name of a `when`
+/// case's condition is its guard text, as for the branches of an
+/// activity diagram, `otherwise` is the fallback taken after every guard
+/// before it failed. A state whose cases all lead back to itself is
+/// terminal. Every `context` declaration becomes a record field of the
+/// machine's context, named after the declaration.
+pub fn lower_program(program: &Program) -> Result<FSMachine, Vec<Diagnostic>> {
+    let mut states = BTreeMap::new();
+    let mut ids = HashMap::new();
+    for (i, state) in program.states().iter().enumerate() {
+        let id = StateId(i);
+        states.insert(id, state.name().as_str().to_string());
+        ids.insert(state.name().as_str(), id);
+    }
+    if program.states().is_empty() {
+        let d = Diagnostic::error(NO_START_STATE, "the program declares no state".to_string());
+        return Err(vec![d]);
+    }
+
+    let mut diagnostics = vec![];
+    let mut transitions = vec![];
+    let mut terminals = vec![];
+    for state in program.states() {
+        let from = ids[state.name().as_str()];
+        let mut only_self_loops = true;
+        for (seq, case) in state.next().cases().iter().enumerate() {
+            let (target, condition, cond) = match case {
+                NextCase::When { condition, target, .. } => {
+                    let cond = CfgCond::new(seq as u64, condition.to_string());
+                    (target, cond.to_string(), cond)
+                }
+                NextCase::Otherwise { target, .. } => {
+                    let cond = CfgCond::new(seq as u64, "otherwise".to_string()).with_fallback(true);
+                    (target, cond.to_string(), cond)
+                }
+            };
+            let Some(to) = ids.get(target.as_str()).copied() else {
+                let mut d = Diagnostic::error(
+                    UNDEFINED_STATE,
+                    format!("transition to undeclared state `{}`", target),
+                );
+                if let Some(span) = target.span() {
+                    d = d.with_span(*span);
+                }
+                diagnostics.push(d);
+                continue;
+            };
+            only_self_loops &= to == from;
+            transitions.push(Transition::new(from, to, Some(condition)).with_cond(Some(cond)));
+        }
+        if only_self_loops {
+            terminals.push(from);
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let mut fsm = FSMachine::new(states, transitions, StateId(0), terminals);
+    let context = program
+        .contexts()
+        .iter()
+        .map(|decl| {
+            let fields = decl
+                .fields()
+                .iter()
+                .map(|(name, ty)| ContextField::new(name.as_str().to_string(), context_type(ty)))
+                .collect();
+            ContextField::new(decl.name().as_str().to_string(), ContextType::Record(fields))
+        })
+        .collect();
+    fsm.set_context(context);
+    Ok(fsm)
+}
+
+fn context_type(ty: &Type) -> ContextType {
+    match ty {
+        Type::Primitive(PrimitiveType::Bool) => ContextType::Bool,
+        Type::Primitive(PrimitiveType::Int) => ContextType::Int,
+        Type::Primitive(PrimitiveType::Float) => ContextType::Float,
+        Type::Primitive(PrimitiveType::String) => ContextType::String,
+        Type::Named(name) => ContextType::Named(name.as_str().to_string()),
+        Type::Generic { base, param } => {
+            ContextType::Named(format!("{}[{}]", base.as_str(), context_type(param)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lower_program;
+    use crate::parser::scl_parser::parse_scl;
+    use crate::sema::checker::UNDEFINED_STATE;
+    use common::fsm::fs_machine::NO_START_STATE;
+    use common::fsm::state_id::StateId;
+
+    #[test]
+    fn test_lower_multi_guard() {
+        let program = parse_scl(include_str!("../../test_data/multi_guard.scl")).unwrap();
+        let fsm = lower_program(&program).unwrap();
+        assert_eq!(fsm.state_map().len(), 4);
+        assert_eq!(fsm.state_map()[&StateId(0)], "Decide");
+        assert_eq!(fsm.start_id(), StateId(0));
+        assert_eq!(fsm.terminals(), &vec![StateId(1), StateId(2), StateId(3)]);
+        assert_eq!(fsm.context()[0].to_string(), "NumCtx: { x: int }");
+
+        let from_decide: Vec<_> = fsm
+            .transitions()
+            .iter()
+            .filter(|t| t.from() == StateId(0))
+            .map(|t| (t.to(), t.condition().clone().unwrap()))
+            .collect();
+        assert_eq!(
+            from_decide,
+            vec![
+                (StateId(1), " 0 NumCtx.x > 10".to_string()),
+                (StateId(2), " 1 NumCtx.x > 0".to_string()),
+                (StateId(3), " 2 otherwise".to_string()),
+            ]
+        );
+        let fallbacks: Vec<_> = fsm
+            .transitions()
+            .iter()
+            .filter(|t| t.from() == StateId(0))
+            .map(|t| t.cond().as_ref().unwrap().is_fallback())
+            .collect();
+        assert_eq!(fallbacks, vec![false, false, true]);
+    }
+
+    #[test]
+    fn test_lower_errors() {
+        let program = parse_scl("").unwrap();
+        let diagnostics = lower_program(&program).unwrap_err();
+        assert_eq!(diagnostics[0].code(), NO_START_STATE);
+
+        let program = parse_scl("context C { x: int; }\n\nstate A uses C {\n  next { otherwise => B; }\n}\n").unwrap();
+        let diagnostics = lower_program(&program).unwrap_err();
+        assert_eq!(diagnostics[0].code(), UNDEFINED_STATE);
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (4, 23));
+    }
+}
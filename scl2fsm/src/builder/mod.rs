@@ -0,0 +1,5 @@
+pub mod lower;
+pub mod tla;
+
+#[allow(clippy::module_inception)]
+pub mod builder;
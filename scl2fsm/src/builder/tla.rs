@@ -0,0 +1,476 @@
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::compare_op::CompareOp;
+use crate::ast::condition::Condition;
+use crate::ast::expr::Expr;
+use crate::ast::literal::Literal;
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::quantifier::QuantifierKind;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::Type;
+use crate::ast::unary_op::UnaryOp;
+use common::diag::diagnostic::Diagnostic;
+use common::fsm::fs_machine::FSMachine;
+use common::fsm::state_id::StateId;
+use common::tla::tla_model::TlaModel;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Diagnostic code: a statement or value has no TLA+ counterpart, it is
+/// left out of the module
+pub const UNSUPPORTED_IN_TLA: &str = "SCL0201";
+
+/// Name of the row bound by the set comprehensions of `update` and
+/// `delete`
+const ROW: &str = "tuple";
+
+/// Builds the TLA+ model of a checked program and the machine it was
+/// lowered to
+///
+/// Guards are translated: the `when` cases of a state are tried in order,
+/// `otherwise` is taken when all of them fail. Every relation the program
+/// touches becomes a variable holding a set of records, with the columns
+/// the program uses; `Init` picks them from the `RowValue` domain. The
+/// statements of a state form its entry action and are applied in order:
+/// `let` and `select` bind values, `update`, `insert` and `delete` rewrite
+/// their relation, an `assert` has to hold for the state to be entered
+/// at all. `commit` has no effect in the model. Preconditions become
+/// invariants, and so do the declared invariants. `foreach` loops and
+/// float values are reported and left out.
+pub fn build_tla_model(program: &Program, fsm: &FSMachine, module: &str) -> (TlaModel, Vec<Diagnostic>) {
+    let mut model = TlaModel::from_fsm(fsm, module);
+    let mut translator = Translator {
+        contexts: program.contexts().iter().map(|c| c.name().as_str().to_string()).collect(),
+        columns: BTreeMap::new(),
+        diagnostics: vec![],
+        uses_null: false,
+    };
+
+    // transitions were lowered state by state, case by case
+    let mut index = 0;
+    let mut preconditions = vec![];
+    for (i, state) in program.states().iter().enumerate() {
+        let id = StateId(i);
+        let mut scope = Scope::default();
+        let mut relations = BTreeMap::new();
+        let mut conjuncts = vec![];
+        for stmt in state.body() {
+            translator.statement(stmt, &mut scope, &mut relations, &mut conjuncts);
+        }
+        if !relations.is_empty() || !conjuncts.is_empty() {
+            let changed = relations.keys().cloned().collect();
+            conjuncts.extend(relations.iter().map(|(name, value)| format!("{}' = {}", name, value)));
+            model.set_entry(id, conjuncts, changed);
+        }
+
+        let mut failed = vec![];
+        for case in state.next().cases() {
+            let mut guard = failed.clone();
+            if let NextCase::When { condition, .. } = case {
+                let condition = translator.condition(condition, &Scope::default());
+                guard.push(condition.clone());
+                failed.push(format!("~{}", condition));
+            }
+            if !guard.is_empty() {
+                model.set_guard(index, guard.join(" /\\ "));
+            }
+            index += 1;
+        }
+
+        for (n, condition) in state.preconditions().iter().enumerate() {
+            let condition = translator.condition(condition, &Scope::default());
+            preconditions.push((id, state.name().as_str().to_string(), n, condition));
+        }
+    }
+
+    for (relation, columns) in &translator.columns {
+        let (type_ok, init) = if columns.is_empty() {
+            (format!("IsFiniteSet({})", relation), format!("{} = {{}}", relation))
+        } else {
+            let names: Vec<_> = columns.iter().map(|c| format!("\"{}\"", c)).collect();
+            let rows: Vec<_> = columns.iter().map(|c| format!("{}: RowValue", c)).collect();
+            (
+                format!(
+                    "IsFiniteSet({}) /\\ \\A r \\in {} : DOMAIN r = {{{}}}",
+                    relation,
+                    relation,
+                    names.join(", ")
+                ),
+                format!("{} \\in SUBSET [{}]", relation, rows.join(", ")),
+            )
+        };
+        model.add_variable(relation, type_ok, init);
+        if !columns.is_empty() {
+            model.add_constant("RowValue", "{0, 1}");
+        }
+    }
+
+    // a precondition holds whenever its state is entered
+    let staged = model.is_staged();
+    for (id, name, n, condition) in preconditions {
+        let mut entered = format!("pc = \"{}\"", model.pc(id));
+        if staged {
+            entered.push_str(" /\\ stage = \"entry\"");
+        }
+        model.add_invariant(
+            &format!("{}Pre{}", model.pc(id), n),
+            &format!("precondition {} of {}", n, name),
+            format!("{} => {}", entered, condition),
+        );
+    }
+    for invariant in program.invariants() {
+        let conditions: Vec<_> = invariant
+            .conditions()
+            .iter()
+            .map(|c| translator.condition(c, &Scope::default()))
+            .collect();
+        model.add_invariant(
+            invariant.name().as_str(),
+            &format!("invariant {}", invariant.name()),
+            conditions.join(" /\\ "),
+        );
+    }
+    if translator.uses_null {
+        // a model value, equal to nothing but itself
+        model.add_constant("Null", "Null");
+    }
+    (model, translator.diagnostics)
+}
+
+/// Names bound by statements and quantifiers
+#[derive(Clone, Default)]
+struct Scope {
+    /// SCL name -> TLA+ expression
+    locals: HashMap<String, String>,
+    /// locals that are rows, with their relation
+    rows: HashMap<String, String>,
+    /// the row bare column names refer to, with its relation
+    row: Option<(String, String)>,
+}
+
+struct Translator {
+    contexts: HashSet<String>,
+    /// relation -> columns the program uses
+    columns: BTreeMap<String, BTreeSet<String>>,
+    diagnostics: Vec<Diagnostic>,
+    uses_null: bool,
+}
+
+impl Translator {
+    /// Translates one statement of a state body; `relations` holds the
+    /// value every written relation has after the statements so far
+    fn statement(
+        &mut self,
+        stmt: &Stmt,
+        scope: &mut Scope,
+        relations: &mut BTreeMap<String, String>,
+        conjuncts: &mut Vec<String>,
+    ) {
+        let current = |relations: &BTreeMap<String, String>, relation: &str| {
+            relations.get(relation).cloned().unwrap_or_else(|| relation.to_string())
+        };
+        match stmt {
+            Stmt::Let { name, value, .. } => {
+                let value = self.expr(value, scope);
+                scope.locals.insert(name.as_str().to_string(), value);
+            }
+            Stmt::Select { name, ty, relation, where_clause, .. } => {
+                let relation = relation.as_str();
+                self.columns.entry(relation.to_string()).or_default();
+                let var = name.as_str();
+                let mut inner = scope.clone();
+                inner.locals.insert(var.to_string(), var.to_string());
+                inner.rows.insert(var.to_string(), relation.to_string());
+                inner.row = Some((var.to_string(), relation.to_string()));
+                let filter = match where_clause {
+                    Some(condition) => self.condition(condition, &inner),
+                    None => "TRUE".to_string(),
+                };
+                let rows = format!("{{{} \\in {} : {}}}", var, current(relations, relation), filter);
+                // a collection keeps every row, a single row is one of them
+                let value = match ty {
+                    Type::Generic { .. } => rows,
+                    _ => {
+                        scope.rows.insert(var.to_string(), relation.to_string());
+                        format!("(CHOOSE {} \\in {} : TRUE)", var, rows)
+                    }
+                };
+                scope.locals.insert(var.to_string(), value);
+            }
+            Stmt::Update { relation, assignments, where_clause } => {
+                let relation = relation.as_str();
+                let mut inner = scope.clone();
+                inner.row = Some((ROW.to_string(), relation.to_string()));
+                let filter = match where_clause {
+                    Some(condition) => self.condition(condition, &inner),
+                    None => "TRUE".to_string(),
+                };
+                let mut changes = vec![];
+                for a in assignments {
+                    self.column(relation, a.field().as_str());
+                    changes.push(format!("!.{} = {}", a.field(), self.expr(a.value(), &inner)));
+                }
+                let value = format!(
+                    "{{IF {} THEN [{} EXCEPT {}] ELSE {} : {} \\in {}}}",
+                    filter,
+                    ROW,
+                    changes.join(", "),
+                    ROW,
+                    ROW,
+                    current(relations, relation)
+                );
+                relations.insert(relation.to_string(), value);
+            }
+            Stmt::Insert { relation, columns, values } => {
+                let relation = relation.as_str();
+                self.columns.entry(relation.to_string()).or_default();
+                let mut fields = vec![];
+                for (column, value) in columns.iter().zip(values) {
+                    self.column(relation, column.as_str());
+                    fields.push(format!("{} |-> {}", column, self.expr(value, scope)));
+                }
+                let row = if fields.is_empty() {
+                    "<<>>".to_string()
+                } else {
+                    format!("[{}]", fields.join(", "))
+                };
+                let value = format!("({} \\cup {{{}}})", current(relations, relation), row);
+                relations.insert(relation.to_string(), value);
+            }
+            Stmt::Delete { relation, where_clause } => {
+                let relation = relation.as_str();
+                self.columns.entry(relation.to_string()).or_default();
+                let mut inner = scope.clone();
+                inner.row = Some((ROW.to_string(), relation.to_string()));
+                let filter = match where_clause {
+                    Some(condition) => self.condition(condition, &inner),
+                    None => "TRUE".to_string(),
+                };
+                let value = format!("{{{} \\in {} : ~{}}}", ROW, current(relations, relation), filter);
+                relations.insert(relation.to_string(), value);
+            }
+            Stmt::Assert { condition } => {
+                // the assertion sees the writes before it
+                let mut inner = scope.clone();
+                for (relation, value) in relations.iter() {
+                    inner.locals.insert(relation.clone(), value.clone());
+                }
+                conjuncts.push(self.condition(condition, &inner));
+            }
+            Stmt::Foreach { item, collection, .. } => {
+                self.diagnostics.push(Diagnostic::warning(
+                    UNSUPPORTED_IN_TLA,
+                    format!("`foreach {} in {}` is not modelled in TLA+, its body is left out", item, collection),
+                ));
+            }
+            Stmt::Commit => {}
+        }
+    }
+
+    /// Records that `relation` has `column`
+    fn column(&mut self, relation: &str, column: &str) {
+        self.columns.entry(relation.to_string()).or_default().insert(column.to_string());
+    }
+
+    fn condition(&mut self, condition: &Condition, scope: &Scope) -> String {
+        match condition {
+            Condition::And(lhs, rhs) => {
+                format!("({} /\\ {})", self.condition(lhs, scope), self.condition(rhs, scope))
+            }
+            Condition::Or(lhs, rhs) => {
+                format!("({} \\/ {})", self.condition(lhs, scope), self.condition(rhs, scope))
+            }
+            Condition::Implies(lhs, rhs) => {
+                format!("({} => {})", self.condition(lhs, scope), self.condition(rhs, scope))
+            }
+            Condition::Not(c) => format!("~{}", self.condition(c, scope)),
+            Condition::Compare { lhs, op, rhs } => {
+                let op = match op {
+                    CompareOp::Eq => "=",
+                    CompareOp::Neq => "#",
+                    CompareOp::Lt => "<",
+                    CompareOp::Le => "=<",
+                    CompareOp::Gt => ">",
+                    CompareOp::Ge => ">=",
+                };
+                format!("({} {} {})", self.expr(lhs, scope), op, self.expr(rhs, scope))
+            }
+            Condition::Expr(expr) => self.expr(expr, scope),
+            Condition::Quantifier { kind, relation, var, pk_binding, body } => {
+                let inner = self.bind_row(scope, relation.as_str(), var.as_str());
+                let pk = self.expr(pk_binding, &inner);
+                let body = self.condition(body, &inner);
+                self.quantifier(*kind, relation.as_str(), var.as_str(), pk, body, scope)
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr, scope: &Scope) -> String {
+        match expr {
+            Expr::Literal(literal) => match literal {
+                Literal::Int(i) => i.to_string(),
+                Literal::Bool(b) => if *b { "TRUE" } else { "FALSE" }.to_string(),
+                Literal::String(s) => format!("{:?}", s),
+                Literal::Float(x) => {
+                    self.diagnostics.push(Diagnostic::warning(
+                        UNSUPPORTED_IN_TLA,
+                        format!("TLC has no float values, `{:?}` is written as it is", x),
+                    ));
+                    format!("{:?}", x)
+                }
+                Literal::Null => {
+                    self.uses_null = true;
+                    "Null".to_string()
+                }
+            },
+            Expr::Struct(fields) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(name, value)| format!("{} |-> {}", name, self.expr(value, scope)))
+                    .collect();
+                format!("[{}]", fields.join(", "))
+            }
+            Expr::Var(name) => {
+                let name = name.as_str();
+                if let Some(value) = scope.locals.get(name) {
+                    return value.clone();
+                }
+                match &scope.row {
+                    Some((row, relation)) if !self.contexts.contains(name) => {
+                        self.column(&relation.clone(), name);
+                        format!("{}.{}", row, name)
+                    }
+                    _ => name.to_string(),
+                }
+            }
+            Expr::Field { base, field } => {
+                if let Expr::Var(var) = base.as_ref()
+                    && let Some(relation) = scope.rows.get(var.as_str())
+                {
+                    self.column(&relation.clone(), field.as_str());
+                }
+                format!("{}.{}", self.expr(base, scope), field)
+            }
+            Expr::Binary { lhs, op, rhs } => {
+                let op = match op {
+                    BinaryOp::Eq => "=",
+                    BinaryOp::Neq => "#",
+                    BinaryOp::Lt => "<",
+                    BinaryOp::Le => "=<",
+                    BinaryOp::Gt => ">",
+                    BinaryOp::Ge => ">=",
+                    BinaryOp::Add => "+",
+                    BinaryOp::Sub => "-",
+                    BinaryOp::Mul => "*",
+                    BinaryOp::Div => "\\div",
+                    BinaryOp::And => "/\\",
+                    BinaryOp::Or => "\\/",
+                    BinaryOp::Implies => "=>",
+                };
+                format!("({} {} {})", self.expr(lhs, scope), op, self.expr(rhs, scope))
+            }
+            Expr::Unary { op, expr } => match op {
+                // `~` binds looser than the comparisons in TLA+
+                UnaryOp::Not => format!("(~{})", self.expr(expr, scope)),
+                UnaryOp::Neg => format!("-{}", self.expr(expr, scope)),
+            },
+            Expr::Quantifier { kind, relation, var, pk_binding, body } => {
+                let inner = self.bind_row(scope, relation.as_str(), var.as_str());
+                let pk = self.expr(pk_binding, &inner);
+                let body = self.expr(body, &inner);
+                self.quantifier(*kind, relation.as_str(), var.as_str(), pk, body, scope)
+            }
+        }
+    }
+
+    /// Scope of a quantifier body, `var` is a row of `relation`
+    fn bind_row(&mut self, scope: &Scope, relation: &str, var: &str) -> Scope {
+        self.columns.entry(relation.to_string()).or_default();
+        let mut inner = scope.clone();
+        inner.locals.insert(var.to_string(), var.to_string());
+        inner.rows.insert(var.to_string(), relation.to_string());
+        inner
+    }
+
+    /// `\E var \in relation : pk /\ body` or `\A var \in relation : pk => body`
+    fn quantifier(
+        &self,
+        kind: QuantifierKind,
+        relation: &str,
+        var: &str,
+        pk: String,
+        body: String,
+        scope: &Scope,
+    ) -> String {
+        // an assertion quantifies over the relation as written so far
+        let rows = scope.locals.get(relation).map_or(relation, String::as_str);
+        match kind {
+            QuantifierKind::Exists => format!("(\\E {} \\in {} : {} /\\ {})", var, rows, pk, body),
+            QuantifierKind::ForAll => format!("(\\A {} \\in {} : {} => {})", var, rows, pk, body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::builder::build_scl;
+
+    fn model(text: &str) -> (String, String, Vec<Diagnostic>) {
+        let (program, fsm) = build_scl(text).unwrap();
+        let (model, diagnostics) = build_tla_model(&program, &fsm, "Spec");
+        let spec = model.render().unwrap();
+        (spec.module, spec.config, diagnostics)
+    }
+
+    #[test]
+    fn test_guards() {
+        let (module, config, diagnostics) = model(include_str!("../../test_data/multi_guard.scl"));
+        assert!(diagnostics.is_empty());
+        assert!(module.contains("    /\\ NumCtx \\in [x: Int]\n"));
+        assert!(module.contains(
+            "Decide0ToSmall2 ==\n    \
+             /\\ pc = \"Decide0\"\n    \
+             /\\ ~(NumCtx.x > 10) /\\ (NumCtx.x > 0)\n"
+        ));
+        assert!(module.contains(
+            "Decide0ToZero3 ==\n    \
+             /\\ pc = \"Decide0\"\n    \
+             /\\ ~(NumCtx.x > 10) /\\ ~(NumCtx.x > 0)\n"
+        ));
+        assert!(config.contains("    IntValue = {0, 1}\n"));
+    }
+
+    #[test]
+    fn test_relation_operations() {
+        let (module, config, diagnostics) = model(include_str!("../../test_data/transfer.scl"));
+        assert!(diagnostics.is_empty());
+        assert!(module.contains("VARIABLES pc, stage, TransferCtx, Account\n"));
+        assert!(module.contains(
+            "    /\\ IsFiniteSet(Account) /\\ \\A r \\in Account : DOMAIN r = {\"balance\", \"id\"}\n"
+        ));
+        assert!(module.contains("    /\\ Account \\in SUBSET [balance: RowValue, id: RowValue]\n"));
+        assert!(module.contains(
+            "    /\\ (\\E a \\in Account : (a.id = TransferCtx.from) /\\ (a.balance >= TransferCtx.amount))\n"
+        ));
+        assert!(module.contains(
+            "    /\\ Account' = {IF (tuple.id = TransferCtx.from) \
+             THEN [tuple EXCEPT !.balance = (tuple.balance - TransferCtx.amount)] \
+             ELSE tuple : tuple \\in Account}\n"
+        ));
+        assert!(config.contains("    RowValue = {0, 1}\n"));
+
+        let (module, config, diagnostics) = model(include_str!("../../test_data/inventory.scl"));
+        assert!(module.contains("Reserve0Pre0 == pc = \"Reserve0\" /\\ stage = \"entry\" => (StockCtx.qty > 0)\n"));
+        assert!(module.contains("    /\\ Orders' = (Orders \\cup {<<>>})\n"));
+        assert!(module.contains("    /\\ ((~StockCtx.active) = FALSE)\n"));
+        assert!(module.contains("CONSTANTS IntValue, Null, RowValue, StringValue\n"));
+        assert!(module.contains(
+            "StockKept == (\\A s \\in Stock : (s.item_id = StockCtx.item_id) => (s.count >= 0)) \
+             /\\ (StockCtx.qty >= 0)\n"
+        ));
+        assert!(config.contains("INVARIANT StockKept\n"));
+        let codes: Vec<_> = diagnostics.iter().map(|d| d.code()).collect();
+        // the float of the loop body is never looked at
+        assert_eq!(codes, vec![UNSUPPORTED_IN_TLA]);
+    }
+}
@@ -0,0 +1,363 @@
+use crate::builder::builder::{build_fsm_from_scl, build_scl};
+use crate::builder::tla::build_tla_model;
+use crate::mc::domains::{parse_domains, Domains};
+use crate::mc::model_checker::{check_model, DEFAULT_MAX_STATES};
+use crate::parser::scl_parser::parse_scl;
+use crate::sema::checker::check_program;
+use crate::sql::ddl::generate_ddl;
+use crate::sql::dialect::Dialect;
+use crate::sql::schema::infer_schemas;
+use crate::sql::sql_gen::{generate_sql, render_sql};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::cli::exit_code::{EXIT_IO, EXIT_OK};
+use common::cli::report::report;
+use common::cli::source::Source;
+use common::codegen::naming::camel_name;
+use common::csharp::csharp_gen::generate_csharp_fsm;
+use common::rust::rust_gen::generate_rust_fsm;
+use common::fsm::analysis::analyze_fsm;
+use common::fsm::fs_machine::fsm_to_dot;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Converts State Construction Language programs into finite state machines
+///
+/// Exit codes: 0 success, 1 the program has errors, 2 invalid command
+/// line, 3 input/output failure.
+#[derive(Parser, Debug)]
+#[command(name = "scl2fsm", version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Parse the program and dump its AST
+    Parse(InputArgs),
+    /// Build the state machine and emit it as DOT
+    Fsm(InputArgs),
+    /// Generate code for the state machine
+    Gen {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Target language
+        #[arg(long, value_enum)]
+        target: Target,
+        /// Directory the generated files are written to
+        #[arg(long)]
+        out: PathBuf,
+        /// SQL dialect, for the `sql` and `ddl` targets
+        #[arg(long, value_enum, default_value_t = Dialect::Postgresql)]
+        dialect: Dialect,
+    },
+    /// Report diagnostics only
+    Check(InputArgs),
+    /// Report unreachable states, deadlocks, states that cannot terminate
+    /// and decisions without a fallback, as warnings
+    Analyze(InputArgs),
+    /// Run the program on every combination of bounded inputs and check
+    /// its asserts, preconditions and invariants; a violation is reported
+    /// with the run that leads to it
+    Verify {
+        #[command(flatten)]
+        input: InputArgs,
+        /// Values of the context fields and initial rows of the relations,
+        /// defaults to the `.domains` file next to the input if there is one
+        #[arg(long)]
+        domains: Option<PathBuf>,
+        /// Number of states looked at before the search gives up
+        #[arg(long, default_value_t = DEFAULT_MAX_STATES)]
+        max_states: usize,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// SCL file to read, stdin if omitted or `-`
+    pub input: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    Csharp,
+    Rust,
+    /// A TLA+ module and its TLC configuration, with the statements of
+    /// the states as entry actions
+    Tla,
+    /// The parameterised queries of every state as an SQL script
+    Sql,
+    /// `CREATE TABLE` statements for the relations the program uses
+    Ddl,
+}
+
+/// Runs a parsed command line, returns the process exit code
+pub fn run<O: Write, E: Write>(cli: Cli, out: &mut O, err: &mut E) -> u8 {
+    let result = match &cli.command {
+        Command::Parse(input) => cmd_parse(input, out, err),
+        Command::Fsm(input) => cmd_fsm(input, out, err),
+        Command::Gen { input, target, out: out_dir, dialect } => cmd_gen(input, *target, *dialect, out_dir, err),
+        Command::Check(input) => cmd_check(input, err),
+        Command::Analyze(input) => cmd_analyze(input, err),
+        Command::Verify { input, domains, max_states } => {
+            cmd_verify(input, domains.as_deref(), *max_states, out, err)
+        }
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            let _ = writeln!(err, "error: {}", e);
+            EXIT_IO
+        }
+    }
+}
+
+fn cmd_parse<O: Write, E: Write>(input: &InputArgs, out: &mut O, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    match parse_scl(&source.text) {
+        Ok(program) => {
+            writeln!(out, "{:#?}", program)?;
+            Ok(EXIT_OK)
+        }
+        Err(diagnostics) => report(err, &source.origin, &diagnostics),
+    }
+}
+
+fn cmd_fsm<O: Write, E: Write>(input: &InputArgs, out: &mut O, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    match build_fsm_from_scl(&source.text) {
+        Ok(fsm) => {
+            write!(out, "{}", fsm_to_dot(&fsm))?;
+            Ok(EXIT_OK)
+        }
+        Err(diagnostics) => report(err, &source.origin, &diagnostics),
+    }
+}
+
+fn cmd_gen<E: Write>(
+    input: &InputArgs,
+    target: Target,
+    dialect: Dialect,
+    out_dir: &Path,
+    err: &mut E,
+) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let (program, fsm) = match build_scl(&source.text) {
+        Ok(built) => built,
+        Err(diagnostics) => return report(err, &source.origin, &diagnostics),
+    };
+    fs::create_dir_all(out_dir)?;
+    let result = match target {
+        Target::Csharp => generate_csharp_fsm(&fsm, out_dir),
+        Target::Rust => generate_rust_fsm(&fsm, out_dir),
+        Target::Tla => {
+            // what the module leaves out is reported against the program
+            let (model, diagnostics) = build_tla_model(&program, &fsm, &module_name(input));
+            report(err, &source.origin, &diagnostics)?;
+            model.render().and_then(|spec| Ok(spec.write(out_dir)?)).map(|_| vec![])
+        }
+        Target::Sql => {
+            // so are the values without an SQL counterpart
+            let (states, diagnostics) = generate_sql(&program, dialect);
+            report(err, &source.origin, &diagnostics)?;
+            let path = out_dir.join(format!("{}.sql", module_name(input)));
+            fs::write(path, render_sql(&states, dialect)).map(|_| vec![]).map_err(Into::into)
+        }
+        Target::Ddl => {
+            let (schemas, diagnostics) = infer_schemas(&program);
+            report(err, &source.origin, &diagnostics)?;
+            let path = out_dir.join(format!("{}.schema.sql", module_name(input)));
+            fs::write(path, generate_ddl(&schemas, dialect)).map(|_| vec![]).map_err(Into::into)
+        }
+    };
+    match result {
+        // orphaned user code is reported against the output directory
+        Ok(diagnostics) => report(err, &out_dir.display().to_string(), &diagnostics),
+        Err(e) => {
+            writeln!(err, "error: code generation failed: {}", e)?;
+            Ok(EXIT_IO)
+        }
+    }
+}
+
+/// Name of the TLA+ module or SQL scripts generated for `input`, after
+/// the input file
+fn module_name(input: &InputArgs) -> String {
+    match input.input.as_ref().and_then(|p| p.file_stem()) {
+        Some(stem) if stem != "-" => camel_name(&stem.to_string_lossy()),
+        _ => "Machine".to_string(),
+    }
+}
+
+fn cmd_check<E: Write>(input: &InputArgs, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let diagnostics = match parse_scl(&source.text) {
+        Ok(program) => check_program(&program),
+        Err(diagnostics) => diagnostics,
+    };
+    report(err, &source.origin, &diagnostics)
+}
+
+fn cmd_analyze<E: Write>(input: &InputArgs, err: &mut E) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let fsm = match build_fsm_from_scl(&source.text) {
+        Ok(fsm) => fsm,
+        Err(diagnostics) => return report(err, &source.origin, &diagnostics),
+    };
+    let diagnostics: Vec<_> = analyze_fsm(&fsm).iter().map(|f| f.to_diagnostic()).collect();
+    report(err, &source.origin, &diagnostics)
+}
+
+/// Reads the domains file given on the command line, or the one next to
+/// the input
+fn read_domains(input: &InputArgs, domains: Option<&Path>) -> io::Result<Option<Source>> {
+    Source::read_sidecar(domains, input.input.as_deref(), "domains")
+}
+
+fn cmd_verify<O: Write, E: Write>(
+    input: &InputArgs,
+    domains: Option<&Path>,
+    max_states: usize,
+    out: &mut O,
+    err: &mut E,
+) -> io::Result<u8> {
+    let source = Source::read(input.input.as_deref())?;
+    let program = match build_scl(&source.text) {
+        Ok((program, _)) => program,
+        Err(diagnostics) => return report(err, &source.origin, &diagnostics),
+    };
+    let domains = match read_domains(input, domains)? {
+        Some(domains) => match parse_domains(&domains.text, &program) {
+            Ok(parsed) => parsed,
+            Err(diagnostics) => return report(err, &domains.origin, &diagnostics),
+        },
+        None => Domains::default(),
+    };
+    let result = check_model(&program, &domains, max_states);
+    writeln!(out, "{} states explored", result.explored())?;
+    report(err, &source.origin, &result.to_diagnostics())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::cli::exit_code::EXIT_DIAGNOSTICS;
+
+    fn test_data(name: &str) -> String {
+        format!("{}/test_data/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    fn run_args(args: &[&str]) -> (u8, String, String) {
+        let cli = Cli::try_parse_from(args).unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run(cli, &mut out, &mut err);
+        (code, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    #[test]
+    fn test_commands() {
+        let input = test_data("transfer.scl");
+        let (code, out, _) = run_args(&["scl2fsm", "parse", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.starts_with("Program"));
+
+        let (code, out, _) = run_args(&["scl2fsm", "fsm", &input]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out.starts_with("digraph FSM"));
+
+        let (code, out, err) = run_args(&["scl2fsm", "check", &input]);
+        assert_eq!((code, out.as_str(), err.as_str()), (EXIT_OK, "", ""));
+
+        let out_dir = std::env::temp_dir().join("scl2fsm_cli_gen");
+        let (code, _, _) = run_args(&[
+            "scl2fsm", "gen", "--target", "csharp", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out_dir.join("State.cs").exists());
+
+        let (code, _, _) = run_args(&[
+            "scl2fsm", "gen", "--target", "rust", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(out_dir.join("dispatcher.rs").exists());
+
+        let (code, _, err) = run_args(&[
+            "scl2fsm", "gen", "--target", "tla", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!((code, err.as_str()), (EXIT_OK, ""));
+        let module = fs::read_to_string(out_dir.join("Transfer.tla")).unwrap();
+        assert!(module.contains("VARIABLES pc, stage, TransferCtx, Account\n"));
+        assert!(out_dir.join("Transfer.cfg").exists());
+
+        // the loop of inventory.scl is not modelled
+        let (code, _, err) = run_args(&[
+            "scl2fsm", "gen", "--target", "tla", "--out", out_dir.to_str().unwrap(),
+            &test_data("inventory.scl"),
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(err.contains("warning[SCL0201]: `foreach row in rows` is not modelled in TLA+"));
+
+        let (code, _, err) = run_args(&[
+            "scl2fsm", "gen", "--target", "sql", "--dialect", "sqlite", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!((code, err.as_str()), (EXIT_OK, ""));
+        let script = fs::read_to_string(out_dir.join("Transfer.sql")).unwrap();
+        assert!(script.contains("-- update: ?1 = TransferCtx.amount, ?2 = TransferCtx.from\n"));
+
+        let (code, _, err) = run_args(&[
+            "scl2fsm", "gen", "--target", "ddl", "--out", out_dir.to_str().unwrap(), &input,
+        ]);
+        assert_eq!((code, err.as_str()), (EXIT_OK, ""));
+        let schema = fs::read_to_string(out_dir.join("Transfer.schema.sql")).unwrap();
+        assert!(schema.contains("    PRIMARY KEY (\"id\")\n"));
+    }
+
+    #[test]
+    fn test_analyze() {
+        let (code, _, err) = run_args(&["scl2fsm", "analyze", &test_data("multi_guard.scl")]);
+        assert_eq!((code, err.as_str()), (EXIT_OK, ""));
+
+        let path = std::env::temp_dir().join("scl2fsm_cli_analyze.scl");
+        let program = "context C { x: int; }\n\n\
+                       state A uses C {\n  next { when C.x > 0 => B; }\n}\n\n\
+                       state B uses C {\n  next { otherwise => B; }\n}\n";
+        fs::write(&path, program).unwrap();
+        let (code, _, err) = run_args(&["scl2fsm", "analyze", path.to_str().unwrap()]);
+        assert_eq!(code, EXIT_OK);
+        assert!(err.contains("warning[FSM0304]: state `A` (id 0) has only guarded transitions"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let (code, out, err) = run_args(&["scl2fsm", "verify", &test_data("transfer.scl")]);
+        assert_eq!((code, out.as_str(), err.as_str()), (EXIT_OK, "40 states explored\n", ""));
+
+        let (code, _, err) = run_args(&["scl2fsm", "verify", &test_data("overdraft.scl")]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.starts_with("error[SCL0303]: invariant `NonNegative` does not hold"));
+        assert!(err.contains("overdraft.scl:38:11\n"));
+        assert!(err.contains("  = note: 2. Credit: Account = [{ balance: 10, id: 1 }, { balance: -5, id: 2 }]"));
+
+        let domains = test_data("transfer.domains");
+        let (code, _, err) = run_args(&[
+            "scl2fsm", "verify", "--max-states", "5", "--domains", &domains, &test_data("transfer.scl"),
+        ]);
+        assert_eq!(code, EXIT_OK);
+        assert!(err.contains("warning[SCL0307]: the search stopped after 5 states"));
+    }
+
+    #[test]
+    fn test_check_reports_errors() {
+        let path = std::env::temp_dir().join("scl2fsm_cli_bad.scl");
+        fs::write(&path, "context C { x: int; }\n\nstate A uses C {\n  next { otherwise => B; }\n}\n").unwrap();
+        let (code, _, err) = run_args(&["scl2fsm", "check", path.to_str().unwrap()]);
+        assert_eq!(code, EXIT_DIAGNOSTICS);
+        assert!(err.contains("error[SCL0103]"));
+        assert!(err.contains("scl2fsm_cli_bad.scl:4:23"));
+
+        let (code, _, _) = run_args(&["scl2fsm", "check", "/nonexistent/program.scl"]);
+        assert_eq!(code, EXIT_IO);
+    }
+}
@@ -0,0 +1,501 @@
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::compare_op::CompareOp;
+use crate::ast::condition::Condition;
+use crate::ast::expr::Expr;
+use crate::ast::literal::Literal;
+use crate::ast::next::NextCase;
+use crate::ast::quantifier::QuantifierKind;
+use crate::ast::state_decl::StateDecl;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::Type;
+use crate::ast::unary_op::UnaryOp;
+use crate::eval::store::TableStore;
+use crate::eval::value::{Row, Value};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Why a state body or condition could not be run to its end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// An `assert` does not hold, with the asserted condition
+    Assertion(String),
+    /// The program cannot be evaluated, e.g. it divides by zero
+    Error(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Assertion(condition) => write!(f, "assertion `{}` failed", condition),
+            Failure::Error(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, Failure>;
+
+fn error<T>(message: String) -> Result<T> {
+    Err(Failure::Error(message))
+}
+
+/// What a state body reads and writes: the contexts of the run, the
+/// relations and the local names of the body
+///
+/// Name lookup follows the checker: `Ctx.field` reads a context unless a
+/// local is called `Ctx`; other names are locals, or the columns of the
+/// row a `where` clause or `set` assignment looks at. A column the row
+/// does not have reads as `null`.
+pub struct Env<'a> {
+    /// context name -> record of its fields
+    contexts: &'a Row,
+    store: &'a mut dyn TableStore,
+    /// innermost frame last
+    frames: Vec<HashMap<String, Value>>,
+}
+
+impl<'a> Env<'a> {
+    /// An environment whose outermost frame holds `imports`, the values
+    /// `use state` brings in
+    pub fn new(contexts: &'a Row, store: &'a mut dyn TableStore, imports: &Row) -> Env<'a> {
+        let frame = imports.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        Env { contexts, store, frames: vec![frame] }
+    }
+
+    /// Value of a local, `None` if no frame binds it
+    pub fn local(&self, name: &str) -> Option<&Value> {
+        self.frames.iter().rev().find_map(|frame| frame.get(name))
+    }
+
+    fn bind(&mut self, name: &str, value: Value) {
+        let frame = self.frames.last_mut().expect("env has no frame");
+        frame.insert(name.to_string(), value);
+    }
+
+    /// Runs the statements of a state in order
+    pub fn run_body(&mut self, state: &StateDecl) -> Result<()> {
+        self.run(state.body())
+    }
+
+    fn run(&mut self, body: &[Stmt]) -> Result<()> {
+        for stmt in body {
+            self.exec(stmt)?;
+        }
+        Ok(())
+    }
+
+    pub fn exec(&mut self, stmt: &Stmt) -> Result<()> {
+        match stmt {
+            Stmt::Let { name, value, .. } => {
+                let value = self.eval(value, None)?;
+                self.bind(name.as_str(), value);
+            }
+            Stmt::Select { name, ty, relation, where_clause, limit } => {
+                let mut rows = vec![];
+                for row in self.store.rows(relation.as_str()) {
+                    if limit.is_some_and(|n| rows.len() as u64 >= n) {
+                        break;
+                    }
+                    if self.holds(where_clause.as_ref(), &row)? {
+                        rows.push(Value::Record(row));
+                    }
+                }
+                // a collection keeps every row, a single row is the first
+                let value = match ty {
+                    Type::Generic { .. } => Value::List(rows),
+                    _ => rows.into_iter().next().unwrap_or(Value::Null),
+                };
+                self.bind(name.as_str(), value);
+            }
+            Stmt::Foreach { item, collection, body, .. } => {
+                let items = match self.local(collection.as_str()) {
+                    Some(Value::List(items)) => items.clone(),
+                    Some(value) => return error(format!("cannot iterate over `{}` of kind {}", collection, value.kind())),
+                    None => return error(format!("unknown name `{}`", collection)),
+                };
+                for value in items {
+                    self.frames.push(HashMap::new());
+                    self.bind(item.as_str(), value);
+                    let result = self.run(body);
+                    self.frames.pop();
+                    result?;
+                }
+            }
+            Stmt::Update { relation, assignments, where_clause } => {
+                let mut rows = self.store.rows(relation.as_str());
+                for row in rows.iter_mut() {
+                    if !self.holds(where_clause.as_ref(), row)? {
+                        continue;
+                    }
+                    // every assignment reads the old row
+                    let mut changes = vec![];
+                    for a in assignments {
+                        changes.push((a.field().as_str().to_string(), self.eval(a.value(), Some(row))?));
+                    }
+                    row.extend(changes);
+                }
+                self.store.replace(relation.as_str(), rows);
+            }
+            Stmt::Insert { relation, columns, values } => {
+                let mut row = Row::new();
+                for (column, value) in columns.iter().zip(values) {
+                    row.insert(column.as_str().to_string(), self.eval(value, None)?);
+                }
+                self.store.insert(relation.as_str(), row);
+            }
+            Stmt::Delete { relation, where_clause } => {
+                let mut kept = vec![];
+                for row in self.store.rows(relation.as_str()) {
+                    if !self.holds(where_clause.as_ref(), &row)? {
+                        kept.push(row);
+                    }
+                }
+                self.store.replace(relation.as_str(), kept);
+            }
+            Stmt::Assert { condition } => {
+                if !self.condition(condition, None)? {
+                    return Err(Failure::Assertion(condition.to_string()));
+                }
+            }
+            Stmt::Commit => self.store.commit(),
+        }
+        Ok(())
+    }
+
+    /// Whether `row` passes an optional `where` clause
+    fn holds(&mut self, where_clause: Option<&Condition>, row: &Row) -> Result<bool> {
+        match where_clause {
+            Some(condition) => self.condition(condition, Some(row)),
+            None => Ok(true),
+        }
+    }
+
+    /// The case of the `next` block taken after the body, the first whose
+    /// condition holds; `None` if no case applies
+    pub fn next_case<'s>(&mut self, state: &'s StateDecl) -> Result<Option<&'s NextCase>> {
+        for case in state.next().cases() {
+            match case {
+                NextCase::When { condition, .. } => {
+                    if self.condition(condition, None)? {
+                        return Ok(Some(case));
+                    }
+                }
+                NextCase::Otherwise { .. } => return Ok(Some(case)),
+            }
+        }
+        Ok(None)
+    }
+
+    // ---------------------------
+    // Conditions and expressions
+    // ---------------------------
+
+    /// Evaluates a condition, `row` is the row bare column names refer to
+    pub fn condition(&mut self, condition: &Condition, row: Option<&Row>) -> Result<bool> {
+        match condition {
+            Condition::And(lhs, rhs) => Ok(self.condition(lhs, row)? && self.condition(rhs, row)?),
+            Condition::Or(lhs, rhs) => Ok(self.condition(lhs, row)? || self.condition(rhs, row)?),
+            Condition::Implies(lhs, rhs) => Ok(!self.condition(lhs, row)? || self.condition(rhs, row)?),
+            Condition::Not(c) => Ok(!self.condition(c, row)?),
+            Condition::Compare { lhs, op, rhs } => {
+                let l = self.eval(lhs, row)?;
+                let r = self.eval(rhs, row)?;
+                compare(&l, *op, &r)
+            }
+            Condition::Expr(expr) => {
+                let value = self.eval(expr, row)?;
+                as_bool(&value)
+            }
+            Condition::Quantifier { kind, relation, var, pk_binding, body } => {
+                self.quantify(*kind, relation.as_str(), var.as_str(), row, |env, row| {
+                    let pk = env.eval(pk_binding, row)?;
+                    Ok((as_bool(&pk)?, env.condition(body, row)?))
+                })
+            }
+        }
+    }
+
+    /// `\E`: some row with a matching key satisfies the body; `\A`:
+    /// every row with a matching key does. The body is only evaluated
+    /// for matching rows.
+    fn quantify(
+        &mut self,
+        kind: QuantifierKind,
+        relation: &str,
+        var: &str,
+        row: Option<&Row>,
+        mut test: impl FnMut(&mut Self, Option<&Row>) -> Result<(bool, bool)>,
+    ) -> Result<bool> {
+        let mut result = kind == QuantifierKind::ForAll;
+        for candidate in self.store.rows(relation) {
+            self.frames.push(HashMap::new());
+            self.bind(var, Value::Record(candidate));
+            let tested = test(self, row);
+            self.frames.pop();
+            let (matches, body) = tested?;
+            if !matches {
+                continue;
+            }
+            match kind {
+                QuantifierKind::Exists if body => {
+                    result = true;
+                    break;
+                }
+                QuantifierKind::ForAll if !body => {
+                    result = false;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn eval(&mut self, expr: &Expr, row: Option<&Row>) -> Result<Value> {
+        match expr {
+            Expr::Literal(literal) => Ok(match literal {
+                Literal::Int(i) => Value::Int(*i),
+                Literal::Bool(b) => Value::Bool(*b),
+                Literal::String(s) => Value::String(s.clone()),
+                Literal::Float(x) => Value::Float(*x),
+                Literal::Null => Value::Null,
+            }),
+            Expr::Struct(fields) => {
+                let mut record = Row::new();
+                for (name, value) in fields {
+                    record.insert(name.as_str().to_string(), self.eval(value, row)?);
+                }
+                Ok(Value::Record(record))
+            }
+            Expr::Var(name) => {
+                let name = name.as_str();
+                if let Some(value) = self.local(name) {
+                    return Ok(value.clone());
+                }
+                match row {
+                    Some(row) => Ok(row.get(name).cloned().unwrap_or(Value::Null)),
+                    None => error(format!("unknown name `{}`", name)),
+                }
+            }
+            Expr::Field { base, field } => {
+                // `Ctx.field` reads a context unless a local shadows the name
+                let base = match base.as_ref() {
+                    Expr::Var(name) if self.local(name.as_str()).is_none() => {
+                        match self.contexts.get(name.as_str()) {
+                            Some(context) => context.clone(),
+                            None => self.eval(base, row)?,
+                        }
+                    }
+                    _ => self.eval(base, row)?,
+                };
+                match base {
+                    Value::Record(record) => match record.get(field.as_str()) {
+                        Some(value) => Ok(value.clone()),
+                        None => error(format!("`{}` has no field `{}`", Value::Record(record), field)),
+                    },
+                    value => error(format!("cannot read field `{}` of {} `{}`", field, value.kind(), value)),
+                }
+            }
+            Expr::Binary { lhs, op, rhs } => {
+                let l = self.eval(lhs, row)?;
+                // the logical operators do not look at the right operand
+                // if the left one decides
+                match (op, &l) {
+                    (BinaryOp::And, Value::Bool(false)) => return Ok(Value::Bool(false)),
+                    (BinaryOp::Or, Value::Bool(true)) => return Ok(Value::Bool(true)),
+                    (BinaryOp::Implies, Value::Bool(false)) => return Ok(Value::Bool(true)),
+                    _ => {}
+                }
+                let r = self.eval(rhs, row)?;
+                binary(&l, *op, &r)
+            }
+            Expr::Unary { op, expr } => {
+                let value = self.eval(expr, row)?;
+                match (op, value) {
+                    (UnaryOp::Not, Value::Bool(b)) => Ok(Value::Bool(!b)),
+                    (UnaryOp::Neg, Value::Int(i)) => match i.checked_neg() {
+                        Some(i) => Ok(Value::Int(i)),
+                        None => error(format!("`-{}` overflows", i)),
+                    },
+                    (UnaryOp::Neg, Value::Float(x)) => Ok(Value::Float(-x)),
+                    (op, value) => error(format!("cannot apply `{}` to {} `{}`", op, value.kind(), value)),
+                }
+            }
+            Expr::Quantifier { kind, relation, var, pk_binding, body } => {
+                let holds = self.quantify(*kind, relation.as_str(), var.as_str(), row, |env, row| {
+                    let pk = env.eval(pk_binding, row)?;
+                    let body = env.eval(body, row)?;
+                    Ok((as_bool(&pk)?, as_bool(&body)?))
+                })?;
+                Ok(Value::Bool(holds))
+            }
+        }
+    }
+}
+
+fn as_bool(value: &Value) -> Result<bool> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        value => error(format!("expected bool, found {} `{}`", value.kind(), value)),
+    }
+}
+
+/// Ordering of two values a comparison may look at: numbers with
+/// numbers, strings with strings; `None` for anything else
+fn order(l: &Value, r: &Value) -> Option<Ordering> {
+    match (l, r) {
+        (Value::Int(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+        (Value::Float(a), Value::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::Int(_), Value::Int(_))
+        | (Value::Float(_), Value::Float(_))
+        | (Value::String(_), Value::String(_)) => Some(l.cmp(r)),
+        _ => None,
+    }
+}
+
+fn compare(l: &Value, op: CompareOp, r: &Value) -> Result<bool> {
+    let equal = match order(l, r) {
+        Some(ordering) => ordering == Ordering::Equal,
+        None => l == r,
+    };
+    let ordered = |accept: fn(Ordering) -> bool| match order(l, r) {
+        Some(ordering) => Ok(accept(ordering)),
+        None => error(format!("cannot order {} `{}` and {} `{}`", l.kind(), l, r.kind(), r)),
+    };
+    match op {
+        CompareOp::Eq => Ok(equal),
+        CompareOp::Neq => Ok(!equal),
+        CompareOp::Lt => ordered(Ordering::is_lt),
+        CompareOp::Le => ordered(Ordering::is_le),
+        CompareOp::Gt => ordered(Ordering::is_gt),
+        CompareOp::Ge => ordered(Ordering::is_ge),
+    }
+}
+
+fn binary(l: &Value, op: BinaryOp, r: &Value) -> Result<Value> {
+    let compared = |op| compare(l, op, r).map(Value::Bool);
+    match op {
+        BinaryOp::Eq => compared(CompareOp::Eq),
+        BinaryOp::Neq => compared(CompareOp::Neq),
+        BinaryOp::Lt => compared(CompareOp::Lt),
+        BinaryOp::Le => compared(CompareOp::Le),
+        BinaryOp::Gt => compared(CompareOp::Gt),
+        BinaryOp::Ge => compared(CompareOp::Ge),
+        BinaryOp::And | BinaryOp::Or | BinaryOp::Implies => {
+            // the left operand did not decide
+            as_bool(l)?;
+            Ok(Value::Bool(as_bool(r)?))
+        }
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div => arithmetic(l, op, r),
+    }
+}
+
+fn arithmetic(l: &Value, op: BinaryOp, r: &Value) -> Result<Value> {
+    let overflow = || Failure::Error(format!("`{} {} {}` overflows", l, op, r));
+    match (l, r) {
+        (Value::Int(a), Value::Int(b)) => {
+            let value = match op {
+                BinaryOp::Add => a.checked_add(*b),
+                BinaryOp::Sub => a.checked_sub(*b),
+                BinaryOp::Mul => a.checked_mul(*b),
+                _ if *b == 0 => return error(format!("`{} / {}` divides by zero", a, b)),
+                _ => a.checked_div(*b),
+            };
+            value.map(Value::Int).ok_or_else(overflow)
+        }
+        (Value::Int(_) | Value::Float(_), Value::Int(_) | Value::Float(_)) => {
+            let float = |v: &Value| match v {
+                Value::Int(i) => *i as f64,
+                Value::Float(x) => *x,
+                _ => unreachable!(),
+            };
+            let (a, b) = (float(l), float(r));
+            match op {
+                BinaryOp::Add => Ok(Value::Float(a + b)),
+                BinaryOp::Sub => Ok(Value::Float(a - b)),
+                BinaryOp::Mul => Ok(Value::Float(a * b)),
+                _ if b == 0.0 => error(format!("`{} / {}` divides by zero", l, r)),
+                _ => Ok(Value::Float(a / b)),
+            }
+        }
+        _ => error(format!("arithmetic on {} `{}` and {} `{}`", l.kind(), l, r.kind(), r)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::store::{MemoryStore, Tables};
+    use crate::parser::scl_parser::parse_scl;
+
+    fn row(fields: &[(&str, Value)]) -> Row {
+        fields.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    #[test]
+    fn test_run_body() {
+        let text = r#"
+context C { id: int; amount: int; }
+
+state A uses C {
+  update Account set balance = balance - C.amount where id = C.id;
+  select rich: List[Account] from Account where balance > 5;
+  foreach r: Account in rich {
+    insert into Audit (id, note) values (r.id, "rich");
+  }
+  delete from Account where balance < 0;
+  assert C.amount / 2 = 2;
+  next {
+    when \E Relation Audit a by a.id = 2 : a.note = "rich" => A;
+    otherwise => A;
+  }
+}
+"#;
+        let program = parse_scl(text).unwrap();
+        let state = &program.states()[0];
+        let context = |amount| {
+            let fields = row(&[("id", Value::Int(1)), ("amount", Value::Int(amount))]);
+            row(&[("C", Value::Record(fields))])
+        };
+        let account = |id, balance| row(&[("id", Value::Int(id)), ("balance", Value::Int(balance))]);
+        let tables = Tables::from([("Account".to_string(), vec![account(1, 3), account(2, 10)])]);
+        let mut store = MemoryStore::new(tables);
+
+        let contexts = context(5);
+        let mut env = Env::new(&contexts, &mut store, &Row::new());
+        env.run_body(state).unwrap();
+        let case = env.next_case(state).unwrap().unwrap();
+        assert!(matches!(case, NextCase::When { .. }));
+        assert_eq!(store.rows("Account"), vec![account(2, 10)]);
+        assert_eq!(store.rows("Audit"), vec![row(&[("id", Value::Int(2)), ("note", Value::String("rich".into()))])]);
+
+        let contexts = context(6);
+        let mut env = Env::new(&contexts, &mut store, &Row::new());
+        assert_eq!(env.run_body(state), Err(Failure::Assertion("C.amount / 2 = 2".to_string())));
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let text = r#"
+context C { x: int; }
+
+state A uses C {
+  let a: int = C.x / 0;
+  next { otherwise => A; }
+}
+"#;
+        let program = parse_scl(text).unwrap();
+        let contexts = row(&[("C", Value::Record(row(&[("x", Value::Int(1))])))]);
+        let mut store = MemoryStore::default();
+        let mut env = Env::new(&contexts, &mut store, &Row::new());
+        let failure = env.run_body(&program.states()[0]).unwrap_err();
+        assert_eq!(failure, Failure::Error("`1 / 0` divides by zero".to_string()));
+
+        assert_eq!(compare(&Value::Int(2), CompareOp::Eq, &Value::Float(2.0)), Ok(true));
+        assert!(compare(&Value::Null, CompareOp::Lt, &Value::Int(1)).is_err());
+        assert_eq!(
+            arithmetic(&Value::Int(i64::MAX), BinaryOp::Add, &Value::Int(1)),
+            Err(Failure::Error(format!("`{} + 1` overflows", i64::MAX)))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+//! Reference interpreter of SCL programs
+//!
+//! A run starts in the first declared state with one record per context
+//! and goes where the program says: each state checks its preconditions,
+//! runs its body against a [`TableStore`] and takes the first `next` case
+//! that applies, the names the case exports become locals of the state it
+//! enters. The run ends after the body of a terminal state, one whose
+//! cases all stay in it.
+//!
+//! `commit` makes the writes so far permanent. When a run fails, because
+//! an `assert` or a precondition does not hold or the program cannot be
+//! evaluated, the writes since the last commit are rolled back. Writes
+//! after the last commit of a run that ends are left pending, the caller
+//! commits or rolls them back.
+
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::state_decl::StateDecl;
+use crate::eval::evaluator::{Env, Failure};
+use crate::eval::store::TableStore;
+use crate::eval::value::{Row, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Number of states a run enters before it is given up
+pub const DEFAULT_MAX_STEPS: usize = 10_000;
+
+/// A run that stopped before reaching a terminal state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunError {
+    /// the state the run stopped in
+    state: String,
+    failure: Failure,
+}
+
+impl RunError {
+    pub fn state(&self) -> &String {
+        &self.state
+    }
+
+    pub fn failure(&self) -> &Failure {
+        &self.failure
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "state `{}`: {}", self.state, self.failure)
+    }
+}
+
+/// Runs a program against a table store
+pub struct Interpreter<'p, S: TableStore> {
+    program: &'p Program,
+    store: S,
+    max_steps: usize,
+    /// state name -> index in the program
+    ids: HashMap<&'p str, usize>,
+}
+
+impl<'p, S: TableStore> Interpreter<'p, S> {
+    pub fn new(program: &'p Program, store: S) -> Self {
+        let ids = program
+            .states()
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.name().as_str(), i))
+            .collect();
+        Self { program, store, max_steps: DEFAULT_MAX_STEPS, ids }
+    }
+
+    /// Gives a run up after it entered `max_steps` states
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Runs the program once, `contexts` maps each context name to the
+    /// record of its fields. Returns the names of the states entered in
+    /// order, the terminal state last.
+    pub fn run(&mut self, contexts: &Row) -> Result<Vec<String>, RunError> {
+        let program = self.program;
+        let mut trace = vec![];
+        if program.states().is_empty() {
+            return Ok(trace);
+        }
+        let mut state = 0;
+        let mut imports = Row::new();
+        loop {
+            let decl = &program.states()[state];
+            let result = if trace.len() == self.max_steps {
+                Err(Failure::Error(format!("no terminal state is reached in {} steps", self.max_steps)))
+            } else {
+                trace.push(decl.name().as_str().to_string());
+                self.step(state, decl, contexts, &imports)
+            };
+            match result {
+                Ok(Some((next, exports))) => {
+                    state = next;
+                    imports = exports;
+                }
+                Ok(None) => return Ok(trace),
+                Err(failure) => {
+                    self.store.rollback();
+                    return Err(RunError { state: decl.name().as_str().to_string(), failure });
+                }
+            }
+        }
+    }
+
+    /// Runs state `state`: the state the run goes on with and its
+    /// imports, `None` if the state is terminal
+    fn step(
+        &mut self,
+        state: usize,
+        decl: &StateDecl,
+        contexts: &Row,
+        imports: &Row,
+    ) -> Result<Option<(usize, Row)>, Failure> {
+        let mut env = Env::new(contexts, &mut self.store, imports);
+        for condition in decl.preconditions() {
+            if !env.condition(condition, None)? {
+                return Err(Failure::Error(format!("precondition `{}` does not hold", condition)));
+            }
+        }
+        env.run_body(decl)?;
+
+        let terminal = decl.next().cases().iter().all(|case| target(case).0 == decl.name().as_str());
+        if terminal {
+            return Ok(None);
+        }
+        let Some(case) = env.next_case(decl)? else {
+            return Err(Failure::Error("no case of the next block applies".to_string()));
+        };
+        let (to, exports) = target(case);
+        let Some(&to) = self.ids.get(to) else {
+            return Err(Failure::Error(format!("unknown state `{}`", to)));
+        };
+        // staying in a state keeps what it was entered with
+        let imports = if to == state {
+            imports.clone()
+        } else {
+            exports
+                .iter()
+                .map(|name| (name.to_string(), env.local(name).cloned().unwrap_or(Value::Null)))
+                .collect()
+        };
+        Ok(Some((to, imports)))
+    }
+}
+
+/// Target of a `next` case and the names it exports
+fn target(case: &NextCase) -> (&str, Vec<&str>) {
+    let (NextCase::When { target, exports, .. } | NextCase::Otherwise { target, exports }) = case;
+    (target.as_str(), exports.iter().map(|(name, _)| name.as_str()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::store::{MemoryStore, Tables};
+    use crate::parser::scl_parser::parse_scl;
+
+    fn record(fields: &[(&str, Value)]) -> Value {
+        Value::Record(fields.iter().map(|(k, v)| (k.to_string(), v.clone())).collect())
+    }
+
+    fn account(id: i64, balance: i64) -> Row {
+        let Value::Record(row) = record(&[("id", Value::Int(id)), ("balance", Value::Int(balance))]) else {
+            unreachable!()
+        };
+        row
+    }
+
+    fn transfer(from: i64, to: i64, amount: i64) -> Row {
+        let ctx = record(&[("from", Value::Int(from)), ("to", Value::Int(to)), ("amount", Value::Int(amount))]);
+        Row::from([("TransferCtx".to_string(), ctx)])
+    }
+
+    #[test]
+    fn test_transfer() {
+        let program = parse_scl(include_str!("../../test_data/transfer.scl")).unwrap();
+        let tables = Tables::from([("Account".to_string(), vec![account(1, 10), account(2, 0)])]);
+        let mut interpreter = Interpreter::new(&program, MemoryStore::new(tables));
+
+        let trace = interpreter.run(&transfer(1, 2, 5)).unwrap();
+        assert_eq!(trace, ["Check", "Debit", "Credit", "Commit"]);
+        let store = interpreter.store();
+        assert_eq!(store.committed()["Account"], vec![account(1, 5), account(2, 5)]);
+        assert_eq!(store.tables(), store.committed());
+
+        // account 2 holds 5 now, 10 is too much
+        let trace = interpreter.run(&transfer(2, 1, 10)).unwrap();
+        assert_eq!(trace, ["Check", "Reject"]);
+        assert_eq!(interpreter.store().rows("Account"), vec![account(1, 5), account(2, 5)]);
+    }
+
+    #[test]
+    fn test_rollback() {
+        let text = r#"
+context C { x: int; }
+
+state A uses C {
+  insert into Log (n) values (1);
+  commit;
+  insert into Log (n) values (2);
+  next {
+    when C.x > 0 => B;
+    otherwise => A;
+  }
+}
+
+state B uses C {
+  insert into Log (n) values (3);
+  assert C.x # 2;
+  next { otherwise => B; }
+}
+"#;
+        let program = parse_scl(text).unwrap();
+        let contexts = |x| Row::from([("C".to_string(), record(&[("x", Value::Int(x))]))]);
+        let n = |n| Row::from([("n".to_string(), Value::Int(n))]);
+
+        let mut interpreter = Interpreter::new(&program, MemoryStore::default());
+        assert_eq!(interpreter.run(&contexts(1)), Ok(vec!["A".to_string(), "B".to_string()]));
+        // the last two inserts are not committed
+        assert_eq!(interpreter.store().rows("Log"), vec![n(1), n(2), n(3)]);
+        assert_eq!(interpreter.store().committed()["Log"], vec![n(1)]);
+        interpreter.store_mut().rollback();
+
+        let error = interpreter.run(&contexts(2)).unwrap_err();
+        assert_eq!(error.to_string(), "state `B`: assertion `C.x != 2` failed");
+        assert_eq!(interpreter.store().rows("Log"), vec![n(1), n(1)]);
+
+        // `A` loops on itself without being terminal
+        let mut interpreter = Interpreter::new(&program, MemoryStore::default()).with_max_steps(3);
+        let error = interpreter.run(&contexts(0)).unwrap_err();
+        assert_eq!(error.state(), "A");
+        assert_eq!(error.failure(), &Failure::Error("no terminal state is reached in 3 steps".to_string()));
+        assert_eq!(interpreter.into_store().tables()["Log"], vec![n(1), n(2), n(1), n(2), n(1)]);
+    }
+}
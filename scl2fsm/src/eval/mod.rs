@@ -0,0 +1,4 @@
+pub mod value;
+pub mod store;
+pub mod evaluator;
+pub mod interpreter;
@@ -0,0 +1,98 @@
+use crate::eval::value::Row;
+use std::collections::BTreeMap;
+
+/// Relations by name, each a list of rows in insertion order
+pub type Tables = BTreeMap<String, Vec<Row>>;
+
+/// Where the relations a program reads and writes live
+///
+/// Writes are pending until `commit`, `rollback` drops every write since
+/// the last commit. Reads see pending writes.
+pub trait TableStore {
+    /// Rows of a relation in insertion order, none if it does not exist
+    fn rows(&self, relation: &str) -> Vec<Row>;
+
+    /// Replaces every row of a relation
+    fn replace(&mut self, relation: &str, rows: Vec<Row>);
+
+    fn insert(&mut self, relation: &str, row: Row);
+
+    fn commit(&mut self);
+
+    fn rollback(&mut self);
+}
+
+/// A table store holding every relation in memory
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MemoryStore {
+    /// the relations as of the last commit
+    committed: Tables,
+    /// the relations with pending writes
+    tables: Tables,
+}
+
+impl MemoryStore {
+    /// A store whose relations start committed as `tables`
+    pub fn new(tables: Tables) -> MemoryStore {
+        Self { committed: tables.clone(), tables }
+    }
+
+    /// The relations with pending writes
+    pub fn tables(&self) -> &Tables {
+        &self.tables
+    }
+
+    /// The relations as of the last commit
+    pub fn committed(&self) -> &Tables {
+        &self.committed
+    }
+
+    pub fn into_tables(self) -> Tables {
+        self.tables
+    }
+}
+
+impl TableStore for MemoryStore {
+    fn rows(&self, relation: &str) -> Vec<Row> {
+        self.tables.get(relation).cloned().unwrap_or_default()
+    }
+
+    fn replace(&mut self, relation: &str, rows: Vec<Row>) {
+        self.tables.insert(relation.to_string(), rows);
+    }
+
+    fn insert(&mut self, relation: &str, row: Row) {
+        self.tables.entry(relation.to_string()).or_default().push(row);
+    }
+
+    fn commit(&mut self) {
+        self.committed = self.tables.clone();
+    }
+
+    fn rollback(&mut self) {
+        self.tables = self.committed.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::value::Value;
+
+    #[test]
+    fn test_commit_and_rollback() {
+        let row = |id| Row::from([("id".to_string(), Value::Int(id))]);
+        let mut store = MemoryStore::new(Tables::from([("T".to_string(), vec![row(1)])]));
+        store.insert("T", row(2));
+        store.commit();
+        store.insert("T", row(3));
+        store.replace("U", vec![row(4)]);
+        assert_eq!(store.rows("T"), vec![row(1), row(2), row(3)]);
+        assert_eq!(store.committed()["T"], vec![row(1), row(2)]);
+
+        store.rollback();
+        assert_eq!(store.rows("T"), vec![row(1), row(2)]);
+        assert!(store.rows("U").is_empty());
+        assert_eq!(store.tables(), store.committed());
+    }
+}
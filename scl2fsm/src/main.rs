@@ -1,6 +1,55 @@
+use clap::Parser;
+use common::cli::exit_code::{EXIT_OK, EXIT_USAGE};
+use std::io;
+use std::process::ExitCode;
+
+#[allow(unused)]
+mod ts_const;
 #[allow(unused)]
 mod ast;
+#[allow(unused)]
+mod parser;
+#[allow(unused)]
+mod sema;
+#[allow(unused)]
+mod builder;
+#[allow(unused)]
+mod eval;
+#[allow(unused)]
+mod mc;
+#[allow(unused)]
+mod sql;
+mod cli;
+
+/// Main entry point for the SCL to State Machine converter
+///
+/// The subcommands:
+/// 1. `parse` dumps the AST of an SCL program
+/// 2. `fsm` emits the state machine as DOT
+/// 3. `gen` generates code for the state machine
+/// 4. `check` only reports diagnostics
+/// 5. `analyze` reports structural problems of the state machine
+/// 6. `verify` model checks the program within bounded inputs
+///
+/// Input is read from a file or stdin; the exit code is non-zero on errors.
+fn main() -> ExitCode {
+    main_inner(std::env::args_os())
+}
 
-fn main() {
-    println!("Hello, world!");
+pub fn main_inner<I, T>(args: I) -> ExitCode
+where
+    I: IntoIterator<Item=T>,
+    T: Into<std::ffi::OsString> + Clone,
+{
+    let cli = match cli::Cli::try_parse_from(args) {
+        Ok(cli) => cli,
+        Err(e) => {
+            // prints help/version to stdout and usage errors to stderr
+            let _ = e.print();
+            let code = if e.use_stderr() { EXIT_USAGE } else { EXIT_OK };
+            return ExitCode::from(code);
+        }
+    };
+    let code = cli::run(cli, &mut io::stdout(), &mut io::stderr());
+    ExitCode::from(code)
 }
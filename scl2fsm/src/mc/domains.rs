@@ -0,0 +1,319 @@
+//! Bounded inputs of a model checking run
+//!
+//! The values a context field may take and the rows the relations start
+//! with are listed next to the program, one assignment per line:
+//!
+//! ```text
+//! # transfer.domains
+//! TransferCtx.amount = 0, 5, 20
+//! Account = { id: 1, balance: 10 }, { id: 2, balance: 0 }
+//! ```
+//!
+//! Values are `null`, `true`, `false`, numbers, double quoted strings and
+//! records `{ name: value, ... }`; every row of a relation is a record.
+//! A context field without a line takes the values of its type: `false`
+//! and `true`, `0` and `1` for `int` and for undeclared named types,
+//! `"a"` and `"b"` for `string`, `0.0` for `float` and `null` for
+//! anything else. A relation without a line starts empty. Empty lines and
+//! lines starting with `#` are skipped.
+
+use crate::ast::program::Program;
+use crate::ast::ty::{PrimitiveType, Type};
+use crate::eval::store::Tables;
+use crate::eval::value::{Row, Value};
+use common::diag::diagnostic::Diagnostic;
+use common::diag::source_span::{SourcePos, SourceSpan};
+use std::collections::BTreeMap;
+
+/// Diagnostic code: a line of a domains file is not an assignment, has a
+/// malformed value or names something the program does not declare
+pub const INVALID_DOMAINS: &str = "SCL0301";
+
+/// Values of the context fields and initial rows of the relations
+#[derive(Debug, Clone, Default)]
+pub struct Domains {
+    /// `(context, field)` -> the values the field takes
+    fields: BTreeMap<(String, String), Vec<Value>>,
+    tables: Tables,
+}
+
+impl Domains {
+    /// The values `context.field` takes, `None` if no line lists them
+    pub fn values(&self, context: &str, field: &str) -> Option<&Vec<Value>> {
+        self.fields.get(&(context.to_string(), field.to_string()))
+    }
+
+    /// The relations a run starts with
+    pub fn tables(&self) -> &Tables {
+        &self.tables
+    }
+
+    /// Every value `context.field` of type `ty` takes in a run
+    pub fn domain(&self, program: &Program, context: &str, field: &str, ty: &Type) -> Vec<Value> {
+        if let Some(values) = self.values(context, field) {
+            return values.clone();
+        }
+        match ty {
+            Type::Primitive(PrimitiveType::Bool) => vec![Value::Bool(false), Value::Bool(true)],
+            Type::Primitive(PrimitiveType::Int) => vec![Value::Int(0), Value::Int(1)],
+            Type::Primitive(PrimitiveType::String) => {
+                vec![Value::String("a".to_string()), Value::String("b".to_string())]
+            }
+            Type::Primitive(PrimitiveType::Float) => vec![Value::Float(0.0)],
+            // an undeclared named type is usually a key
+            Type::Named(name) if !program.types().iter().any(|t| t.name() == name) => {
+                vec![Value::Int(0), Value::Int(1)]
+            }
+            _ => vec![Value::Null],
+        }
+    }
+}
+
+/// Reads a domains file, names are checked against `program`
+pub fn parse_domains(text: &str, program: &Program) -> Result<Domains, Vec<Diagnostic>> {
+    let mut domains = Domains::default();
+    let mut relations = BTreeMap::new();
+    let mut diagnostics = vec![];
+    let mut offset = 0;
+    for (i, raw) in text.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let span = SourceSpan::new(
+            SourcePos { line: i + 1, column: 1 },
+            SourcePos { line: i + 1, column: line.len() + 1 },
+            offset,
+            offset + line.len(),
+        );
+        offset += raw.len();
+
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut invalid = |message: String| {
+            diagnostics.push(Diagnostic::error(INVALID_DOMAINS, message).with_span(span));
+        };
+        let Some((target, values)) = line.split_once('=') else {
+            invalid(format!("expected `Context.field = values` or `Relation = rows`, found `{}`", line));
+            continue;
+        };
+        let values = match ValueParser::new(values).list() {
+            Ok(values) => values,
+            Err(e) => {
+                invalid(e);
+                continue;
+            }
+        };
+        let target = target.trim();
+        match target.split_once('.') {
+            Some((context, field)) => {
+                let declared = program.contexts().iter().find(|c| c.name().as_str() == context);
+                let Some(declared) = declared else {
+                    invalid(format!("undeclared context `{}`", context));
+                    continue;
+                };
+                if !declared.fields().iter().any(|(name, _)| name.as_str() == field) {
+                    invalid(format!("context `{}` has no field `{}`", context, field));
+                    continue;
+                }
+                if values.is_empty() {
+                    invalid(format!("`{}` takes no value", target));
+                    continue;
+                }
+                let key = (context.to_string(), field.to_string());
+                if domains.fields.insert(key, values).is_some() {
+                    invalid(format!("`{}` is listed twice", target));
+                }
+            }
+            None => {
+                if !is_identifier(target) {
+                    invalid(format!("`{}` is not a relation name", target));
+                    continue;
+                }
+                let mut rows = vec![];
+                for value in values {
+                    match value {
+                        Value::Record(row) => rows.push(row),
+                        value => {
+                            invalid(format!("a row of `{}` is a record, found `{}`", target, value));
+                            break;
+                        }
+                    }
+                }
+                if relations.insert(target.to_string(), rows).is_some() {
+                    invalid(format!("`{}` is listed twice", target));
+                }
+            }
+        }
+    }
+    if diagnostics.is_empty() {
+        domains.tables = relations;
+        Ok(domains)
+    } else {
+        Err(diagnostics)
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Reads the comma separated values of one line
+struct ValueParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> ValueParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+
+    fn skip_space(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, token: char) -> bool {
+        self.skip_space();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn list(&mut self) -> Result<Vec<Value>, String> {
+        let mut values = vec![];
+        self.skip_space();
+        if self.rest.is_empty() {
+            return Ok(values);
+        }
+        loop {
+            values.push(self.value()?);
+            if !self.eat(',') {
+                break;
+            }
+        }
+        self.skip_space();
+        if !self.rest.is_empty() {
+            return Err(format!("unexpected `{}`", self.rest));
+        }
+        Ok(values)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_space();
+        if self.eat('{') {
+            return self.record();
+        }
+        if self.rest.starts_with('"') {
+            return self.string();
+        }
+        let end = self
+            .rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.' || c == '-'))
+            .unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        match word {
+            "" => Err("expected a value".to_string()),
+            "null" => Ok(Value::Null),
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ if word.contains('.') => word.parse().map(Value::Float).map_err(|_| format!("invalid number `{}`", word)),
+            _ => word.parse().map(Value::Int).map_err(|_| format!("invalid value `{}`", word)),
+        }
+    }
+
+    /// `{ name: value, ... }`, the `{` is read already
+    fn record(&mut self) -> Result<Value, String> {
+        let mut row = Row::new();
+        if self.eat('}') {
+            return Ok(Value::Record(row));
+        }
+        loop {
+            self.skip_space();
+            let end = self.rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(self.rest.len());
+            let (name, rest) = self.rest.split_at(end);
+            self.rest = rest;
+            if !is_identifier(name) || !self.eat(':') {
+                return Err("expected `name: value` in record".to_string());
+            }
+            let value = self.value()?;
+            if row.insert(name.to_string(), value).is_some() {
+                return Err(format!("field `{}` is listed twice", name));
+            }
+            if self.eat('}') {
+                return Ok(Value::Record(row));
+            }
+            if !self.eat(',') {
+                return Err("expected `,` or `}` in record".to_string());
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Value, String> {
+        let mut value = String::new();
+        let mut chars = self.rest[1..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[i + 2..];
+                    return Ok(Value::String(value));
+                }
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, 't')) => value.push('\t'),
+                    Some((_, c)) => value.push(c),
+                    None => break,
+                },
+                c => value.push(c),
+            }
+        }
+        Err("unterminated string".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::scl_parser::parse_scl;
+
+    #[test]
+    fn test_parse_domains() {
+        let program = parse_scl(include_str!("../../test_data/transfer.scl")).unwrap();
+        let text = "# accounts\n\
+                    TransferCtx.amount = 0, 5, -2\n\
+                    Account = { id: 1, balance: 10, owner: \"a \\\"b\\\"\" }, {}\n\
+                    Log =\n";
+        let domains = parse_domains(text, &program).unwrap();
+        assert_eq!(
+            domains.values("TransferCtx", "amount"),
+            Some(&vec![Value::Int(0), Value::Int(5), Value::Int(-2)])
+        );
+        let account = &domains.tables()["Account"];
+        assert_eq!(account[0]["owner"], Value::String("a \"b\"".to_string()));
+        assert!(account[1].is_empty());
+        assert!(domains.tables()["Log"].is_empty());
+
+        let from = &program.contexts()[0].fields()[0];
+        assert_eq!(
+            domains.domain(&program, "TransferCtx", "from", &from.1),
+            vec![Value::Int(0), Value::Int(1)]
+        );
+
+        let text = "TransferCtx.fee = 1\nAccount = 1\nNope.x = 1\nAccount { id: 1 }\nT = { a 1 }\n";
+        let diagnostics = parse_domains(text, &program).unwrap_err();
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message().as_str()).collect();
+        assert_eq!(messages, [
+            "context `TransferCtx` has no field `fee`",
+            "a row of `Account` is a record, found `1`",
+            "undeclared context `Nope`",
+            "expected `Context.field = values` or `Relation = rows`, found `Account { id: 1 }`",
+            "expected `name: value` in record",
+        ]);
+        assert_eq!(diagnostics[2].span().unwrap().start.line, 3);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod domains;
+pub mod violation;
+pub mod model_checker;
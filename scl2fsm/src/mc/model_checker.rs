@@ -0,0 +1,402 @@
+//! Explicit-state model checking of SCL programs
+//!
+//! A state of the search is an SCL state together with the contexts of
+//! the run, the values `use state` imported and the contents of every
+//! relation. Runs start in the first declared state, once for every
+//! combination of context values the [`Domains`] allow, with the
+//! relations the domains list. From each state the body is run and the
+//! first `next` case that applies is taken; a run ends after the body of
+//! a terminal state, one whose cases all stay in it.
+//!
+//! The search is breadth first and stops at the first violation, its
+//! trace is as short as a trace to that violation can be. Checked are:
+//! the declared invariants in every state entered and at the end of a
+//! run, the preconditions of every state entered, every `assert`, and
+//! that a `next` case applies.
+
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::eval::evaluator::{Env, Failure};
+use crate::eval::store::{MemoryStore, Tables};
+use crate::eval::value::{Row, Value};
+use crate::mc::domains::Domains;
+use crate::mc::violation::{Step, Violation, ViolationKind};
+use common::diag::diagnostic::Diagnostic;
+use common::diag::source_span::SourceSpan;
+use std::collections::{HashMap, VecDeque};
+
+/// Diagnostic code: the search stopped at the state limit, states beyond
+/// it were not checked
+pub const STATE_LIMIT_REACHED: &str = "SCL0307";
+
+/// Number of states a search looks at unless told otherwise
+pub const DEFAULT_MAX_STATES: usize = 100_000;
+
+/// A state of the search
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Config {
+    /// index of the SCL state
+    state: usize,
+    contexts: Row,
+    imports: Row,
+    tables: Tables,
+}
+
+/// Outcome of a search
+#[derive(Debug, Clone)]
+pub struct Report {
+    explored: usize,
+    violation: Option<Violation>,
+    /// false if the state limit stopped the search
+    complete: bool,
+}
+
+impl Report {
+    /// Number of states whose body was run
+    pub fn explored(&self) -> usize {
+        self.explored
+    }
+
+    pub fn violation(&self) -> Option<&Violation> {
+        self.violation.as_ref()
+    }
+
+    /// Whether every reachable state was checked, or a violation was found
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    pub fn to_diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<_> = self.violation.iter().map(|v| v.to_diagnostic()).collect();
+        if !self.complete {
+            diagnostics.push(Diagnostic::warning(
+                STATE_LIMIT_REACHED,
+                format!("the search stopped after {} states, the rest were not checked", self.explored),
+            ));
+        }
+        diagnostics
+    }
+}
+
+/// Checks a program against bounded inputs, looking at no more than
+/// `max_states` states
+pub fn check_model(program: &Program, domains: &Domains, max_states: usize) -> Report {
+    let ids: HashMap<_, _> = program
+        .states()
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.name().as_str(), i))
+        .collect();
+    let mut search = Search { program, configs: vec![], index: HashMap::new(), queue: VecDeque::new() };
+    let mut complete = true;
+    if !program.states().is_empty() {
+        for contexts in initial_contexts(program, domains) {
+            if search.configs.len() >= max_states {
+                complete = false;
+                break;
+            }
+            let config = Config { state: 0, contexts, imports: Row::new(), tables: domains.tables().clone() };
+            search.add(config, None);
+        }
+    }
+
+    let mut explored = 0;
+    while let Some(i) = search.queue.pop_front() {
+        explored += 1;
+        let (violation, next) = search.step(i, &ids);
+        if let Some((kind, message, span, last)) = violation {
+            let config = &search.configs[i].0;
+            let mut trace = search.trace(i);
+            trace.extend(last);
+            let mut violation = Violation::new(kind, message, config.contexts.clone(), trace);
+            if let Some(span) = span {
+                violation = violation.with_span(span);
+            }
+            return Report { explored, violation: Some(violation), complete: true };
+        }
+        if let Some(next) = next
+            && !search.index.contains_key(&next)
+        {
+            if search.configs.len() >= max_states {
+                complete = false;
+                continue;
+            }
+            search.add(next, Some(i));
+        }
+    }
+    Report { explored, violation: None, complete }
+}
+
+/// A violation found in a state: its kind, the message, the declaration
+/// of the property that fails if it has a span, and the last step of the
+/// trace if the state's body has run
+type Found = (ViolationKind, String, Option<SourceSpan>, Option<Step>);
+
+struct Search<'a> {
+    program: &'a Program,
+    /// every state seen, with the state it was reached from
+    configs: Vec<(Config, Option<usize>)>,
+    index: HashMap<Config, usize>,
+    queue: VecDeque<usize>,
+}
+
+impl Search<'_> {
+    fn add(&mut self, config: Config, parent: Option<usize>) {
+        if self.index.contains_key(&config) {
+            return;
+        }
+        self.index.insert(config.clone(), self.configs.len());
+        self.queue.push_back(self.configs.len());
+        self.configs.push((config, parent));
+    }
+
+    /// The states from a start state to state `i`
+    fn trace(&self, mut i: usize) -> Vec<Step> {
+        let mut trace = vec![];
+        loop {
+            let (config, parent) = &self.configs[i];
+            trace.push(Step {
+                state: self.program.states()[config.state].name().as_str().to_string(),
+                tables: config.tables.clone(),
+                done: false,
+            });
+            match parent {
+                Some(p) => i = *p,
+                None => break,
+            }
+        }
+        trace.reverse();
+        trace
+    }
+
+    /// Runs state `i`: the violation found in it, or the state the run
+    /// goes on with, `None` at the end of a run
+    fn step(&self, i: usize, ids: &HashMap<&str, usize>) -> (Option<Found>, Option<Config>) {
+        let config = &self.configs[i].0;
+        let state = &self.program.states()[config.state];
+        let name = state.name();
+        if let Some((kind, message, span)) = self.check_invariants(&config.contexts, &config.tables) {
+            return (Some((kind, message, span, None)), None);
+        }
+
+        let mut store = MemoryStore::new(config.tables.clone());
+        let mut env = Env::new(&config.contexts, &mut store, &config.imports);
+        for condition in state.preconditions() {
+            let found = match env.condition(condition, None) {
+                Ok(true) => continue,
+                Ok(false) => (
+                    ViolationKind::Precondition,
+                    format!("precondition `{}` of state `{}` does not hold", condition, name),
+                ),
+                Err(e) => evaluation_failed(name.as_str(), &e),
+            };
+            return (Some((found.0, found.1, None, None)), None);
+        }
+        match env.run_body(state) {
+            Ok(()) => {}
+            Err(Failure::Assertion(condition)) => {
+                let message = format!("assertion `{}` fails in state `{}`", condition, name);
+                return (Some((ViolationKind::Assertion, message, None, None)), None);
+            }
+            Err(e) => {
+                let (kind, message) = evaluation_failed(name.as_str(), &e);
+                return (Some((kind, message, None, None)), None);
+            }
+        }
+
+        let terminal = state.next().cases().iter().all(|case| target(case).0 == name.as_str());
+        if terminal {
+            // the run ends, the invariants hold after the last body too
+            let tables = store.tables();
+            let found = self.check_invariants(&config.contexts, tables).map(|(kind, message, span)| {
+                let last = Step { state: name.as_str().to_string(), tables: tables.clone(), done: true };
+                (kind, message, span, Some(last))
+            });
+            return (found, None);
+        }
+
+        let case = match env.next_case(state) {
+            Ok(Some(case)) => case,
+            Ok(None) => {
+                let message = format!("no case of the next block of state `{}` applies", name);
+                return (Some((ViolationKind::NoCaseApplies, message, None, None)), None);
+            }
+            Err(e) => {
+                let (kind, message) = evaluation_failed(name.as_str(), &e);
+                return (Some((kind, message, None, None)), None);
+            }
+        };
+        let (to, exports) = target(case);
+        let to = ids[to];
+        // staying in a state keeps what it was entered with
+        let imports = if to == config.state {
+            config.imports.clone()
+        } else {
+            exports
+                .iter()
+                .map(|name| (name.to_string(), env.local(name).cloned().unwrap_or(Value::Null)))
+                .collect()
+        };
+        let next = Config { state: to, contexts: config.contexts.clone(), imports, tables: store.into_tables() };
+        (None, Some(next))
+    }
+
+    /// The first invariant that fails, reported at its name
+    fn check_invariants(
+        &self,
+        contexts: &Row,
+        tables: &Tables,
+    ) -> Option<(ViolationKind, String, Option<SourceSpan>)> {
+        let mut store = MemoryStore::new(tables.clone());
+        let mut env = Env::new(contexts, &mut store, &Row::new());
+        for invariant in self.program.invariants() {
+            let span = invariant.name().span().copied();
+            for condition in invariant.conditions() {
+                match env.condition(condition, None) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        let message = format!("invariant `{}` does not hold: `{}`", invariant.name(), condition);
+                        return Some((ViolationKind::Invariant, message, span));
+                    }
+                    Err(e) => {
+                        let message = format!("invariant `{}` cannot be evaluated: {}", invariant.name(), e);
+                        return Some((ViolationKind::Evaluation, message, span));
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn evaluation_failed(state: &str, e: &Failure) -> (ViolationKind, String) {
+    (ViolationKind::Evaluation, format!("state `{}` cannot be evaluated: {}", state, e))
+}
+
+/// Target of a `next` case and the names it exports
+fn target(case: &NextCase) -> (&str, Vec<&str>) {
+    let (NextCase::When { target, exports, .. } | NextCase::Otherwise { target, exports }) = case;
+    (target.as_str(), exports.iter().map(|(name, _)| name.as_str()).collect())
+}
+
+/// Every combination of context values the domains allow
+fn initial_contexts(program: &Program, domains: &Domains) -> Vec<Row> {
+    let mut runs = vec![Row::new()];
+    for decl in program.contexts() {
+        let context = decl.name().as_str();
+        let mut records = vec![Row::new()];
+        for (field, ty) in decl.fields() {
+            let values = domains.domain(program, context, field.as_str(), ty);
+            records = records
+                .iter()
+                .flat_map(|record| {
+                    values.iter().map(move |value| {
+                        let mut record = record.clone();
+                        record.insert(field.as_str().to_string(), value.clone());
+                        record
+                    })
+                })
+                .collect();
+        }
+        runs = runs
+            .iter()
+            .flat_map(|run| {
+                records.iter().map(move |record| {
+                    let mut run = run.clone();
+                    run.insert(context.to_string(), Value::Record(record.clone()));
+                    run
+                })
+            })
+            .collect();
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mc::domains::parse_domains;
+    use crate::mc::violation::INVARIANT_VIOLATED;
+    use crate::parser::scl_parser::parse_scl;
+
+    fn check(text: &str, domains: &str) -> Report {
+        let program = parse_scl(text).unwrap();
+        let domains = parse_domains(domains, &program).unwrap();
+        check_model(&program, &domains, DEFAULT_MAX_STATES)
+    }
+
+    #[test]
+    fn test_transfer() {
+        let domains = include_str!("../../test_data/transfer.domains");
+        let report = check(include_str!("../../test_data/transfer.scl"), domains);
+        assert!(report.violation().is_none(), "{:?}", report.violation());
+        assert!(report.is_complete());
+        // 12 runs: 8 transfers through Check, Debit, Credit and Commit,
+        // the 4 that would overdraw account 2 through Check and Reject
+        assert_eq!(report.explored(), 40);
+    }
+
+    #[test]
+    fn test_overdraft() {
+        let domains = include_str!("../../test_data/overdraft.domains");
+        let report = check(include_str!("../../test_data/overdraft.scl"), domains);
+        let violation = report.violation().unwrap();
+        assert_eq!(violation.kind(), ViolationKind::Invariant);
+        let d = violation.to_diagnostic();
+        assert_eq!(d.code(), INVARIANT_VIOLATED);
+        let span = d.span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (38, 11));
+        assert_eq!(
+            d.message(),
+            "invariant `NonNegative` does not hold: `\\A Relation Account a by true : a.balance >= 0`"
+        );
+        assert_eq!(
+            d.notes(),
+            &vec![
+                "TransferCtx = { amount: 5, from: 2, to: 1 }".to_string(),
+                "1. Debit: Account = [{ balance: 10, id: 1 }, { balance: 0, id: 2 }]".to_string(),
+                "2. Credit: Account = [{ balance: 10, id: 1 }, { balance: -5, id: 2 }]".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runtime_checks() {
+        let text = r#"
+context C { x: int; }
+
+state A uses C {
+  precondition { C.x >= 0; }
+  insert into Log (n) values (C.x);
+  assert C.x # 2;
+  next {
+    when C.x = 1 => B;
+    when C.x = 3 => A;
+    otherwise => End;
+  }
+}
+
+state B uses C {
+  let y: int = 10 / (C.x - 1);
+  next { otherwise => End; }
+}
+
+state End uses C {
+  next { when C.x > 100 => End; }
+}
+"#;
+        let kind = |domains| check(text, domains).violation().map(|v| v.kind());
+        assert_eq!(kind("C.x = 0"), None);
+        assert_eq!(kind("C.x = -1"), Some(ViolationKind::Precondition));
+        assert_eq!(kind("C.x = 0, 2"), Some(ViolationKind::Assertion));
+        assert_eq!(kind("C.x = 1"), Some(ViolationKind::Evaluation));
+
+        // `A` keeps inserting into `Log`, every state is new
+        let program = parse_scl(text).unwrap();
+        let domains = parse_domains("C.x = 3", &program).unwrap();
+        let report = check_model(&program, &domains, 10);
+        assert!(!report.is_complete());
+        assert_eq!(report.explored(), 10);
+        assert_eq!(report.to_diagnostics()[0].code(), STATE_LIMIT_REACHED);
+    }
+}
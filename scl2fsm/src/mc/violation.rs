@@ -0,0 +1,117 @@
+use crate::eval::store::Tables;
+use crate::eval::value::{Row, Value};
+use common::diag::diagnostic::Diagnostic;
+use common::diag::source_span::SourceSpan;
+
+/// Diagnostic code: an `assert` of a state body does not hold
+pub const ASSERTION_FAILED: &str = "SCL0302";
+/// Diagnostic code: a declared invariant does not hold in a reachable state
+pub const INVARIANT_VIOLATED: &str = "SCL0303";
+/// Diagnostic code: a state is entered while one of its preconditions
+/// does not hold
+pub const PRECONDITION_VIOLATED: &str = "SCL0304";
+/// Diagnostic code: a reachable state cannot be evaluated, e.g. it divides
+/// by zero or reads a field of `null`
+pub const EVALUATION_FAILED: &str = "SCL0305";
+/// Diagnostic code: no case of a `next` block applies
+pub const NO_CASE_APPLIES: &str = "SCL0306";
+
+/// Kind of violation found by the model checker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationKind {
+    Assertion,
+    Invariant,
+    Precondition,
+    Evaluation,
+    NoCaseApplies,
+}
+
+/// One state of a counterexample
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// Name of the state
+    pub state: String,
+    /// The relations when the state is entered, or after its body for the
+    /// last step of a run that ended in a terminal state
+    pub tables: Tables,
+    /// Whether the body of the state has run
+    pub done: bool,
+}
+
+/// A property that fails in a reachable state, with the run that gets
+/// there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    kind: ViolationKind,
+    message: String,
+    /// context name -> the record the run works on
+    contexts: Row,
+    /// States of the run, the violation is found in the last one
+    trace: Vec<Step>,
+    /// Where the property that fails is declared, if it is known
+    span: Option<SourceSpan>,
+}
+
+impl Violation {
+    pub fn new(kind: ViolationKind, message: String, contexts: Row, trace: Vec<Step>) -> Violation {
+        Self { kind, message, contexts, trace, span: None }
+    }
+
+    /// Attaches the declaration of the property that fails
+    pub fn with_span(mut self, span: SourceSpan) -> Violation {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn kind(&self) -> ViolationKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+
+    pub fn contexts(&self) -> &Row {
+        &self.contexts
+    }
+
+    pub fn trace(&self) -> &Vec<Step> {
+        &self.trace
+    }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
+
+    /// The violation as an error, the notes are the counterexample: the
+    /// contexts, then one note per state with the relations in it
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let code = match self.kind {
+            ViolationKind::Assertion => ASSERTION_FAILED,
+            ViolationKind::Invariant => INVARIANT_VIOLATED,
+            ViolationKind::Precondition => PRECONDITION_VIOLATED,
+            ViolationKind::Evaluation => EVALUATION_FAILED,
+            ViolationKind::NoCaseApplies => NO_CASE_APPLIES,
+        };
+        let mut d = Diagnostic::error(code, self.message.clone());
+        if let Some(span) = self.span {
+            d = d.with_span(span);
+        }
+        for (name, value) in &self.contexts {
+            d = d.with_note(format!("{} = {}", name, value));
+        }
+        for (i, step) in self.trace.iter().enumerate() {
+            let tables: Vec<_> = step
+                .tables
+                .iter()
+                .map(|(name, rows)| {
+                    let rows = rows.iter().map(|row| Value::Record(row.clone())).collect();
+                    format!("{} = {}", name, Value::List(rows))
+                })
+                .collect();
+            let done = if step.done { " (done)" } else { "" };
+            d = d.with_note(format!("{}. {}{}: {}", i + 1, step.state, done, tables.join(", ")));
+        }
+        d
+    }
+}
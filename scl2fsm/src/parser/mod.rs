@@ -0,0 +1 @@
+pub mod scl_parser;
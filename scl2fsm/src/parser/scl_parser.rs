@@ -0,0 +1,923 @@
+use crate::ast::assignment::Assignment;
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::compare_op::CompareOp;
+use crate::ast::condition::Condition;
+use crate::ast::context_decl::ContextDecl;
+use crate::ast::expr::Expr;
+use crate::ast::invariant_decl::InvariantDecl;
+use crate::ast::literal::Literal;
+use crate::ast::name::Name;
+use crate::ast::next::{NextBlock, NextCase};
+use crate::ast::program::Program;
+use crate::ast::quantifier::QuantifierKind;
+use crate::ast::state_decl::StateDecl;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::{PrimitiveType, Type};
+use crate::ast::type_decl::TypeDecl;
+use crate::ast::unary_op::UnaryOp;
+use crate::ast::use_stmt::{QualifiedName, UseStmt};
+use crate::ts_const;
+use anyhow::{Error, Result};
+use common::diag::diagnostic::{has_errors, Diagnostic};
+use common::diag::parse_context::{span_of_node, ParseContext};
+use tree_sitter::{Node, Parser};
+use tree_sitter_scl::LANGUAGE;
+
+/// Parser for State Construction Language sources
+///
+/// This parser uses Tree-sitter to parse SCL and walks the concrete
+/// syntax tree to build the typed AST rooted at [`Program`].
+pub struct SCLParser {
+    parser: Parser,
+}
+
+/// Diagnostic code: the source contains text the grammar cannot parse
+pub const SYNTAX_ERROR: &str = "SCL0001";
+/// Diagnostic code: the parser had to assume a token that is not there
+pub const MISSING_TOKEN: &str = "SCL0002";
+/// Diagnostic code: the tree-sitter grammar could not be loaded
+pub const PARSER_INIT: &str = "SCL0003";
+/// Diagnostic code: a syntax node lacks a part the AST needs
+pub const MALFORMED_NODE: &str = "SCL0004";
+/// Diagnostic code: a number or string literal cannot be represented
+pub const INVALID_LITERAL: &str = "SCL0005";
+
+/// A list of `name: type;` fields
+type Fields = Vec<(Name, Type)>;
+
+impl SCLParser {
+    /// Creates a new parser instance with the SCL grammar
+    pub fn new() -> std::result::Result<Self, Diagnostic> {
+        let mut parser = Parser::new();
+
+        parser.set_language(&LANGUAGE.into())
+            .map_err(|e| {
+                Diagnostic::error(PARSER_INIT, format!("failed to set language: {}", e))
+            })?;
+
+        Ok(SCLParser { parser })
+    }
+
+    /// Parses SCL source code and builds a program
+    ///
+    /// # Returns
+    /// * `Ok(Program)` - the AST if the source parsed without errors
+    /// * `Err(Vec<Diagnostic>)` - every syntax error found otherwise
+    pub fn parse(&mut self, source_code: &str) -> std::result::Result<Program, Vec<Diagnostic>> {
+        let Some(tree) = self.parser.parse(source_code, None) else {
+            let d = Diagnostic::error(PARSER_INIT, "failed to parse source code".to_string());
+            return Err(vec![d]);
+        };
+
+        let root_node = tree.root_node();
+        let mut context = ParseContext::new(source_code.to_string(), SYNTAX_ERROR, MISSING_TOKEN);
+
+        if root_node.has_error() {
+            Self::report_syntax_errors(root_node, &mut context);
+        }
+
+        let result = self.visit_program(root_node, &mut context);
+        let has_syntax_error = has_errors(context.diagnostics());
+        match result {
+            Ok(program) if !has_syntax_error => Ok(program),
+            Ok(_) => Err(context.into_diagnostics()),
+            Err(e) => {
+                // a malformed node is expected once a syntax error was reported
+                if !has_syntax_error {
+                    let d = match e.downcast::<Diagnostic>() {
+                        Ok(d) => d,
+                        Err(e) => Diagnostic::error(MALFORMED_NODE, e.to_string()),
+                    };
+                    context.report(d);
+                }
+                Err(context.into_diagnostics())
+            }
+        }
+    }
+
+    /// Reports every `ERROR` and missing node below `node`
+    ///
+    /// Recovery often wraps a whole declaration in one `ERROR` node,
+    /// the innermost error nodes point closer to the mistake.
+    fn report_syntax_errors(node: Node, context: &mut ParseContext) {
+        let mut cursor = node.walk();
+        let inner_error = node.children(&mut cursor).any(|c| c.has_error());
+        if (node.is_error() && !inner_error) || node.is_missing() {
+            if let Err(e) = context.report_syntax_error(node) {
+                let d = Diagnostic::error(SYNTAX_ERROR, e.to_string())
+                    .with_span(span_of_node(&node));
+                context.report(d);
+            }
+            return;
+        }
+        for child in node.children(&mut cursor) {
+            if child.has_error() {
+                Self::report_syntax_errors(child, context);
+            }
+        }
+    }
+
+    fn visit_program(&self, node: Node, context: &mut ParseContext) -> Result<Program> {
+        let mut types = vec![];
+        let mut contexts = vec![];
+        let mut states = vec![];
+        let mut invariants = vec![];
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                ts_const::ts_kind_name::S_TYPE_DECL => {
+                    types.push(self.visit_type_decl(child, context)?);
+                }
+                ts_const::ts_kind_name::S_CONTEXT_DECL => {
+                    contexts.push(self.visit_context_decl(child, context)?);
+                }
+                ts_const::ts_kind_name::S_STATE_DECL => {
+                    states.push(self.visit_state_decl(child, context)?);
+                }
+                ts_const::ts_kind_name::S_INVARIANT_DECL => {
+                    invariants.push(self.visit_invariant_decl(child, context)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(Program::new(types, contexts, states, invariants))
+    }
+
+    // ---------------------------
+    // Declarations
+    // ---------------------------
+
+    fn visit_type_decl(&self, node: Node, context: &mut ParseContext) -> Result<TypeDecl> {
+        let name = self.visit_name_field(&node, ts_const::ts_field_name::NAME, context)?;
+        let fields = self.visit_fields(&node, ts_const::ts_kind_name::S_TYPE_FIELD, context)?;
+        TypeDecl::new(name, fields).map_err(|e| malformed(&node, e))
+    }
+
+    fn visit_context_decl(&self, node: Node, context: &mut ParseContext) -> Result<ContextDecl> {
+        let name = self.visit_name_field(&node, ts_const::ts_field_name::NAME, context)?;
+        let fields = self.visit_fields(&node, ts_const::ts_kind_name::S_CONTEXT_FIELD, context)?;
+        ContextDecl::new(name, fields).map_err(|e| malformed(&node, e))
+    }
+
+    fn visit_invariant_decl(&self, node: Node, context: &mut ParseContext) -> Result<InvariantDecl> {
+        let name = self.visit_name_field(&node, ts_const::ts_field_name::NAME, context)?;
+        let mut conditions = vec![];
+        for node_cond in children_of_kind(&node, ts_const::ts_kind_name::S_CONDITION) {
+            conditions.push(self.visit_condition(node_cond, context)?);
+        }
+        InvariantDecl::new(name, conditions).map_err(|e| malformed(&node, e))
+    }
+
+    /// Collects the `name: type;` children of kind `field_kind`
+    fn visit_fields(&self, node: &Node, field_kind: &str, context: &mut ParseContext) -> Result<Fields> {
+        let mut fields = vec![];
+        for child in children_of_kind(node, field_kind) {
+            let name = self.visit_name_field(&child, ts_const::ts_field_name::NAME, context)?;
+            let node_type = self.get_named_field(&child, ts_const::ts_field_name::TYPE)?;
+            let ty = self.visit_type(node_type, context)?;
+            fields.push((name, ty));
+        }
+        Ok(fields)
+    }
+
+    fn visit_state_decl(&self, node: Node, context: &mut ParseContext) -> Result<StateDecl> {
+        let node_name = self.get_named_field(&node, ts_const::ts_field_name::NAME)?;
+        let name = self.visit_name(node_name, context)?;
+        let mut contexts = vec![];
+        let mut uses = vec![];
+        let mut preconditions = vec![];
+        let mut body = vec![];
+        let mut next = None;
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                // the state name is an identifier too
+                ts_const::ts_kind_name::S_IDENTIFIER if child.id() != node_name.id() => {
+                    contexts.push(self.visit_name(child, context)?);
+                }
+                ts_const::ts_kind_name::S_USE_STMT => {
+                    uses.push(self.visit_use_stmt(child, context)?);
+                }
+                ts_const::ts_kind_name::S_PRECONDITION_BLOCK => {
+                    for node_cond in children_of_kind(&child, ts_const::ts_kind_name::S_CONDITION) {
+                        preconditions.push(self.visit_condition(node_cond, context)?);
+                    }
+                }
+                ts_const::ts_kind_name::S_STATEMENT => {
+                    body.push(self.visit_statement(child, context)?);
+                }
+                ts_const::ts_kind_name::S_NEXT_BLOCK => {
+                    next = Some(self.visit_next_block(child, context)?);
+                }
+                _ => {}
+            }
+        }
+        let next = next.ok_or_else(|| malformed(&node, "expected next block in state_decl"))?;
+        Ok(StateDecl::new(name, contexts, uses, preconditions, body, next))
+    }
+
+    fn visit_use_stmt(&self, node: Node, context: &mut ParseContext) -> Result<UseStmt> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            ts_const::ts_kind_name::S_USE_STATE_STMT => {
+                let node_source = self.get_named_field(&child, ts_const::ts_field_name::SOURCE)?;
+                let source = self.visit_qualified_name(node_source, context)?;
+                let fields = self.visit_fields(&child, ts_const::ts_kind_name::S_USE_FIELD, context)?;
+                Ok(UseStmt::State { source, fields })
+            }
+            ts_const::ts_kind_name::S_USE_CONTEXT_STMT => {
+                let node_context = self.get_named_field(&child, ts_const::ts_field_name::CONTEXT)?;
+                let context_name = self.visit_qualified_name(node_context, context)?;
+                Ok(UseStmt::Context { context: context_name })
+            }
+            ts_const::ts_kind_name::S_USE_TYPE_STMT => {
+                let node_type = self.get_named_field(&child, ts_const::ts_field_name::TYPE)?;
+                let ty = self.visit_qualified_name(node_type, context)?;
+                Ok(UseStmt::Type { ty })
+            }
+            kind => Err(malformed(&child, format!("unexpected {} in use_stmt", kind))),
+        }
+    }
+
+    /// `Name` or `"path"::Name`
+    fn visit_qualified_name(&self, node: Node, context: &mut ParseContext) -> Result<QualifiedName> {
+        let node_name = children_of_kind(&node, ts_const::ts_kind_name::S_IDENTIFIER)
+            .into_iter()
+            .next()
+            .ok_or_else(|| malformed(&node, "expected identifier in qualified_name"))?;
+        let name = self.visit_name(node_name, context)?;
+        match children_of_kind(&node, ts_const::ts_kind_name::S_STRING).first() {
+            Some(node_path) => {
+                let path = self.visit_string(*node_path, context)?;
+                Ok(QualifiedName::with_path(path, name))
+            }
+            None => Ok(QualifiedName::local(name)),
+        }
+    }
+
+    // ---------------------------
+    // Statements
+    // ---------------------------
+
+    fn visit_statement(&self, node: Node, context: &mut ParseContext) -> Result<Stmt> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            ts_const::ts_kind_name::S_LET_STMT => {
+                let name = self.visit_name_field(&child, ts_const::ts_field_name::NAME, context)?;
+                let node_type = self.get_named_field(&child, ts_const::ts_field_name::TYPE)?;
+                let ty = self.visit_type(node_type, context)?;
+                let node_value = self.get_named_field(&child, ts_const::ts_field_name::VALUE)?;
+                let value = self.visit_expr(node_value, context)?;
+                Ok(Stmt::Let { name, ty, value })
+            }
+            ts_const::ts_kind_name::S_SELECT_STMT => {
+                let name = self.visit_name_field(&child, ts_const::ts_field_name::NAME, context)?;
+                let node_type = self.get_named_field(&child, ts_const::ts_field_name::TYPE)?;
+                let ty = self.visit_type(node_type, context)?;
+                let relation = self.visit_name_field(&child, ts_const::ts_field_name::ENTITY, context)?;
+                let where_clause = self.visit_where_clause(&child, context)?;
+                let limit = match children_of_kind(&child, ts_const::ts_kind_name::S_LIMIT_CLAUSE).first() {
+                    Some(node_limit) => Some(self.visit_limit_clause(*node_limit, context)?),
+                    None => None,
+                };
+                Ok(Stmt::Select { name, ty, relation, where_clause, limit })
+            }
+            ts_const::ts_kind_name::S_FOREACH_STMT => {
+                let item = self.visit_name_field(&child, ts_const::ts_field_name::ITEM, context)?;
+                let node_type = self.get_named_field(&child, ts_const::ts_field_name::ITEM_TYPE)?;
+                let item_ty = self.visit_type(node_type, context)?;
+                let collection = self.visit_name_field(&child, ts_const::ts_field_name::COLLECTION, context)?;
+                let mut body = vec![];
+                for node_stmt in children_of_kind(&child, ts_const::ts_kind_name::S_STATEMENT) {
+                    body.push(self.visit_statement(node_stmt, context)?);
+                }
+                Ok(Stmt::Foreach { item, item_ty, collection, body })
+            }
+            ts_const::ts_kind_name::S_UPDATE_STMT => {
+                let relation = self.visit_name_field(&child, ts_const::ts_field_name::ENTITY, context)?;
+                let mut assignments = vec![];
+                for node_assign in children_of_kind(&child, ts_const::ts_kind_name::S_ASSIGNMENT) {
+                    let field = self.visit_name_field(&node_assign, ts_const::ts_field_name::FIELD, context)?;
+                    let node_value = self.get_named_field(&node_assign, ts_const::ts_field_name::VALUE)?;
+                    let value = self.visit_expr(node_value, context)?;
+                    assignments.push(Assignment::new(field, value));
+                }
+                let where_clause = self.visit_where_clause(&child, context)?;
+                Ok(Stmt::Update { relation, assignments, where_clause })
+            }
+            ts_const::ts_kind_name::S_INSERT_STMT => {
+                let node_entity = self.get_named_field(&child, ts_const::ts_field_name::ENTITY)?;
+                let relation = self.visit_name(node_entity, context)?;
+                let mut columns = vec![];
+                let mut values = vec![];
+                let mut cursor = child.walk();
+                for c in child.named_children(&mut cursor) {
+                    match c.kind() {
+                        ts_const::ts_kind_name::S_IDENTIFIER if c.id() != node_entity.id() => {
+                            columns.push(self.visit_name(c, context)?);
+                        }
+                        ts_const::ts_kind_name::S_EXPR => {
+                            values.push(self.visit_expr(c, context)?);
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(Stmt::Insert { relation, columns, values })
+            }
+            ts_const::ts_kind_name::S_DELETE_STMT => {
+                let relation = self.visit_name_field(&child, ts_const::ts_field_name::ENTITY, context)?;
+                let where_clause = self.visit_where_clause(&child, context)?;
+                Ok(Stmt::Delete { relation, where_clause })
+            }
+            ts_const::ts_kind_name::S_ASSERT_STMT => {
+                let node_expr = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_EXPR)?;
+                let condition = self.visit_expr_condition(node_expr, context)?;
+                Ok(Stmt::Assert { condition })
+            }
+            ts_const::ts_kind_name::S_COMMIT_STMT => Ok(Stmt::Commit),
+            kind => Err(malformed(&child, format!("unexpected {} in statement", kind))),
+        }
+    }
+
+    fn visit_where_clause(&self, node: &Node, context: &mut ParseContext) -> Result<Option<Condition>> {
+        match children_of_kind(node, ts_const::ts_kind_name::S_WHERE_CLAUSE).first() {
+            Some(node_where) => {
+                let node_expr = self.get_child_of_kind(node_where, ts_const::ts_kind_name::S_EXPR)?;
+                Ok(Some(self.visit_expr_condition(node_expr, context)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn visit_limit_clause(&self, node: Node, context: &mut ParseContext) -> Result<u64> {
+        let node_number = self.get_child_of_kind(&node, ts_const::ts_kind_name::S_NUMBER)?;
+        let text = context.text_of_node(&node_number)?;
+        text.parse::<u64>().map_err(|e| {
+            let d = Diagnostic::error(INVALID_LITERAL, format!("invalid limit `{}`: {}", text, e))
+                .with_span(span_of_node(&node_number));
+            Error::new(d)
+        })
+    }
+
+    // ---------------------------
+    // Next block
+    // ---------------------------
+
+    fn visit_next_block(&self, node: Node, context: &mut ParseContext) -> Result<NextBlock> {
+        let mut cases = vec![];
+        for node_case in children_of_kind(&node, ts_const::ts_kind_name::S_NEXT_CASE) {
+            cases.push(self.visit_next_case(node_case, context)?);
+        }
+        NextBlock::new(cases).map_err(|e| malformed(&node, e))
+    }
+
+    /// `when <condition> => Target` or `otherwise => Target`, either
+    /// optionally followed by an `{ export ... }` block
+    fn visit_next_case(&self, node: Node, context: &mut ParseContext) -> Result<NextCase> {
+        let keyword = node.child(0)
+            .ok_or_else(|| malformed(&node, "expected when or otherwise keyword"))?;
+        let target = self.visit_name_field(&node, ts_const::ts_field_name::TARGET, context)?;
+        let exports = match children_of_kind(&node, ts_const::ts_kind_name::S_EDGE_EXPORT_BLOCK).first() {
+            Some(node_export) => {
+                self.visit_fields(node_export, ts_const::ts_kind_name::S_EDGE_FIELD, context)?
+            }
+            None => vec![],
+        };
+        match keyword.kind() {
+            "when" => {
+                let node_cond = self.get_child_of_kind(&node, ts_const::ts_kind_name::S_CONDITION)?;
+                let condition = self.visit_condition(node_cond, context)?;
+                Ok(NextCase::When { condition, target, exports })
+            }
+            "otherwise" => Ok(NextCase::Otherwise { target, exports }),
+            kind => Err(malformed(&keyword, format!("unexpected keyword {} in next_case", kind))),
+        }
+    }
+
+    // ---------------------------
+    // Conditions
+    // ---------------------------
+
+    fn visit_condition(&self, node: Node, context: &mut ParseContext) -> Result<Condition> {
+        let node_tla = self.get_child_of_kind(&node, ts_const::ts_kind_name::S_TLA_EXPR)?;
+        self.visit_tla_expr(node_tla, context)
+    }
+
+    fn visit_tla_expr(&self, node: Node, context: &mut ParseContext) -> Result<Condition> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            ts_const::ts_kind_name::S_TLA_BINARY_EXPR => {
+                let (lhs, op, rhs) = self.binary_operands(&child)?;
+                let lhs = Box::new(self.visit_tla_expr(lhs, context)?);
+                let rhs = Box::new(self.visit_tla_expr(rhs, context)?);
+                match op.kind() {
+                    "=>" => Ok(Condition::Implies(lhs, rhs)),
+                    "\\/" => Ok(Condition::Or(lhs, rhs)),
+                    "/\\" => Ok(Condition::And(lhs, rhs)),
+                    kind => Err(malformed(&op, format!("unexpected operator {}", kind))),
+                }
+            }
+            ts_const::ts_kind_name::S_TLA_UNARY_EXPR => {
+                let operand = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_TLA_EXPR)?;
+                Ok(Condition::Not(Box::new(self.visit_tla_expr(operand, context)?)))
+            }
+            ts_const::ts_kind_name::S_TLA_QUANTIFIER => {
+                let kind = self.visit_quantifier_kind(&child)?;
+                let relation = self.visit_name_field(&child, ts_const::ts_field_name::RELATION, context)?;
+                let var = self.visit_name_field(&child, ts_const::ts_field_name::VAR, context)?;
+                let node_binding = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_EXPR)?;
+                let pk_binding = self.visit_expr(node_binding, context)?;
+                let node_body = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_TLA_EXPR)?;
+                let body = Box::new(self.visit_tla_expr(node_body, context)?);
+                Ok(Condition::Quantifier { kind, relation, var, pk_binding, body })
+            }
+            ts_const::ts_kind_name::S_EXPR => self.visit_expr_condition(child, context),
+            kind => Err(malformed(&child, format!("unexpected {} in tla_expr", kind))),
+        }
+    }
+
+    fn visit_quantifier_kind(&self, node: &Node) -> Result<QuantifierKind> {
+        let keyword = node.child(0)
+            .ok_or_else(|| malformed(node, "expected quantifier"))?;
+        match keyword.kind() {
+            "\\E" => Ok(QuantifierKind::Exists),
+            "\\A" => Ok(QuantifierKind::ForAll),
+            kind => Err(malformed(&keyword, format!("unexpected quantifier {}", kind))),
+        }
+    }
+
+    /// Reads an expression in condition position, comparisons and `!`
+    /// become conditions, anything else is kept as a boolean expression
+    fn visit_expr_condition(&self, node: Node, context: &mut ParseContext) -> Result<Condition> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            ts_const::ts_kind_name::S_EXPR => self.visit_expr_condition(child, context),
+            ts_const::ts_kind_name::S_BINARY_EXPR => {
+                let (lhs, op, rhs) = self.binary_operands(&child)?;
+                match compare_op(op.kind()) {
+                    Some(op) => {
+                        let lhs = self.visit_expr(lhs, context)?;
+                        let rhs = self.visit_expr(rhs, context)?;
+                        Ok(Condition::Compare { lhs, op, rhs })
+                    }
+                    None => Ok(Condition::Expr(self.visit_expr(node, context)?)),
+                }
+            }
+            ts_const::ts_kind_name::S_UNARY_EXPR if child.child(0).map(|c| c.kind()) == Some("!") => {
+                let operand = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_EXPR)?;
+                Ok(Condition::Not(Box::new(self.visit_expr_condition(operand, context)?)))
+            }
+            _ => Ok(Condition::Expr(self.visit_expr(node, context)?)),
+        }
+    }
+
+    // ---------------------------
+    // Expressions
+    // ---------------------------
+
+    fn visit_expr(&self, node: Node, context: &mut ParseContext) -> Result<Expr> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            // `( expr )`
+            ts_const::ts_kind_name::S_EXPR => self.visit_expr(child, context),
+            ts_const::ts_kind_name::S_BINARY_EXPR => {
+                let (lhs, op, rhs) = self.binary_operands(&child)?;
+                let op = binary_op(op.kind())
+                    .ok_or_else(|| malformed(&op, format!("unexpected operator {}", op.kind())))?;
+                let lhs = Box::new(self.visit_expr(lhs, context)?);
+                let rhs = Box::new(self.visit_expr(rhs, context)?);
+                Ok(Expr::Binary { lhs, op, rhs })
+            }
+            ts_const::ts_kind_name::S_UNARY_EXPR => {
+                let node_op = child.child(0)
+                    .ok_or_else(|| malformed(&child, "expected unary operator"))?;
+                let op = match node_op.kind() {
+                    "!" => UnaryOp::Not,
+                    "-" => UnaryOp::Neg,
+                    kind => return Err(malformed(&node_op, format!("unexpected operator {}", kind))),
+                };
+                let operand = self.get_child_of_kind(&child, ts_const::ts_kind_name::S_EXPR)?;
+                let expr = Box::new(self.visit_expr(operand, context)?);
+                Ok(Expr::Unary { op, expr })
+            }
+            ts_const::ts_kind_name::S_FIELD_ACCESS => {
+                let object = self.visit_name_field(&child, ts_const::ts_field_name::OBJECT, context)?;
+                let field = self.visit_name_field(&child, ts_const::ts_field_name::FIELD, context)?;
+                Ok(Expr::Field { base: Box::new(Expr::Var(object)), field })
+            }
+            ts_const::ts_kind_name::S_STRUCT_LITERAL => {
+                let mut fields = vec![];
+                for node_field in children_of_kind(&child, ts_const::ts_kind_name::S_STRUCT_FIELD) {
+                    let name = self.visit_name_field(&node_field, ts_const::ts_field_name::NAME, context)?;
+                    let node_value = self.get_named_field(&node_field, ts_const::ts_field_name::VALUE)?;
+                    fields.push((name, self.visit_expr(node_value, context)?));
+                }
+                Ok(Expr::Struct(fields))
+            }
+            ts_const::ts_kind_name::S_IDENTIFIER => Ok(Expr::Var(self.visit_name(child, context)?)),
+            ts_const::ts_kind_name::S_LITERAL => Ok(Expr::Literal(self.visit_literal(child, context)?)),
+            kind => Err(malformed(&child, format!("unexpected {} in expr", kind))),
+        }
+    }
+
+    /// Splits `lhs op rhs`, the operator is the only anonymous child
+    fn binary_operands<'a>(&self, node: &Node<'a>) -> Result<(Node<'a>, Node<'a>, Node<'a>)> {
+        match (node.child(0), node.child(1), node.child(2)) {
+            (Some(lhs), Some(op), Some(rhs)) => Ok((lhs, op, rhs)),
+            _ => Err(malformed(node, format!("expected two operands in {}", node.kind()))),
+        }
+    }
+
+    fn visit_literal(&self, node: Node, context: &mut ParseContext) -> Result<Literal> {
+        let Some(child) = node.child(0) else {
+            return Err(malformed(&node, "expected literal"));
+        };
+        let text = context.text_of_node(&child)?;
+        let invalid = |e: String| {
+            let d = Diagnostic::error(INVALID_LITERAL, format!("invalid number `{}`: {}", text, e))
+                .with_span(span_of_node(&child));
+            Error::new(d)
+        };
+        match child.kind() {
+            ts_const::ts_kind_name::S_NUMBER if text.contains('.') => {
+                text.parse::<f64>().map(Literal::Float).map_err(|e| invalid(e.to_string()))
+            }
+            ts_const::ts_kind_name::S_NUMBER => {
+                text.parse::<i64>().map(Literal::Int).map_err(|e| invalid(e.to_string()))
+            }
+            ts_const::ts_kind_name::S_STRING => Ok(Literal::String(self.visit_string(child, context)?)),
+            ts_const::ts_kind_name::S_BOOLEAN => Ok(Literal::Bool(text == "true")),
+            "null" => Ok(Literal::Null),
+            kind => Err(malformed(&child, format!("unexpected {} in literal", kind))),
+        }
+    }
+
+    /// Content of a string token without the quotes, escapes resolved
+    fn visit_string(&self, node: Node, context: &mut ParseContext) -> Result<String> {
+        let text = context.text_of_node(&node)?;
+        let inner = text.strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .ok_or_else(|| malformed(&node, "expected quoted string"))?;
+        let mut value = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                value.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some(c) => value.push(c),
+                None => {}
+            }
+        }
+        Ok(value)
+    }
+
+    // ---------------------------
+    // Types and names
+    // ---------------------------
+
+    fn visit_type(&self, node: Node, context: &mut ParseContext) -> Result<Type> {
+        let child = self.get_named_child(&node)?;
+        match child.kind() {
+            ts_const::ts_kind_name::S_PRIMITIVE_TYPE => {
+                let primitive = match context.text_of_node(&child)?.as_str() {
+                    "int" => PrimitiveType::Int,
+                    "bool" => PrimitiveType::Bool,
+                    "string" => PrimitiveType::String,
+                    "float" => PrimitiveType::Float,
+                    text => return Err(malformed(&child, format!("unknown primitive type {}", text))),
+                };
+                Ok(Type::Primitive(primitive))
+            }
+            ts_const::ts_kind_name::S_GENERIC_TYPE => {
+                let base = self.visit_name_field(&child, ts_const::ts_field_name::BASE, context)?;
+                let node_param = self.get_named_field(&child, ts_const::ts_field_name::PARAM)?;
+                let param = Box::new(self.visit_type(node_param, context)?);
+                Ok(Type::Generic { base, param })
+            }
+            ts_const::ts_kind_name::S_IDENTIFIER => Ok(Type::Named(self.visit_name(child, context)?)),
+            kind => Err(malformed(&child, format!("unexpected {} in type", kind))),
+        }
+    }
+
+    fn visit_name_field(&self, node: &Node, field_name: &str, context: &mut ParseContext) -> Result<Name> {
+        let child = self.get_named_field(node, field_name)?;
+        self.visit_name(child, context)
+    }
+
+    fn visit_name(&self, node: Node, context: &mut ParseContext) -> Result<Name> {
+        let text = context.text_of_node(&node)?;
+        Name::new(text)
+            .map(|name| name.with_span(span_of_node(&node)))
+            .map_err(|e| malformed(&node, e))
+    }
+
+    fn get_named_field<'a>(&self, node: &Node<'a>, field_name: &str) -> Result<Node<'a>> {
+        node.child_by_field_name(field_name)
+            .ok_or_else(|| malformed(node, format!("expected field {} in {}", field_name, node.kind())))
+    }
+
+    /// First named child, the nodes of choice rules have exactly one
+    fn get_named_child<'a>(&self, node: &Node<'a>) -> Result<Node<'a>> {
+        node.named_child(0)
+            .ok_or_else(|| malformed(node, format!("expected child in {}", node.kind())))
+    }
+
+    fn get_child_of_kind<'a>(&self, node: &Node<'a>, kind: &str) -> Result<Node<'a>> {
+        children_of_kind(node, kind)
+            .into_iter()
+            .next()
+            .ok_or_else(|| malformed(node, format!("expected {} in {}", kind, node.kind())))
+    }
+}
+
+fn children_of_kind<'a>(node: &Node<'a>, kind: &str) -> Vec<Node<'a>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|c| c.kind() == kind)
+        .collect()
+}
+
+fn malformed(node: &Node, message: impl Into<String>) -> Error {
+    let d = Diagnostic::error(MALFORMED_NODE, message.into()).with_span(span_of_node(node));
+    Error::new(d)
+}
+
+fn compare_op(token: &str) -> Option<CompareOp> {
+    match token {
+        "=" | "==" => Some(CompareOp::Eq),
+        "#" | "!=" => Some(CompareOp::Neq),
+        "<" => Some(CompareOp::Lt),
+        "<=" => Some(CompareOp::Le),
+        ">" => Some(CompareOp::Gt),
+        ">=" => Some(CompareOp::Ge),
+        _ => None,
+    }
+}
+
+fn binary_op(token: &str) -> Option<BinaryOp> {
+    match token {
+        "=" | "==" => Some(BinaryOp::Eq),
+        "#" | "!=" => Some(BinaryOp::Neq),
+        "<" => Some(BinaryOp::Lt),
+        "<=" => Some(BinaryOp::Le),
+        ">" => Some(BinaryOp::Gt),
+        ">=" => Some(BinaryOp::Ge),
+        "+" => Some(BinaryOp::Add),
+        "-" => Some(BinaryOp::Sub),
+        "*" => Some(BinaryOp::Mul),
+        "/" => Some(BinaryOp::Div),
+        _ => None,
+    }
+}
+
+pub fn parse_scl(source: &str) -> std::result::Result<Program, Vec<Diagnostic>> {
+    let mut parser = SCLParser::new().map_err(|d| vec![d])?;
+    parser.parse(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::condition::Condition;
+    use crate::ast::expr::Expr;
+    use crate::ast::literal::Literal;
+    use crate::ast::next::NextCase;
+    use crate::ast::quantifier::QuantifierKind;
+    use crate::ast::stmt::Stmt;
+    use crate::ast::use_stmt::UseStmt;
+    use crate::parser::scl_parser::{parse_scl, SCLParser, INVALID_LITERAL, MISSING_TOKEN, SYNTAX_ERROR};
+    use std::fs;
+
+    #[test]
+    fn test_parse_fixtures() {
+        let dir = format!("{}/test_data", env!("CARGO_MANIFEST_DIR"));
+        let mut parser = SCLParser::new().unwrap();
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "scl") {
+                let text = fs::read_to_string(&path).unwrap();
+                if let Err(diagnostics) = parser.parse(&text) {
+                    panic!("{}: {:?}", path.display(), diagnostics);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transfer() {
+        let program = parse_scl(include_str!("../../test_data/transfer.scl")).unwrap();
+        assert_eq!(program.types().len(), 1);
+        assert_eq!(program.contexts()[0].fields().len(), 3);
+        let states: Vec<_> = program.states().iter().map(|s| s.name().as_str()).collect();
+        assert_eq!(states, ["Check", "Debit", "Credit", "Reject", "Commit"]);
+
+        let check = &program.states()[0];
+        assert_eq!(check.contexts()[0].as_str(), "TransferCtx");
+        match &check.next().cases()[0] {
+            NextCase::When { condition, target, exports } => {
+                assert!(matches!(
+                    condition,
+                    Condition::Quantifier { kind: QuantifierKind::Exists, .. }
+                ));
+                assert_eq!(target.as_str(), "Debit");
+                assert_eq!(exports[0].0.as_str(), "summary");
+            }
+            case => panic!("unexpected {:?}", case),
+        }
+        assert!(matches!(&check.next().cases()[1], NextCase::Otherwise { .. }));
+
+        let debit = &program.states()[1];
+        match &debit.uses()[0] {
+            UseStmt::State { source, fields } => {
+                assert_eq!(source.name().as_str(), "Check");
+                assert_eq!(fields.len(), 1);
+            }
+            u => panic!("unexpected {:?}", u),
+        }
+        match &debit.body()[0] {
+            Stmt::Update { relation, assignments, where_clause } => {
+                assert_eq!(relation.as_str(), "Account");
+                assert_eq!(assignments[0].field().as_str(), "balance");
+                assert!(matches!(where_clause, Some(Condition::Compare { .. })));
+            }
+            s => panic!("unexpected {:?}", s),
+        }
+    }
+
+    #[test]
+    fn test_every_construct() {
+        let program = parse_scl(include_str!("../../test_data/inventory.scl")).unwrap();
+        let reserve = &program.states()[0];
+        assert_eq!(reserve.contexts().len(), 2);
+        assert_eq!(reserve.uses().len(), 2);
+        match &reserve.uses()[1] {
+            UseStmt::Type { ty } => assert_eq!(ty.path(), Some("catalog.scl")),
+            u => panic!("unexpected {:?}", u),
+        }
+        assert_eq!(reserve.preconditions().len(), 2);
+        match &reserve.preconditions()[1] {
+            Condition::Quantifier { kind, body, .. } => {
+                assert_eq!(*kind, QuantifierKind::ForAll);
+                assert!(matches!(**body, Condition::Implies(..)));
+            }
+            c => panic!("unexpected {:?}", c),
+        }
+
+        let body = reserve.body();
+        assert_eq!(body.len(), 5);
+        assert!(matches!(&body[1], Stmt::Select { limit: Some(10), .. }));
+        match &body[2] {
+            Stmt::Foreach { item, collection, body, .. } => {
+                assert_eq!(item.as_str(), "row");
+                assert_eq!(collection.as_str(), "rows");
+                assert!(matches!(&body[0], Stmt::Update { assignments, .. } if assignments.len() == 2));
+                match &body[1] {
+                    Stmt::Insert { columns, values, .. } => {
+                        assert_eq!(columns.len(), 3);
+                        assert_eq!(values.len(), 3);
+                    }
+                    s => panic!("unexpected {:?}", s),
+                }
+            }
+            s => panic!("unexpected {:?}", s),
+        }
+        assert!(matches!(&body[3], Stmt::Delete { where_clause: Some(_), .. }));
+        assert!(matches!(&body[4], Stmt::Assert { .. }));
+
+        let cases = reserve.next().cases();
+        assert_eq!(cases.len(), 3);
+        // `/\` binds tighter than `\/`
+        assert!(matches!(&cases[0], NextCase::When { condition: Condition::Or(..), .. }));
+        match &cases[1] {
+            NextCase::When { target, exports, .. } => {
+                assert_eq!(target.as_str(), "Backorder");
+                assert_eq!(exports.len(), 2);
+            }
+            c => panic!("unexpected {:?}", c),
+        }
+
+        let backorder = &program.states()[1];
+        assert!(matches!(
+            &backorder.body()[0],
+            Stmt::Insert { columns, values, .. } if columns.is_empty() && values.is_empty()
+        ));
+
+        let invariant = &program.invariants()[0];
+        assert_eq!(invariant.name().as_str(), "StockKept");
+        assert!(matches!(&invariant.conditions()[0], Condition::Quantifier { .. }));
+        assert!(matches!(&invariant.conditions()[1], Condition::Compare { .. }));
+    }
+
+    #[test]
+    fn test_literals() {
+        let text = r#"
+state S uses C {
+  let a: string = "say \"hi\"\n";
+  let b: float = 1.5;
+  let c: bool = true;
+  let d: int = null;
+  next { otherwise => S; }
+}
+"#;
+        let program = parse_scl(text).unwrap();
+        let values: Vec<_> = program.states()[0]
+            .body()
+            .iter()
+            .map(|s| match s {
+                Stmt::Let { value: crate::ast::expr::Expr::Literal(l), .. } => l.clone(),
+                s => panic!("unexpected {:?}", s),
+            })
+            .collect();
+        assert_eq!(values, [
+            Literal::String("say \"hi\"\n".to_string()),
+            Literal::Float(1.5),
+            Literal::Bool(true),
+            Literal::Null,
+        ]);
+    }
+
+    #[test]
+    fn test_float_numbers() {
+        let text = "state S uses C {\n  let a: float = 0.25;\n  let b: int = 3;\n  next { when C.x > 2.5 => S; }\n}\n";
+        let program = parse_scl(text).unwrap();
+        let body = program.states()[0].body();
+        assert!(matches!(&body[0], Stmt::Let { value: Expr::Literal(Literal::Float(f)), .. } if *f == 0.25));
+        assert!(matches!(&body[1], Stmt::Let { value: Expr::Literal(Literal::Int(3)), .. }));
+        match &program.states()[0].next().cases()[0] {
+            NextCase::When { condition, .. } => assert_eq!(condition.to_string(), "C.x > 2.5"),
+            c => panic!("unexpected {:?}", c),
+        }
+
+        // a float needs digits on both sides of the dot
+        let text = "state S uses C {\n  let a: float = 1.;\n  next { otherwise => S; }\n}\n";
+        assert_eq!(parse_scl(text).unwrap_err()[0].code(), SYNTAX_ERROR);
+    }
+
+    #[test]
+    fn test_guard_ends_at_arrow() {
+        let text = "state S uses C {\n  next { when ~(C.x > 1) \\/ C.y > 2 => T; }\n}\n";
+        let program = parse_scl(text).unwrap();
+        match &program.states()[0].next().cases()[0] {
+            NextCase::When { condition, target, .. } => {
+                assert!(matches!(condition, Condition::Or(..)));
+                assert_eq!(target.as_str(), "T");
+            }
+            c => panic!("unexpected {:?}", c),
+        }
+
+        // the first `=>` starts the target, a guard cannot hold an implication
+        let text = "state S uses C {\n  next { when C.x > 1 => C.y > 2 => T; }\n}\n";
+        assert_eq!(parse_scl(text).unwrap_err()[0].code(), SYNTAX_ERROR);
+    }
+
+    #[test]
+    fn test_next_case_semicolons() {
+        let with = "state S uses C {\n  next {\n    when C.x > 1 => A;\n    otherwise => B;\n  }\n}\n";
+        let without = "state S uses C {\n  next {\n    when C.x > 1 => A\n    otherwise => B\n  }\n}\n";
+        for text in [with, without] {
+            let program = parse_scl(text).unwrap();
+            let targets: Vec<_> = program.states()[0]
+                .next()
+                .cases()
+                .iter()
+                .map(|c| match c {
+                    NextCase::When { target, .. } | NextCase::Otherwise { target, .. } => target.as_str(),
+                })
+                .collect();
+            assert_eq!(targets, ["A", "B"]);
+        }
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let text = "context C {\n  x: int;\n}\n\nstate S uses C {\n  commit\n  next { otherwise => S; }\n}\n";
+        let diagnostics = parse_scl(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        let d = &diagnostics[0];
+        assert_eq!(d.code(), SYNTAX_ERROR);
+        // the missing `;` after commit shows up at the next token
+        let span = d.span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (7, 3));
+    }
+
+    #[test]
+    fn test_missing_token() {
+        let text = "type T {\n  x: int\n}\n";
+        let diagnostics = parse_scl(text).unwrap_err();
+        assert!(diagnostics.iter().any(|d| d.code() == MISSING_TOKEN && d.span().is_some()));
+    }
+
+    #[test]
+    fn test_invalid_literal() {
+        let text = "state S uses C {\n  let x: int = 99999999999999999999;\n  next { otherwise => S; }\n}\n";
+        let diagnostics = parse_scl(text).unwrap_err();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), INVALID_LITERAL);
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (2, 16));
+    }
+}
@@ -0,0 +1,934 @@
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::compare_op::CompareOp;
+use crate::ast::condition::Condition;
+use crate::ast::context_decl::ContextDecl;
+use crate::ast::expr::Expr;
+use crate::ast::invariant_decl::InvariantDecl;
+use crate::ast::literal::Literal;
+use crate::ast::name::Name;
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::state_decl::StateDecl;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::{PrimitiveType, Type};
+use crate::ast::type_decl::TypeDecl;
+use crate::ast::unary_op::UnaryOp;
+use crate::ast::use_stmt::UseStmt;
+use crate::sema::scope::Scope;
+use crate::sema::sem_type::SemType;
+use common::diag::diagnostic::Diagnostic;
+use common::diag::source_span::SourceSpan;
+use std::collections::{HashMap, HashSet};
+
+/// Diagnostic code: a type, context, state, invariant, field or local is
+/// declared twice
+pub const DUPLICATE_DECL: &str = "SCL0101";
+/// Diagnostic code: a state uses a context that is not declared
+pub const UNDEFINED_CONTEXT: &str = "SCL0102";
+/// Diagnostic code: a `next` target or `use state` source is not declared
+pub const UNDEFINED_STATE: &str = "SCL0103";
+/// Diagnostic code: `use type` names a type that is not declared
+pub const UNDEFINED_TYPE: &str = "SCL0104";
+/// Diagnostic code: an expression refers to a name that is not in scope
+pub const UNDEFINED_NAME: &str = "SCL0105";
+/// Diagnostic code: a context, type or relation has no such field
+pub const UNKNOWN_FIELD: &str = "SCL0106";
+/// Diagnostic code: a `use state` field is not exported on an incoming edge
+pub const MISSING_EXPORT: &str = "SCL0107";
+/// Diagnostic code: a value does not have the type its use requires
+pub const TYPE_MISMATCH: &str = "SCL0108";
+/// Diagnostic code: an insert lists a different number of columns and values
+pub const ARITY_MISMATCH: &str = "SCL0109";
+/// Diagnostic code: a declared context is read by a state that does not use it
+pub const CONTEXT_NOT_USED: &str = "SCL0110";
+
+/// An edge into a state, as seen from its target
+#[derive(Clone, Copy)]
+struct IncomingEdge<'a> {
+    source: &'a Name,
+    exports: &'a [(Name, Type)],
+}
+
+/// Resolves names and checks types of a parsed program
+///
+/// Relations are not declared in SCL. A relation whose name matches a
+/// declared `type` takes its columns from that type, any other relation
+/// and any undeclared named type is opaque and never reported.
+struct Checker<'a> {
+    types: HashMap<&'a str, &'a TypeDecl>,
+    contexts: HashMap<&'a str, &'a ContextDecl>,
+    states: HashMap<&'a str, &'a StateDecl>,
+    incoming: HashMap<&'a str, Vec<IncomingEdge<'a>>>,
+    /// contexts the state being checked may read
+    used_contexts: HashSet<&'a str>,
+    scope: Scope,
+    /// relation whose columns bare names refer to, inside `where`
+    /// clauses and `set` assignments
+    relation: Option<&'a Name>,
+    /// location reported for expressions without a name in them
+    anchor: Option<SourceSpan>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Checks a program and returns every problem found
+pub fn check_program(program: &Program) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        types: HashMap::new(),
+        contexts: HashMap::new(),
+        states: HashMap::new(),
+        incoming: HashMap::new(),
+        used_contexts: HashSet::new(),
+        scope: Scope::new(),
+        relation: None,
+        anchor: None,
+        diagnostics: vec![],
+    };
+    checker.check_program(program);
+    checker.diagnostics
+}
+
+impl<'a> Checker<'a> {
+    fn report(&mut self, code: &'static str, message: String, span: Option<&SourceSpan>) {
+        let mut d = Diagnostic::error(code, message);
+        if let Some(span) = span.or(self.anchor.as_ref()) {
+            d = d.with_span(*span);
+        }
+        self.diagnostics.push(d);
+    }
+
+    fn check_program(&mut self, program: &'a Program) {
+        for decl in program.types() {
+            if self.types.insert(decl.name().as_str(), decl).is_some() {
+                self.report_duplicate("type", decl.name());
+            }
+        }
+        for decl in program.contexts() {
+            if self.contexts.insert(decl.name().as_str(), decl).is_some() {
+                self.report_duplicate("context", decl.name());
+            }
+        }
+        for decl in program.states() {
+            if self.states.insert(decl.name().as_str(), decl).is_some() {
+                self.report_duplicate("state", decl.name());
+            }
+        }
+        for decl in program.types() {
+            self.check_fields(decl.fields());
+        }
+        for decl in program.contexts() {
+            self.check_fields(decl.fields());
+        }
+
+        for state in program.states() {
+            for case in state.next().cases() {
+                let (NextCase::When { target, exports, .. }
+                | NextCase::Otherwise { target, exports }) = case;
+                // staying in a state keeps what it was entered with
+                if target == state.name() {
+                    continue;
+                }
+                if self.states.contains_key(target.as_str()) {
+                    self.incoming
+                        .entry(target.as_str())
+                        .or_default()
+                        .push(IncomingEdge { source: state.name(), exports });
+                } else {
+                    self.report(
+                        UNDEFINED_STATE,
+                        format!("transition to undeclared state `{}`", target),
+                        target.span(),
+                    );
+                }
+            }
+        }
+
+        for state in program.states() {
+            self.check_state(state);
+        }
+
+        let mut invariants = HashSet::new();
+        for invariant in program.invariants() {
+            if !invariants.insert(invariant.name().as_str()) {
+                self.report_duplicate("invariant", invariant.name());
+            }
+            self.check_invariant(invariant);
+        }
+    }
+
+    fn report_duplicate(&mut self, what: &str, name: &Name) {
+        self.report(DUPLICATE_DECL, format!("{} `{}` is declared more than once", what, name), name.span());
+    }
+
+    /// Reports duplicate field names of a type, context or export block
+    fn check_fields(&mut self, fields: &[(Name, Type)]) {
+        let mut seen = HashSet::new();
+        for (name, _) in fields {
+            if !seen.insert(name.as_str()) {
+                self.report_duplicate("field", name);
+            }
+        }
+    }
+
+    fn check_state(&mut self, state: &'a StateDecl) {
+        self.scope = Scope::new();
+        self.used_contexts.clear();
+        self.anchor = state.name().span().copied();
+
+        for context in state.contexts() {
+            if self.contexts.contains_key(context.as_str()) {
+                self.used_contexts.insert(context.as_str());
+            } else {
+                self.report(
+                    UNDEFINED_CONTEXT,
+                    format!("state `{}` uses undeclared context `{}`", state.name(), context),
+                    context.span(),
+                );
+            }
+        }
+        for use_stmt in state.uses() {
+            self.check_use(state, use_stmt);
+        }
+
+        for condition in state.preconditions() {
+            self.check_condition(condition);
+        }
+        for stmt in state.body() {
+            self.check_stmt(stmt);
+        }
+
+        for case in state.next().cases() {
+            let (NextCase::When { target, exports, .. }
+            | NextCase::Otherwise { target, exports }) = case;
+            self.anchor = target.span().copied();
+            if let NextCase::When { condition, .. } = case {
+                self.check_condition(condition);
+            }
+            self.check_fields(exports);
+        }
+    }
+
+    /// An invariant is not tied to a state, it may read every context
+    fn check_invariant(&mut self, invariant: &'a InvariantDecl) {
+        self.scope = Scope::new();
+        self.used_contexts = self.contexts.keys().copied().collect();
+        self.anchor = invariant.name().span().copied();
+        for condition in invariant.conditions() {
+            self.check_condition(condition);
+        }
+    }
+
+    fn check_use(&mut self, state: &'a StateDecl, use_stmt: &'a UseStmt) {
+        match use_stmt {
+            UseStmt::Context { context } => {
+                // names qualified by a path come from another file
+                if context.path().is_some() {
+                    return;
+                }
+                let name = context.name();
+                if self.contexts.contains_key(name.as_str()) {
+                    self.used_contexts.insert(name.as_str());
+                } else {
+                    self.report(UNDEFINED_CONTEXT, format!("undeclared context `{}`", name), name.span());
+                }
+            }
+            UseStmt::Type { ty } => {
+                let name = ty.name();
+                if ty.path().is_none() && !self.types.contains_key(name.as_str()) {
+                    self.report(UNDEFINED_TYPE, format!("undeclared type `{}`", name), name.span());
+                }
+            }
+            UseStmt::State { source, fields } => {
+                self.check_fields(fields);
+                if source.path().is_none() {
+                    self.check_use_state(state, source.name(), fields);
+                }
+                for (name, ty) in fields {
+                    let ty = self.sem_type(ty);
+                    if !self.scope.bind(name.as_str(), ty) {
+                        self.report_duplicate("name", name);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every edge into `state` must export each field `use state`
+    /// imports, otherwise the field is unset when the state is entered
+    /// along that edge
+    fn check_use_state(&mut self, state: &'a StateDecl, source: &'a Name, fields: &'a [(Name, Type)]) {
+        if !self.states.contains_key(source.as_str()) {
+            self.report(UNDEFINED_STATE, format!("undeclared state `{}`", source), source.span());
+            return;
+        }
+        let edges = self.incoming.get(state.name().as_str()).cloned().unwrap_or_default();
+        if !edges.iter().any(|e| e.source == source) {
+            self.report(
+                MISSING_EXPORT,
+                format!("state `{}` has no transition to `{}`", source, state.name()),
+                source.span(),
+            );
+        }
+        for (name, ty) in fields {
+            let expected = self.sem_type(ty);
+            for edge in &edges {
+                match edge.exports.iter().find(|(n, _)| n == name) {
+                    None => {
+                        self.report(
+                            MISSING_EXPORT,
+                            format!("`{}` is not exported on the transition from `{}`", name, edge.source),
+                            name.span(),
+                        );
+                    }
+                    Some((_, exported)) => {
+                        let exported = self.sem_type(exported);
+                        if !self.assignable(&expected, &exported) {
+                            self.report(
+                                TYPE_MISMATCH,
+                                format!(
+                                    "`{}` is used as `{}` but exported as `{}` by `{}`",
+                                    name, expected, exported, edge.source
+                                ),
+                                name.span(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // ---------------------------
+    // Statements
+    // ---------------------------
+
+    fn check_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Let { name, ty, value } => {
+                self.anchor = name.span().copied();
+                let expected = self.sem_type(ty);
+                let actual = self.infer(value);
+                if !self.assignable(&expected, &actual) {
+                    self.report(
+                        TYPE_MISMATCH,
+                        format!("`{}` is declared `{}` but the value is `{}`", name, expected, actual),
+                        name.span(),
+                    );
+                }
+                self.bind(name, expected);
+            }
+            Stmt::Select { name, ty, relation, where_clause, .. } => {
+                self.anchor = name.span().copied();
+                let ty = self.sem_type(ty);
+                self.bind(name, ty);
+                self.check_where(relation, where_clause.as_ref());
+            }
+            Stmt::Foreach { item, item_ty, collection, body } => {
+                self.anchor = item.span().copied();
+                let expected = self.sem_type(item_ty);
+                match self.scope.lookup(collection.as_str()).cloned() {
+                    Some(SemType::Generic { param, .. }) => {
+                        if !self.assignable(&expected, &param) {
+                            self.report(
+                                TYPE_MISMATCH,
+                                format!("`{}` is declared `{}` but `{}` holds `{}`", item, expected, collection, param),
+                                item.span(),
+                            );
+                        }
+                    }
+                    Some(ty) if !ty.is_unknown() => {
+                        self.report(
+                            TYPE_MISMATCH,
+                            format!("cannot iterate over `{}` of type `{}`", collection, ty),
+                            collection.span(),
+                        );
+                    }
+                    Some(_) => {}
+                    None => {
+                        self.report(UNDEFINED_NAME, format!("unknown name `{}`", collection), collection.span());
+                    }
+                }
+                self.scope.push();
+                self.scope.bind(item.as_str(), expected);
+                for stmt in body {
+                    self.check_stmt(stmt);
+                }
+                self.scope.pop();
+            }
+            Stmt::Update { relation, assignments, where_clause } => {
+                self.anchor = relation.span().copied();
+                for assignment in assignments {
+                    let field = assignment.field();
+                    let column = self.column_type(relation, field);
+                    // `set balance = balance - 1` reads the old row
+                    self.relation = Some(relation);
+                    let actual = self.infer(assignment.value());
+                    self.relation = None;
+                    if !self.assignable(&column, &actual) {
+                        self.report(
+                            TYPE_MISMATCH,
+                            format!("column `{}` is `{}` but the value is `{}`", field, column, actual),
+                            field.span(),
+                        );
+                    }
+                }
+                self.check_where(relation, where_clause.as_ref());
+            }
+            Stmt::Insert { relation, columns, values } => {
+                self.anchor = relation.span().copied();
+                if columns.len() != values.len() {
+                    self.report(
+                        ARITY_MISMATCH,
+                        format!(
+                            "insert into `{}` lists {} columns but {} values",
+                            relation,
+                            columns.len(),
+                            values.len()
+                        ),
+                        relation.span(),
+                    );
+                }
+                for (column, value) in columns.iter().zip(values) {
+                    let expected = self.column_type(relation, column);
+                    let actual = self.infer(value);
+                    if !self.assignable(&expected, &actual) {
+                        self.report(
+                            TYPE_MISMATCH,
+                            format!("column `{}` is `{}` but the value is `{}`", column, expected, actual),
+                            column.span(),
+                        );
+                    }
+                }
+            }
+            Stmt::Delete { relation, where_clause } => {
+                self.anchor = relation.span().copied();
+                self.check_where(relation, where_clause.as_ref());
+            }
+            Stmt::Assert { condition } => {
+                self.check_condition(condition);
+            }
+            Stmt::Commit => {}
+        }
+    }
+
+    fn bind(&mut self, name: &Name, ty: SemType) {
+        if !self.scope.bind(name.as_str(), ty) {
+            self.report_duplicate("name", name);
+        }
+    }
+
+    fn check_where(&mut self, relation: &'a Name, where_clause: Option<&'a Condition>) {
+        if let Some(condition) = where_clause {
+            self.relation = Some(relation);
+            self.check_condition(condition);
+            self.relation = None;
+        }
+    }
+
+    /// Type of a relation column, unknown unless a `type` declares the
+    /// relation's row
+    fn column_type(&mut self, relation: &Name, column: &Name) -> SemType {
+        let Some(decl) = self.types.get(relation.as_str()).copied() else {
+            return SemType::Unknown;
+        };
+        match decl.fields().iter().find(|(n, _)| n == column) {
+            Some((_, ty)) => self.sem_type(ty),
+            None => {
+                self.report(
+                    UNKNOWN_FIELD,
+                    format!("relation `{}` has no column `{}`", relation, column),
+                    column.span(),
+                );
+                SemType::Unknown
+            }
+        }
+    }
+
+    // ---------------------------
+    // Conditions and expressions
+    // ---------------------------
+
+    fn check_condition(&mut self, condition: &'a Condition) {
+        match condition {
+            Condition::And(lhs, rhs) | Condition::Or(lhs, rhs) | Condition::Implies(lhs, rhs) => {
+                self.check_condition(lhs);
+                self.check_condition(rhs);
+            }
+            Condition::Not(c) => self.check_condition(c),
+            Condition::Compare { lhs, op, rhs } => {
+                let l = self.infer(lhs);
+                let r = self.infer(rhs);
+                let ordered = !matches!(op, CompareOp::Eq | CompareOp::Neq);
+                self.check_comparison(lhs, &l, &r, ordered);
+            }
+            Condition::Expr(expr) => {
+                let ty = self.infer(expr);
+                self.expect_bool(expr, &ty);
+            }
+            Condition::Quantifier { relation, var, pk_binding, body, .. } => {
+                self.scope.push();
+                let row = self.row_type(relation);
+                self.scope.bind(var.as_str(), row);
+                let ty = self.infer(pk_binding);
+                self.expect_bool(pk_binding, &ty);
+                self.check_condition(body);
+                self.scope.pop();
+            }
+        }
+    }
+
+    fn check_comparison(&mut self, at: &Expr, l: &SemType, r: &SemType, ordered: bool) {
+        let comparable = self.assignable(l, r) || self.assignable(r, l);
+        let orderable = l.is_unknown()
+            || r.is_unknown()
+            || (l.is_numeric() && r.is_numeric())
+            || (*l == SemType::String && *r == SemType::String);
+        if !comparable || (ordered && !orderable) {
+            self.report(TYPE_MISMATCH, format!("cannot compare `{}` with `{}`", l, r), first_span(at));
+        }
+    }
+
+    fn expect_bool(&mut self, at: &Expr, ty: &SemType) {
+        if !ty.is_unknown() && *ty != SemType::Bool {
+            self.report(TYPE_MISMATCH, format!("expected `bool`, found `{}`", ty), first_span(at));
+        }
+    }
+
+    fn row_type(&self, relation: &Name) -> SemType {
+        if self.types.contains_key(relation.as_str()) {
+            SemType::Record(relation.as_str().to_string())
+        } else {
+            SemType::Opaque(relation.as_str().to_string())
+        }
+    }
+
+    fn infer(&mut self, expr: &Expr) -> SemType {
+        match expr {
+            Expr::Literal(literal) => match literal {
+                Literal::Int(_) => SemType::Int,
+                Literal::Bool(_) => SemType::Bool,
+                Literal::String(_) => SemType::String,
+                Literal::Float(_) => SemType::Float,
+                Literal::Null => SemType::Null,
+            },
+            Expr::Struct(fields) => {
+                let mut seen = HashSet::new();
+                let mut types = vec![];
+                for (name, value) in fields {
+                    if !seen.insert(name.as_str()) {
+                        self.report_duplicate("field", name);
+                    }
+                    types.push((name.as_str().to_string(), self.infer(value)));
+                }
+                SemType::Struct(types)
+            }
+            Expr::Var(name) => self.resolve_var(name),
+            Expr::Field { base, field } => self.resolve_field(base, field),
+            Expr::Binary { lhs, op, rhs } => {
+                let l = self.infer(lhs);
+                let r = self.infer(rhs);
+                self.infer_binary(lhs, *op, &l, &r)
+            }
+            Expr::Unary { op, expr } => {
+                let ty = self.infer(expr);
+                match op {
+                    UnaryOp::Not => {
+                        self.expect_bool(expr, &ty);
+                        SemType::Bool
+                    }
+                    UnaryOp::Neg if ty.is_numeric() || ty.is_unknown() => ty,
+                    UnaryOp::Neg => {
+                        self.report(TYPE_MISMATCH, format!("cannot negate `{}`", ty), first_span(expr));
+                        SemType::Unknown
+                    }
+                }
+            }
+            Expr::Quantifier { relation, var, pk_binding, body, .. } => {
+                self.scope.push();
+                let row = self.row_type(relation);
+                self.scope.bind(var.as_str(), row);
+                let ty = self.infer(pk_binding);
+                self.expect_bool(pk_binding, &ty);
+                let ty = self.infer(body);
+                self.expect_bool(body, &ty);
+                self.scope.pop();
+                SemType::Bool
+            }
+        }
+    }
+
+    fn infer_binary(&mut self, at: &Expr, op: BinaryOp, l: &SemType, r: &SemType) -> SemType {
+        match op {
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div => {
+                if l.is_unknown() || r.is_unknown() {
+                    SemType::Unknown
+                } else if l.is_numeric() && r.is_numeric() {
+                    if *l == SemType::Int && *r == SemType::Int {
+                        SemType::Int
+                    } else {
+                        SemType::Float
+                    }
+                } else {
+                    self.report(
+                        TYPE_MISMATCH,
+                        format!("arithmetic on `{}` and `{}`", l, r),
+                        first_span(at),
+                    );
+                    SemType::Unknown
+                }
+            }
+            BinaryOp::Eq | BinaryOp::Neq => {
+                self.check_comparison(at, l, r, false);
+                SemType::Bool
+            }
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+                self.check_comparison(at, l, r, true);
+                SemType::Bool
+            }
+            BinaryOp::And | BinaryOp::Or | BinaryOp::Implies => {
+                self.expect_bool(at, l);
+                self.expect_bool(at, r);
+                SemType::Bool
+            }
+        }
+    }
+
+    fn resolve_var(&mut self, name: &Name) -> SemType {
+        if let Some(ty) = self.scope.lookup(name.as_str()) {
+            return ty.clone();
+        }
+        if let Some(relation) = self.relation {
+            return self.column_type(relation, name);
+        }
+        self.report(UNDEFINED_NAME, format!("unknown name `{}`", name), name.span());
+        SemType::Unknown
+    }
+
+    fn resolve_field(&mut self, base: &Expr, field: &Name) -> SemType {
+        // `Ctx.field` reads a context unless a local shadows the name
+        if let Expr::Var(name) = base
+            && self.scope.lookup(name.as_str()).is_none()
+            && let Some(decl) = self.contexts.get(name.as_str()).copied()
+        {
+            if !self.used_contexts.contains(name.as_str()) {
+                self.report(
+                    CONTEXT_NOT_USED,
+                    format!("context `{}` is read but not listed in `uses`", name),
+                    name.span(),
+                );
+            }
+            return match decl.fields().iter().find(|(n, _)| n == field) {
+                Some((_, ty)) => self.sem_type(ty),
+                None => {
+                    self.report(
+                        UNKNOWN_FIELD,
+                        format!("context `{}` has no field `{}`", name, field),
+                        field.span(),
+                    );
+                    SemType::Unknown
+                }
+            };
+        }
+        let base_ty = self.infer(base);
+        match &base_ty {
+            SemType::Record(type_name) => {
+                let decl = self.types[type_name.as_str()];
+                match decl.fields().iter().find(|(n, _)| n == field) {
+                    Some((_, ty)) => self.sem_type(ty),
+                    None => {
+                        self.report(
+                            UNKNOWN_FIELD,
+                            format!("type `{}` has no field `{}`", type_name, field),
+                            field.span(),
+                        );
+                        SemType::Unknown
+                    }
+                }
+            }
+            SemType::Struct(fields) => match fields.iter().find(|(n, _)| n == field.as_str()) {
+                Some((_, ty)) => ty.clone(),
+                None => {
+                    self.report(
+                        UNKNOWN_FIELD,
+                        format!("`{}` has no field `{}`", base_ty, field),
+                        field.span(),
+                    );
+                    SemType::Unknown
+                }
+            },
+            ty if ty.is_unknown() => SemType::Unknown,
+            ty => {
+                self.report(UNKNOWN_FIELD, format!("`{}` has no field `{}`", ty, field), field.span());
+                SemType::Unknown
+            }
+        }
+    }
+
+    // ---------------------------
+    // Types
+    // ---------------------------
+
+    fn sem_type(&self, ty: &Type) -> SemType {
+        match ty {
+            Type::Primitive(p) => match p {
+                PrimitiveType::Int => SemType::Int,
+                PrimitiveType::Bool => SemType::Bool,
+                PrimitiveType::String => SemType::String,
+                PrimitiveType::Float => SemType::Float,
+            },
+            Type::Named(name) => self.row_type(name),
+            Type::Generic { base, param } => SemType::Generic {
+                base: base.as_str().to_string(),
+                param: Box::new(self.sem_type(param)),
+            },
+        }
+    }
+
+    /// Whether a value of type `actual` can be stored where `expected`
+    /// is declared
+    fn assignable(&self, expected: &SemType, actual: &SemType) -> bool {
+        if expected.is_unknown() || actual.is_unknown() || *actual == SemType::Null {
+            return true;
+        }
+        match (expected, actual) {
+            (SemType::Float, SemType::Int) => true,
+            (SemType::Record(a), SemType::Record(b)) => a == b,
+            (SemType::Record(name), SemType::Struct(fields)) => {
+                let decl = self.types[name.as_str()];
+                decl.fields().len() == fields.len()
+                    && decl.fields().iter().all(|(n, ty)| {
+                        fields
+                            .iter()
+                            .find(|(f, _)| f == n.as_str())
+                            .is_some_and(|(_, f_ty)| self.assignable(&self.sem_type(ty), f_ty))
+                    })
+            }
+            (SemType::Struct(a), SemType::Struct(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(n, ty)| {
+                        b.iter().find(|(f, _)| f == n).is_some_and(|(_, f_ty)| self.assignable(ty, f_ty))
+                    })
+            }
+            (
+                SemType::Generic { base: a, param: pa },
+                SemType::Generic { base: b, param: pb },
+            ) => a == b && self.assignable(pa, pb),
+            (a, b) => a == b,
+        }
+    }
+}
+
+/// Location of the first name in an expression
+fn first_span(expr: &Expr) -> Option<&SourceSpan> {
+    match expr {
+        Expr::Literal(_) => None,
+        Expr::Struct(fields) => fields.first().and_then(|(name, _)| name.span()),
+        Expr::Var(name) => name.span(),
+        Expr::Field { base, field } => first_span(base).or(field.span()),
+        Expr::Binary { lhs, rhs, .. } => first_span(lhs).or_else(|| first_span(rhs)),
+        Expr::Unary { expr, .. } => first_span(expr),
+        Expr::Quantifier { relation, .. } => relation.span(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::scl_parser::parse_scl;
+    use crate::sema::checker::*;
+    use std::fs;
+
+    fn check(text: &str) -> Vec<Diagnostic> {
+        let program = parse_scl(text).unwrap();
+        check_program(&program)
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<&'static str> {
+        diagnostics.iter().map(|d| d.code()).collect()
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let dir = format!("{}/test_data", env!("CARGO_MANIFEST_DIR"));
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|e| e == "scl") {
+                let diagnostics = check(&fs::read_to_string(&path).unwrap());
+                assert!(diagnostics.is_empty(), "{}: {:?}", path.display(), diagnostics);
+            }
+        }
+    }
+
+    #[test]
+    fn test_name_resolution() {
+        let text = r#"
+context C { x: int; }
+context D { y: int; }
+
+state A uses C, Missing {
+  let a: int = C.z + D.y + nowhere;
+  next {
+    when C.x > 0 => Nowhere;
+    otherwise => A;
+  }
+}
+"#;
+        let diagnostics = check(text);
+        assert_eq!(codes(&diagnostics), [
+            UNDEFINED_STATE,
+            UNDEFINED_CONTEXT,
+            UNKNOWN_FIELD,
+            CONTEXT_NOT_USED,
+            UNDEFINED_NAME,
+        ]);
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (8, 21));
+        let span = diagnostics[1].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (5, 17));
+    }
+
+    #[test]
+    fn test_use_state_exports() {
+        let text = r#"
+type Summary { amount: int; }
+context C { x: int; }
+
+state A uses C {
+  next {
+    when C.x > 0 => Target { export s: Summary; n: int; }
+    otherwise => B;
+  }
+}
+
+state B uses C {
+  next { otherwise => Target { export s: int; } }
+}
+
+state Other uses C {
+  next { otherwise => Other; }
+}
+
+state Target uses C {
+  use state A {
+    s: Summary;
+  }
+  use state Other {
+    n: int;
+  }
+  let total: int = s.amount + n;
+  next { otherwise => Target; }
+}
+"#;
+        let diagnostics = check(text);
+        assert_eq!(codes(&diagnostics), [TYPE_MISMATCH, MISSING_EXPORT, MISSING_EXPORT]);
+        assert!(diagnostics[0].message().contains("exported as `int` by `B`"));
+        assert!(diagnostics[1].message().contains("`Other` has no transition to `Target`"));
+        assert!(diagnostics[2].message().contains("`n` is not exported on the transition from `B`"));
+        let span = diagnostics[2].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (25, 5));
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let text = r#"
+type Account { id: int; balance: int; owner: string; }
+type Pair { a: int; b: bool; }
+context C { x: int; name: string; }
+
+state A uses C {
+  let s: int = "s";
+  let f: float = C.x * 2;
+  let p: Pair = { a: 1, b: C.x };
+  let q: Pair = { a: 1, b: true };
+  update Account set balance = balance + 1, owner = 3 where id = C.x;
+  update Account set missing = 1;
+  assert C.name < 3;
+  assert C.x;
+  next { otherwise => A; }
+}
+"#;
+        let diagnostics = check(text);
+        assert_eq!(codes(&diagnostics), [
+            TYPE_MISMATCH,
+            TYPE_MISMATCH,
+            TYPE_MISMATCH,
+            UNKNOWN_FIELD,
+            TYPE_MISMATCH,
+            TYPE_MISMATCH,
+        ]);
+        assert_eq!(diagnostics[0].message(), "`s` is declared `int` but the value is `string`");
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (7, 7));
+        assert_eq!(diagnostics[2].message(), "column `owner` is `string` but the value is `int`");
+    }
+
+    #[test]
+    fn test_insert_arity() {
+        let text = r#"
+type Account { id: int; balance: int; }
+context C { x: int; }
+
+state A uses C {
+  insert into Account (id, balance) values (C.x);
+  insert into Account (id, balance) values (C.x, "b");
+  insert into Log (a) values (1);
+  next { otherwise => A; }
+}
+"#;
+        let diagnostics = check(text);
+        assert_eq!(codes(&diagnostics), [ARITY_MISMATCH, TYPE_MISMATCH]);
+        let span = diagnostics[0].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (6, 15));
+    }
+
+    #[test]
+    fn test_relation_columns() {
+        // a relation named like a type has its fields as columns
+        let text = r#"
+type Account { id: int; balance: int; }
+context C { x: int; }
+
+state A uses C {
+  insert into Account (id, owner) values (C.x, 1);
+  select a: Account from Account where iban = C.x;
+  delete from Account where id = C.x;
+  update Log set anything = 1 where whatever = C.x;
+  next {
+    when \E Relation Account r by r.id = C.x : r.limit > 0 => A;
+    otherwise => A;
+  }
+}
+"#;
+        let diagnostics = check(text);
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message().as_str()).collect();
+        assert_eq!(messages, [
+            "relation `Account` has no column `owner`",
+            "relation `Account` has no column `iban`",
+            "type `Account` has no field `limit`",
+        ]);
+        assert_eq!(codes(&diagnostics), [UNKNOWN_FIELD, UNKNOWN_FIELD, UNKNOWN_FIELD]);
+    }
+
+    #[test]
+    fn test_invariants() {
+        let text = r#"
+type Account { id: int; balance: int; }
+context C { x: int; }
+
+state A uses C {
+  next { otherwise => A; }
+}
+
+invariant Positive {
+  \A Relation Account a by a.id = C.x : a.balance >= 0;
+  C.y > 0;
+}
+
+invariant Positive {
+  local;
+}
+"#;
+        let diagnostics = check(text);
+        assert_eq!(codes(&diagnostics), [UNKNOWN_FIELD, DUPLICATE_DECL, UNDEFINED_NAME]);
+        let span = diagnostics[1].span().unwrap();
+        assert_eq!((span.start.line, span.start.column), (14, 11));
+    }
+}
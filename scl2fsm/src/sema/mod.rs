@@ -0,0 +1,3 @@
+pub mod sem_type;
+pub mod scope;
+pub mod checker;
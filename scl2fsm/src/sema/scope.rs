@@ -0,0 +1,33 @@
+// scope.rs
+use crate::sema::sem_type::SemType;
+use std::collections::HashMap;
+
+/// Nested bindings of local names, the innermost frame wins
+pub struct Scope {
+    frames: Vec<HashMap<String, SemType>>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self { frames: vec![HashMap::new()] }
+    }
+
+    pub fn push(&mut self) {
+        self.frames.push(HashMap::new());
+    }
+
+    pub fn pop(&mut self) {
+        self.frames.pop();
+    }
+
+    /// Binds `name` in the innermost frame, returns false if the frame
+    /// already binds it
+    pub fn bind(&mut self, name: &str, ty: SemType) -> bool {
+        let frame = self.frames.last_mut().expect("scope has no frame");
+        frame.insert(name.to_string(), ty).is_none()
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&SemType> {
+        self.frames.iter().rev().find_map(|frame| frame.get(name))
+    }
+}
@@ -0,0 +1,63 @@
+// sem_type.rs
+use std::fmt;
+
+/// Type of a value as seen by the checker
+#[derive(Clone, Debug, PartialEq)]
+pub enum SemType {
+    Int,
+    Float,
+    Bool,
+    String,
+    /// `null`, assignable to every type
+    Null,
+    /// A composite type declared with `type`
+    Record(String),
+    /// A named type without declaration, e.g. the key or row type of
+    /// a relation, nothing is known about its values
+    Opaque(String),
+    Generic {
+        base: String,
+        param: Box<SemType>,
+    },
+    /// Type of a struct literal
+    Struct(Vec<(String, SemType)>),
+    /// The type cannot be determined, an error was reported already
+    Unknown,
+}
+
+impl SemType {
+    /// Whether nothing is known about the type, such values are never
+    /// reported as mismatches
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, SemType::Unknown | SemType::Opaque(_))
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, SemType::Int | SemType::Float)
+    }
+}
+
+impl fmt::Display for SemType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SemType::Int => write!(f, "int"),
+            SemType::Float => write!(f, "float"),
+            SemType::Bool => write!(f, "bool"),
+            SemType::String => write!(f, "string"),
+            SemType::Null => write!(f, "null"),
+            SemType::Record(name) | SemType::Opaque(name) => write!(f, "{}", name),
+            SemType::Generic { base, param } => write!(f, "{}[{}]", base, param),
+            SemType::Struct(fields) => {
+                write!(f, "{{ ")?;
+                for (i, (name, ty)) in fields.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", name, ty)?;
+                }
+                write!(f, " }}")
+            }
+            SemType::Unknown => write!(f, "unknown"),
+        }
+    }
+}
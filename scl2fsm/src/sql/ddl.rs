@@ -0,0 +1,80 @@
+use crate::sql::dialect::{quote_ident, Dialect};
+use crate::sql::schema::RelationSchema;
+use std::fmt::Write;
+
+/// Writes a `CREATE TABLE` statement for every relation; key columns are
+/// `NOT NULL` and form the primary key. SQLite has no tables without
+/// columns, such a relation is left out.
+pub fn generate_ddl(schemas: &[RelationSchema], dialect: Dialect) -> String {
+    let mut out = format!("-- Auto-generated from an SCL program, {} dialect\n", dialect);
+    for schema in schemas {
+        let mut lines: Vec<_> = schema
+            .columns()
+            .iter()
+            .map(|c| {
+                let mut line = format!("{} {}", quote_ident(&c.name), dialect.type_name(c.ty));
+                if schema.key().contains(&c.name) {
+                    line.push_str(" NOT NULL");
+                }
+                line
+            })
+            .collect();
+        if !schema.key().is_empty() {
+            let key: Vec<_> = schema.key().iter().map(|c| quote_ident(c)).collect();
+            lines.push(format!("PRIMARY KEY ({})", key.join(", ")));
+        }
+        let table = quote_ident(schema.name());
+        if lines.is_empty() {
+            match dialect {
+                Dialect::Postgresql => {
+                    let _ = writeln!(out, "\nCREATE TABLE {} ();", table);
+                }
+                Dialect::Sqlite => {
+                    let _ = writeln!(out, "\n-- {} has no columns, SQLite cannot create it", table);
+                }
+            }
+            continue;
+        }
+        let _ = writeln!(out, "\nCREATE TABLE {} (", table);
+        for (i, line) in lines.iter().enumerate() {
+            let comma = if i + 1 < lines.len() { "," } else { "" };
+            let _ = writeln!(out, "    {}{}", line, comma);
+        }
+        out.push_str(");\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::scl_parser::parse_scl;
+    use crate::sql::schema::infer_schemas;
+
+    #[test]
+    fn test_generate_ddl() {
+        let program = parse_scl(include_str!("../../test_data/transfer.scl")).unwrap();
+        let (schemas, diagnostics) = infer_schemas(&program);
+        assert!(diagnostics.is_empty());
+        assert_eq!(
+            generate_ddl(&schemas, Dialect::Postgresql),
+            "-- Auto-generated from an SCL program, PostgreSQL dialect\n\
+             \n\
+             CREATE TABLE \"Account\" (\n    \
+                 \"id\" BIGINT NOT NULL,\n    \
+                 \"balance\" BIGINT,\n    \
+                 PRIMARY KEY (\"id\")\n\
+             );\n"
+        );
+
+        let program = parse_scl(include_str!("../../test_data/inventory.scl")).unwrap();
+        let (schemas, _) = infer_schemas(&program);
+        let ddl = generate_ddl(&schemas, Dialect::Sqlite);
+        assert!(ddl.contains("\n-- \"Orders\" has no columns, SQLite cannot create it\n"));
+        assert!(ddl.contains(
+            "CREATE TABLE \"Audit\" (\n    \"item_id\" INTEGER,\n    \"note\" TEXT,\n    \"amount\" REAL\n);\n"
+        ));
+        let ddl = generate_ddl(&schemas, Dialect::Postgresql);
+        assert!(ddl.contains("CREATE TABLE \"Orders\" ();\n"));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::sql::schema::ColumnType;
+use clap::ValueEnum;
+use std::fmt;
+
+/// SQL dialect of the generated queries
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// `$1` placeholders, `TRUE` and `FALSE`, records are `JSONB`
+    Postgresql,
+    /// `?1` placeholders, booleans are `1` and `0`, records are `TEXT`
+    Sqlite,
+}
+
+impl Dialect {
+    /// Placeholder of the `n`th bind parameter, counting from 1
+    pub fn placeholder(&self, n: usize) -> String {
+        match self {
+            Dialect::Postgresql => format!("${}", n),
+            Dialect::Sqlite => format!("?{}", n),
+        }
+    }
+
+    pub fn bool_literal(&self, b: bool) -> &'static str {
+        match (self, b) {
+            (Dialect::Postgresql, true) => "TRUE",
+            (Dialect::Postgresql, false) => "FALSE",
+            (Dialect::Sqlite, true) => "1",
+            (Dialect::Sqlite, false) => "0",
+        }
+    }
+
+    /// Name of a column type, a column of unknown type holds text
+    pub fn type_name(&self, ty: Option<ColumnType>) -> &'static str {
+        match (self, ty) {
+            (Dialect::Postgresql, Some(ColumnType::Int)) => "BIGINT",
+            (Dialect::Postgresql, Some(ColumnType::Bool)) => "BOOLEAN",
+            (Dialect::Postgresql, Some(ColumnType::Float)) => "DOUBLE PRECISION",
+            (Dialect::Postgresql, Some(ColumnType::Json)) => "JSONB",
+            (Dialect::Sqlite, Some(ColumnType::Int | ColumnType::Bool)) => "INTEGER",
+            (Dialect::Sqlite, Some(ColumnType::Float)) => "REAL",
+            (_, _) => "TEXT",
+        }
+    }
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Dialect::Postgresql => write!(f, "PostgreSQL"),
+            Dialect::Sqlite => write!(f, "SQLite"),
+        }
+    }
+}
+
+/// `name` as a quoted identifier, both dialects keep its case
+pub fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// `s` as a string literal
+pub fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
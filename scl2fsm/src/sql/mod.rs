@@ -0,0 +1,5 @@
+pub mod dialect;
+pub mod query;
+pub mod sql_gen;
+pub mod schema;
+pub mod ddl;
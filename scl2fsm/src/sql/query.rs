@@ -0,0 +1,117 @@
+use std::fmt;
+
+/// A value a query is run with, known to the caller and not to the database
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Param {
+    /// A field of a context, `Ctx.field`
+    Context { context: String, field: String },
+    /// A local of the state body, or a field of it: `r.id` is local `r`
+    /// with fields `["id"]`
+    Local { name: String, fields: Vec<String> },
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Param::Context { context, field } => write!(f, "{}.{}", context, field),
+            Param::Local { name, fields } => {
+                write!(f, "{}", name)?;
+                for field in fields {
+                    write!(f, ".{}", field)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// What a query returns and what the caller does with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryKind {
+    /// `let`: a single value, bound to the local
+    Value { into: String },
+    /// `select`: rows bound to the local, every row if `many`, otherwise
+    /// the first one or none
+    Rows { into: String, many: bool },
+    /// `update`, `insert` and `delete`
+    Write,
+    /// An `assert`, a precondition or the condition of a `next` case: a
+    /// single boolean
+    Check,
+}
+
+/// A parameterised SQL statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    kind: QueryKind,
+    sql: String,
+    /// `params[i]` is bound to placeholder `i + 1`
+    params: Vec<Param>,
+}
+
+impl Query {
+    pub fn new(kind: QueryKind, sql: String, params: Vec<Param>) -> Query {
+        Self { kind, sql, params }
+    }
+
+    pub fn kind(&self) -> &QueryKind {
+        &self.kind
+    }
+
+    pub fn sql(&self) -> &String {
+        &self.sql
+    }
+
+    pub fn params(&self) -> &Vec<Param> {
+        &self.params
+    }
+}
+
+/// A statement of a state body in SQL terms
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqlStmt {
+    Query(Query),
+    /// Runs `body` once for every row of the local `collection`, bound to
+    /// the local `item`
+    Foreach { item: String, collection: String, body: Vec<SqlStmt> },
+    /// Commits the transaction the state runs in
+    Commit,
+}
+
+/// The queries of one state, in the order the state runs them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateSql {
+    state: String,
+    preconditions: Vec<Query>,
+    body: Vec<SqlStmt>,
+    /// The target of every `next` case in order, with the query deciding
+    /// whether it is taken; `otherwise` has none
+    next: Vec<(String, Option<Query>)>,
+}
+
+impl StateSql {
+    pub fn new(
+        state: String,
+        preconditions: Vec<Query>,
+        body: Vec<SqlStmt>,
+        next: Vec<(String, Option<Query>)>,
+    ) -> StateSql {
+        Self { state, preconditions, body, next }
+    }
+
+    pub fn state(&self) -> &String {
+        &self.state
+    }
+
+    pub fn preconditions(&self) -> &Vec<Query> {
+        &self.preconditions
+    }
+
+    pub fn body(&self) -> &Vec<SqlStmt> {
+        &self.body
+    }
+
+    pub fn next(&self) -> &Vec<(String, Option<Query>)> {
+        &self.next
+    }
+}
@@ -0,0 +1,460 @@
+//! Schemas of the relations a program uses
+//!
+//! Relations are not declared in SCL. A relation whose name matches a
+//! declared `type` has the fields of that type as its columns, like the
+//! checker assumes; the columns of any other relation are the ones the
+//! program uses, in the order they first appear, with the type of the
+//! values written to or compared with them. The key of a relation is the
+//! column its quantifiers bind by, `\E Relation Account a by a.id = ...`
+//! makes `id` the key of `Account`.
+
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::condition::Condition;
+use crate::ast::expr::Expr;
+use crate::ast::literal::Literal;
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::state_decl::StateDecl;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::{PrimitiveType, Type};
+use crate::ast::unary_op::UnaryOp;
+use crate::ast::use_stmt::UseStmt;
+use common::diag::diagnostic::Diagnostic;
+use std::collections::{BTreeMap, HashMap};
+
+/// Diagnostic code: the quantifiers of a relation bind it by different
+/// columns, the first one is its key
+pub const CONFLICTING_KEYS: &str = "SCL0402";
+/// Diagnostic code: nothing tells the type of a column, it is stored as
+/// text
+pub const UNKNOWN_COLUMN_TYPE: &str = "SCL0403";
+
+/// Type of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Int,
+    Bool,
+    String,
+    Float,
+    /// A record or collection, stored as a document
+    Json,
+}
+
+/// A column of a relation, its type `None` if nothing tells it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub ty: Option<ColumnType>,
+}
+
+/// Columns and key of a relation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationSchema {
+    name: String,
+    columns: Vec<Column>,
+    /// columns of the primary key, none if no quantifier binds the
+    /// relation by a column
+    key: Vec<String>,
+    /// whether a `type` declares the columns
+    declared: bool,
+}
+
+impl RelationSchema {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn columns(&self) -> &Vec<Column> {
+        &self.columns
+    }
+
+    pub fn key(&self) -> &Vec<String> {
+        &self.key
+    }
+
+    pub fn is_declared(&self) -> bool {
+        self.declared
+    }
+}
+
+/// The schema of every relation a checked program uses, by name
+pub fn infer_schemas(program: &Program) -> (Vec<RelationSchema>, Vec<Diagnostic>) {
+    let mut inference = Inference {
+        program,
+        relations: BTreeMap::new(),
+        keys: HashMap::new(),
+        diagnostics: vec![],
+    };
+    for state in program.states() {
+        inference.state(state);
+    }
+    for invariant in program.invariants() {
+        for condition in invariant.conditions() {
+            inference.condition(condition, &Scope::default());
+        }
+    }
+
+    let Inference { relations, mut keys, mut diagnostics, .. } = inference;
+    let schemas = relations
+        .into_iter()
+        .map(|(name, columns)| {
+            let declared = program.types().iter().find(|t| t.name().as_str() == name);
+            let columns = match declared {
+                Some(decl) => decl
+                    .fields()
+                    .iter()
+                    .map(|(field, ty)| Column { name: field.as_str().to_string(), ty: column_type(program, ty) })
+                    .collect(),
+                None => columns,
+            };
+            for column in columns.iter().filter(|c| c.ty.is_none()) {
+                diagnostics.push(Diagnostic::warning(
+                    UNKNOWN_COLUMN_TYPE,
+                    format!("the type of `{}.{}` is not known, it is stored as text", name, column.name),
+                ));
+            }
+            let key = keys
+                .remove(&name)
+                .filter(|key| columns.iter().any(|c| c.name == *key))
+                .into_iter()
+                .collect();
+            RelationSchema { name, columns, key, declared: declared.is_some() }
+        })
+        .collect();
+    (schemas, diagnostics)
+}
+
+/// Column type of an SCL type; an undeclared named type is taken to be
+/// an integer key, like the model checker does
+fn column_type(program: &Program, ty: &Type) -> Option<ColumnType> {
+    match ty {
+        Type::Primitive(PrimitiveType::Int) => Some(ColumnType::Int),
+        Type::Primitive(PrimitiveType::Bool) => Some(ColumnType::Bool),
+        Type::Primitive(PrimitiveType::String) => Some(ColumnType::String),
+        Type::Primitive(PrimitiveType::Float) => Some(ColumnType::Float),
+        Type::Named(name) if !program.types().iter().any(|t| t.name() == name) => Some(ColumnType::Int),
+        Type::Named(_) | Type::Generic { .. } => Some(ColumnType::Json),
+    }
+}
+
+/// Names visible to an expression
+#[derive(Clone, Default)]
+struct Scope<'a> {
+    /// locals with their declared types
+    locals: HashMap<&'a str, &'a Type>,
+    /// quantifier variables with the relation they range over
+    rows: HashMap<&'a str, &'a str>,
+    /// the relation bare column names refer to
+    relation: Option<&'a str>,
+}
+
+struct Inference<'a> {
+    program: &'a Program,
+    /// relation -> the columns used, in order of use
+    relations: BTreeMap<String, Vec<Column>>,
+    /// relation -> the column its quantifiers bind by
+    keys: HashMap<String, String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Inference<'a> {
+    fn state(&mut self, state: &'a StateDecl) {
+        let mut scope = Scope::default();
+        for u in state.uses() {
+            if let UseStmt::State { fields, .. } = u {
+                scope.locals.extend(fields.iter().map(|(name, ty)| (name.as_str(), ty)));
+            }
+        }
+        for condition in state.preconditions() {
+            self.condition(condition, &scope);
+        }
+        self.body(state.body(), &mut scope);
+        for case in state.next().cases() {
+            if let NextCase::When { condition, .. } = case {
+                self.condition(condition, &scope);
+            }
+        }
+    }
+
+    fn body(&mut self, body: &'a [Stmt], scope: &mut Scope<'a>) {
+        for stmt in body {
+            match stmt {
+                Stmt::Let { name, ty, value } => {
+                    self.expr(value, scope);
+                    scope.locals.insert(name.as_str(), ty);
+                }
+                Stmt::Select { name, ty, relation, where_clause, .. } => {
+                    self.relation(relation.as_str());
+                    self.where_clause(relation.as_str(), where_clause.as_ref(), scope);
+                    scope.locals.insert(name.as_str(), ty);
+                }
+                Stmt::Foreach { item, item_ty, body, .. } => {
+                    let mut inner = scope.clone();
+                    inner.locals.insert(item.as_str(), item_ty);
+                    self.body(body, &mut inner);
+                }
+                Stmt::Update { relation, assignments, where_clause } => {
+                    let relation = relation.as_str();
+                    self.relation(relation);
+                    let inner = Scope { relation: Some(relation), ..scope.clone() };
+                    for a in assignments {
+                        let ty = self.expr(a.value(), &inner);
+                        self.column(relation, a.field().as_str(), ty);
+                    }
+                    self.where_clause(relation, where_clause.as_ref(), scope);
+                }
+                Stmt::Insert { relation, columns, values } => {
+                    self.relation(relation.as_str());
+                    for (column, value) in columns.iter().zip(values) {
+                        let ty = self.expr(value, scope);
+                        self.column(relation.as_str(), column.as_str(), ty);
+                    }
+                }
+                Stmt::Delete { relation, where_clause } => {
+                    self.relation(relation.as_str());
+                    self.where_clause(relation.as_str(), where_clause.as_ref(), scope);
+                }
+                Stmt::Assert { condition } => self.condition(condition, scope),
+                Stmt::Commit => {}
+            }
+        }
+    }
+
+    fn where_clause(&mut self, relation: &'a str, condition: Option<&'a Condition>, scope: &Scope<'a>) {
+        if let Some(condition) = condition {
+            let inner = Scope { relation: Some(relation), ..scope.clone() };
+            self.condition(condition, &inner);
+        }
+    }
+
+    fn relation(&mut self, relation: &str) {
+        self.relations.entry(relation.to_string()).or_default();
+    }
+
+    /// Records that `relation` has `column`, of type `ty` if nothing told
+    /// its type before
+    fn column(&mut self, relation: &str, column: &str, ty: Option<ColumnType>) {
+        let columns = self.relations.entry(relation.to_string()).or_default();
+        match columns.iter_mut().find(|c| c.name == column) {
+            Some(c) => c.ty = c.ty.or(ty),
+            None => columns.push(Column { name: column.to_string(), ty }),
+        }
+    }
+
+    fn condition(&mut self, condition: &'a Condition, scope: &Scope<'a>) {
+        match condition {
+            Condition::And(lhs, rhs) | Condition::Or(lhs, rhs) | Condition::Implies(lhs, rhs) => {
+                self.condition(lhs, scope);
+                self.condition(rhs, scope);
+            }
+            Condition::Not(c) => self.condition(c, scope),
+            Condition::Compare { lhs, rhs, .. } => self.compare(lhs, rhs, scope),
+            Condition::Expr(expr) => {
+                if let Some((relation, column)) = self.column_ref(expr, scope) {
+                    self.column(relation, column, Some(ColumnType::Bool));
+                }
+                self.expr(expr, scope);
+            }
+            Condition::Quantifier { relation, var, pk_binding, body, .. } => {
+                let inner = self.bind_row(scope, relation.as_str(), var.as_str());
+                self.key(relation.as_str(), var.as_str(), pk_binding);
+                self.expr(pk_binding, &inner);
+                self.condition(body, &inner);
+            }
+        }
+    }
+
+    /// A column compared with a value has the value's type
+    fn compare(&mut self, lhs: &'a Expr, rhs: &'a Expr, scope: &Scope<'a>) {
+        let l = self.expr(lhs, scope);
+        let r = self.expr(rhs, scope);
+        self.compared(lhs, r, scope);
+        self.compared(rhs, l, scope);
+    }
+
+    fn compared(&mut self, expr: &'a Expr, other: Option<ColumnType>, scope: &Scope<'a>) {
+        if let Some((relation, column)) = self.column_ref(expr, scope) {
+            self.column(relation, column, other);
+        }
+    }
+
+    /// The relation and column `expr` reads, if it is a column
+    fn column_ref(&self, expr: &'a Expr, scope: &Scope<'a>) -> Option<(&'a str, &'a str)> {
+        match expr {
+            Expr::Var(name) if !scope.locals.contains_key(name.as_str()) => {
+                scope.relation.map(|relation| (relation, name.as_str()))
+            }
+            Expr::Field { base, field } => match base.as_ref() {
+                Expr::Var(var) => scope.rows.get(var.as_str()).map(|relation| (*relation, field.as_str())),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `var.column = value` or `value = var.column` binds by `column`
+    fn key(&mut self, relation: &str, var: &str, pk_binding: &Expr) {
+        let Expr::Binary { lhs, op: BinaryOp::Eq, rhs } = pk_binding else {
+            return;
+        };
+        let column = [lhs, rhs].into_iter().find_map(|side| match side.as_ref() {
+            Expr::Field { base, field } if matches!(base.as_ref(), Expr::Var(v) if v.as_str() == var) => {
+                Some(field.as_str())
+            }
+            _ => None,
+        });
+        let Some(column) = column else {
+            return;
+        };
+        let key = self.keys.entry(relation.to_string()).or_insert_with(|| column.to_string());
+        if key != column {
+            let message = format!("`{}` is bound by `{}` and by `{}`, `{}` is its key", relation, key, column, key);
+            self.diagnostics.push(Diagnostic::warning(CONFLICTING_KEYS, message));
+        }
+    }
+
+    fn bind_row(&mut self, scope: &Scope<'a>, relation: &'a str, var: &'a str) -> Scope<'a> {
+        self.relation(relation);
+        let mut inner = scope.clone();
+        inner.locals.remove(var);
+        inner.rows.insert(var, relation);
+        inner
+    }
+
+    /// Type of an expression, recording the columns it reads
+    fn expr(&mut self, expr: &'a Expr, scope: &Scope<'a>) -> Option<ColumnType> {
+        if let Some((relation, column)) = self.column_ref(expr, scope) {
+            self.column(relation, column, None);
+            let columns = &self.relations[relation];
+            return columns.iter().find(|c| c.name == column).and_then(|c| c.ty);
+        }
+        match expr {
+            Expr::Literal(literal) => match literal {
+                Literal::Int(_) => Some(ColumnType::Int),
+                Literal::Bool(_) => Some(ColumnType::Bool),
+                Literal::String(_) => Some(ColumnType::String),
+                Literal::Float(_) => Some(ColumnType::Float),
+                Literal::Null => None,
+            },
+            Expr::Struct(fields) => {
+                for (_, value) in fields {
+                    self.expr(value, scope);
+                }
+                Some(ColumnType::Json)
+            }
+            Expr::Var(name) => scope.locals.get(name.as_str()).and_then(|ty| column_type(self.program, ty)),
+            Expr::Field { base, field } => {
+                // `Ctx.field` reads a context unless a local shadows it
+                let Expr::Var(name) = base.as_ref() else {
+                    self.expr(base, scope);
+                    return None;
+                };
+                let fields = match scope.locals.get(name.as_str()) {
+                    Some(Type::Named(ty)) => {
+                        self.program.types().iter().find(|t| t.name() == ty).map(|t| t.fields())
+                    }
+                    Some(_) => None,
+                    None => self.program.contexts().iter().find(|c| c.name() == name).map(|c| c.fields()),
+                };
+                let (_, ty) = fields?.iter().find(|(n, _)| n == field)?;
+                column_type(self.program, ty)
+            }
+            Expr::Binary { lhs, op, rhs } => {
+                let l = self.expr(lhs, scope);
+                let r = self.expr(rhs, scope);
+                match op {
+                    BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div => {
+                        if l == Some(ColumnType::Float) || r == Some(ColumnType::Float) {
+                            Some(ColumnType::Float)
+                        } else {
+                            l.or(r)
+                        }
+                    }
+                    BinaryOp::And | BinaryOp::Or | BinaryOp::Implies => Some(ColumnType::Bool),
+                    _ => {
+                        self.compared(lhs, r, scope);
+                        self.compared(rhs, l, scope);
+                        Some(ColumnType::Bool)
+                    }
+                }
+            }
+            Expr::Unary { op, expr } => {
+                let ty = self.expr(expr, scope);
+                match op {
+                    UnaryOp::Not => Some(ColumnType::Bool),
+                    UnaryOp::Neg => ty,
+                }
+            }
+            Expr::Quantifier { relation, var, pk_binding, body, .. } => {
+                let inner = self.bind_row(scope, relation.as_str(), var.as_str());
+                self.key(relation.as_str(), var.as_str(), pk_binding);
+                self.expr(pk_binding, &inner);
+                self.expr(body, &inner);
+                Some(ColumnType::Bool)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::scl_parser::parse_scl;
+
+    fn column(name: &str, ty: Option<ColumnType>) -> Column {
+        Column { name: name.to_string(), ty }
+    }
+
+    #[test]
+    fn test_infer_schemas() {
+        let program = parse_scl(include_str!("../../test_data/inventory.scl")).unwrap();
+        let (schemas, diagnostics) = infer_schemas(&program);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+        let names: Vec<_> = schemas.iter().map(|s| s.name().as_str()).collect();
+        assert_eq!(names, ["Audit", "Orders", "Pending", "Stock"]);
+        let stock = &schemas[3];
+        assert_eq!(stock.columns(), &vec![
+            column("item_id", Some(ColumnType::Int)),
+            column("count", Some(ColumnType::Int)),
+            column("touched", Some(ColumnType::Bool)),
+        ]);
+        assert_eq!(stock.key(), &vec!["item_id".to_string()]);
+        assert!(schemas[1].columns().is_empty());
+
+        let text = r#"
+type Account { id: int; owner: Owner; tags: List[string]; }
+type Owner { name: string; }
+context C { id: int; }
+
+state A uses C {
+  update Ledger set note = null where id = C.id;
+  next {
+    when \E Relation Ledger l by l.id = C.id : true => A;
+    when \E Relation Ledger l by C.id = l.ref : true => A;
+    when \E Relation Account a by a.id = C.id : a.owner = null => A;
+    otherwise => A;
+  }
+}
+"#;
+        let (schemas, diagnostics) = infer_schemas(&parse_scl(text).unwrap());
+        let messages: Vec<_> = diagnostics.iter().map(|d| (d.code(), d.message().as_str())).collect();
+        assert_eq!(messages, [
+            (CONFLICTING_KEYS, "`Ledger` is bound by `id` and by `ref`, `id` is its key"),
+            (UNKNOWN_COLUMN_TYPE, "the type of `Ledger.note` is not known, it is stored as text"),
+        ]);
+        // the columns of a declared relation are the fields of its type
+        let account = &schemas[0];
+        assert!(account.is_declared());
+        assert_eq!(account.columns(), &vec![
+            column("id", Some(ColumnType::Int)),
+            column("owner", Some(ColumnType::Json)),
+            column("tags", Some(ColumnType::Json)),
+        ]);
+        assert_eq!(account.key(), &vec!["id".to_string()]);
+        assert_eq!(schemas[1].columns(), &vec![
+            column("note", None),
+            column("id", Some(ColumnType::Int)),
+            column("ref", Some(ColumnType::Int)),
+        ]);
+    }
+}
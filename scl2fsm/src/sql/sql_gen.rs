@@ -0,0 +1,717 @@
+use crate::ast::binary_op::BinaryOp;
+use crate::ast::compare_op::CompareOp;
+use crate::ast::condition::Condition;
+use crate::ast::expr::Expr;
+use crate::ast::literal::Literal;
+use crate::ast::next::NextCase;
+use crate::ast::program::Program;
+use crate::ast::quantifier::QuantifierKind;
+use crate::ast::state_decl::StateDecl;
+use crate::ast::stmt::Stmt;
+use crate::ast::ty::Type;
+use crate::ast::unary_op::UnaryOp;
+use crate::ast::use_stmt::UseStmt;
+use crate::sql::dialect::{quote_ident, quote_string, Dialect};
+use crate::sql::query::{Param, Query, QueryKind, SqlStmt, StateSql};
+use common::diag::diagnostic::Diagnostic;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Diagnostic code: a value has no SQL counterpart, `NULL` is written in
+/// its place
+pub const UNSUPPORTED_IN_SQL: &str = "SCL0401";
+
+/// Translates the states of a checked program into parameterised queries
+///
+/// Context fields and the locals of a body are bind parameters, filled in
+/// by the caller; the caller also binds what a `let` or `select` returns
+/// to its local for the queries after it. Bare names in a `where` clause
+/// or `set` assignment are columns of the row looked at, like in SCL.
+/// `\E` becomes an `EXISTS` subquery and `\A` a `NOT EXISTS` subquery for
+/// a row breaking the body. Asserts, preconditions and the conditions of
+/// the `next` cases are queries returning one boolean. Comparisons follow
+/// SQL's three-valued logic, except that comparing with the literal
+/// `null` is `IS NULL` or `IS NOT NULL`.
+pub fn generate_sql(program: &Program, dialect: Dialect) -> (Vec<StateSql>, Vec<Diagnostic>) {
+    let mut generator = SqlGen {
+        dialect,
+        contexts: program.contexts().iter().map(|c| c.name().as_str().to_string()).collect(),
+        diagnostics: vec![],
+    };
+    let states = program.states().iter().map(|s| generator.state(s)).collect();
+    (states, generator.diagnostics)
+}
+
+/// Writes the queries of every state as one SQL script, the parameters
+/// of each query are listed in a comment above it
+pub fn render_sql(states: &[StateSql], dialect: Dialect) -> String {
+    let mut out = format!("-- Auto-generated from an SCL program, {} dialect\n", dialect);
+    for state in states {
+        let _ = writeln!(out, "\n-- state {}", state.state());
+        for query in state.preconditions() {
+            render_query(&mut out, "precondition", query, dialect, "");
+        }
+        render_body(&mut out, state.body(), dialect, "");
+        for (target, query) in state.next() {
+            match query {
+                Some(query) => render_query(&mut out, &format!("next {} when", target), query, dialect, ""),
+                None => {
+                    let _ = writeln!(out, "-- next {} otherwise", target);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn render_body(out: &mut String, body: &[SqlStmt], dialect: Dialect, indent: &str) {
+    for stmt in body {
+        match stmt {
+            SqlStmt::Query(query) => {
+                let what = match query.kind() {
+                    QueryKind::Value { into } => format!("let {}", into),
+                    QueryKind::Rows { into, .. } => format!("select {}", into),
+                    QueryKind::Write => query.sql().split(' ').next().unwrap_or_default().to_lowercase(),
+                    QueryKind::Check => "assert".to_string(),
+                };
+                render_query(out, &what, query, dialect, indent);
+            }
+            SqlStmt::Foreach { item, collection, body } => {
+                let _ = writeln!(out, "{}-- foreach {} in {}", indent, item, collection);
+                render_body(out, body, dialect, &format!("{}  ", indent));
+                let _ = writeln!(out, "{}-- end foreach", indent);
+            }
+            SqlStmt::Commit => {
+                let _ = writeln!(out, "{}-- commit", indent);
+            }
+        }
+    }
+}
+
+fn render_query(out: &mut String, what: &str, query: &Query, dialect: Dialect, indent: &str) {
+    let params: Vec<_> = query
+        .params()
+        .iter()
+        .enumerate()
+        .map(|(i, p)| format!("{} = {}", dialect.placeholder(i + 1), p))
+        .collect();
+    if params.is_empty() {
+        let _ = writeln!(out, "{}-- {}", indent, what);
+    } else {
+        let _ = writeln!(out, "{}-- {}: {}", indent, what, params.join(", "));
+    }
+    let _ = writeln!(out, "{}{};", indent, query.sql());
+}
+
+/// Names visible to an expression
+#[derive(Clone, Default)]
+struct Scope {
+    /// locals of the body, they are parameters
+    locals: HashSet<String>,
+    /// quantifier variables, each the alias of a table in a subquery
+    aliases: HashSet<String>,
+    /// the table bare column names refer to
+    table: Option<String>,
+}
+
+/// The parameters of the query being built
+#[derive(Default)]
+struct Params(Vec<Param>);
+
+impl Params {
+    /// Number of the placeholder `param` is bound to
+    fn bind(&mut self, param: Param) -> usize {
+        match self.0.iter().position(|p| *p == param) {
+            Some(i) => i + 1,
+            None => {
+                self.0.push(param);
+                self.0.len()
+            }
+        }
+    }
+}
+
+struct SqlGen {
+    dialect: Dialect,
+    contexts: HashSet<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl SqlGen {
+    fn state(&mut self, state: &StateDecl) -> StateSql {
+        let mut scope = Scope::default();
+        for u in state.uses() {
+            if let UseStmt::State { fields, .. } = u {
+                scope.locals.extend(fields.iter().map(|(name, _)| name.as_str().to_string()));
+            }
+        }
+        let preconditions = state.preconditions().iter().map(|c| self.check(c, &scope)).collect();
+        let body = self.body(state.body(), &mut scope);
+        let next = state
+            .next()
+            .cases()
+            .iter()
+            .map(|case| match case {
+                NextCase::When { condition, target, .. } => {
+                    (target.as_str().to_string(), Some(self.check(condition, &scope)))
+                }
+                NextCase::Otherwise { target, .. } => (target.as_str().to_string(), None),
+            })
+            .collect();
+        StateSql::new(state.name().as_str().to_string(), preconditions, body, next)
+    }
+
+    fn body(&mut self, body: &[Stmt], scope: &mut Scope) -> Vec<SqlStmt> {
+        let mut stmts = vec![];
+        for stmt in body {
+            let mut params = Params::default();
+            let (kind, sql) = match stmt {
+                Stmt::Let { name, value, .. } => {
+                    let sql = format!("SELECT {}", self.expr(value, scope, &mut params));
+                    scope.locals.insert(name.as_str().to_string());
+                    (QueryKind::Value { into: name.as_str().to_string() }, sql)
+                }
+                Stmt::Select { name, ty, relation, where_clause, limit } => {
+                    let table = relation.as_str();
+                    let mut sql = format!("SELECT * FROM {}", quote_ident(table));
+                    self.where_clause(&mut sql, where_clause.as_ref(), table, scope, &mut params);
+                    // a single row is the first one
+                    let many = matches!(ty, Type::Generic { .. });
+                    let limit = if many { *limit } else { Some(limit.unwrap_or(1).min(1)) };
+                    if let Some(n) = limit {
+                        let _ = write!(sql, " LIMIT {}", n);
+                    }
+                    scope.locals.insert(name.as_str().to_string());
+                    (QueryKind::Rows { into: name.as_str().to_string(), many }, sql)
+                }
+                Stmt::Foreach { item, collection, body, .. } => {
+                    let mut inner = scope.clone();
+                    inner.locals.insert(item.as_str().to_string());
+                    stmts.push(SqlStmt::Foreach {
+                        item: item.as_str().to_string(),
+                        collection: collection.as_str().to_string(),
+                        body: self.body(body, &mut inner),
+                    });
+                    continue;
+                }
+                Stmt::Update { relation, assignments, where_clause } => {
+                    let table = relation.as_str();
+                    let inner = Scope { table: Some(table.to_string()), ..scope.clone() };
+                    let set: Vec<_> = assignments
+                        .iter()
+                        .map(|a| {
+                            let value = self.expr(a.value(), &inner, &mut params);
+                            format!("{} = {}", quote_ident(a.field().as_str()), value)
+                        })
+                        .collect();
+                    let mut sql = format!("UPDATE {} SET {}", quote_ident(table), set.join(", "));
+                    self.where_clause(&mut sql, where_clause.as_ref(), table, scope, &mut params);
+                    (QueryKind::Write, sql)
+                }
+                Stmt::Insert { relation, columns, values } => {
+                    let table = quote_ident(relation.as_str());
+                    let sql = if columns.is_empty() {
+                        format!("INSERT INTO {} DEFAULT VALUES", table)
+                    } else {
+                        let columns: Vec<_> = columns.iter().map(|c| quote_ident(c.as_str())).collect();
+                        let values: Vec<_> = values.iter().map(|v| self.expr(v, scope, &mut params)).collect();
+                        format!("INSERT INTO {} ({}) VALUES ({})", table, columns.join(", "), values.join(", "))
+                    };
+                    (QueryKind::Write, sql)
+                }
+                Stmt::Delete { relation, where_clause } => {
+                    let table = relation.as_str();
+                    let mut sql = format!("DELETE FROM {}", quote_ident(table));
+                    self.where_clause(&mut sql, where_clause.as_ref(), table, scope, &mut params);
+                    (QueryKind::Write, sql)
+                }
+                Stmt::Assert { condition } => {
+                    stmts.push(SqlStmt::Query(self.check(condition, scope)));
+                    continue;
+                }
+                Stmt::Commit => {
+                    stmts.push(SqlStmt::Commit);
+                    continue;
+                }
+            };
+            stmts.push(SqlStmt::Query(Query::new(kind, sql, params.0)));
+        }
+        stmts
+    }
+
+    /// A query returning whether `condition` holds
+    fn check(&mut self, condition: &Condition, scope: &Scope) -> Query {
+        let mut params = Params::default();
+        let sql = format!("SELECT {}", self.condition(condition, scope, &mut params));
+        Query::new(QueryKind::Check, sql, params.0)
+    }
+
+    fn where_clause(
+        &mut self,
+        sql: &mut String,
+        condition: Option<&Condition>,
+        table: &str,
+        scope: &Scope,
+        params: &mut Params,
+    ) {
+        if let Some(condition) = condition {
+            let inner = Scope { table: Some(table.to_string()), ..scope.clone() };
+            let _ = write!(sql, " WHERE {}", self.condition(condition, &inner, params));
+        }
+    }
+
+    fn condition(&mut self, condition: &Condition, scope: &Scope, params: &mut Params) -> String {
+        match condition {
+            Condition::And(lhs, rhs) => {
+                format!("({} AND {})", self.condition(lhs, scope, params), self.condition(rhs, scope, params))
+            }
+            Condition::Or(lhs, rhs) => {
+                format!("({} OR {})", self.condition(lhs, scope, params), self.condition(rhs, scope, params))
+            }
+            Condition::Implies(lhs, rhs) => {
+                format!("(NOT {} OR {})", self.condition(lhs, scope, params), self.condition(rhs, scope, params))
+            }
+            Condition::Not(c) => format!("(NOT {})", self.condition(c, scope, params)),
+            Condition::Compare { lhs, op, rhs } => self.compare(lhs, *op, rhs, scope, params),
+            Condition::Expr(expr) => self.expr(expr, scope, params),
+            Condition::Quantifier { kind, relation, var, pk_binding, body } => {
+                let mut inner = scope.clone();
+                inner.aliases.insert(var.as_str().to_string());
+                inner.locals.remove(var.as_str());
+                let pk = self.expr(pk_binding, &inner, params);
+                let body = self.condition(body, &inner, params);
+                self.quantifier(*kind, relation.as_str(), var.as_str(), pk, body)
+            }
+        }
+    }
+
+    fn compare(&mut self, lhs: &Expr, op: CompareOp, rhs: &Expr, scope: &Scope, params: &mut Params) -> String {
+        let is_null = |e: &Expr| matches!(e, Expr::Literal(Literal::Null));
+        let null_test = match op {
+            CompareOp::Eq => Some("IS NULL"),
+            CompareOp::Neq => Some("IS NOT NULL"),
+            _ => None,
+        };
+        if let Some(test) = null_test {
+            if is_null(rhs) {
+                return format!("({} {})", self.expr(lhs, scope, params), test);
+            }
+            if is_null(lhs) {
+                return format!("({} {})", self.expr(rhs, scope, params), test);
+            }
+        }
+        let op = match op {
+            CompareOp::Eq => "=",
+            CompareOp::Neq => "<>",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        };
+        format!("({} {} {})", self.expr(lhs, scope, params), op, self.expr(rhs, scope, params))
+    }
+
+    fn expr(&mut self, expr: &Expr, scope: &Scope, params: &mut Params) -> String {
+        match expr {
+            Expr::Literal(literal) => match literal {
+                Literal::Int(i) => i.to_string(),
+                Literal::Bool(b) => self.dialect.bool_literal(*b).to_string(),
+                Literal::String(s) => quote_string(s),
+                Literal::Float(x) => format!("{:?}", x),
+                Literal::Null => "NULL".to_string(),
+            },
+            Expr::Struct(_) => self.unsupported(format!("the record `{}` has no SQL counterpart", expr)),
+            Expr::Var(name) => {
+                let name = name.as_str();
+                if scope.aliases.contains(name) {
+                    return self.unsupported(format!("the row `{}` is not a value in SQL", name));
+                }
+                match &scope.table {
+                    Some(table) if !scope.locals.contains(name) => {
+                        format!("{}.{}", quote_ident(table), quote_ident(name))
+                    }
+                    _ => self.param(Param::Local { name: name.to_string(), fields: vec![] }, params),
+                }
+            }
+            Expr::Field { base, field } => {
+                let Some(mut path) = path(base) else {
+                    return self.unsupported(format!("`{}` has no SQL counterpart", expr));
+                };
+                path.push(field.as_str());
+                let (first, fields) = (path[0], &path[1..]);
+                let to_strings = |fields: &[&str]| fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+                if scope.aliases.contains(first) {
+                    return match fields {
+                        [column] => format!("{}.{}", quote_ident(first), quote_ident(column)),
+                        _ => self.unsupported(format!("`{}` is not a column", expr)),
+                    };
+                }
+                if scope.locals.contains(first) {
+                    let param = Param::Local { name: first.to_string(), fields: to_strings(fields) };
+                    return self.param(param, params);
+                }
+                match fields {
+                    [field] if self.contexts.contains(first) => {
+                        let param = Param::Context { context: first.to_string(), field: field.to_string() };
+                        self.param(param, params)
+                    }
+                    _ if scope.table.is_some() => {
+                        self.unsupported(format!("`{}` reads a field of a column", expr))
+                    }
+                    _ => {
+                        let param = Param::Local { name: first.to_string(), fields: to_strings(fields) };
+                        self.param(param, params)
+                    }
+                }
+            }
+            Expr::Binary { lhs, op, rhs } => {
+                let l = self.expr(lhs, scope, params);
+                let r = self.expr(rhs, scope, params);
+                let op = match op {
+                    BinaryOp::Eq => "=",
+                    BinaryOp::Neq => "<>",
+                    BinaryOp::Lt => "<",
+                    BinaryOp::Le => "<=",
+                    BinaryOp::Gt => ">",
+                    BinaryOp::Ge => ">=",
+                    BinaryOp::Add => "+",
+                    BinaryOp::Sub => "-",
+                    BinaryOp::Mul => "*",
+                    BinaryOp::Div => "/",
+                    BinaryOp::And => "AND",
+                    BinaryOp::Or => "OR",
+                    BinaryOp::Implies => return format!("(NOT {} OR {})", l, r),
+                };
+                format!("({} {} {})", l, op, r)
+            }
+            Expr::Unary { op, expr } => match op {
+                UnaryOp::Not => format!("(NOT {})", self.expr(expr, scope, params)),
+                UnaryOp::Neg => format!("(-{})", self.expr(expr, scope, params)),
+            },
+            Expr::Quantifier { kind, relation, var, pk_binding, body } => {
+                let mut inner = scope.clone();
+                inner.aliases.insert(var.as_str().to_string());
+                inner.locals.remove(var.as_str());
+                let pk = self.expr(pk_binding, &inner, params);
+                let body = self.expr(body, &inner, params);
+                self.quantifier(*kind, relation.as_str(), var.as_str(), pk, body)
+            }
+        }
+    }
+
+    fn param(&self, param: Param, params: &mut Params) -> String {
+        self.dialect.placeholder(params.bind(param))
+    }
+
+    /// `\E`: a row with the key satisfying the body exists; `\A`: no row
+    /// with the key breaks the body
+    fn quantifier(&self, kind: QuantifierKind, relation: &str, var: &str, pk: String, body: String) -> String {
+        let from = format!("SELECT 1 FROM {} AS {}", quote_ident(relation), quote_ident(var));
+        match kind {
+            QuantifierKind::Exists => format!("EXISTS ({} WHERE {} AND {})", from, pk, body),
+            QuantifierKind::ForAll => format!("NOT EXISTS ({} WHERE {} AND NOT {})", from, pk, body),
+        }
+    }
+
+    fn unsupported(&mut self, message: String) -> String {
+        self.diagnostics.push(Diagnostic::warning(UNSUPPORTED_IN_SQL, message));
+        "NULL".to_string()
+    }
+}
+
+/// `a.b.c` as `["a", "b", "c"]`, `None` if `expr` is not a name with
+/// fields
+fn path(expr: &Expr) -> Option<Vec<&str>> {
+    match expr {
+        Expr::Var(name) => Some(vec![name.as_str()]),
+        Expr::Field { base, field } => {
+            let mut path = path(base)?;
+            path.push(field.as_str());
+            Some(path)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::interpreter::Interpreter;
+    use crate::eval::store::{MemoryStore, TableStore, Tables};
+    use crate::eval::value::{Row, Value};
+    use crate::parser::scl_parser::parse_scl;
+    use crate::sql::ddl::generate_ddl;
+    use crate::sql::schema::infer_schemas;
+    use rusqlite::types::{ToSqlOutput, ValueRef};
+    use rusqlite::{params_from_iter, Connection, ToSql};
+    use std::collections::HashMap;
+
+    fn generate(text: &str, dialect: Dialect) -> (Vec<StateSql>, Vec<Diagnostic>) {
+        generate_sql(&parse_scl(text).unwrap(), dialect)
+    }
+
+    #[test]
+    fn test_queries() {
+        let (states, diagnostics) = generate(include_str!("../../test_data/transfer.scl"), Dialect::Postgresql);
+        assert!(diagnostics.is_empty());
+        let (target, check) = &states[0].next()[0];
+        assert_eq!(target, "Debit");
+        let check = check.as_ref().unwrap();
+        assert_eq!(
+            check.sql(),
+            "SELECT EXISTS (SELECT 1 FROM \"Account\" AS \"a\" \
+             WHERE (\"a\".\"id\" = $1) AND (\"a\".\"balance\" >= $2))"
+        );
+        let context = |field: &str| Param::Context { context: "TransferCtx".to_string(), field: field.to_string() };
+        assert_eq!(check.params(), &vec![context("from"), context("amount")]);
+        assert_eq!(states[0].next()[1], ("Reject".to_string(), None));
+
+        let (states, diagnostics) = generate(include_str!("../../test_data/inventory.scl"), Dialect::Sqlite);
+        assert!(diagnostics.is_empty());
+        let sql: Vec<_> = states[0]
+            .body()
+            .iter()
+            .map(|stmt| match stmt {
+                SqlStmt::Query(query) => query.sql().as_str(),
+                SqlStmt::Foreach { .. } => "foreach",
+                SqlStmt::Commit => "commit",
+            })
+            .collect();
+        assert_eq!(sql, [
+            "SELECT (((?1 + 1) * 2) - (-3))",
+            "SELECT * FROM \"Stock\" WHERE (\"Stock\".\"item_id\" = ?1) LIMIT 10",
+            "foreach",
+            "DELETE FROM \"Pending\" WHERE (\"Pending\".\"item_id\" <> ?1)",
+            "SELECT ((NOT ?1) = 0)",
+        ]);
+        let SqlStmt::Foreach { body, .. } = &states[0].body()[2] else { unreachable!() };
+        let SqlStmt::Query(update) = &body[0] else { unreachable!() };
+        assert_eq!(
+            update.sql(),
+            "UPDATE \"Stock\" SET \"count\" = (\"Stock\".\"count\" - ?1), \"touched\" = 1 \
+             WHERE (\"Stock\".\"item_id\" = ?2)"
+        );
+        let row_id = Param::Local { name: "row".to_string(), fields: vec!["id".to_string()] };
+        assert_eq!(update.params()[1], row_id);
+        assert_eq!(states[0].preconditions()[1].sql(), "SELECT NOT EXISTS (SELECT 1 FROM \"Stock\" AS \"s\" \
+             WHERE (\"s\".\"item_id\" = ?1) AND NOT (NOT (\"s\".\"count\" >= 0) OR (\"s\".\"count\" <> (-1))))");
+        assert_eq!(states[1].body()[0], SqlStmt::Query(Query::new(
+            QueryKind::Write,
+            "INSERT INTO \"Orders\" DEFAULT VALUES".to_string(),
+            vec![],
+        )));
+
+        let text = "context C { x: int; }\n\
+                    state A uses C { let r: R = { a: C.x }; next { when r = null => A; otherwise => A; } }";
+        let (states, diagnostics) = generate(text, Dialect::Postgresql);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code(), UNSUPPORTED_IN_SQL);
+        let check = states[0].next()[0].1.as_ref().unwrap();
+        assert_eq!(check.sql(), "SELECT ($1 IS NULL)");
+    }
+
+    impl ToSql for Value {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(match self {
+                Value::Null => ToSqlOutput::from(rusqlite::types::Null),
+                Value::Bool(b) => ToSqlOutput::from(*b),
+                Value::Int(i) => ToSqlOutput::from(*i),
+                Value::Float(x) => ToSqlOutput::from(*x),
+                Value::String(s) => ToSqlOutput::from(s.as_str()),
+                value => panic!("cannot bind {}", value),
+            })
+        }
+    }
+
+    /// Runs the queries of a program on SQLite the way generated code
+    /// would, returns the states entered
+    struct Runner<'a> {
+        conn: &'a Connection,
+        contexts: &'a Row,
+        locals: HashMap<String, Value>,
+    }
+
+    impl Runner<'_> {
+        fn run(&mut self, states: &[StateSql]) -> Vec<String> {
+            let mut trace = vec![];
+            let mut state = &states[0];
+            loop {
+                trace.push(state.state().clone());
+                for query in state.preconditions() {
+                    assert!(self.check(query), "precondition of {}", state.state());
+                }
+                self.body(state.body());
+                if state.next().iter().all(|(target, _)| target == state.state()) {
+                    return trace;
+                }
+                let (target, _) = state
+                    .next()
+                    .iter()
+                    .find(|(_, query)| query.as_ref().is_none_or(|q| self.check(q)))
+                    .unwrap();
+                state = states.iter().find(|s| s.state() == target).unwrap();
+            }
+        }
+
+        fn body(&mut self, body: &[SqlStmt]) {
+            for stmt in body {
+                match stmt {
+                    SqlStmt::Query(query) => self.query(query),
+                    SqlStmt::Foreach { item, collection, body } => {
+                        let Value::List(rows) = self.locals[collection].clone() else { unreachable!() };
+                        for row in rows {
+                            self.locals.insert(item.clone(), row);
+                            self.body(body);
+                        }
+                    }
+                    SqlStmt::Commit => {}
+                }
+            }
+        }
+
+        fn query(&mut self, query: &Query) {
+            let mut stmt = self.conn.prepare(query.sql()).unwrap();
+            let params = params_from_iter(query.params().iter().map(|p| self.param(p)));
+            let names: Vec<_> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+            let rows: Vec<Row> = stmt
+                .query_map(params, |row| {
+                    Ok(names.iter().enumerate().map(|(i, name)| (name.clone(), value(row.get_ref_unwrap(i)))).collect())
+                })
+                .unwrap()
+                .map(|row| row.unwrap())
+                .collect();
+            match query.kind() {
+                QueryKind::Value { into } => {
+                    let value = rows[0].values().next().unwrap().clone();
+                    self.locals.insert(into.clone(), value);
+                }
+                QueryKind::Rows { into, many } => {
+                    let mut rows = rows.into_iter().map(Value::Record);
+                    let value = if *many { Value::List(rows.collect()) } else { rows.next().unwrap_or(Value::Null) };
+                    self.locals.insert(into.clone(), value);
+                }
+                QueryKind::Write => {}
+                QueryKind::Check => assert_eq!(rows[0].values().next(), Some(&Value::Int(1)), "{}", query.sql()),
+            }
+        }
+
+        fn check(&self, query: &Query) -> bool {
+            let params = params_from_iter(query.params().iter().map(|p| self.param(p)));
+            let holds: Option<i64> = self.conn.query_row(query.sql(), params, |row| row.get(0)).unwrap();
+            holds == Some(1)
+        }
+
+        fn param(&self, param: &Param) -> Value {
+            let (mut value, fields) = match param {
+                Param::Context { context, field } => (self.contexts[context].clone(), vec![field.clone()]),
+                Param::Local { name, fields } => (self.locals[name].clone(), fields.clone()),
+            };
+            for field in fields {
+                let Value::Record(record) = value else { unreachable!() };
+                value = record[&field].clone();
+            }
+            value
+        }
+    }
+
+    fn value(value: ValueRef) -> Value {
+        match value {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(i) => Value::Int(i),
+            ValueRef::Real(x) => Value::Float(x),
+            ValueRef::Text(s) => Value::String(String::from_utf8(s.to_vec()).unwrap()),
+            ValueRef::Blob(_) => unreachable!(),
+        }
+    }
+
+    /// Runs the program on SQLite and on the interpreter, they end in the
+    /// same state with the same rows
+    #[test]
+    fn test_sqlite() {
+        let text = r#"
+context C { id: int; amount: int; }
+
+state Pay uses C {
+  precondition { C.amount >= 0; }
+  let fee: int = C.amount / 10 + 1;
+  update Account set balance = balance - C.amount - fee where id = C.id;
+  select rich: List[Account] from Account where balance > 5 limit 2;
+  foreach r: Account in rich {
+    insert into Audit (id, note) values (r.id, "rich");
+  }
+  delete from Account where balance < 0;
+  delete from Account where owner = null;
+  assert C.amount < 100;
+  next {
+    when \E Relation Audit x by x.id = C.id : x.note = "rich" => Bonus;
+    when \A Relation Account a by a.id = C.id : a.balance < 0 => Done;
+    otherwise => Gone;
+  }
+}
+
+state Bonus uses C {
+  select me: Account from Account where id = C.id;
+  update Account set balance = balance + 1 where id = me.id;
+  next { otherwise => Done; }
+}
+
+state Gone uses C {
+  next { otherwise => Gone; }
+}
+
+state Done uses C {
+  commit;
+  next { otherwise => Done; }
+}
+"#;
+        let program = parse_scl(text).unwrap();
+        let (states, diagnostics) = generate_sql(&program, Dialect::Sqlite);
+        assert!(diagnostics.is_empty());
+        let (schemas, diagnostics) = infer_schemas(&program);
+        assert_eq!(diagnostics.len(), 1, "only the type of `Account.owner` is unknown");
+        let ddl = generate_ddl(&schemas, Dialect::Sqlite);
+
+        let account = |id: i64, balance: i64, owner: Option<&str>| {
+            Row::from([
+                ("id".to_string(), Value::Int(id)),
+                ("balance".to_string(), Value::Int(balance)),
+                ("owner".to_string(), owner.map_or(Value::Null, |o| Value::String(o.to_string()))),
+            ])
+        };
+        let accounts = vec![
+            account(1, 50, Some("a")),
+            account(2, 3, Some("b")),
+            account(3, 8, Some("c")),
+            account(4, 9, None),
+        ];
+        for (id, amount) in [(1, 20), (2, 3), (2, 0), (3, 1), (5, 0)] {
+            let conn = Connection::open_in_memory().unwrap();
+            conn.execute_batch(&ddl).unwrap();
+            for a in &accounts {
+                // the columns of a row are in name order
+                conn.execute("INSERT INTO \"Account\" (\"balance\", \"id\", \"owner\") VALUES (?1, ?2, ?3)", params_from_iter(a.values()))
+                    .unwrap();
+            }
+            let ctx = Row::from([("id".to_string(), Value::Int(id)), ("amount".to_string(), Value::Int(amount))]);
+            let contexts = Row::from([("C".to_string(), Value::Record(ctx))]);
+            let mut runner = Runner { conn: &conn, contexts: &contexts, locals: HashMap::new() };
+            let trace = runner.run(&states);
+
+            let tables = Tables::from([("Account".to_string(), accounts.clone())]);
+            let mut interpreter = Interpreter::new(&program, MemoryStore::new(tables));
+            assert_eq!(interpreter.run(&contexts).unwrap(), trace, "C.id = {}", id);
+            for relation in ["Account", "Audit"] {
+                let mut stmt = conn.prepare(&format!("SELECT * FROM \"{}\"", relation)).unwrap();
+                let names: Vec<_> = stmt.column_names().iter().map(|s| s.to_string()).collect();
+                let rows: Vec<Row> = stmt
+                    .query_map([], |row| {
+                        Ok(names.iter().enumerate().map(|(i, n)| (n.clone(), value(row.get_ref_unwrap(i)))).collect())
+                    })
+                    .unwrap()
+                    .map(|row| row.unwrap())
+                    .collect();
+                assert_eq!(rows, interpreter.store().rows(relation), "{} for C.id = {}", relation, id);
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod ts_field_name;
+pub mod ts_field_id;
+pub mod ts_kind_id;
+pub mod ts_kind_name;
+pub mod ts_seq_index;
\ No newline at end of file
@@ -0,0 +1,22 @@
+//
+// When change grammar.js, re-run ``cargo build`` to generate this file
+// Caution, do not change this file manually!!!
+//
+// field id
+
+pub const FI_BASE: u16 = 1;
+pub const FI_COLLECTION: u16 = 2;
+pub const FI_CONTEXT: u16 = 3;
+pub const FI_ENTITY: u16 = 4;
+pub const FI_FIELD: u16 = 5;
+pub const FI_ITEM: u16 = 6;
+pub const FI_ITEM_TYPE: u16 = 7;
+pub const FI_NAME: u16 = 8;
+pub const FI_OBJECT: u16 = 9;
+pub const FI_PARAM: u16 = 10;
+pub const FI_RELATION: u16 = 11;
+pub const FI_SOURCE: u16 = 12;
+pub const FI_TARGET: u16 = 13;
+pub const FI_TYPE: u16 = 14;
+pub const FI_VALUE: u16 = 15;
+pub const FI_VAR: u16 = 16;
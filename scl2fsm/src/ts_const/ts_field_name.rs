@@ -0,0 +1,22 @@
+//
+// When change grammar.js, re-run ``cargo build`` to generate this file
+// Caution, do not change this file manually!!!
+//
+// field name
+
+pub const BASE: &str = "base";
+pub const COLLECTION: &str = "collection";
+pub const CONTEXT: &str = "context";
+pub const ENTITY: &str = "entity";
+pub const FIELD: &str = "field";
+pub const ITEM: &str = "item";
+pub const ITEM_TYPE: &str = "item_type";
+pub const NAME: &str = "name";
+pub const OBJECT: &str = "object";
+pub const PARAM: &str = "param";
+pub const RELATION: &str = "relation";
+pub const SOURCE: &str = "source";
+pub const TARGET: &str = "target";
+pub const TYPE: &str = "type";
+pub const VALUE: &str = "value";
+pub const VAR: &str = "var";
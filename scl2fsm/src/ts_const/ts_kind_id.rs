@@ -0,0 +1,61 @@
+//
+// When change grammar.js, re-run ``cargo build`` to generate this file
+// Caution, do not change this file manually!!!
+//
+// kind id of Node
+
+pub const GUARD: u16 = 0;
+pub const GUARD_BINARY_EXPR: u16 = 0;
+pub const GUARD_EXPR: u16 = 0;
+pub const GUARD_QUANTIFIER: u16 = 0;
+pub const GUARD_UNARY_EXPR: u16 = 0;
+pub const IDENTIFIER: u16 = 1;
+pub const NUMBER: u16 = 66;
+pub const COMMENT: u16 = 72;
+pub const PROGRAM: u16 = 73;
+pub const TYPE_DECL: u16 = 74;
+pub const TYPE_FIELD: u16 = 75;
+pub const CONTEXT_DECL: u16 = 76;
+pub const CONTEXT_FIELD: u16 = 77;
+pub const INVARIANT_DECL: u16 = 78;
+pub const STATE_DECL: u16 = 79;
+pub const USE_STMT: u16 = 80;
+pub const USE_STATE_STMT: u16 = 81;
+pub const USE_CONTEXT_STMT: u16 = 82;
+pub const USE_TYPE_STMT: u16 = 83;
+pub const USE_FIELD: u16 = 84;
+pub const PRECONDITION_BLOCK: u16 = 85;
+pub const STATEMENT: u16 = 86;
+pub const LET_STMT: u16 = 87;
+pub const SELECT_STMT: u16 = 88;
+pub const FOREACH_STMT: u16 = 89;
+pub const UPDATE_STMT: u16 = 90;
+pub const ASSIGNMENT: u16 = 91;
+pub const INSERT_STMT: u16 = 92;
+pub const DELETE_STMT: u16 = 93;
+pub const WHERE_CLAUSE: u16 = 94;
+pub const LIMIT_CLAUSE: u16 = 95;
+pub const ASSERT_STMT: u16 = 96;
+pub const COMMIT_STMT: u16 = 97;
+pub const NEXT_BLOCK: u16 = 98;
+pub const NEXT_CASE: u16 = 99;
+pub const EDGE_EXPORT_BLOCK: u16 = 105;
+pub const EDGE_FIELD: u16 = 106;
+pub const CONDITION: u16 = 107;
+pub const TLA_EXPR: u16 = 108;
+pub const TLA_BINARY_EXPR: u16 = 109;
+pub const TLA_UNARY_EXPR: u16 = 110;
+pub const TLA_QUANTIFIER: u16 = 111;
+pub const EXPR: u16 = 112;
+pub const STRUCT_LITERAL: u16 = 113;
+pub const STRUCT_FIELD: u16 = 114;
+pub const BINARY_EXPR: u16 = 115;
+pub const UNARY_EXPR: u16 = 116;
+pub const FIELD_ACCESS: u16 = 117;
+pub const TYPE: u16 = 118;
+pub const PRIMITIVE_TYPE: u16 = 119;
+pub const GENERIC_TYPE: u16 = 120;
+pub const QUALIFIED_NAME: u16 = 121;
+pub const LITERAL: u16 = 122;
+pub const BOOLEAN: u16 = 123;
+pub const STRING: u16 = 124;
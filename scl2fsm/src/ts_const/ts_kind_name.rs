@@ -0,0 +1,61 @@
+//
+// When change grammar.js, re-run ``cargo build`` to generate this file
+// Caution, do not change this file manually!!!
+//
+// kind name of Node
+
+pub const S_GUARD: &str = "guard";
+pub const S_GUARD_BINARY_EXPR: &str = "guard_binary_expr";
+pub const S_GUARD_EXPR: &str = "guard_expr";
+pub const S_GUARD_QUANTIFIER: &str = "guard_quantifier";
+pub const S_GUARD_UNARY_EXPR: &str = "guard_unary_expr";
+pub const S_IDENTIFIER: &str = "identifier";
+pub const S_NUMBER: &str = "number";
+pub const S_COMMENT: &str = "comment";
+pub const S_PROGRAM: &str = "program";
+pub const S_TYPE_DECL: &str = "type_decl";
+pub const S_TYPE_FIELD: &str = "type_field";
+pub const S_CONTEXT_DECL: &str = "context_decl";
+pub const S_CONTEXT_FIELD: &str = "context_field";
+pub const S_INVARIANT_DECL: &str = "invariant_decl";
+pub const S_STATE_DECL: &str = "state_decl";
+pub const S_USE_STMT: &str = "use_stmt";
+pub const S_USE_STATE_STMT: &str = "use_state_stmt";
+pub const S_USE_CONTEXT_STMT: &str = "use_context_stmt";
+pub const S_USE_TYPE_STMT: &str = "use_type_stmt";
+pub const S_USE_FIELD: &str = "use_field";
+pub const S_PRECONDITION_BLOCK: &str = "precondition_block";
+pub const S_STATEMENT: &str = "statement";
+pub const S_LET_STMT: &str = "let_stmt";
+pub const S_SELECT_STMT: &str = "select_stmt";
+pub const S_FOREACH_STMT: &str = "foreach_stmt";
+pub const S_UPDATE_STMT: &str = "update_stmt";
+pub const S_ASSIGNMENT: &str = "assignment";
+pub const S_INSERT_STMT: &str = "insert_stmt";
+pub const S_DELETE_STMT: &str = "delete_stmt";
+pub const S_WHERE_CLAUSE: &str = "where_clause";
+pub const S_LIMIT_CLAUSE: &str = "limit_clause";
+pub const S_ASSERT_STMT: &str = "assert_stmt";
+pub const S_COMMIT_STMT: &str = "commit_stmt";
+pub const S_NEXT_BLOCK: &str = "next_block";
+pub const S_NEXT_CASE: &str = "next_case";
+pub const S_EDGE_EXPORT_BLOCK: &str = "edge_export_block";
+pub const S_EDGE_FIELD: &str = "edge_field";
+pub const S_CONDITION: &str = "condition";
+pub const S_TLA_EXPR: &str = "tla_expr";
+pub const S_TLA_BINARY_EXPR: &str = "tla_binary_expr";
+pub const S_TLA_UNARY_EXPR: &str = "tla_unary_expr";
+pub const S_TLA_QUANTIFIER: &str = "tla_quantifier";
+pub const S_EXPR: &str = "expr";
+pub const S_STRUCT_LITERAL: &str = "struct_literal";
+pub const S_STRUCT_FIELD: &str = "struct_field";
+pub const S_BINARY_EXPR: &str = "binary_expr";
+pub const S_UNARY_EXPR: &str = "unary_expr";
+pub const S_FIELD_ACCESS: &str = "field_access";
+pub const S_TYPE: &str = "type";
+pub const S_PRIMITIVE_TYPE: &str = "primitive_type";
+pub const S_GENERIC_TYPE: &str = "generic_type";
+pub const S_QUALIFIED_NAME: &str = "qualified_name";
+pub const S_LITERAL: &str = "literal";
+pub const S_BOOLEAN: &str = "boolean";
+pub const S_STRING: &str = "string";
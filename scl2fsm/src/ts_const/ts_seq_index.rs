@@ -0,0 +1,214 @@
+//
+// When change grammar.js, re-run ``cargo build`` to generate this file
+// Caution, do not change this file manually!!!
+//
+// sequence index in array of SEQ type
+
+pub const ASSERT_STMT_SEQ_STRING_0: usize = 0;
+pub const ASSERT_STMT_SEQ_STRING_2: usize = 2;
+pub const ASSERT_STMT_SEQ_SYMBOL: usize = 1;
+pub const ASSIGNMENT_SEQ_FIELD_0: usize = 0;
+pub const ASSIGNMENT_SEQ_FIELD_2: usize = 2;
+pub const ASSIGNMENT_SEQ_STRING: usize = 1;
+pub const BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_CHOICE: usize = 1;
+pub const BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_SYMBOL_0: usize = 0;
+pub const BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_SYMBOL_2: usize = 2;
+pub const COMMIT_STMT_SEQ_STRING_0: usize = 0;
+pub const COMMIT_STMT_SEQ_STRING_1: usize = 1;
+pub const CONTEXT_DECL_SEQ_FIELD: usize = 1;
+pub const CONTEXT_DECL_SEQ_REPEAT: usize = 3;
+pub const CONTEXT_DECL_SEQ_STRING_0: usize = 0;
+pub const CONTEXT_DECL_SEQ_STRING_2: usize = 2;
+pub const CONTEXT_DECL_SEQ_STRING_4: usize = 4;
+pub const CONTEXT_FIELD_SEQ_FIELD_0: usize = 0;
+pub const CONTEXT_FIELD_SEQ_FIELD_2: usize = 2;
+pub const CONTEXT_FIELD_SEQ_STRING_1: usize = 1;
+pub const CONTEXT_FIELD_SEQ_STRING_3: usize = 3;
+pub const DELETE_STMT_SEQ_CHOICE: usize = 3;
+pub const DELETE_STMT_SEQ_FIELD: usize = 2;
+pub const DELETE_STMT_SEQ_STRING_0: usize = 0;
+pub const DELETE_STMT_SEQ_STRING_1: usize = 1;
+pub const DELETE_STMT_SEQ_STRING_4: usize = 4;
+pub const EDGE_EXPORT_BLOCK_SEQ_REPEAT1: usize = 2;
+pub const EDGE_EXPORT_BLOCK_SEQ_STRING_0: usize = 0;
+pub const EDGE_EXPORT_BLOCK_SEQ_STRING_1: usize = 1;
+pub const EDGE_EXPORT_BLOCK_SEQ_STRING_3: usize = 3;
+pub const EDGE_FIELD_SEQ_FIELD_0: usize = 0;
+pub const EDGE_FIELD_SEQ_FIELD_2: usize = 2;
+pub const EDGE_FIELD_SEQ_STRING_1: usize = 1;
+pub const EDGE_FIELD_SEQ_STRING_3: usize = 3;
+pub const EXPR_CHOICE_SEQ_STRING_0: usize = 0;
+pub const EXPR_CHOICE_SEQ_STRING_2: usize = 2;
+pub const EXPR_CHOICE_SEQ_SYMBOL: usize = 1;
+pub const FIELD_ACCESS_SEQ_FIELD_0: usize = 0;
+pub const FIELD_ACCESS_SEQ_FIELD_2: usize = 2;
+pub const FIELD_ACCESS_SEQ_STRING: usize = 1;
+pub const FOREACH_STMT_SEQ_FIELD_1: usize = 1;
+pub const FOREACH_STMT_SEQ_FIELD_3: usize = 3;
+pub const FOREACH_STMT_SEQ_FIELD_5: usize = 5;
+pub const FOREACH_STMT_SEQ_REPEAT: usize = 7;
+pub const FOREACH_STMT_SEQ_STRING_0: usize = 0;
+pub const FOREACH_STMT_SEQ_STRING_2: usize = 2;
+pub const FOREACH_STMT_SEQ_STRING_4: usize = 4;
+pub const FOREACH_STMT_SEQ_STRING_6: usize = 6;
+pub const FOREACH_STMT_SEQ_STRING_8: usize = 8;
+pub const GENERIC_TYPE_SEQ_FIELD_0: usize = 0;
+pub const GENERIC_TYPE_SEQ_FIELD_2: usize = 2;
+pub const GENERIC_TYPE_SEQ_STRING_1: usize = 1;
+pub const GENERIC_TYPE_SEQ_STRING_3: usize = 3;
+pub const GUARD_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_ALIAS_0: usize = 0;
+pub const GUARD_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_ALIAS_2: usize = 2;
+pub const GUARD_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_STRING: usize = 1;
+pub const GUARD_QUANTIFIER_SEQ_ALIAS: usize = 7;
+pub const GUARD_QUANTIFIER_SEQ_CHOICE: usize = 0;
+pub const GUARD_QUANTIFIER_SEQ_FIELD_2: usize = 2;
+pub const GUARD_QUANTIFIER_SEQ_FIELD_3: usize = 3;
+pub const GUARD_QUANTIFIER_SEQ_STRING_1: usize = 1;
+pub const GUARD_QUANTIFIER_SEQ_STRING_4: usize = 4;
+pub const GUARD_QUANTIFIER_SEQ_STRING_6: usize = 6;
+pub const GUARD_QUANTIFIER_SEQ_SYMBOL: usize = 5;
+pub const GUARD_UNARY_EXPR_PREC_SEQ_ALIAS: usize = 1;
+pub const GUARD_UNARY_EXPR_PREC_SEQ_STRING: usize = 0;
+pub const INSERT_STMT_SEQ_CHOICE_4: usize = 4;
+pub const INSERT_STMT_SEQ_CHOICE_8: usize = 8;
+pub const INSERT_STMT_SEQ_CHOICE_SEQ_REPEAT: usize = 1;
+pub const INSERT_STMT_SEQ_CHOICE_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const INSERT_STMT_SEQ_CHOICE_SEQ_REPEAT_SEQ_SYMBOL: usize = 1;
+pub const INSERT_STMT_SEQ_CHOICE_SEQ_SYMBOL: usize = 0;
+pub const INSERT_STMT_SEQ_FIELD: usize = 2;
+pub const INSERT_STMT_SEQ_STRING_0: usize = 0;
+pub const INSERT_STMT_SEQ_STRING_1: usize = 1;
+pub const INSERT_STMT_SEQ_STRING_3: usize = 3;
+pub const INSERT_STMT_SEQ_STRING_5: usize = 5;
+pub const INSERT_STMT_SEQ_STRING_6: usize = 6;
+pub const INSERT_STMT_SEQ_STRING_7: usize = 7;
+pub const INSERT_STMT_SEQ_STRING_9: usize = 9;
+pub const INSERT_STMT_SEQ_STRING_10: usize = 10;
+pub const INVARIANT_DECL_SEQ_FIELD: usize = 1;
+pub const INVARIANT_DECL_SEQ_REPEAT1: usize = 3;
+pub const INVARIANT_DECL_SEQ_REPEAT1_SEQ_STRING: usize = 1;
+pub const INVARIANT_DECL_SEQ_REPEAT1_SEQ_SYMBOL: usize = 0;
+pub const INVARIANT_DECL_SEQ_STRING_0: usize = 0;
+pub const INVARIANT_DECL_SEQ_STRING_2: usize = 2;
+pub const INVARIANT_DECL_SEQ_STRING_4: usize = 4;
+pub const LET_STMT_SEQ_FIELD_1: usize = 1;
+pub const LET_STMT_SEQ_FIELD_3: usize = 3;
+pub const LET_STMT_SEQ_FIELD_5: usize = 5;
+pub const LET_STMT_SEQ_STRING_0: usize = 0;
+pub const LET_STMT_SEQ_STRING_2: usize = 2;
+pub const LET_STMT_SEQ_STRING_4: usize = 4;
+pub const LET_STMT_SEQ_STRING_6: usize = 6;
+pub const LIMIT_CLAUSE_SEQ_STRING: usize = 0;
+pub const LIMIT_CLAUSE_SEQ_SYMBOL: usize = 1;
+pub const NEXT_BLOCK_SEQ_REPEAT1: usize = 2;
+pub const NEXT_BLOCK_SEQ_STRING_0: usize = 0;
+pub const NEXT_BLOCK_SEQ_STRING_1: usize = 1;
+pub const NEXT_BLOCK_SEQ_STRING_3: usize = 3;
+pub const NEXT_CASE_CHOICE_SEQ_ALIAS: usize = 1;
+pub const NEXT_CASE_CHOICE_SEQ_CHOICE_4: usize = 4;
+pub const NEXT_CASE_CHOICE_SEQ_CHOICE_5: usize = 5;
+pub const NEXT_CASE_CHOICE_SEQ_CHOICE_3: usize = 3;
+pub const NEXT_CASE_CHOICE_SEQ_FIELD_3: usize = 3;
+pub const NEXT_CASE_CHOICE_SEQ_FIELD_2: usize = 2;
+pub const NEXT_CASE_CHOICE_SEQ_STRING_0: usize = 0;
+pub const NEXT_CASE_CHOICE_SEQ_STRING_2: usize = 2;
+pub const NEXT_CASE_CHOICE_SEQ_STRING_1: usize = 1;
+pub const PRECONDITION_BLOCK_SEQ_CHOICE: usize = 0;
+pub const PRECONDITION_BLOCK_SEQ_REPEAT1: usize = 2;
+pub const PRECONDITION_BLOCK_SEQ_REPEAT1_SEQ_STRING: usize = 1;
+pub const PRECONDITION_BLOCK_SEQ_REPEAT1_SEQ_SYMBOL: usize = 0;
+pub const PRECONDITION_BLOCK_SEQ_STRING_1: usize = 1;
+pub const PRECONDITION_BLOCK_SEQ_STRING_3: usize = 3;
+pub const QUALIFIED_NAME_CHOICE_SEQ_STRING: usize = 1;
+pub const QUALIFIED_NAME_CHOICE_SEQ_SYMBOL_0: usize = 0;
+pub const QUALIFIED_NAME_CHOICE_SEQ_SYMBOL_2: usize = 2;
+pub const SELECT_STMT_SEQ_CHOICE_6: usize = 6;
+pub const SELECT_STMT_SEQ_CHOICE_7: usize = 7;
+pub const SELECT_STMT_SEQ_FIELD_1: usize = 1;
+pub const SELECT_STMT_SEQ_FIELD_3: usize = 3;
+pub const SELECT_STMT_SEQ_FIELD_5: usize = 5;
+pub const SELECT_STMT_SEQ_STRING_0: usize = 0;
+pub const SELECT_STMT_SEQ_STRING_2: usize = 2;
+pub const SELECT_STMT_SEQ_STRING_4: usize = 4;
+pub const SELECT_STMT_SEQ_STRING_8: usize = 8;
+pub const STATE_DECL_SEQ_CHOICE: usize = 6;
+pub const STATE_DECL_SEQ_FIELD: usize = 1;
+pub const STATE_DECL_SEQ_REPEAT_5: usize = 5;
+pub const STATE_DECL_SEQ_REPEAT_7: usize = 7;
+pub const STATE_DECL_SEQ_SEQ: usize = 3;
+pub const STATE_DECL_SEQ_SEQ_REPEAT: usize = 1;
+pub const STATE_DECL_SEQ_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const STATE_DECL_SEQ_SEQ_REPEAT_SEQ_SYMBOL: usize = 1;
+pub const STATE_DECL_SEQ_SEQ_SYMBOL: usize = 0;
+pub const STATE_DECL_SEQ_STRING_0: usize = 0;
+pub const STATE_DECL_SEQ_STRING_2: usize = 2;
+pub const STATE_DECL_SEQ_STRING_4: usize = 4;
+pub const STATE_DECL_SEQ_STRING_9: usize = 9;
+pub const STATE_DECL_SEQ_SYMBOL: usize = 8;
+pub const STRING_SEQ_REPEAT: usize = 1;
+pub const STRING_SEQ_STRING_0: usize = 0;
+pub const STRING_SEQ_STRING_2: usize = 2;
+pub const STRUCT_FIELD_SEQ_FIELD_0: usize = 0;
+pub const STRUCT_FIELD_SEQ_FIELD_2: usize = 2;
+pub const STRUCT_FIELD_SEQ_STRING: usize = 1;
+pub const STRUCT_LITERAL_SEQ_SEQ: usize = 1;
+pub const STRUCT_LITERAL_SEQ_SEQ_REPEAT: usize = 1;
+pub const STRUCT_LITERAL_SEQ_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const STRUCT_LITERAL_SEQ_SEQ_REPEAT_SEQ_SYMBOL: usize = 1;
+pub const STRUCT_LITERAL_SEQ_SEQ_SYMBOL: usize = 0;
+pub const STRUCT_LITERAL_SEQ_STRING_0: usize = 0;
+pub const STRUCT_LITERAL_SEQ_STRING_2: usize = 2;
+pub const TLA_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_STRING: usize = 1;
+pub const TLA_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_SYMBOL_0: usize = 0;
+pub const TLA_BINARY_EXPR_CHOICE_PREC_LEFT_SEQ_SYMBOL_2: usize = 2;
+pub const TLA_QUANTIFIER_SEQ_CHOICE: usize = 0;
+pub const TLA_QUANTIFIER_SEQ_FIELD_2: usize = 2;
+pub const TLA_QUANTIFIER_SEQ_FIELD_3: usize = 3;
+pub const TLA_QUANTIFIER_SEQ_STRING_1: usize = 1;
+pub const TLA_QUANTIFIER_SEQ_STRING_4: usize = 4;
+pub const TLA_QUANTIFIER_SEQ_STRING_6: usize = 6;
+pub const TLA_QUANTIFIER_SEQ_SYMBOL_5: usize = 5;
+pub const TLA_QUANTIFIER_SEQ_SYMBOL_7: usize = 7;
+pub const TLA_UNARY_EXPR_PREC_SEQ_STRING: usize = 0;
+pub const TLA_UNARY_EXPR_PREC_SEQ_SYMBOL: usize = 1;
+pub const TYPE_DECL_SEQ_FIELD: usize = 1;
+pub const TYPE_DECL_SEQ_REPEAT1: usize = 3;
+pub const TYPE_DECL_SEQ_STRING_0: usize = 0;
+pub const TYPE_DECL_SEQ_STRING_2: usize = 2;
+pub const TYPE_DECL_SEQ_STRING_4: usize = 4;
+pub const TYPE_FIELD_SEQ_FIELD_0: usize = 0;
+pub const TYPE_FIELD_SEQ_FIELD_2: usize = 2;
+pub const TYPE_FIELD_SEQ_STRING_1: usize = 1;
+pub const TYPE_FIELD_SEQ_STRING_3: usize = 3;
+pub const UNARY_EXPR_PREC_SEQ_CHOICE: usize = 0;
+pub const UNARY_EXPR_PREC_SEQ_SYMBOL: usize = 1;
+pub const UPDATE_STMT_SEQ_CHOICE: usize = 4;
+pub const UPDATE_STMT_SEQ_FIELD: usize = 1;
+pub const UPDATE_STMT_SEQ_SEQ: usize = 3;
+pub const UPDATE_STMT_SEQ_SEQ_REPEAT: usize = 1;
+pub const UPDATE_STMT_SEQ_SEQ_REPEAT_SEQ_STRING: usize = 0;
+pub const UPDATE_STMT_SEQ_SEQ_REPEAT_SEQ_SYMBOL: usize = 1;
+pub const UPDATE_STMT_SEQ_SEQ_SYMBOL: usize = 0;
+pub const UPDATE_STMT_SEQ_STRING_0: usize = 0;
+pub const UPDATE_STMT_SEQ_STRING_2: usize = 2;
+pub const UPDATE_STMT_SEQ_STRING_5: usize = 5;
+pub const USE_CONTEXT_STMT_SEQ_FIELD: usize = 2;
+pub const USE_CONTEXT_STMT_SEQ_STRING_0: usize = 0;
+pub const USE_CONTEXT_STMT_SEQ_STRING_1: usize = 1;
+pub const USE_CONTEXT_STMT_SEQ_STRING_3: usize = 3;
+pub const USE_FIELD_SEQ_FIELD_0: usize = 0;
+pub const USE_FIELD_SEQ_FIELD_2: usize = 2;
+pub const USE_FIELD_SEQ_STRING_1: usize = 1;
+pub const USE_FIELD_SEQ_STRING_3: usize = 3;
+pub const USE_STATE_STMT_SEQ_FIELD: usize = 2;
+pub const USE_STATE_STMT_SEQ_REPEAT1: usize = 4;
+pub const USE_STATE_STMT_SEQ_STRING_0: usize = 0;
+pub const USE_STATE_STMT_SEQ_STRING_1: usize = 1;
+pub const USE_STATE_STMT_SEQ_STRING_3: usize = 3;
+pub const USE_STATE_STMT_SEQ_STRING_5: usize = 5;
+pub const USE_TYPE_STMT_SEQ_FIELD: usize = 2;
+pub const USE_TYPE_STMT_SEQ_STRING_0: usize = 0;
+pub const USE_TYPE_STMT_SEQ_STRING_1: usize = 1;
+pub const USE_TYPE_STMT_SEQ_STRING_3: usize = 3;
+pub const WHERE_CLAUSE_SEQ_STRING: usize = 0;
+pub const WHERE_CLAUSE_SEQ_SYMBOL: usize = 1;
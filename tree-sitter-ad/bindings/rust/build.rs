@@ -1,3 +1,5 @@
+#![allow(clippy::const_is_empty)]
+
 fn main() {
     let src_dir = std::path::Path::new("src");
 
@@ -5,9 +5,10 @@
 //!
 //! ```
 //! let code = r#"
+//! :action;
 //! "#;
 //! let mut parser = tree_sitter::Parser::new();
-//! let language = tree_sitter_activity_diagram::LANGUAGE;
+//! let language = tree_sitter_ad::LANGUAGE;
 //! parser
 //!     .set_language(&language.into())
 //!     .expect("Error loading ActivityDiagram parser");
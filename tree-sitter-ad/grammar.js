@@ -35,6 +35,7 @@ export default grammar({
             $.switch_statement,
             $.while_statement,
             $.repeat_statement,
+            $.fork_statement,
             $.group_statement,
             $.terminal_statement
         ),
@@ -63,6 +64,7 @@ export default grammar({
             $.stop,
             $.break_statement,                // break
             $.goto_statement,                 // goto and label
+            $.activity_statement,             // nested activity
 
             $.note_statement,                 // notes
             $.arrow,                          // arrows
@@ -97,7 +99,7 @@ export default grammar({
             'if',
             optional('('), field('expression', $.expression), optional(')'),
             optional('then'),
-            optional(seq('(', $.text_content, ')')),  // Optional label in parentheses
+            optional(seq('(', field('label', $.text_content), ')')),  // Optional label in parentheses
             field('block_statement_list', $.block_statement_list)
         ),
 
@@ -105,13 +107,13 @@ export default grammar({
             'elseif',
             optional('('), field('expression', $.expression), optional(')'),
             optional('then'),
-            optional(seq('(', $.text_content, ')')),  // Optional label in parentheses
+            optional(seq('(', field('label', $.text_content), ')')),  // Optional label in parentheses
             field('block_statement_list', $.block_statement_list)
         ),
 
         else_condition: $ => seq(
             'else',
-            optional(seq('(', $.text_content, ')')),  // Optional label in parentheses
+            optional(seq('(', field('label', $.text_content), ')')),  // Optional label in parentheses
             field('block_statement_list', $.block_statement_list)
         ),
 
@@ -123,6 +125,7 @@ export default grammar({
             $.switch_statement,
             $.while_statement,
             $.repeat_statement,
+            $.fork_statement,
             $.group_statement,
             $.terminal_statement
         ),
@@ -179,10 +182,10 @@ export default grammar({
             optional('('),
             field('expression', $.expression),
             optional(')'),
-            optional(seq(optional('is'), '(', $.text_content, ')')),  // Optional label in parentheses
+            optional(seq(optional('is'), '(', field('label', $.text_content), ')')),  // Optional label in parentheses
             field('block_statement_list', $.block_statement_list),
             $.endwhile_directive,
-            optional(seq('(', $.text_content, ')'))  // Optional label in parentheses
+            optional(seq('(', field('exit_label', $.text_content), ')'))  // Optional label in parentheses
         ),
 
         endwhile_directive: $ => choice(
@@ -190,8 +193,39 @@ export default grammar({
             seq('end', 'while')
         ),
 
+        // ===================================================================
+        // CONCURRENCY
+        // ===================================================================
+
+        // Fork/split: the branches run concurrently and join at the end
+        fork_statement: $ => seq(
+            choice('fork', 'split'),
+            field('block_statement_list', $.block_statement_list),
+            repeat($.fork_again),
+            $.end_fork_directive
+        ),
+
+        // `fork again` and `split again` are normalized to one word before parsing
+        fork_again: $ => seq(
+            choice('forkagain', 'splitagain'),
+            field('block_statement_list', $.block_statement_list)
+        ),
+
+        // `end fork {or}` and `end merge` continue once any branch ended,
+        // otherwise every branch has to end
+        end_fork_directive: $ => seq(
+            'end',
+            choice(
+                seq(
+                    choice('fork', 'split'),
+                    optional(seq('{', field('join', choice('and', 'or')), '}'))
+                ),
+                field('join', 'merge')
+            )
+        ),
+
         // Break statement
-        break_statement: $ => 'break',
+        break_statement: $ => prec.right(seq('break', optional(';'))),
 
         // Goto and label statements
         goto_statement: $ => prec.right(3, seq(
@@ -202,6 +236,13 @@ export default grammar({
             optional(';')
         )),
 
+        // Reference to the activity diagram of another file
+        activity_statement: $ => prec.right(seq(
+            'activity',
+            field('activity_identifier', $.identifier),
+            optional(';')
+        )),
+
         // ===================================================================
         // GROUPING AND ORGANIZATION
         // ===================================================================
@@ -239,10 +280,13 @@ export default grammar({
             'note',
             optional(field('position', choice('left', 'right', 'top', 'bottom'))),
             optional(':'),
-            field('content', $.text_content),
+            field('content', $.note_text),
             choice('endnote', seq('end', 'note'))
         ),
 
+        // Note text, free prose with punctuation
+        note_text: $ => repeat1(choice($.text_word, ',', ';', '(', ')', '{', '}')),
+
         // ===================================================================
         // CONNECTORS AND ARROWS
         // ===================================================================
@@ -250,7 +294,8 @@ export default grammar({
         // Arrow with optional styling
         arrow: $ => seq(
             field('arrow', $.arrow_style),              // Arrow style
-            $.action_statement
+            optional(field('label', $.text_content)),   // Optional label
+            ';'
         ),
 
         // Arrow style definition
@@ -327,12 +372,8 @@ export default grammar({
         // SDL AND UML SHAPES (Stereotypes)
         // ===================================================================
 
-        // SDL/UML shape with stereotype
-        sdl_shape: $ => seq(
-            $.text_content,
-            ';',
-            optional(seq('<<', $.stereotype, '>>'))
-        ),
+        // SDL/UML shape with stereotype, written after an action
+        sdl_shape: $ => seq('<<', field('stereotype', $.stereotype), '>>'),
 
         // Stereotype definitions
         stereotype: $ => choice(
@@ -387,7 +428,8 @@ export default grammar({
         action_statement: $ => seq(
             ':',
             field('action', $.text_content),
-            ';'
+            ';',
+            optional(field('shape', $.sdl_shape))
         ),
 
         // Identifier (variable names, labels, etc.)
@@ -43,7 +43,8 @@ export default grammar({
                 choice(
                     $.type_decl,
                     $.context_decl,
-                    $.state_decl
+                    $.state_decl,
+                    $.invariant_decl
                 )
             ),
 
@@ -89,6 +90,19 @@ export default grammar({
                 ';'
             ),
 
+        /* =====================================================
+         * Invariant — holds in every state a run gets to
+         * ===================================================== */
+
+        invariant_decl: $ =>
+            seq(
+                'invariant',
+                field('name', $.identifier),
+                '{',
+                repeat1(seq($.condition, ';')),
+                '}'
+            ),
+
         /* =====================================================
          * State
          * ===================================================== */
@@ -282,23 +296,70 @@ export default grammar({
                 '}'
             ),
 
+        /*
+         * The `;` after a case is optional, `when c => T;` and
+         * `when c => T` are the same case.
+         */
         next_case: $ =>
             choice(
                 seq(
                     'when',
-                    $.condition,
+                    alias($.guard, $.condition),
                     '=>',
                     field('target', $.identifier),
-                    optional($.edge_export_block)
+                    optional($.edge_export_block),
+                    optional(';')
                 ),
                 seq(
                     'otherwise',
                     '=>',
                     field('target', $.identifier),
-                    optional($.edge_export_block)
+                    optional($.edge_export_block),
+                    optional(';')
                 )
             ),
 
+        /*
+         * A guard is a condition without `=>`, the arrow after it
+         * always starts the target. An implication `a => b` has to be
+         * written as `~a \/ b` in a guard. The aliases keep the tree
+         * shape of a plain condition.
+         */
+        guard: $ => alias($.guard_expr, $.tla_expr),
+
+        guard_expr: $ =>
+            choice(
+                alias($.guard_binary_expr, $.tla_binary_expr),
+                alias($.guard_unary_expr, $.tla_unary_expr),
+                alias($.guard_quantifier, $.tla_quantifier),
+                $.expr
+            ),
+
+        guard_binary_expr: $ =>
+            choice(
+                prec.left(PREC.OR, seq(
+                    alias($.guard_expr, $.tla_expr), '\\/', alias($.guard_expr, $.tla_expr)
+                )),
+                prec.left(PREC.AND, seq(
+                    alias($.guard_expr, $.tla_expr), '/\\', alias($.guard_expr, $.tla_expr)
+                ))
+            ),
+
+        guard_unary_expr: $ =>
+            prec(PREC.UNARY, seq('~', alias($.guard_expr, $.tla_expr))),
+
+        guard_quantifier: $ =>
+            seq(
+                choice('\\E', '\\A'),
+                'Relation',
+                field('relation', $.identifier),
+                field('var', $.identifier),
+                'by',
+                $.expr,
+                ':',
+                alias($.guard_expr, $.tla_expr)
+            ),
+
         edge_export_block: $ =>
             seq(
                 '{',
@@ -438,7 +499,8 @@ export default grammar({
                 'null'
             ),
 
-        number: _ => /\d+/,
+        // an int, or a float with digits on both sides of the dot
+        number: _ => /\d+(\.\d+)?/,
 
         boolean: _ => choice('true', 'false'),
 
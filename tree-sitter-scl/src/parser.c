@@ -7,11 +7,11 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 264
+#define STATE_COUNT 290
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 132
+#define SYMBOL_COUNT 139
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 72
+#define TOKEN_COUNT 73
 #define EXTERNAL_TOKEN_COUNT 0
 #define FIELD_COUNT 16
 #define MAX_ALIAS_SEQUENCE_LENGTH 13
@@ -27,35 +27,35 @@ enum ts_symbol_identifiers {
   anon_sym_COLON = 5,
   anon_sym_SEMI = 6,
   anon_sym_context = 7,
-  anon_sym_state = 8,
-  anon_sym_uses = 9,
-  anon_sym_COMMA = 10,
-  anon_sym_use = 11,
-  anon_sym_precondition = 12,
-  anon_sym_pre = 13,
-  anon_sym_let = 14,
-  anon_sym_EQ = 15,
-  anon_sym_select = 16,
-  anon_sym_from = 17,
-  anon_sym_foreach = 18,
-  anon_sym_in = 19,
-  anon_sym_update = 20,
-  anon_sym_set = 21,
-  anon_sym_insert = 22,
-  anon_sym_into = 23,
-  anon_sym_LPAREN = 24,
-  anon_sym_RPAREN = 25,
-  anon_sym_values = 26,
-  anon_sym_delete = 27,
-  anon_sym_where = 28,
-  anon_sym_limit = 29,
-  anon_sym_assert = 30,
-  anon_sym_commit = 31,
-  anon_sym_next = 32,
-  anon_sym_when = 33,
-  anon_sym_EQ_GT = 34,
-  anon_sym_otherwise = 35,
-  anon_sym_export = 36,
+  anon_sym_invariant = 8,
+  anon_sym_state = 9,
+  anon_sym_uses = 10,
+  anon_sym_COMMA = 11,
+  anon_sym_use = 12,
+  anon_sym_precondition = 13,
+  anon_sym_pre = 14,
+  anon_sym_let = 15,
+  anon_sym_EQ = 16,
+  anon_sym_select = 17,
+  anon_sym_from = 18,
+  anon_sym_foreach = 19,
+  anon_sym_in = 20,
+  anon_sym_update = 21,
+  anon_sym_set = 22,
+  anon_sym_insert = 23,
+  anon_sym_into = 24,
+  anon_sym_LPAREN = 25,
+  anon_sym_RPAREN = 26,
+  anon_sym_values = 27,
+  anon_sym_delete = 28,
+  anon_sym_where = 29,
+  anon_sym_limit = 30,
+  anon_sym_assert = 31,
+  anon_sym_commit = 32,
+  anon_sym_next = 33,
+  anon_sym_when = 34,
+  anon_sym_EQ_GT = 35,
+  anon_sym_otherwise = 36,
   anon_sym_BSLASH_SLASH = 37,
   anon_sym_SLASH_BSLASH = 38,
   anon_sym_TILDE = 39,
@@ -63,94 +63,101 @@ enum ts_symbol_identifiers {
   anon_sym_BSLASHA = 41,
   anon_sym_Relation = 42,
   anon_sym_by = 43,
-  anon_sym_POUND = 44,
-  anon_sym_EQ_EQ = 45,
-  anon_sym_BANG_EQ = 46,
-  anon_sym_LT = 47,
-  anon_sym_LT_EQ = 48,
-  anon_sym_GT = 49,
-  anon_sym_GT_EQ = 50,
-  anon_sym_PLUS = 51,
-  anon_sym_DASH = 52,
-  anon_sym_STAR = 53,
-  anon_sym_SLASH = 54,
-  anon_sym_BANG = 55,
-  anon_sym_DOT = 56,
-  anon_sym_Int = 57,
-  anon_sym_Bool = 58,
-  anon_sym_String = 59,
-  anon_sym_Float = 60,
-  anon_sym_LBRACK = 61,
-  anon_sym_RBRACK = 62,
-  anon_sym_COLON_COLON = 63,
-  anon_sym_null = 64,
-  sym_number = 65,
-  anon_sym_true = 66,
-  anon_sym_false = 67,
-  anon_sym_DQUOTE = 68,
-  aux_sym_string_token1 = 69,
-  aux_sym_string_token2 = 70,
-  sym_comment = 71,
-  sym_program = 72,
-  sym_type_decl = 73,
-  sym_type_field = 74,
-  sym_context_decl = 75,
-  sym_context_field = 76,
-  sym_state_decl = 77,
-  sym_use_stmt = 78,
-  sym_use_state_stmt = 79,
-  sym_use_context_stmt = 80,
-  sym_use_type_stmt = 81,
-  sym_use_field = 82,
-  sym_precondition_block = 83,
-  sym_statement = 84,
-  sym_let_stmt = 85,
-  sym_select_stmt = 86,
-  sym_foreach_stmt = 87,
-  sym_update_stmt = 88,
-  sym_assignment = 89,
-  sym_insert_stmt = 90,
-  sym_delete_stmt = 91,
-  sym_where_clause = 92,
-  sym_limit_clause = 93,
-  sym_assert_stmt = 94,
-  sym_commit_stmt = 95,
-  sym_next_block = 96,
-  sym_next_case = 97,
-  sym_edge_export_block = 98,
-  sym_edge_field = 99,
-  sym_condition = 100,
-  sym_tla_expr = 101,
-  sym_tla_binary_expr = 102,
-  sym_tla_unary_expr = 103,
-  sym_tla_quantifier = 104,
-  sym_expr = 105,
-  sym_struct_literal = 106,
-  sym_struct_field = 107,
-  sym_binary_expr = 108,
-  sym_unary_expr = 109,
-  sym_field_access = 110,
-  sym_type = 111,
-  sym_primitive_type = 112,
-  sym_generic_type = 113,
-  sym_qualified_name = 114,
-  sym_literal = 115,
-  sym_boolean = 116,
-  sym_string = 117,
-  aux_sym_program_repeat1 = 118,
-  aux_sym_type_decl_repeat1 = 119,
-  aux_sym_context_decl_repeat1 = 120,
-  aux_sym_state_decl_repeat1 = 121,
-  aux_sym_state_decl_repeat2 = 122,
-  aux_sym_state_decl_repeat3 = 123,
-  aux_sym_use_state_stmt_repeat1 = 124,
-  aux_sym_precondition_block_repeat1 = 125,
-  aux_sym_update_stmt_repeat1 = 126,
-  aux_sym_insert_stmt_repeat1 = 127,
-  aux_sym_next_block_repeat1 = 128,
-  aux_sym_edge_export_block_repeat1 = 129,
-  aux_sym_struct_literal_repeat1 = 130,
-  aux_sym_string_repeat1 = 131,
+  anon_sym_export = 44,
+  anon_sym_POUND = 45,
+  anon_sym_EQ_EQ = 46,
+  anon_sym_BANG_EQ = 47,
+  anon_sym_LT = 48,
+  anon_sym_LT_EQ = 49,
+  anon_sym_GT = 50,
+  anon_sym_GT_EQ = 51,
+  anon_sym_PLUS = 52,
+  anon_sym_DASH = 53,
+  anon_sym_STAR = 54,
+  anon_sym_SLASH = 55,
+  anon_sym_BANG = 56,
+  anon_sym_DOT = 57,
+  anon_sym_int = 58,
+  anon_sym_bool = 59,
+  anon_sym_string = 60,
+  anon_sym_float = 61,
+  anon_sym_LBRACK = 62,
+  anon_sym_RBRACK = 63,
+  anon_sym_COLON_COLON = 64,
+  anon_sym_null = 65,
+  sym_number = 66,
+  anon_sym_true = 67,
+  anon_sym_false = 68,
+  anon_sym_DQUOTE = 69,
+  aux_sym_string_token1 = 70,
+  aux_sym_string_token2 = 71,
+  sym_comment = 72,
+  sym_program = 73,
+  sym_type_decl = 74,
+  sym_type_field = 75,
+  sym_context_decl = 76,
+  sym_context_field = 77,
+  sym_invariant_decl = 78,
+  sym_state_decl = 79,
+  sym_use_stmt = 80,
+  sym_use_state_stmt = 81,
+  sym_use_context_stmt = 82,
+  sym_use_type_stmt = 83,
+  sym_use_field = 84,
+  sym_precondition_block = 85,
+  sym_statement = 86,
+  sym_let_stmt = 87,
+  sym_select_stmt = 88,
+  sym_foreach_stmt = 89,
+  sym_update_stmt = 90,
+  sym_assignment = 91,
+  sym_insert_stmt = 92,
+  sym_delete_stmt = 93,
+  sym_where_clause = 94,
+  sym_limit_clause = 95,
+  sym_assert_stmt = 96,
+  sym_commit_stmt = 97,
+  sym_next_block = 98,
+  sym_next_case = 99,
+  sym_guard = 100,
+  sym_guard_expr = 101,
+  sym_guard_binary_expr = 102,
+  sym_guard_unary_expr = 103,
+  sym_guard_quantifier = 104,
+  sym_edge_export_block = 105,
+  sym_edge_field = 106,
+  sym_condition = 107,
+  sym_tla_expr = 108,
+  sym_tla_binary_expr = 109,
+  sym_tla_unary_expr = 110,
+  sym_tla_quantifier = 111,
+  sym_expr = 112,
+  sym_struct_literal = 113,
+  sym_struct_field = 114,
+  sym_binary_expr = 115,
+  sym_unary_expr = 116,
+  sym_field_access = 117,
+  sym_type = 118,
+  sym_primitive_type = 119,
+  sym_generic_type = 120,
+  sym_qualified_name = 121,
+  sym_literal = 122,
+  sym_boolean = 123,
+  sym_string = 124,
+  aux_sym_program_repeat1 = 125,
+  aux_sym_type_decl_repeat1 = 126,
+  aux_sym_context_decl_repeat1 = 127,
+  aux_sym_invariant_decl_repeat1 = 128,
+  aux_sym_state_decl_repeat1 = 129,
+  aux_sym_state_decl_repeat2 = 130,
+  aux_sym_state_decl_repeat3 = 131,
+  aux_sym_use_state_stmt_repeat1 = 132,
+  aux_sym_update_stmt_repeat1 = 133,
+  aux_sym_insert_stmt_repeat1 = 134,
+  aux_sym_next_block_repeat1 = 135,
+  aux_sym_edge_export_block_repeat1 = 136,
+  aux_sym_struct_literal_repeat1 = 137,
+  aux_sym_string_repeat1 = 138,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -162,6 +169,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_COLON] = ":",
   [anon_sym_SEMI] = ";",
   [anon_sym_context] = "context",
+  [anon_sym_invariant] = "invariant",
   [anon_sym_state] = "state",
   [anon_sym_uses] = "uses",
   [anon_sym_COMMA] = ",",
@@ -190,7 +198,6 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_when] = "when",
   [anon_sym_EQ_GT] = "=>",
   [anon_sym_otherwise] = "otherwise",
-  [anon_sym_export] = "export",
   [anon_sym_BSLASH_SLASH] = "\\/",
   [anon_sym_SLASH_BSLASH] = "/\\",
   [anon_sym_TILDE] = "~",
@@ -198,6 +205,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_BSLASHA] = "\\A",
   [anon_sym_Relation] = "Relation",
   [anon_sym_by] = "by",
+  [anon_sym_export] = "export",
   [anon_sym_POUND] = "#",
   [anon_sym_EQ_EQ] = "==",
   [anon_sym_BANG_EQ] = "!=",
@@ -211,10 +219,10 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_SLASH] = "/",
   [anon_sym_BANG] = "!",
   [anon_sym_DOT] = ".",
-  [anon_sym_Int] = "Int",
-  [anon_sym_Bool] = "Bool",
-  [anon_sym_String] = "String",
-  [anon_sym_Float] = "Float",
+  [anon_sym_int] = "int",
+  [anon_sym_bool] = "bool",
+  [anon_sym_string] = "string",
+  [anon_sym_float] = "float",
   [anon_sym_LBRACK] = "[",
   [anon_sym_RBRACK] = "]",
   [anon_sym_COLON_COLON] = "::",
@@ -231,6 +239,7 @@ static const char * const ts_symbol_names[] = {
   [sym_type_field] = "type_field",
   [sym_context_decl] = "context_decl",
   [sym_context_field] = "context_field",
+  [sym_invariant_decl] = "invariant_decl",
   [sym_state_decl] = "state_decl",
   [sym_use_stmt] = "use_stmt",
   [sym_use_state_stmt] = "use_state_stmt",
@@ -252,6 +261,11 @@ static const char * const ts_symbol_names[] = {
   [sym_commit_stmt] = "commit_stmt",
   [sym_next_block] = "next_block",
   [sym_next_case] = "next_case",
+  [sym_guard] = "condition",
+  [sym_guard_expr] = "tla_expr",
+  [sym_guard_binary_expr] = "tla_binary_expr",
+  [sym_guard_unary_expr] = "tla_unary_expr",
+  [sym_guard_quantifier] = "tla_quantifier",
   [sym_edge_export_block] = "edge_export_block",
   [sym_edge_field] = "edge_field",
   [sym_condition] = "condition",
@@ -275,11 +289,11 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_program_repeat1] = "program_repeat1",
   [aux_sym_type_decl_repeat1] = "type_decl_repeat1",
   [aux_sym_context_decl_repeat1] = "context_decl_repeat1",
+  [aux_sym_invariant_decl_repeat1] = "invariant_decl_repeat1",
   [aux_sym_state_decl_repeat1] = "state_decl_repeat1",
   [aux_sym_state_decl_repeat2] = "state_decl_repeat2",
   [aux_sym_state_decl_repeat3] = "state_decl_repeat3",
   [aux_sym_use_state_stmt_repeat1] = "use_state_stmt_repeat1",
-  [aux_sym_precondition_block_repeat1] = "precondition_block_repeat1",
   [aux_sym_update_stmt_repeat1] = "update_stmt_repeat1",
   [aux_sym_insert_stmt_repeat1] = "insert_stmt_repeat1",
   [aux_sym_next_block_repeat1] = "next_block_repeat1",
@@ -297,6 +311,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_COLON] = anon_sym_COLON,
   [anon_sym_SEMI] = anon_sym_SEMI,
   [anon_sym_context] = anon_sym_context,
+  [anon_sym_invariant] = anon_sym_invariant,
   [anon_sym_state] = anon_sym_state,
   [anon_sym_uses] = anon_sym_uses,
   [anon_sym_COMMA] = anon_sym_COMMA,
@@ -325,7 +340,6 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_when] = anon_sym_when,
   [anon_sym_EQ_GT] = anon_sym_EQ_GT,
   [anon_sym_otherwise] = anon_sym_otherwise,
-  [anon_sym_export] = anon_sym_export,
   [anon_sym_BSLASH_SLASH] = anon_sym_BSLASH_SLASH,
   [anon_sym_SLASH_BSLASH] = anon_sym_SLASH_BSLASH,
   [anon_sym_TILDE] = anon_sym_TILDE,
@@ -333,6 +347,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_BSLASHA] = anon_sym_BSLASHA,
   [anon_sym_Relation] = anon_sym_Relation,
   [anon_sym_by] = anon_sym_by,
+  [anon_sym_export] = anon_sym_export,
   [anon_sym_POUND] = anon_sym_POUND,
   [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
   [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
@@ -346,10 +361,10 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_SLASH] = anon_sym_SLASH,
   [anon_sym_BANG] = anon_sym_BANG,
   [anon_sym_DOT] = anon_sym_DOT,
-  [anon_sym_Int] = anon_sym_Int,
-  [anon_sym_Bool] = anon_sym_Bool,
-  [anon_sym_String] = anon_sym_String,
-  [anon_sym_Float] = anon_sym_Float,
+  [anon_sym_int] = anon_sym_int,
+  [anon_sym_bool] = anon_sym_bool,
+  [anon_sym_string] = anon_sym_string,
+  [anon_sym_float] = anon_sym_float,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
   [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
@@ -366,6 +381,7 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_type_field] = sym_type_field,
   [sym_context_decl] = sym_context_decl,
   [sym_context_field] = sym_context_field,
+  [sym_invariant_decl] = sym_invariant_decl,
   [sym_state_decl] = sym_state_decl,
   [sym_use_stmt] = sym_use_stmt,
   [sym_use_state_stmt] = sym_use_state_stmt,
@@ -387,6 +403,11 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_commit_stmt] = sym_commit_stmt,
   [sym_next_block] = sym_next_block,
   [sym_next_case] = sym_next_case,
+  [sym_guard] = sym_condition,
+  [sym_guard_expr] = sym_tla_expr,
+  [sym_guard_binary_expr] = sym_tla_binary_expr,
+  [sym_guard_unary_expr] = sym_tla_unary_expr,
+  [sym_guard_quantifier] = sym_tla_quantifier,
   [sym_edge_export_block] = sym_edge_export_block,
   [sym_edge_field] = sym_edge_field,
   [sym_condition] = sym_condition,
@@ -410,11 +431,11 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_program_repeat1] = aux_sym_program_repeat1,
   [aux_sym_type_decl_repeat1] = aux_sym_type_decl_repeat1,
   [aux_sym_context_decl_repeat1] = aux_sym_context_decl_repeat1,
+  [aux_sym_invariant_decl_repeat1] = aux_sym_invariant_decl_repeat1,
   [aux_sym_state_decl_repeat1] = aux_sym_state_decl_repeat1,
   [aux_sym_state_decl_repeat2] = aux_sym_state_decl_repeat2,
   [aux_sym_state_decl_repeat3] = aux_sym_state_decl_repeat3,
   [aux_sym_use_state_stmt_repeat1] = aux_sym_use_state_stmt_repeat1,
-  [aux_sym_precondition_block_repeat1] = aux_sym_precondition_block_repeat1,
   [aux_sym_update_stmt_repeat1] = aux_sym_update_stmt_repeat1,
   [aux_sym_insert_stmt_repeat1] = aux_sym_insert_stmt_repeat1,
   [aux_sym_next_block_repeat1] = aux_sym_next_block_repeat1,
@@ -456,6 +477,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_invariant] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_state] = {
     .visible = true,
     .named = false,
@@ -568,10 +593,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_export] = {
-    .visible = true,
-    .named = false,
-  },
   [anon_sym_BSLASH_SLASH] = {
     .visible = true,
     .named = false,
@@ -600,6 +621,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_export] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_POUND] = {
     .visible = true,
     .named = false,
@@ -652,19 +677,19 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_Int] = {
+  [anon_sym_int] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_Bool] = {
+  [anon_sym_bool] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_String] = {
+  [anon_sym_string] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_Float] = {
+  [anon_sym_float] = {
     .visible = true,
     .named = false,
   },
@@ -732,6 +757,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_invariant_decl] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_state_decl] = {
     .visible = true,
     .named = true,
@@ -816,6 +845,26 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_guard] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_guard_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_guard_binary_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_guard_unary_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_guard_quantifier] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_edge_export_block] = {
     .visible = true,
     .named = true,
@@ -908,6 +957,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_invariant_decl_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_state_decl_repeat1] = {
     .visible = false,
     .named = false,
@@ -924,10 +977,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-  [aux_sym_precondition_block_repeat1] = {
-    .visible = false,
-    .named = false,
-  },
   [aux_sym_update_stmt_repeat1] = {
     .visible = false,
     .named = false,
@@ -997,69 +1046,69 @@ static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
   [2] = {.index = 1, .length = 2},
   [3] = {.index = 3, .length = 2},
-  [4] = {.index = 5, .length = 1},
-  [5] = {.index = 6, .length = 1},
-  [6] = {.index = 7, .length = 1},
-  [7] = {.index = 8, .length = 2},
-  [8] = {.index = 10, .length = 1},
-  [9] = {.index = 11, .length = 2},
+  [4] = {.index = 5, .length = 2},
+  [5] = {.index = 7, .length = 2},
+  [6] = {.index = 9, .length = 1},
+  [7] = {.index = 10, .length = 1},
+  [8] = {.index = 11, .length = 1},
+  [9] = {.index = 12, .length = 1},
   [10] = {.index = 13, .length = 1},
-  [11] = {.index = 14, .length = 1},
-  [12] = {.index = 15, .length = 2},
-  [13] = {.index = 17, .length = 1},
-  [14] = {.index = 18, .length = 3},
-  [15] = {.index = 21, .length = 3},
-  [16] = {.index = 24, .length = 3},
-  [17] = {.index = 27, .length = 2},
+  [11] = {.index = 14, .length = 2},
+  [12] = {.index = 16, .length = 1},
+  [13] = {.index = 17, .length = 2},
+  [14] = {.index = 19, .length = 1},
+  [15] = {.index = 20, .length = 3},
+  [16] = {.index = 23, .length = 3},
+  [17] = {.index = 26, .length = 3},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
     {field_name, 1},
   [1] =
+    {field_field, 2},
+    {field_object, 0},
+  [3] =
     {field_name, 0},
     {field_type, 2},
-  [3] =
+  [5] =
+    {field_name, 0},
+    {field_value, 2},
+  [7] =
     {field_base, 0},
     {field_param, 2},
-  [5] =
+  [9] =
     {field_type, 2},
-  [6] =
+  [10] =
     {field_context, 2},
-  [7] =
+  [11] =
     {field_entity, 2},
-  [8] =
-    {field_field, 2},
-    {field_object, 0},
-  [10] =
+  [12] =
     {field_entity, 1},
-  [11] =
-    {field_name, 0},
-    {field_value, 2},
   [13] =
     {field_target, 2},
   [14] =
+    {field_relation, 2},
+    {field_var, 3},
+  [16] =
     {field_source, 2},
-  [15] =
+  [17] =
     {field_field, 0},
     {field_value, 2},
-  [17] =
+  [19] =
     {field_target, 3},
-  [18] =
+  [20] =
     {field_name, 1},
     {field_type, 3},
     {field_value, 5},
-  [21] =
+  [23] =
     {field_entity, 5},
     {field_name, 1},
     {field_type, 3},
-  [24] =
+  [26] =
     {field_collection, 5},
     {field_item, 1},
     {field_item_type, 3},
-  [27] =
-    {field_relation, 2},
-    {field_var, 3},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -1335,6 +1384,32 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [261] = 261,
   [262] = 262,
   [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1342,309 +1417,327 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(15);
+      if (eof) ADVANCE(18);
       ADVANCE_MAP(
-        '!', 43,
-        '"', 49,
-        '#', 31,
-        '(', 23,
-        ')', 24,
-        '*', 40,
-        '+', 38,
-        ',', 21,
-        '-', 39,
-        '.', 44,
-        '/', 41,
-        ':', 19,
-        ';', 20,
-        '<', 34,
-        '=', 22,
-        '>', 36,
-        '[', 45,
-        '\\', 10,
-        ']', 46,
-        '{', 16,
-        '}', 17,
-        '~', 28,
+        '!', 46,
+        '"', 53,
+        '#', 34,
+        '(', 26,
+        ')', 27,
+        '*', 43,
+        '+', 41,
+        ',', 24,
+        '-', 42,
+        '.', 47,
+        '/', 44,
+        ':', 22,
+        ';', 23,
+        '<', 37,
+        '=', 25,
+        '>', 39,
+        '[', 48,
+        '\\', 11,
+        ']', 49,
+        '{', 19,
+        '}', 20,
+        '~', 31,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(48);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(51);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(54);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(58);
       END_STATE();
     case 1:
       ADVANCE_MAP(
-        '!', 42,
-        '"', 49,
-        '(', 23,
-        ')', 24,
-        '-', 39,
-        '/', 5,
-        ':', 11,
-        ';', 20,
-        '=', 13,
-        '\\', 9,
-        '{', 16,
-        '}', 17,
-        '~', 28,
+        '!', 45,
+        '"', 53,
+        '(', 26,
+        ')', 27,
+        '-', 42,
+        '/', 6,
+        ':', 21,
+        ';', 23,
+        '=', 15,
+        '\\', 10,
+        '{', 19,
+        '}', 20,
+        '~', 31,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(1);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(48);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(51);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(54);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(58);
       END_STATE();
     case 2:
       ADVANCE_MAP(
-        '!', 12,
-        '#', 31,
-        ')', 24,
-        '*', 40,
-        '+', 38,
-        ',', 21,
-        '-', 39,
-        '.', 44,
-        '/', 41,
-        ':', 18,
-        ';', 20,
-        '<', 34,
-        '=', 22,
-        '>', 36,
-        '\\', 8,
-        '}', 17,
+        '!', 14,
+        '#', 34,
+        ')', 27,
+        '*', 43,
+        '+', 41,
+        ',', 24,
+        '-', 42,
+        '.', 47,
+        '/', 44,
+        ':', 21,
+        ';', 23,
+        '<', 37,
+        '=', 25,
+        '>', 39,
+        '\\', 9,
+        '}', 20,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(2);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(54);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(58);
       END_STATE();
     case 3:
       ADVANCE_MAP(
-        '!', 12,
-        '#', 31,
-        ')', 24,
-        '*', 40,
-        '+', 38,
-        ',', 21,
-        '-', 39,
-        '/', 41,
-        ':', 19,
-        ';', 20,
-        '<', 34,
-        '=', 22,
-        '>', 36,
-        '\\', 8,
-        '}', 17,
+        '!', 14,
+        '#', 34,
+        ')', 27,
+        '*', 43,
+        '+', 41,
+        ',', 24,
+        '-', 42,
+        '/', 44,
+        ':', 22,
+        ';', 23,
+        '<', 37,
+        '=', 25,
+        '>', 39,
+        '\\', 9,
+        '}', 20,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(3);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(54);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(58);
       END_STATE();
     case 4:
-      if (lookahead == '"') ADVANCE(49);
-      if (lookahead == '/') ADVANCE(51);
-      if (lookahead == '\\') ADVANCE(14);
+      if (lookahead == '"') ADVANCE(53);
+      if (lookahead == '/') ADVANCE(55);
+      if (lookahead == '\\') ADVANCE(17);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(52);
-      if (lookahead != 0) ADVANCE(50);
+          lookahead == ' ') ADVANCE(56);
+      if (lookahead != 0) ADVANCE(54);
       END_STATE();
     case 5:
-      if (lookahead == '*') ADVANCE(7);
-      if (lookahead == '/') ADVANCE(56);
-      if (lookahead == '\\') ADVANCE(27);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(60);
       END_STATE();
     case 6:
-      if (lookahead == '*') ADVANCE(6);
-      if (lookahead == '/') ADVANCE(55);
-      if (lookahead != 0) ADVANCE(7);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(60);
+      if (lookahead == '\\') ADVANCE(30);
       END_STATE();
     case 7:
-      if (lookahead == '*') ADVANCE(6);
-      if (lookahead != 0) ADVANCE(7);
+      if (lookahead == '*') ADVANCE(7);
+      if (lookahead == '/') ADVANCE(59);
+      if (lookahead != 0) ADVANCE(8);
       END_STATE();
     case 8:
-      if (lookahead == '/') ADVANCE(26);
+      if (lookahead == '*') ADVANCE(7);
+      if (lookahead != 0) ADVANCE(8);
       END_STATE();
     case 9:
-      if (lookahead == '/') ADVANCE(26);
-      if (lookahead == 'A') ADVANCE(30);
-      if (lookahead == 'E') ADVANCE(29);
+      if (lookahead == '/') ADVANCE(29);
       END_STATE();
     case 10:
-      if (lookahead == '/') ADVANCE(26);
-      if (lookahead == 'A') ADVANCE(30);
-      if (lookahead == 'E') ADVANCE(29);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(53);
+      if (lookahead == '/') ADVANCE(29);
+      if (lookahead == 'A') ADVANCE(33);
+      if (lookahead == 'E') ADVANCE(32);
       END_STATE();
     case 11:
-      if (lookahead == ':') ADVANCE(47);
+      if (lookahead == '/') ADVANCE(29);
+      if (lookahead == 'A') ADVANCE(33);
+      if (lookahead == 'E') ADVANCE(32);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(57);
       END_STATE();
     case 12:
-      if (lookahead == '=') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(5);
+      if (lookahead == ':') ADVANCE(13);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(12);
       END_STATE();
     case 13:
-      if (lookahead == '>') ADVANCE(25);
+      if (lookahead == ':') ADVANCE(50);
       END_STATE();
     case 14:
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(53);
+      if (lookahead == '=') ADVANCE(36);
       END_STATE();
     case 15:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == '>') ADVANCE(28);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(52);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(57);
       END_STATE();
     case 18:
-      ACCEPT_TOKEN(anon_sym_COLON);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_COLON);
-      if (lookahead == ':') ADVANCE(47);
+      ACCEPT_TOKEN(anon_sym_LBRACE);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(anon_sym_SEMI);
+      ACCEPT_TOKEN(anon_sym_RBRACE);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      ACCEPT_TOKEN(anon_sym_COLON);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_EQ);
-      if (lookahead == '=') ADVANCE(32);
-      if (lookahead == '>') ADVANCE(25);
+      ACCEPT_TOKEN(anon_sym_COLON);
+      if (lookahead == ':') ADVANCE(50);
       END_STATE();
     case 23:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      ACCEPT_TOKEN(anon_sym_SEMI);
       END_STATE();
     case 24:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(anon_sym_EQ_GT);
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '=') ADVANCE(35);
+      if (lookahead == '>') ADVANCE(28);
       END_STATE();
     case 26:
-      ACCEPT_TOKEN(anon_sym_BSLASH_SLASH);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(anon_sym_SLASH_BSLASH);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(anon_sym_TILDE);
+      ACCEPT_TOKEN(anon_sym_EQ_GT);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(anon_sym_BSLASHE);
+      ACCEPT_TOKEN(anon_sym_BSLASH_SLASH);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(anon_sym_BSLASHA);
+      ACCEPT_TOKEN(anon_sym_SLASH_BSLASH);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(anon_sym_POUND);
+      ACCEPT_TOKEN(anon_sym_TILDE);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(anon_sym_EQ_EQ);
+      ACCEPT_TOKEN(anon_sym_BSLASHE);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(anon_sym_BANG_EQ);
+      ACCEPT_TOKEN(anon_sym_BSLASHA);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(35);
+      ACCEPT_TOKEN(anon_sym_POUND);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_LT_EQ);
+      ACCEPT_TOKEN(anon_sym_EQ_EQ);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(37);
+      ACCEPT_TOKEN(anon_sym_BANG_EQ);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(anon_sym_GT_EQ);
+      ACCEPT_TOKEN(anon_sym_LT);
+      if (lookahead == '=') ADVANCE(38);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_PLUS);
+      ACCEPT_TOKEN(anon_sym_LT_EQ);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_DASH);
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(40);
       END_STATE();
     case 40:
-      ACCEPT_TOKEN(anon_sym_STAR);
+      ACCEPT_TOKEN(anon_sym_GT_EQ);
       END_STATE();
     case 41:
-      ACCEPT_TOKEN(anon_sym_SLASH);
-      if (lookahead == '*') ADVANCE(7);
-      if (lookahead == '/') ADVANCE(56);
-      if (lookahead == '\\') ADVANCE(27);
+      ACCEPT_TOKEN(anon_sym_PLUS);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_BANG);
+      ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(anon_sym_BANG);
-      if (lookahead == '=') ADVANCE(33);
+      ACCEPT_TOKEN(anon_sym_STAR);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(60);
+      if (lookahead == '\\') ADVANCE(30);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      ACCEPT_TOKEN(anon_sym_BANG);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(36);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      ACCEPT_TOKEN(anon_sym_DOT);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(48);
+      ACCEPT_TOKEN(anon_sym_LBRACK);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      ACCEPT_TOKEN(anon_sym_RBRACK);
       END_STATE();
     case 50:
-      ACCEPT_TOKEN(aux_sym_string_token1);
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
       END_STATE();
     case 51:
-      ACCEPT_TOKEN(aux_sym_string_token1);
-      if (lookahead == '*') ADVANCE(7);
-      if (lookahead == '/') ADVANCE(56);
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(16);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(51);
       END_STATE();
     case 52:
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(52);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(aux_sym_string_token1);
+      if (lookahead == '*') ADVANCE(8);
+      if (lookahead == '/') ADVANCE(60);
+      END_STATE();
+    case 56:
       ACCEPT_TOKEN(aux_sym_string_token1);
-      if (lookahead == '/') ADVANCE(51);
+      if (lookahead == '/') ADVANCE(55);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(52);
+          lookahead == ' ') ADVANCE(56);
       if (lookahead != 0 &&
           lookahead != '"' &&
-          lookahead != '\\') ADVANCE(50);
+          lookahead != '\\') ADVANCE(54);
       END_STATE();
-    case 53:
+    case 57:
       ACCEPT_TOKEN(aux_sym_string_token2);
       END_STATE();
-    case 54:
+    case 58:
       ACCEPT_TOKEN(sym_identifier);
       if (('0' <= lookahead && lookahead <= '9') ||
           ('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(54);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(58);
       END_STATE();
-    case 55:
+    case 59:
       ACCEPT_TOKEN(sym_comment);
       END_STATE();
-    case 56:
+    case 60:
       ACCEPT_TOKEN(sym_comment);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(56);
+          lookahead != '\n') ADVANCE(60);
       END_STATE();
     default:
       return false;
@@ -1657,488 +1750,489 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
   switch (state) {
     case 0:
       ADVANCE_MAP(
-        'B', 1,
-        'F', 2,
-        'I', 3,
-        'R', 4,
-        'S', 5,
-        'a', 6,
-        'b', 7,
-        'c', 8,
-        'd', 9,
-        'e', 10,
-        'f', 11,
-        'i', 12,
-        'l', 13,
-        'n', 14,
-        'o', 15,
-        'p', 16,
-        's', 17,
-        't', 18,
-        'u', 19,
-        'v', 20,
-        'w', 21,
+        'R', 1,
+        'a', 2,
+        'b', 3,
+        'c', 4,
+        'd', 5,
+        'e', 6,
+        'f', 7,
+        'i', 8,
+        'l', 9,
+        'n', 10,
+        'o', 11,
+        'p', 12,
+        's', 13,
+        't', 14,
+        'u', 15,
+        'v', 16,
+        'w', 17,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
       END_STATE();
     case 1:
-      if (lookahead == 'o') ADVANCE(22);
+      if (lookahead == 'e') ADVANCE(18);
       END_STATE();
     case 2:
-      if (lookahead == 'l') ADVANCE(23);
+      if (lookahead == 's') ADVANCE(19);
       END_STATE();
     case 3:
-      if (lookahead == 'n') ADVANCE(24);
+      if (lookahead == 'o') ADVANCE(20);
+      if (lookahead == 'y') ADVANCE(21);
       END_STATE();
     case 4:
-      if (lookahead == 'e') ADVANCE(25);
+      if (lookahead == 'o') ADVANCE(22);
       END_STATE();
     case 5:
-      if (lookahead == 't') ADVANCE(26);
+      if (lookahead == 'e') ADVANCE(23);
       END_STATE();
     case 6:
-      if (lookahead == 's') ADVANCE(27);
+      if (lookahead == 'x') ADVANCE(24);
       END_STATE();
     case 7:
-      if (lookahead == 'y') ADVANCE(28);
+      if (lookahead == 'a') ADVANCE(25);
+      if (lookahead == 'l') ADVANCE(26);
+      if (lookahead == 'o') ADVANCE(27);
+      if (lookahead == 'r') ADVANCE(28);
       END_STATE();
     case 8:
-      if (lookahead == 'o') ADVANCE(29);
+      if (lookahead == 'n') ADVANCE(29);
       END_STATE();
     case 9:
       if (lookahead == 'e') ADVANCE(30);
+      if (lookahead == 'i') ADVANCE(31);
       END_STATE();
     case 10:
-      if (lookahead == 'x') ADVANCE(31);
+      if (lookahead == 'e') ADVANCE(32);
+      if (lookahead == 'u') ADVANCE(33);
       END_STATE();
     case 11:
-      if (lookahead == 'a') ADVANCE(32);
-      if (lookahead == 'o') ADVANCE(33);
-      if (lookahead == 'r') ADVANCE(34);
+      if (lookahead == 't') ADVANCE(34);
       END_STATE();
     case 12:
-      if (lookahead == 'n') ADVANCE(35);
+      if (lookahead == 'r') ADVANCE(35);
       END_STATE();
     case 13:
       if (lookahead == 'e') ADVANCE(36);
-      if (lookahead == 'i') ADVANCE(37);
+      if (lookahead == 't') ADVANCE(37);
       END_STATE();
     case 14:
-      if (lookahead == 'e') ADVANCE(38);
-      if (lookahead == 'u') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(38);
+      if (lookahead == 'y') ADVANCE(39);
       END_STATE();
     case 15:
-      if (lookahead == 't') ADVANCE(40);
+      if (lookahead == 'p') ADVANCE(40);
+      if (lookahead == 's') ADVANCE(41);
       END_STATE();
     case 16:
-      if (lookahead == 'r') ADVANCE(41);
+      if (lookahead == 'a') ADVANCE(42);
       END_STATE();
     case 17:
-      if (lookahead == 'e') ADVANCE(42);
-      if (lookahead == 't') ADVANCE(43);
+      if (lookahead == 'h') ADVANCE(43);
       END_STATE();
     case 18:
-      if (lookahead == 'r') ADVANCE(44);
-      if (lookahead == 'y') ADVANCE(45);
+      if (lookahead == 'l') ADVANCE(44);
       END_STATE();
     case 19:
-      if (lookahead == 'p') ADVANCE(46);
-      if (lookahead == 's') ADVANCE(47);
+      if (lookahead == 's') ADVANCE(45);
       END_STATE();
     case 20:
-      if (lookahead == 'a') ADVANCE(48);
+      if (lookahead == 'o') ADVANCE(46);
       END_STATE();
     case 21:
-      if (lookahead == 'h') ADVANCE(49);
+      ACCEPT_TOKEN(anon_sym_by);
       END_STATE();
     case 22:
-      if (lookahead == 'o') ADVANCE(50);
+      if (lookahead == 'm') ADVANCE(47);
+      if (lookahead == 'n') ADVANCE(48);
       END_STATE();
     case 23:
-      if (lookahead == 'o') ADVANCE(51);
+      if (lookahead == 'l') ADVANCE(49);
       END_STATE();
     case 24:
-      if (lookahead == 't') ADVANCE(52);
+      if (lookahead == 'p') ADVANCE(50);
       END_STATE();
     case 25:
-      if (lookahead == 'l') ADVANCE(53);
+      if (lookahead == 'l') ADVANCE(51);
       END_STATE();
     case 26:
-      if (lookahead == 'r') ADVANCE(54);
+      if (lookahead == 'o') ADVANCE(52);
       END_STATE();
     case 27:
-      if (lookahead == 's') ADVANCE(55);
+      if (lookahead == 'r') ADVANCE(53);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(anon_sym_by);
+      if (lookahead == 'o') ADVANCE(54);
       END_STATE();
     case 29:
-      if (lookahead == 'm') ADVANCE(56);
-      if (lookahead == 'n') ADVANCE(57);
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 's') ADVANCE(55);
+      if (lookahead == 't') ADVANCE(56);
+      if (lookahead == 'v') ADVANCE(57);
       END_STATE();
     case 30:
-      if (lookahead == 'l') ADVANCE(58);
+      if (lookahead == 't') ADVANCE(58);
       END_STATE();
     case 31:
-      if (lookahead == 'p') ADVANCE(59);
+      if (lookahead == 'm') ADVANCE(59);
       END_STATE();
     case 32:
-      if (lookahead == 'l') ADVANCE(60);
+      if (lookahead == 'x') ADVANCE(60);
       END_STATE();
     case 33:
-      if (lookahead == 'r') ADVANCE(61);
+      if (lookahead == 'l') ADVANCE(61);
       END_STATE();
     case 34:
-      if (lookahead == 'o') ADVANCE(62);
+      if (lookahead == 'h') ADVANCE(62);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(anon_sym_in);
-      if (lookahead == 's') ADVANCE(63);
-      if (lookahead == 't') ADVANCE(64);
+      if (lookahead == 'e') ADVANCE(63);
       END_STATE();
     case 36:
+      if (lookahead == 'l') ADVANCE(64);
       if (lookahead == 't') ADVANCE(65);
       END_STATE();
     case 37:
-      if (lookahead == 'm') ADVANCE(66);
+      if (lookahead == 'a') ADVANCE(66);
+      if (lookahead == 'r') ADVANCE(67);
       END_STATE();
     case 38:
-      if (lookahead == 'x') ADVANCE(67);
+      if (lookahead == 'u') ADVANCE(68);
       END_STATE();
     case 39:
-      if (lookahead == 'l') ADVANCE(68);
+      if (lookahead == 'p') ADVANCE(69);
       END_STATE();
     case 40:
-      if (lookahead == 'h') ADVANCE(69);
+      if (lookahead == 'd') ADVANCE(70);
       END_STATE();
     case 41:
-      if (lookahead == 'e') ADVANCE(70);
+      if (lookahead == 'e') ADVANCE(71);
       END_STATE();
     case 42:
-      if (lookahead == 'l') ADVANCE(71);
-      if (lookahead == 't') ADVANCE(72);
+      if (lookahead == 'l') ADVANCE(72);
       END_STATE();
     case 43:
-      if (lookahead == 'a') ADVANCE(73);
+      if (lookahead == 'e') ADVANCE(73);
       END_STATE();
     case 44:
-      if (lookahead == 'u') ADVANCE(74);
+      if (lookahead == 'a') ADVANCE(74);
       END_STATE();
     case 45:
-      if (lookahead == 'p') ADVANCE(75);
+      if (lookahead == 'e') ADVANCE(75);
       END_STATE();
     case 46:
-      if (lookahead == 'd') ADVANCE(76);
+      if (lookahead == 'l') ADVANCE(76);
       END_STATE();
     case 47:
-      if (lookahead == 'e') ADVANCE(77);
+      if (lookahead == 'm') ADVANCE(77);
       END_STATE();
     case 48:
-      if (lookahead == 'l') ADVANCE(78);
+      if (lookahead == 't') ADVANCE(78);
       END_STATE();
     case 49:
       if (lookahead == 'e') ADVANCE(79);
       END_STATE();
     case 50:
-      if (lookahead == 'l') ADVANCE(80);
+      if (lookahead == 'o') ADVANCE(80);
       END_STATE();
     case 51:
-      if (lookahead == 'a') ADVANCE(81);
+      if (lookahead == 's') ADVANCE(81);
       END_STATE();
     case 52:
-      ACCEPT_TOKEN(anon_sym_Int);
+      if (lookahead == 'a') ADVANCE(82);
       END_STATE();
     case 53:
-      if (lookahead == 'a') ADVANCE(82);
+      if (lookahead == 'e') ADVANCE(83);
       END_STATE();
     case 54:
-      if (lookahead == 'i') ADVANCE(83);
+      if (lookahead == 'm') ADVANCE(84);
       END_STATE();
     case 55:
-      if (lookahead == 'e') ADVANCE(84);
+      if (lookahead == 'e') ADVANCE(85);
       END_STATE();
     case 56:
-      if (lookahead == 'm') ADVANCE(85);
+      ACCEPT_TOKEN(anon_sym_int);
+      if (lookahead == 'o') ADVANCE(86);
       END_STATE();
     case 57:
-      if (lookahead == 't') ADVANCE(86);
+      if (lookahead == 'a') ADVANCE(87);
       END_STATE();
     case 58:
-      if (lookahead == 'e') ADVANCE(87);
+      ACCEPT_TOKEN(anon_sym_let);
       END_STATE();
     case 59:
-      if (lookahead == 'o') ADVANCE(88);
+      if (lookahead == 'i') ADVANCE(88);
       END_STATE();
     case 60:
-      if (lookahead == 's') ADVANCE(89);
+      if (lookahead == 't') ADVANCE(89);
       END_STATE();
     case 61:
-      if (lookahead == 'e') ADVANCE(90);
+      if (lookahead == 'l') ADVANCE(90);
       END_STATE();
     case 62:
-      if (lookahead == 'm') ADVANCE(91);
+      if (lookahead == 'e') ADVANCE(91);
       END_STATE();
     case 63:
-      if (lookahead == 'e') ADVANCE(92);
+      ACCEPT_TOKEN(anon_sym_pre);
+      if (lookahead == 'c') ADVANCE(92);
       END_STATE();
     case 64:
-      if (lookahead == 'o') ADVANCE(93);
+      if (lookahead == 'e') ADVANCE(93);
       END_STATE();
     case 65:
-      ACCEPT_TOKEN(anon_sym_let);
+      ACCEPT_TOKEN(anon_sym_set);
       END_STATE();
     case 66:
-      if (lookahead == 'i') ADVANCE(94);
+      if (lookahead == 't') ADVANCE(94);
       END_STATE();
     case 67:
-      if (lookahead == 't') ADVANCE(95);
+      if (lookahead == 'i') ADVANCE(95);
       END_STATE();
     case 68:
-      if (lookahead == 'l') ADVANCE(96);
+      if (lookahead == 'e') ADVANCE(96);
       END_STATE();
     case 69:
       if (lookahead == 'e') ADVANCE(97);
       END_STATE();
     case 70:
-      ACCEPT_TOKEN(anon_sym_pre);
-      if (lookahead == 'c') ADVANCE(98);
+      if (lookahead == 'a') ADVANCE(98);
       END_STATE();
     case 71:
-      if (lookahead == 'e') ADVANCE(99);
+      ACCEPT_TOKEN(anon_sym_use);
+      if (lookahead == 's') ADVANCE(99);
       END_STATE();
     case 72:
-      ACCEPT_TOKEN(anon_sym_set);
+      if (lookahead == 'u') ADVANCE(100);
       END_STATE();
     case 73:
-      if (lookahead == 't') ADVANCE(100);
+      if (lookahead == 'n') ADVANCE(101);
+      if (lookahead == 'r') ADVANCE(102);
       END_STATE();
     case 74:
-      if (lookahead == 'e') ADVANCE(101);
+      if (lookahead == 't') ADVANCE(103);
       END_STATE();
     case 75:
-      if (lookahead == 'e') ADVANCE(102);
+      if (lookahead == 'r') ADVANCE(104);
       END_STATE();
     case 76:
-      if (lookahead == 'a') ADVANCE(103);
+      ACCEPT_TOKEN(anon_sym_bool);
       END_STATE();
     case 77:
-      ACCEPT_TOKEN(anon_sym_use);
-      if (lookahead == 's') ADVANCE(104);
+      if (lookahead == 'i') ADVANCE(105);
       END_STATE();
     case 78:
-      if (lookahead == 'u') ADVANCE(105);
+      if (lookahead == 'e') ADVANCE(106);
       END_STATE();
     case 79:
-      if (lookahead == 'n') ADVANCE(106);
-      if (lookahead == 'r') ADVANCE(107);
+      if (lookahead == 't') ADVANCE(107);
       END_STATE();
     case 80:
-      ACCEPT_TOKEN(anon_sym_Bool);
+      if (lookahead == 'r') ADVANCE(108);
       END_STATE();
     case 81:
-      if (lookahead == 't') ADVANCE(108);
+      if (lookahead == 'e') ADVANCE(109);
       END_STATE();
     case 82:
-      if (lookahead == 't') ADVANCE(109);
+      if (lookahead == 't') ADVANCE(110);
       END_STATE();
     case 83:
-      if (lookahead == 'n') ADVANCE(110);
+      if (lookahead == 'a') ADVANCE(111);
       END_STATE();
     case 84:
-      if (lookahead == 'r') ADVANCE(111);
+      ACCEPT_TOKEN(anon_sym_from);
       END_STATE();
     case 85:
-      if (lookahead == 'i') ADVANCE(112);
+      if (lookahead == 'r') ADVANCE(112);
       END_STATE();
     case 86:
-      if (lookahead == 'e') ADVANCE(113);
+      ACCEPT_TOKEN(anon_sym_into);
       END_STATE();
     case 87:
-      if (lookahead == 't') ADVANCE(114);
+      if (lookahead == 'r') ADVANCE(113);
       END_STATE();
     case 88:
-      if (lookahead == 'r') ADVANCE(115);
+      if (lookahead == 't') ADVANCE(114);
       END_STATE();
     case 89:
-      if (lookahead == 'e') ADVANCE(116);
+      ACCEPT_TOKEN(anon_sym_next);
       END_STATE();
     case 90:
-      if (lookahead == 'a') ADVANCE(117);
+      ACCEPT_TOKEN(anon_sym_null);
       END_STATE();
     case 91:
-      ACCEPT_TOKEN(anon_sym_from);
+      if (lookahead == 'r') ADVANCE(115);
       END_STATE();
     case 92:
-      if (lookahead == 'r') ADVANCE(118);
+      if (lookahead == 'o') ADVANCE(116);
       END_STATE();
     case 93:
-      ACCEPT_TOKEN(anon_sym_into);
+      if (lookahead == 'c') ADVANCE(117);
       END_STATE();
     case 94:
-      if (lookahead == 't') ADVANCE(119);
+      if (lookahead == 'e') ADVANCE(118);
       END_STATE();
     case 95:
-      ACCEPT_TOKEN(anon_sym_next);
+      if (lookahead == 'n') ADVANCE(119);
       END_STATE();
     case 96:
-      ACCEPT_TOKEN(anon_sym_null);
+      ACCEPT_TOKEN(anon_sym_true);
       END_STATE();
     case 97:
-      if (lookahead == 'r') ADVANCE(120);
+      ACCEPT_TOKEN(anon_sym_type);
       END_STATE();
     case 98:
-      if (lookahead == 'o') ADVANCE(121);
+      if (lookahead == 't') ADVANCE(120);
       END_STATE();
     case 99:
-      if (lookahead == 'c') ADVANCE(122);
+      ACCEPT_TOKEN(anon_sym_uses);
       END_STATE();
     case 100:
-      if (lookahead == 'e') ADVANCE(123);
+      if (lookahead == 'e') ADVANCE(121);
       END_STATE();
     case 101:
-      ACCEPT_TOKEN(anon_sym_true);
+      ACCEPT_TOKEN(anon_sym_when);
       END_STATE();
     case 102:
-      ACCEPT_TOKEN(anon_sym_type);
+      if (lookahead == 'e') ADVANCE(122);
       END_STATE();
     case 103:
-      if (lookahead == 't') ADVANCE(124);
+      if (lookahead == 'i') ADVANCE(123);
       END_STATE();
     case 104:
-      ACCEPT_TOKEN(anon_sym_uses);
+      if (lookahead == 't') ADVANCE(124);
       END_STATE();
     case 105:
-      if (lookahead == 'e') ADVANCE(125);
+      if (lookahead == 't') ADVANCE(125);
       END_STATE();
     case 106:
-      ACCEPT_TOKEN(anon_sym_when);
+      if (lookahead == 'x') ADVANCE(126);
       END_STATE();
     case 107:
-      if (lookahead == 'e') ADVANCE(126);
+      if (lookahead == 'e') ADVANCE(127);
       END_STATE();
     case 108:
-      ACCEPT_TOKEN(anon_sym_Float);
+      if (lookahead == 't') ADVANCE(128);
       END_STATE();
     case 109:
-      if (lookahead == 'i') ADVANCE(127);
+      ACCEPT_TOKEN(anon_sym_false);
       END_STATE();
     case 110:
-      if (lookahead == 'g') ADVANCE(128);
+      ACCEPT_TOKEN(anon_sym_float);
       END_STATE();
     case 111:
-      if (lookahead == 't') ADVANCE(129);
+      if (lookahead == 'c') ADVANCE(129);
       END_STATE();
     case 112:
       if (lookahead == 't') ADVANCE(130);
       END_STATE();
     case 113:
-      if (lookahead == 'x') ADVANCE(131);
+      if (lookahead == 'i') ADVANCE(131);
       END_STATE();
     case 114:
-      if (lookahead == 'e') ADVANCE(132);
+      ACCEPT_TOKEN(anon_sym_limit);
       END_STATE();
     case 115:
-      if (lookahead == 't') ADVANCE(133);
+      if (lookahead == 'w') ADVANCE(132);
       END_STATE();
     case 116:
-      ACCEPT_TOKEN(anon_sym_false);
+      if (lookahead == 'n') ADVANCE(133);
       END_STATE();
     case 117:
-      if (lookahead == 'c') ADVANCE(134);
+      if (lookahead == 't') ADVANCE(134);
       END_STATE();
     case 118:
-      if (lookahead == 't') ADVANCE(135);
+      ACCEPT_TOKEN(anon_sym_state);
       END_STATE();
     case 119:
-      ACCEPT_TOKEN(anon_sym_limit);
+      if (lookahead == 'g') ADVANCE(135);
       END_STATE();
     case 120:
-      if (lookahead == 'w') ADVANCE(136);
+      if (lookahead == 'e') ADVANCE(136);
       END_STATE();
     case 121:
-      if (lookahead == 'n') ADVANCE(137);
+      if (lookahead == 's') ADVANCE(137);
       END_STATE();
     case 122:
-      if (lookahead == 't') ADVANCE(138);
+      ACCEPT_TOKEN(anon_sym_where);
       END_STATE();
     case 123:
-      ACCEPT_TOKEN(anon_sym_state);
+      if (lookahead == 'o') ADVANCE(138);
       END_STATE();
     case 124:
-      if (lookahead == 'e') ADVANCE(139);
+      ACCEPT_TOKEN(anon_sym_assert);
       END_STATE();
     case 125:
-      if (lookahead == 's') ADVANCE(140);
+      ACCEPT_TOKEN(anon_sym_commit);
       END_STATE();
     case 126:
-      ACCEPT_TOKEN(anon_sym_where);
+      if (lookahead == 't') ADVANCE(139);
       END_STATE();
     case 127:
-      if (lookahead == 'o') ADVANCE(141);
+      ACCEPT_TOKEN(anon_sym_delete);
       END_STATE();
     case 128:
-      ACCEPT_TOKEN(anon_sym_String);
+      ACCEPT_TOKEN(anon_sym_export);
       END_STATE();
     case 129:
-      ACCEPT_TOKEN(anon_sym_assert);
+      if (lookahead == 'h') ADVANCE(140);
       END_STATE();
     case 130:
-      ACCEPT_TOKEN(anon_sym_commit);
+      ACCEPT_TOKEN(anon_sym_insert);
       END_STATE();
     case 131:
-      if (lookahead == 't') ADVANCE(142);
+      if (lookahead == 'a') ADVANCE(141);
       END_STATE();
     case 132:
-      ACCEPT_TOKEN(anon_sym_delete);
+      if (lookahead == 'i') ADVANCE(142);
       END_STATE();
     case 133:
-      ACCEPT_TOKEN(anon_sym_export);
+      if (lookahead == 'd') ADVANCE(143);
       END_STATE();
     case 134:
-      if (lookahead == 'h') ADVANCE(143);
+      ACCEPT_TOKEN(anon_sym_select);
       END_STATE();
     case 135:
-      ACCEPT_TOKEN(anon_sym_insert);
+      ACCEPT_TOKEN(anon_sym_string);
       END_STATE();
     case 136:
-      if (lookahead == 'i') ADVANCE(144);
+      ACCEPT_TOKEN(anon_sym_update);
       END_STATE();
     case 137:
-      if (lookahead == 'd') ADVANCE(145);
+      ACCEPT_TOKEN(anon_sym_values);
       END_STATE();
     case 138:
-      ACCEPT_TOKEN(anon_sym_select);
+      if (lookahead == 'n') ADVANCE(144);
       END_STATE();
     case 139:
-      ACCEPT_TOKEN(anon_sym_update);
+      ACCEPT_TOKEN(anon_sym_context);
       END_STATE();
     case 140:
-      ACCEPT_TOKEN(anon_sym_values);
+      ACCEPT_TOKEN(anon_sym_foreach);
       END_STATE();
     case 141:
-      if (lookahead == 'n') ADVANCE(146);
+      if (lookahead == 'n') ADVANCE(145);
       END_STATE();
     case 142:
-      ACCEPT_TOKEN(anon_sym_context);
+      if (lookahead == 's') ADVANCE(146);
       END_STATE();
     case 143:
-      ACCEPT_TOKEN(anon_sym_foreach);
+      if (lookahead == 'i') ADVANCE(147);
       END_STATE();
     case 144:
-      if (lookahead == 's') ADVANCE(147);
+      ACCEPT_TOKEN(anon_sym_Relation);
       END_STATE();
     case 145:
-      if (lookahead == 'i') ADVANCE(148);
+      if (lookahead == 't') ADVANCE(148);
       END_STATE();
     case 146:
-      ACCEPT_TOKEN(anon_sym_Relation);
+      if (lookahead == 'e') ADVANCE(149);
       END_STATE();
     case 147:
-      if (lookahead == 'e') ADVANCE(149);
+      if (lookahead == 't') ADVANCE(150);
       END_STATE();
     case 148:
-      if (lookahead == 't') ADVANCE(150);
+      ACCEPT_TOKEN(anon_sym_invariant);
       END_STATE();
     case 149:
       ACCEPT_TOKEN(anon_sym_otherwise);
@@ -2176,39 +2270,39 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [12] = {.lex_state = 1},
   [13] = {.lex_state = 1},
   [14] = {.lex_state = 1},
-  [15] = {.lex_state = 2},
-  [16] = {.lex_state = 3},
-  [17] = {.lex_state = 3},
-  [18] = {.lex_state = 2},
-  [19] = {.lex_state = 2},
-  [20] = {.lex_state = 2},
+  [15] = {.lex_state = 1},
+  [16] = {.lex_state = 1},
+  [17] = {.lex_state = 1},
+  [18] = {.lex_state = 1},
+  [19] = {.lex_state = 1},
+  [20] = {.lex_state = 1},
   [21] = {.lex_state = 2},
-  [22] = {.lex_state = 2},
-  [23] = {.lex_state = 2},
+  [22] = {.lex_state = 3},
+  [23] = {.lex_state = 3},
   [24] = {.lex_state = 2},
   [25] = {.lex_state = 2},
   [26] = {.lex_state = 2},
   [27] = {.lex_state = 2},
   [28] = {.lex_state = 2},
   [29] = {.lex_state = 2},
-  [30] = {.lex_state = 0},
-  [31] = {.lex_state = 0},
-  [32] = {.lex_state = 0},
-  [33] = {.lex_state = 0},
-  [34] = {.lex_state = 0},
-  [35] = {.lex_state = 0},
+  [30] = {.lex_state = 2},
+  [31] = {.lex_state = 2},
+  [32] = {.lex_state = 2},
+  [33] = {.lex_state = 2},
+  [34] = {.lex_state = 2},
+  [35] = {.lex_state = 2},
   [36] = {.lex_state = 0},
   [37] = {.lex_state = 0},
-  [38] = {.lex_state = 1},
+  [38] = {.lex_state = 0},
   [39] = {.lex_state = 0},
-  [40] = {.lex_state = 1},
+  [40] = {.lex_state = 0},
   [41] = {.lex_state = 0},
-  [42] = {.lex_state = 1},
-  [43] = {.lex_state = 1},
+  [42] = {.lex_state = 0},
+  [43] = {.lex_state = 0},
   [44] = {.lex_state = 1},
   [45] = {.lex_state = 1},
-  [46] = {.lex_state = 1},
-  [47] = {.lex_state = 1},
+  [46] = {.lex_state = 0},
+  [47] = {.lex_state = 0},
   [48] = {.lex_state = 1},
   [49] = {.lex_state = 1},
   [50] = {.lex_state = 1},
@@ -2217,29 +2311,29 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [53] = {.lex_state = 1},
   [54] = {.lex_state = 1},
   [55] = {.lex_state = 1},
-  [56] = {.lex_state = 0},
-  [57] = {.lex_state = 2},
-  [58] = {.lex_state = 2},
-  [59] = {.lex_state = 2},
-  [60] = {.lex_state = 2},
-  [61] = {.lex_state = 2},
+  [56] = {.lex_state = 1},
+  [57] = {.lex_state = 1},
+  [58] = {.lex_state = 1},
+  [59] = {.lex_state = 1},
+  [60] = {.lex_state = 1},
+  [61] = {.lex_state = 1},
   [62] = {.lex_state = 1},
-  [63] = {.lex_state = 2},
+  [63] = {.lex_state = 0},
   [64] = {.lex_state = 2},
   [65] = {.lex_state = 2},
   [66] = {.lex_state = 2},
   [67] = {.lex_state = 2},
   [68] = {.lex_state = 2},
   [69] = {.lex_state = 2},
-  [70] = {.lex_state = 0},
-  [71] = {.lex_state = 0},
-  [72] = {.lex_state = 0},
-  [73] = {.lex_state = 0},
-  [74] = {.lex_state = 0},
-  [75] = {.lex_state = 0},
-  [76] = {.lex_state = 0},
-  [77] = {.lex_state = 0},
-  [78] = {.lex_state = 0},
+  [70] = {.lex_state = 2},
+  [71] = {.lex_state = 2},
+  [72] = {.lex_state = 1},
+  [73] = {.lex_state = 2},
+  [74] = {.lex_state = 2},
+  [75] = {.lex_state = 2},
+  [76] = {.lex_state = 2},
+  [77] = {.lex_state = 2},
+  [78] = {.lex_state = 2},
   [79] = {.lex_state = 0},
   [80] = {.lex_state = 0},
   [81] = {.lex_state = 0},
@@ -2280,23 +2374,23 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [116] = {.lex_state = 0},
   [117] = {.lex_state = 0},
   [118] = {.lex_state = 0},
-  [119] = {.lex_state = 1},
+  [119] = {.lex_state = 0},
   [120] = {.lex_state = 0},
   [121] = {.lex_state = 0},
   [122] = {.lex_state = 0},
   [123] = {.lex_state = 0},
-  [124] = {.lex_state = 4},
+  [124] = {.lex_state = 0},
   [125] = {.lex_state = 0},
   [126] = {.lex_state = 0},
-  [127] = {.lex_state = 4},
+  [127] = {.lex_state = 0},
   [128] = {.lex_state = 0},
   [129] = {.lex_state = 0},
   [130] = {.lex_state = 0},
-  [131] = {.lex_state = 1},
-  [132] = {.lex_state = 1},
-  [133] = {.lex_state = 1},
-  [134] = {.lex_state = 0},
-  [135] = {.lex_state = 0},
+  [131] = {.lex_state = 0},
+  [132] = {.lex_state = 0},
+  [133] = {.lex_state = 0},
+  [134] = {.lex_state = 1},
+  [135] = {.lex_state = 4},
   [136] = {.lex_state = 0},
   [137] = {.lex_state = 0},
   [138] = {.lex_state = 0},
@@ -2305,35 +2399,35 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [141] = {.lex_state = 1},
   [142] = {.lex_state = 1},
   [143] = {.lex_state = 0},
-  [144] = {.lex_state = 4},
+  [144] = {.lex_state = 1},
   [145] = {.lex_state = 0},
-  [146] = {.lex_state = 1},
-  [147] = {.lex_state = 0},
+  [146] = {.lex_state = 0},
+  [147] = {.lex_state = 1},
   [148] = {.lex_state = 0},
-  [149] = {.lex_state = 0},
+  [149] = {.lex_state = 4},
   [150] = {.lex_state = 0},
-  [151] = {.lex_state = 0},
+  [151] = {.lex_state = 1},
   [152] = {.lex_state = 0},
   [153] = {.lex_state = 0},
   [154] = {.lex_state = 0},
-  [155] = {.lex_state = 0},
+  [155] = {.lex_state = 1},
   [156] = {.lex_state = 0},
-  [157] = {.lex_state = 0},
+  [157] = {.lex_state = 4},
   [158] = {.lex_state = 0},
   [159] = {.lex_state = 0},
   [160] = {.lex_state = 0},
   [161] = {.lex_state = 0},
   [162] = {.lex_state = 0},
   [163] = {.lex_state = 0},
-  [164] = {.lex_state = 0},
-  [165] = {.lex_state = 0},
+  [164] = {.lex_state = 1},
+  [165] = {.lex_state = 1},
   [166] = {.lex_state = 0},
-  [167] = {.lex_state = 0},
+  [167] = {.lex_state = 1},
   [168] = {.lex_state = 0},
   [169] = {.lex_state = 0},
   [170] = {.lex_state = 0},
-  [171] = {.lex_state = 0},
-  [172] = {.lex_state = 0},
+  [171] = {.lex_state = 1},
+  [172] = {.lex_state = 1},
   [173] = {.lex_state = 0},
   [174] = {.lex_state = 0},
   [175] = {.lex_state = 0},
@@ -2347,7 +2441,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [183] = {.lex_state = 0},
   [184] = {.lex_state = 0},
   [185] = {.lex_state = 0},
-  [186] = {.lex_state = 0},
+  [186] = {.lex_state = 1},
   [187] = {.lex_state = 0},
   [188] = {.lex_state = 0},
   [189] = {.lex_state = 0},
@@ -2360,7 +2454,7 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [196] = {.lex_state = 0},
   [197] = {.lex_state = 0},
   [198] = {.lex_state = 0},
-  [199] = {.lex_state = 1},
+  [199] = {.lex_state = 0},
   [200] = {.lex_state = 0},
   [201] = {.lex_state = 0},
   [202] = {.lex_state = 0},
@@ -2378,28 +2472,28 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [214] = {.lex_state = 0},
   [215] = {.lex_state = 0},
   [216] = {.lex_state = 0},
-  [217] = {.lex_state = 2},
+  [217] = {.lex_state = 0},
   [218] = {.lex_state = 0},
   [219] = {.lex_state = 0},
   [220] = {.lex_state = 0},
-  [221] = {.lex_state = 2},
-  [222] = {.lex_state = 2},
+  [221] = {.lex_state = 0},
+  [222] = {.lex_state = 0},
   [223] = {.lex_state = 0},
-  [224] = {.lex_state = 2},
+  [224] = {.lex_state = 0},
   [225] = {.lex_state = 0},
   [226] = {.lex_state = 0},
   [227] = {.lex_state = 0},
   [228] = {.lex_state = 0},
   [229] = {.lex_state = 0},
-  [230] = {.lex_state = 0},
+  [230] = {.lex_state = 1},
   [231] = {.lex_state = 0},
   [232] = {.lex_state = 0},
   [233] = {.lex_state = 0},
-  [234] = {.lex_state = 2},
-  [235] = {.lex_state = 2},
+  [234] = {.lex_state = 0},
+  [235] = {.lex_state = 0},
   [236] = {.lex_state = 0},
-  [237] = {.lex_state = 0},
-  [238] = {.lex_state = 2},
+  [237] = {.lex_state = 1},
+  [238] = {.lex_state = 12},
   [239] = {.lex_state = 0},
   [240] = {.lex_state = 0},
   [241] = {.lex_state = 0},
@@ -2410,21 +2504,47 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [246] = {.lex_state = 0},
   [247] = {.lex_state = 0},
   [248] = {.lex_state = 0},
-  [249] = {.lex_state = 2},
+  [249] = {.lex_state = 0},
   [250] = {.lex_state = 0},
   [251] = {.lex_state = 0},
   [252] = {.lex_state = 0},
   [253] = {.lex_state = 0},
   [254] = {.lex_state = 0},
-  [255] = {.lex_state = 0},
+  [255] = {.lex_state = 1},
   [256] = {.lex_state = 0},
-  [257] = {.lex_state = 0},
-  [258] = {.lex_state = 0},
-  [259] = {.lex_state = 0},
+  [257] = {.lex_state = 1},
+  [258] = {.lex_state = 1},
+  [259] = {.lex_state = 1},
   [260] = {.lex_state = 0},
   [261] = {.lex_state = 0},
   [262] = {.lex_state = 0},
   [263] = {.lex_state = 0},
+  [264] = {.lex_state = 0},
+  [265] = {.lex_state = 0},
+  [266] = {.lex_state = 0},
+  [267] = {.lex_state = 0},
+  [268] = {.lex_state = 0},
+  [269] = {.lex_state = 1},
+  [270] = {.lex_state = 1},
+  [271] = {.lex_state = 0},
+  [272] = {.lex_state = 0},
+  [273] = {.lex_state = 0},
+  [274] = {.lex_state = 0},
+  [275] = {.lex_state = 0},
+  [276] = {.lex_state = 0},
+  [277] = {.lex_state = 0},
+  [278] = {.lex_state = 0},
+  [279] = {.lex_state = 0},
+  [280] = {.lex_state = 0},
+  [281] = {.lex_state = 0},
+  [282] = {.lex_state = 0},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 0},
+  [285] = {.lex_state = 0},
+  [286] = {.lex_state = 0},
+  [287] = {.lex_state = 0},
+  [288] = {.lex_state = 0},
+  [289] = {.lex_state = 0},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -2437,6 +2557,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_COLON] = ACTIONS(1),
     [anon_sym_SEMI] = ACTIONS(1),
     [anon_sym_context] = ACTIONS(1),
+    [anon_sym_invariant] = ACTIONS(1),
     [anon_sym_state] = ACTIONS(1),
     [anon_sym_uses] = ACTIONS(1),
     [anon_sym_COMMA] = ACTIONS(1),
@@ -2465,7 +2586,6 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_when] = ACTIONS(1),
     [anon_sym_EQ_GT] = ACTIONS(1),
     [anon_sym_otherwise] = ACTIONS(1),
-    [anon_sym_export] = ACTIONS(1),
     [anon_sym_BSLASH_SLASH] = ACTIONS(1),
     [anon_sym_SLASH_BSLASH] = ACTIONS(1),
     [anon_sym_TILDE] = ACTIONS(1),
@@ -2473,6 +2593,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_BSLASHA] = ACTIONS(1),
     [anon_sym_Relation] = ACTIONS(1),
     [anon_sym_by] = ACTIONS(1),
+    [anon_sym_export] = ACTIONS(1),
     [anon_sym_POUND] = ACTIONS(1),
     [anon_sym_EQ_EQ] = ACTIONS(1),
     [anon_sym_BANG_EQ] = ACTIONS(1),
@@ -2486,10 +2607,10 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_SLASH] = ACTIONS(1),
     [anon_sym_BANG] = ACTIONS(1),
     [anon_sym_DOT] = ACTIONS(1),
-    [anon_sym_Int] = ACTIONS(1),
-    [anon_sym_Bool] = ACTIONS(1),
-    [anon_sym_String] = ACTIONS(1),
-    [anon_sym_Float] = ACTIONS(1),
+    [anon_sym_int] = ACTIONS(1),
+    [anon_sym_bool] = ACTIONS(1),
+    [anon_sym_string] = ACTIONS(1),
+    [anon_sym_float] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
     [anon_sym_COLON_COLON] = ACTIONS(1),
@@ -2502,15 +2623,17 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_comment] = ACTIONS(3),
   },
   [STATE(1)] = {
-    [sym_program] = STATE(185),
-    [sym_type_decl] = STATE(98),
-    [sym_context_decl] = STATE(98),
-    [sym_state_decl] = STATE(98),
-    [aux_sym_program_repeat1] = STATE(98),
+    [sym_program] = STATE(212),
+    [sym_type_decl] = STATE(100),
+    [sym_context_decl] = STATE(100),
+    [sym_invariant_decl] = STATE(100),
+    [sym_state_decl] = STATE(100),
+    [aux_sym_program_repeat1] = STATE(100),
     [ts_builtin_sym_end] = ACTIONS(5),
     [anon_sym_type] = ACTIONS(7),
     [anon_sym_context] = ACTIONS(9),
-    [anon_sym_state] = ACTIONS(11),
+    [anon_sym_invariant] = ACTIONS(11),
+    [anon_sym_state] = ACTIONS(13),
     [sym_comment] = ACTIONS(3),
   },
 };
@@ -2519,45 +2642,45 @@ static const uint16_t ts_small_parse_table[] = {
   [0] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(13), 1,
-      anon_sym_use,
     ACTIONS(15), 1,
-      anon_sym_precondition,
+      anon_sym_use,
     ACTIONS(17), 1,
-      anon_sym_pre,
+      anon_sym_precondition,
     ACTIONS(19), 1,
-      anon_sym_let,
+      anon_sym_pre,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(34), 1,
+    STATE(36), 1,
       sym_precondition_block,
-    STATE(247), 1,
+    STATE(210), 1,
       sym_next_block,
-    STATE(3), 2,
+    STATE(4), 2,
       sym_use_stmt,
       aux_sym_state_decl_repeat2,
-    STATE(37), 2,
+    STATE(38), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(70), 3,
+    STATE(80), 3,
       sym_use_state_stmt,
       sym_use_context_stmt,
       sym_use_type_stmt,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -2569,45 +2692,45 @@ static const uint16_t ts_small_parse_table[] = {
   [69] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(13), 1,
-      anon_sym_use,
     ACTIONS(15), 1,
-      anon_sym_precondition,
+      anon_sym_use,
     ACTIONS(17), 1,
-      anon_sym_pre,
+      anon_sym_precondition,
     ACTIONS(19), 1,
-      anon_sym_let,
+      anon_sym_pre,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(32), 1,
+    STATE(42), 1,
       sym_precondition_block,
-    STATE(258), 1,
+    STATE(217), 1,
       sym_next_block,
-    STATE(31), 2,
-      sym_statement,
-      aux_sym_state_decl_repeat3,
-    STATE(56), 2,
+    STATE(5), 2,
       sym_use_stmt,
       aux_sym_state_decl_repeat2,
-    STATE(70), 3,
+    STATE(43), 2,
+      sym_statement,
+      aux_sym_state_decl_repeat3,
+    STATE(80), 3,
       sym_use_state_stmt,
       sym_use_context_stmt,
       sym_use_type_stmt,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -2619,45 +2742,45 @@ static const uint16_t ts_small_parse_table[] = {
   [138] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(13), 1,
-      anon_sym_use,
     ACTIONS(15), 1,
-      anon_sym_precondition,
+      anon_sym_use,
     ACTIONS(17), 1,
-      anon_sym_pre,
+      anon_sym_precondition,
     ACTIONS(19), 1,
-      anon_sym_let,
+      anon_sym_pre,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(32), 1,
+    STATE(40), 1,
       sym_precondition_block,
-    STATE(258), 1,
+    STATE(271), 1,
       sym_next_block,
-    STATE(5), 2,
-      sym_use_stmt,
-      aux_sym_state_decl_repeat2,
-    STATE(31), 2,
+    STATE(39), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(70), 3,
+    STATE(63), 2,
+      sym_use_stmt,
+      aux_sym_state_decl_repeat2,
+    STATE(80), 3,
       sym_use_state_stmt,
       sym_use_context_stmt,
       sym_use_type_stmt,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -2669,45 +2792,45 @@ static const uint16_t ts_small_parse_table[] = {
   [207] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(13), 1,
-      anon_sym_use,
     ACTIONS(15), 1,
-      anon_sym_precondition,
+      anon_sym_use,
     ACTIONS(17), 1,
-      anon_sym_pre,
+      anon_sym_precondition,
     ACTIONS(19), 1,
-      anon_sym_let,
+      anon_sym_pre,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
     STATE(36), 1,
       sym_precondition_block,
-    STATE(260), 1,
+    STATE(210), 1,
       sym_next_block,
-    STATE(35), 2,
+    STATE(38), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(56), 2,
+    STATE(63), 2,
       sym_use_stmt,
       aux_sym_state_decl_repeat2,
-    STATE(70), 3,
+    STATE(80), 3,
       sym_use_state_stmt,
       sym_use_context_stmt,
       sym_use_type_stmt,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -2719,47 +2842,47 @@ static const uint16_t ts_small_parse_table[] = {
   [276] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
-      sym_identifier,
     ACTIONS(39), 1,
+      sym_identifier,
+    ACTIONS(42), 1,
       anon_sym_LBRACE,
-    ACTIONS(41), 1,
+    ACTIONS(45), 1,
       anon_sym_RBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(47), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(50), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(59), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(62), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(68), 1,
       anon_sym_DQUOTE,
-    STATE(7), 1,
-      aux_sym_precondition_block_repeat1,
-    STATE(57), 1,
+    STATE(6), 1,
+      aux_sym_invariant_decl_repeat1,
+    STATE(64), 1,
       sym_expr,
-    STATE(141), 1,
+    STATE(134), 1,
       sym_tla_expr,
-    STATE(216), 1,
+    STATE(247), 1,
       sym_condition,
-    ACTIONS(47), 2,
+    ACTIONS(53), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(56), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(65), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
@@ -2768,378 +2891,646 @@ static const uint16_t ts_small_parse_table[] = {
   [344] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(59), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(62), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(65), 1,
+    ACTIONS(75), 1,
       anon_sym_RBRACE,
-    ACTIONS(67), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(70), 1,
-      anon_sym_TILDE,
     ACTIONS(79), 1,
+      anon_sym_TILDE,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(82), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(88), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(7), 1,
-      aux_sym_precondition_block_repeat1,
-    STATE(57), 1,
+    STATE(6), 1,
+      aux_sym_invariant_decl_repeat1,
+    STATE(64), 1,
       sym_expr,
-    STATE(141), 1,
+    STATE(134), 1,
       sym_tla_expr,
-    STATE(216), 1,
+    STATE(247), 1,
       sym_condition,
-    ACTIONS(73), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(76), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(85), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [412] = 18,
+  [412] = 19,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
+    ACTIONS(93), 1,
+      anon_sym_RBRACE,
     STATE(6), 1,
-      aux_sym_precondition_block_repeat1,
-    STATE(57), 1,
+      aux_sym_invariant_decl_repeat1,
+    STATE(64), 1,
       sym_expr,
-    STATE(141), 1,
+    STATE(134), 1,
       sym_tla_expr,
-    STATE(216), 1,
+    STATE(247), 1,
       sym_condition,
-    ACTIONS(47), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [477] = 17,
+  [480] = 18,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    STATE(7), 1,
+      aux_sym_invariant_decl_repeat1,
+    STATE(64), 1,
       sym_expr,
-    STATE(141), 1,
+    STATE(134), 1,
       sym_tla_expr,
-    STATE(198), 1,
+    STATE(247), 1,
       sym_condition,
-    ACTIONS(47), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [539] = 16,
+  [545] = 18,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    STATE(8), 1,
+      aux_sym_invariant_decl_repeat1,
+    STATE(64), 1,
       sym_expr,
-    STATE(119), 1,
+    STATE(134), 1,
       sym_tla_expr,
-    ACTIONS(47), 2,
+    STATE(247), 1,
+      sym_condition,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [598] = 16,
+  [610] = 17,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(95), 1,
+      anon_sym_TILDE,
+    STATE(67), 1,
+      sym_expr,
+    STATE(164), 1,
+      sym_guard_expr,
+    STATE(222), 1,
+      sym_guard,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(97), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(165), 3,
+      sym_guard_binary_expr,
+      sym_guard_unary_expr,
+      sym_guard_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [672] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    STATE(64), 1,
       sym_expr,
-    STATE(131), 1,
+    STATE(141), 1,
       sym_tla_expr,
-    ACTIONS(47), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [657] = 16,
+  [731] = 16,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    STATE(64), 1,
       sym_expr,
-    STATE(132), 1,
+    STATE(151), 1,
       sym_tla_expr,
-    ACTIONS(47), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [716] = 16,
+  [790] = 16,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
-      anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    ACTIONS(95), 1,
+      anon_sym_TILDE,
+    STATE(67), 1,
       sym_expr,
-    STATE(133), 1,
-      sym_tla_expr,
-    ACTIONS(47), 2,
+    STATE(186), 1,
+      sym_guard_expr,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(97), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(165), 3,
+      sym_guard_binary_expr,
+      sym_guard_unary_expr,
+      sym_guard_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [849] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(95), 1,
+      anon_sym_TILDE,
+    STATE(67), 1,
+      sym_expr,
+    STATE(167), 1,
+      sym_guard_expr,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    ACTIONS(97), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(142), 3,
-      sym_tla_binary_expr,
-      sym_tla_unary_expr,
-      sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(165), 3,
+      sym_guard_binary_expr,
+      sym_guard_unary_expr,
+      sym_guard_quantifier,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [775] = 16,
+  [908] = 16,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(45), 1,
+    ACTIONS(79), 1,
       anon_sym_TILDE,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(57), 1,
+    STATE(64), 1,
       sym_expr,
-    STATE(146), 1,
+    STATE(144), 1,
       sym_tla_expr,
-    ACTIONS(47), 2,
+    ACTIONS(81), 2,
       anon_sym_BSLASHE,
       anon_sym_BSLASHA,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
     STATE(142), 3,
       sym_tla_binary_expr,
       sym_tla_unary_expr,
       sym_tla_quantifier,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [834] = 4,
+  [967] = 16,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(95), 1,
-      anon_sym_DOT,
-    ACTIONS(93), 4,
-      anon_sym_EQ,
-      anon_sym_LT,
-      anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(91), 18,
-      anon_sym_RBRACE,
-      anon_sym_COLON,
-      anon_sym_SEMI,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_where,
-      anon_sym_limit,
-      anon_sym_EQ_GT,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(79), 1,
+      anon_sym_TILDE,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    STATE(64), 1,
+      sym_expr,
+    STATE(155), 1,
+      sym_tla_expr,
+    ACTIONS(81), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(142), 3,
+      sym_tla_binary_expr,
+      sym_tla_unary_expr,
+      sym_tla_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [1026] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(95), 1,
+      anon_sym_TILDE,
+    STATE(67), 1,
+      sym_expr,
+    STATE(172), 1,
+      sym_guard_expr,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(97), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(165), 3,
+      sym_guard_binary_expr,
+      sym_guard_unary_expr,
+      sym_guard_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [1085] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(95), 1,
+      anon_sym_TILDE,
+    STATE(67), 1,
+      sym_expr,
+    STATE(171), 1,
+      sym_guard_expr,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    ACTIONS(97), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(165), 3,
+      sym_guard_binary_expr,
+      sym_guard_unary_expr,
+      sym_guard_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [1144] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(79), 1,
+      anon_sym_TILDE,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    STATE(64), 1,
+      sym_expr,
+    STATE(147), 1,
+      sym_tla_expr,
+    ACTIONS(81), 2,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(142), 3,
+      sym_tla_binary_expr,
+      sym_tla_unary_expr,
+      sym_tla_quantifier,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [1203] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(103), 1,
+      anon_sym_DOT,
+    ACTIONS(101), 4,
+      anon_sym_EQ,
+      anon_sym_LT,
+      anon_sym_GT,
+      anon_sym_SLASH,
+    ACTIONS(99), 18,
+      anon_sym_RBRACE,
+      anon_sym_COLON,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_where,
+      anon_sym_limit,
+      anon_sym_EQ_GT,
       anon_sym_BSLASH_SLASH,
       anon_sym_SLASH_BSLASH,
       anon_sym_POUND,
@@ -3150,16 +3541,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [867] = 3,
+  [1236] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(99), 5,
+    ACTIONS(107), 5,
       anon_sym_COLON,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(97), 18,
+    ACTIONS(105), 18,
       anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_COMMA,
@@ -3178,16 +3569,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_COLON_COLON,
-  [898] = 3,
+  [1267] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(103), 5,
+    ACTIONS(111), 5,
       anon_sym_COLON,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(101), 18,
+    ACTIONS(109), 18,
       anon_sym_RBRACE,
       anon_sym_SEMI,
       anon_sym_COMMA,
@@ -3206,15 +3597,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_STAR,
       anon_sym_COLON_COLON,
-  [929] = 3,
+  [1298] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(107), 4,
+    ACTIONS(115), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(105), 18,
+    ACTIONS(113), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3233,15 +3624,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [959] = 3,
+  [1328] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(93), 4,
+    ACTIONS(119), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(91), 18,
+    ACTIONS(117), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3260,15 +3651,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [989] = 3,
+  [1358] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(111), 4,
+    ACTIONS(123), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(109), 18,
+    ACTIONS(121), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3287,15 +3678,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [1019] = 3,
+  [1388] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(115), 4,
+    ACTIONS(127), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(113), 18,
+    ACTIONS(125), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3314,15 +3705,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [1049] = 3,
+  [1418] = 8,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(119), 4,
+    ACTIONS(131), 1,
       anon_sym_EQ,
+    ACTIONS(139), 1,
+      anon_sym_STAR,
+    ACTIONS(141), 1,
+      anon_sym_SLASH,
+    ACTIONS(133), 2,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(117), 18,
+    ACTIONS(135), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(137), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(129), 13,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3336,20 +3737,21 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-  [1079] = 3,
+  [1458] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(123), 4,
+    ACTIONS(139), 1,
+      anon_sym_STAR,
+    ACTIONS(141), 1,
+      anon_sym_SLASH,
+    ACTIONS(137), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(131), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(121), 18,
+    ACTIONS(129), 15,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3365,18 +3767,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-      anon_sym_STAR,
-  [1109] = 3,
+  [1494] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(127), 4,
+    ACTIONS(139), 1,
+      anon_sym_STAR,
+    ACTIONS(141), 1,
+      anon_sym_SLASH,
+    ACTIONS(131), 3,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-      anon_sym_SLASH,
-    ACTIONS(125), 18,
+    ACTIONS(129), 17,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3394,26 +3796,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_GT_EQ,
       anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_STAR,
-  [1139] = 8,
+  [1528] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(131), 1,
+    ACTIONS(131), 4,
       anon_sym_EQ,
-    ACTIONS(139), 1,
-      anon_sym_STAR,
-    ACTIONS(141), 1,
-      anon_sym_SLASH,
-    ACTIONS(133), 2,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(135), 2,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-    ACTIONS(137), 2,
-      anon_sym_PLUS,
-      anon_sym_DASH,
-    ACTIONS(129), 13,
+      anon_sym_SLASH,
+    ACTIONS(129), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3427,21 +3818,20 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [1179] = 6,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(139), 1,
-      anon_sym_STAR,
-    ACTIONS(141), 1,
-      anon_sym_SLASH,
-    ACTIONS(137), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(131), 3,
+      anon_sym_STAR,
+  [1558] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(101), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(129), 15,
+      anon_sym_SLASH,
+    ACTIONS(99), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3457,18 +3847,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-  [1215] = 5,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [1588] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(139), 1,
-      anon_sym_STAR,
-    ACTIONS(141), 1,
-      anon_sym_SLASH,
-    ACTIONS(131), 3,
+    ACTIONS(145), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(129), 17,
+      anon_sym_SLASH,
+    ACTIONS(143), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3486,15 +3876,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_GT_EQ,
       anon_sym_PLUS,
       anon_sym_DASH,
-  [1249] = 3,
+      anon_sym_STAR,
+  [1618] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(131), 4,
+    ACTIONS(149), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(129), 18,
+    ACTIONS(147), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3513,15 +3904,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [1279] = 3,
+  [1648] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(145), 4,
+    ACTIONS(153), 4,
       anon_sym_EQ,
       anon_sym_LT,
       anon_sym_GT,
       anon_sym_SLASH,
-    ACTIONS(143), 18,
+    ACTIONS(151), 18,
       anon_sym_RBRACE,
       anon_sym_COLON,
       anon_sym_SEMI,
@@ -3540,33 +3931,33 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_STAR,
-  [1309] = 13,
+  [1678] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(189), 1,
+    STATE(271), 1,
       sym_next_block,
-    STATE(33), 2,
+    STATE(39), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3575,33 +3966,32 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1357] = 13,
+  [1726] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
+    ACTIONS(157), 1,
       anon_sym_let,
-    ACTIONS(21), 1,
+    ACTIONS(160), 1,
       anon_sym_select,
-    ACTIONS(23), 1,
+    ACTIONS(163), 1,
       anon_sym_foreach,
-    ACTIONS(25), 1,
+    ACTIONS(166), 1,
       anon_sym_update,
-    ACTIONS(27), 1,
+    ACTIONS(169), 1,
       anon_sym_insert,
-    ACTIONS(29), 1,
+    ACTIONS(172), 1,
       anon_sym_delete,
-    ACTIONS(31), 1,
+    ACTIONS(175), 1,
       anon_sym_assert,
-    ACTIONS(33), 1,
+    ACTIONS(178), 1,
       anon_sym_commit,
-    ACTIONS(35), 1,
+    ACTIONS(155), 2,
+      anon_sym_RBRACE,
       anon_sym_next,
-    STATE(260), 1,
-      sym_next_block,
-    STATE(33), 2,
+    STATE(37), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3610,33 +4000,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1405] = 13,
+  [1772] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(260), 1,
+    STATE(271), 1,
       sym_next_block,
-    STATE(35), 2,
+    STATE(37), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3645,32 +4035,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1453] = 12,
+  [1820] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(149), 1,
+    ACTIONS(21), 1,
       anon_sym_let,
-    ACTIONS(152), 1,
+    ACTIONS(23), 1,
       anon_sym_select,
-    ACTIONS(155), 1,
+    ACTIONS(25), 1,
       anon_sym_foreach,
-    ACTIONS(158), 1,
+    ACTIONS(27), 1,
       anon_sym_update,
-    ACTIONS(161), 1,
+    ACTIONS(29), 1,
       anon_sym_insert,
-    ACTIONS(164), 1,
+    ACTIONS(31), 1,
       anon_sym_delete,
-    ACTIONS(167), 1,
+    ACTIONS(33), 1,
       anon_sym_assert,
-    ACTIONS(170), 1,
+    ACTIONS(35), 1,
       anon_sym_commit,
-    ACTIONS(147), 2,
-      anon_sym_RBRACE,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(33), 2,
+    STATE(289), 1,
+      sym_next_block,
+    STATE(37), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3679,33 +4070,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1499] = 13,
+  [1868] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(258), 1,
+    STATE(289), 1,
       sym_next_block,
-    STATE(31), 2,
+    STATE(41), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3714,33 +4105,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1547] = 13,
+  [1916] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(214), 1,
+    STATE(207), 1,
       sym_next_block,
-    STATE(33), 2,
+    STATE(37), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3749,33 +4140,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1595] = 13,
+  [1964] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(214), 1,
+    STATE(210), 1,
       sym_next_block,
-    STATE(30), 2,
+    STATE(38), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3784,33 +4175,33 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1643] = 13,
+  [2012] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
-      anon_sym_assert,
+      anon_sym_delete,
     ACTIONS(33), 1,
-      anon_sym_commit,
+      anon_sym_assert,
     ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(37), 1,
       anon_sym_next,
-    STATE(258), 1,
+    STATE(210), 1,
       sym_next_block,
-    STATE(33), 2,
+    STATE(37), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3819,132 +4210,132 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1691] = 13,
+  [2060] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    ACTIONS(173), 1,
+    ACTIONS(181), 1,
       anon_sym_RPAREN,
-    STATE(61), 1,
+    STATE(69), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [1738] = 12,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
-    ACTIONS(21), 1,
-      anon_sym_select,
-    ACTIONS(23), 1,
-      anon_sym_foreach,
-    ACTIONS(25), 1,
-      anon_sym_update,
-    ACTIONS(27), 1,
-      anon_sym_insert,
-    ACTIONS(29), 1,
-      anon_sym_delete,
-    ACTIONS(31), 1,
-      anon_sym_assert,
-    ACTIONS(33), 1,
-      anon_sym_commit,
-    ACTIONS(175), 1,
-      anon_sym_RBRACE,
-    STATE(41), 2,
-      sym_statement,
-      aux_sym_state_decl_repeat3,
-    STATE(76), 8,
-      sym_let_stmt,
-      sym_select_stmt,
-      sym_foreach_stmt,
-      sym_update_stmt,
-      sym_insert_stmt,
-      sym_delete_stmt,
-      sym_assert_stmt,
-      sym_commit_stmt,
-  [1783] = 13,
+  [2107] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    ACTIONS(177), 1,
+    ACTIONS(183), 1,
       anon_sym_RPAREN,
-    STATE(58), 1,
+    STATE(66), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [1830] = 12,
+  [2154] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(19), 1,
-      anon_sym_let,
     ACTIONS(21), 1,
-      anon_sym_select,
+      anon_sym_let,
     ACTIONS(23), 1,
-      anon_sym_foreach,
+      anon_sym_select,
     ACTIONS(25), 1,
-      anon_sym_update,
+      anon_sym_foreach,
     ACTIONS(27), 1,
-      anon_sym_insert,
+      anon_sym_update,
     ACTIONS(29), 1,
-      anon_sym_delete,
+      anon_sym_insert,
     ACTIONS(31), 1,
+      anon_sym_delete,
+    ACTIONS(33), 1,
       anon_sym_assert,
+    ACTIONS(35), 1,
+      anon_sym_commit,
+    ACTIONS(185), 1,
+      anon_sym_RBRACE,
+    STATE(37), 2,
+      sym_statement,
+      aux_sym_state_decl_repeat3,
+    STATE(84), 8,
+      sym_let_stmt,
+      sym_select_stmt,
+      sym_foreach_stmt,
+      sym_update_stmt,
+      sym_insert_stmt,
+      sym_delete_stmt,
+      sym_assert_stmt,
+      sym_commit_stmt,
+  [2199] = 12,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(21), 1,
+      anon_sym_let,
+    ACTIONS(23), 1,
+      anon_sym_select,
+    ACTIONS(25), 1,
+      anon_sym_foreach,
+    ACTIONS(27), 1,
+      anon_sym_update,
+    ACTIONS(29), 1,
+      anon_sym_insert,
+    ACTIONS(31), 1,
+      anon_sym_delete,
     ACTIONS(33), 1,
+      anon_sym_assert,
+    ACTIONS(35), 1,
       anon_sym_commit,
-    ACTIONS(179), 1,
+    ACTIONS(187), 1,
       anon_sym_RBRACE,
-    STATE(33), 2,
+    STATE(46), 2,
       sym_statement,
       aux_sym_state_decl_repeat3,
-    STATE(76), 8,
+    STATE(84), 8,
       sym_let_stmt,
       sym_select_stmt,
       sym_foreach_stmt,
@@ -3953,471 +4344,503 @@ static const uint16_t ts_small_parse_table[] = {
       sym_delete_stmt,
       sym_assert_stmt,
       sym_commit_stmt,
-  [1875] = 13,
+  [2244] = 13,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    ACTIONS(181), 1,
+    ACTIONS(189), 1,
       anon_sym_RPAREN,
-    STATE(59), 1,
+    STATE(65), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [1922] = 12,
+  [2291] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(65), 1,
+    STATE(74), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [1966] = 12,
+  [2335] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(28), 1,
+    STATE(76), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2010] = 12,
+  [2379] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(66), 1,
+    STATE(71), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2054] = 12,
+  [2423] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(63), 1,
+    STATE(30), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2098] = 12,
+  [2467] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(64), 1,
+    STATE(31), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2142] = 12,
+  [2511] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(68), 1,
+    STATE(70), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2186] = 12,
+  [2555] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(60), 1,
+    STATE(75), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2230] = 12,
+  [2599] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(27), 1,
+    STATE(77), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2274] = 12,
+  [2643] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(26), 1,
+    STATE(68), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2318] = 12,
+  [2687] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(25), 1,
+    STATE(78), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2362] = 12,
+  [2731] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(67), 1,
+    STATE(73), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2406] = 12,
+  [2775] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(21), 1,
+    STATE(33), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2450] = 12,
+  [2819] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(37), 1,
+    ACTIONS(71), 1,
       sym_identifier,
-    ACTIONS(39), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACE,
-    ACTIONS(43), 1,
+    ACTIONS(77), 1,
       anon_sym_LPAREN,
-    ACTIONS(51), 1,
+    ACTIONS(85), 1,
       anon_sym_null,
-    ACTIONS(53), 1,
+    ACTIONS(87), 1,
       sym_number,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    STATE(69), 1,
+    STATE(28), 1,
       sym_expr,
-    ACTIONS(49), 2,
+    ACTIONS(83), 2,
       anon_sym_DASH,
       anon_sym_BANG,
-    ACTIONS(55), 2,
+    ACTIONS(89), 2,
       anon_sym_true,
       anon_sym_false,
-    STATE(20), 2,
+    STATE(35), 2,
       sym_boolean,
       sym_string,
-    STATE(19), 5,
+    STATE(32), 5,
       sym_struct_literal,
       sym_binary_expr,
       sym_unary_expr,
       sym_field_access,
       sym_literal,
-  [2494] = 6,
+  [2863] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(183), 1,
+    ACTIONS(71), 1,
+      sym_identifier,
+    ACTIONS(73), 1,
+      anon_sym_LBRACE,
+    ACTIONS(77), 1,
+      anon_sym_LPAREN,
+    ACTIONS(85), 1,
+      anon_sym_null,
+    ACTIONS(87), 1,
+      sym_number,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    STATE(29), 1,
+      sym_expr,
+    ACTIONS(83), 2,
+      anon_sym_DASH,
+      anon_sym_BANG,
+    ACTIONS(89), 2,
+      anon_sym_true,
+      anon_sym_false,
+    STATE(35), 2,
+      sym_boolean,
+      sym_string,
+    STATE(32), 5,
+      sym_struct_literal,
+      sym_binary_expr,
+      sym_unary_expr,
+      sym_field_access,
+      sym_literal,
+  [2907] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(191), 1,
       anon_sym_use,
-    ACTIONS(188), 1,
+    ACTIONS(196), 1,
       anon_sym_pre,
-    STATE(56), 2,
+    STATE(63), 2,
       sym_use_stmt,
       aux_sym_state_decl_repeat2,
-    STATE(70), 3,
+    STATE(80), 3,
       sym_use_state_stmt,
       sym_use_context_stmt,
       sym_use_type_stmt,
-    ACTIONS(186), 10,
+    ACTIONS(194), 10,
       anon_sym_precondition,
       anon_sym_let,
       anon_sym_select,
@@ -4428,14 +4851,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [2525] = 9,
+  [2938] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4446,29 +4869,29 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(190), 4,
+    ACTIONS(198), 4,
       anon_sym_SEMI,
       anon_sym_EQ_GT,
       anon_sym_BSLASH_SLASH,
       anon_sym_SLASH_BSLASH,
-  [2561] = 11,
+  [2974] = 11,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(181), 1,
+    ACTIONS(183), 1,
       anon_sym_RPAREN,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(196), 1,
+    ACTIONS(204), 1,
       anon_sym_COMMA,
-    STATE(162), 1,
+    STATE(184), 1,
       aux_sym_insert_stmt_repeat1,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4479,24 +4902,24 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2600] = 11,
+  [3013] = 11,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(173), 1,
-      anon_sym_RPAREN,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(196), 1,
+    ACTIONS(204), 1,
       anon_sym_COMMA,
-    STATE(148), 1,
+    ACTIONS(206), 1,
+      anon_sym_RPAREN,
+    STATE(185), 1,
       aux_sym_insert_stmt_repeat1,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4507,18 +4930,44 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
+      anon_sym_POUND,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [3052] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(139), 1,
+      anon_sym_STAR,
+    ACTIONS(141), 1,
+      anon_sym_SLASH,
+    ACTIONS(200), 1,
+      anon_sym_EQ,
+    ACTIONS(133), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(135), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(137), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2639] = 9,
+    ACTIONS(208), 3,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [3087] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4529,28 +4978,28 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-    ACTIONS(198), 3,
+    ACTIONS(210), 3,
       anon_sym_SEMI,
       anon_sym_COMMA,
       anon_sym_where,
-  [2674] = 11,
+  [3122] = 11,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(189), 1,
+      anon_sym_RPAREN,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(196), 1,
+    ACTIONS(204), 1,
       anon_sym_COMMA,
-    ACTIONS(200), 1,
-      anon_sym_RPAREN,
-    STATE(149), 1,
+    STATE(182), 1,
       aux_sym_insert_stmt_repeat1,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4561,37 +5010,43 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2713] = 3,
+  [3161] = 9,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(202), 4,
-      anon_sym_null,
-      anon_sym_true,
-      anon_sym_false,
-      sym_identifier,
-    ACTIONS(65), 10,
-      anon_sym_LBRACE,
-      anon_sym_RBRACE,
-      anon_sym_LPAREN,
-      anon_sym_TILDE,
-      anon_sym_BSLASHE,
-      anon_sym_BSLASHA,
+    ACTIONS(139), 1,
+      anon_sym_STAR,
+    ACTIONS(141), 1,
+      anon_sym_SLASH,
+    ACTIONS(200), 1,
+      anon_sym_EQ,
+    ACTIONS(133), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(135), 2,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+    ACTIONS(137), 2,
+      anon_sym_PLUS,
       anon_sym_DASH,
-      anon_sym_BANG,
-      sym_number,
-      anon_sym_DQUOTE,
-  [2735] = 9,
+    ACTIONS(212), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+    ACTIONS(202), 3,
+      anon_sym_POUND,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+  [3195] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4602,21 +5057,40 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(204), 2,
+    ACTIONS(214), 2,
       anon_sym_SEMI,
       anon_sym_limit,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2769] = 9,
+  [3229] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(216), 4,
+      anon_sym_null,
+      anon_sym_true,
+      anon_sym_false,
+      sym_identifier,
+    ACTIONS(45), 10,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      anon_sym_LPAREN,
+      anon_sym_TILDE,
+      anon_sym_BSLASHE,
+      anon_sym_BSLASHA,
+      anon_sym_DASH,
+      anon_sym_BANG,
+      sym_number,
+      anon_sym_DQUOTE,
+  [3251] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4627,22 +5101,24 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(206), 2,
-      anon_sym_RBRACE,
+    ACTIONS(218), 2,
       anon_sym_COMMA,
-    ACTIONS(194), 3,
+      anon_sym_RPAREN,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2803] = 9,
+  [3285] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
+    ACTIONS(220), 1,
+      anon_sym_COLON,
     ACTIONS(133), 2,
       anon_sym_LT,
       anon_sym_GT,
@@ -4652,23 +5128,20 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(208), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2837] = 9,
+  [3318] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(210), 1,
+    ACTIONS(222), 1,
       anon_sym_SEMI,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4679,21 +5152,21 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2870] = 9,
+  [3351] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(212), 1,
-      anon_sym_COLON,
+    ACTIONS(224), 1,
+      anon_sym_RPAREN,
     ACTIONS(133), 2,
       anon_sym_LT,
       anon_sym_GT,
@@ -4703,20 +5176,20 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2903] = 9,
+  [3384] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(214), 1,
+    ACTIONS(226), 1,
       anon_sym_SEMI,
     ACTIONS(133), 2,
       anon_sym_LT,
@@ -4727,21 +5200,21 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2936] = 9,
+  [3417] = 9,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(139), 1,
       anon_sym_STAR,
     ACTIONS(141), 1,
       anon_sym_SLASH,
-    ACTIONS(192), 1,
+    ACTIONS(200), 1,
       anon_sym_EQ,
-    ACTIONS(216), 1,
-      anon_sym_RPAREN,
+    ACTIONS(228), 1,
+      anon_sym_COLON,
     ACTIONS(133), 2,
       anon_sym_LT,
       anon_sym_GT,
@@ -4751,16 +5224,16 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(137), 2,
       anon_sym_PLUS,
       anon_sym_DASH,
-    ACTIONS(194), 3,
+    ACTIONS(202), 3,
       anon_sym_POUND,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
-  [2969] = 3,
+  [3450] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(220), 1,
+    ACTIONS(232), 1,
       anon_sym_pre,
-    ACTIONS(218), 11,
+    ACTIONS(230), 11,
       anon_sym_use,
       anon_sym_precondition,
       anon_sym_let,
@@ -4772,12 +5245,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [2989] = 3,
+  [3470] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(224), 1,
+    ACTIONS(236), 1,
       anon_sym_pre,
-    ACTIONS(222), 11,
+    ACTIONS(234), 11,
       anon_sym_use,
       anon_sym_precondition,
       anon_sym_let,
@@ -4789,12 +5262,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3009] = 3,
+  [3490] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(228), 1,
+    ACTIONS(240), 1,
       anon_sym_pre,
-    ACTIONS(226), 11,
+    ACTIONS(238), 11,
       anon_sym_use,
       anon_sym_precondition,
       anon_sym_let,
@@ -4806,12 +5279,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3029] = 3,
+  [3510] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(232), 1,
+    ACTIONS(244), 1,
       anon_sym_pre,
-    ACTIONS(230), 11,
+    ACTIONS(242), 11,
       anon_sym_use,
       anon_sym_precondition,
       anon_sym_let,
@@ -4823,10 +5296,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3049] = 2,
+  [3530] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(234), 10,
+    ACTIONS(246), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4837,10 +5310,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3065] = 2,
+  [3546] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(236), 10,
+    ACTIONS(248), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4851,10 +5324,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3081] = 2,
+  [3562] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(238), 10,
+    ACTIONS(250), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4865,10 +5338,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3097] = 2,
+  [3578] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(240), 10,
+    ACTIONS(252), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4879,10 +5352,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3113] = 2,
+  [3594] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(242), 10,
+    ACTIONS(254), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4893,10 +5366,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3129] = 2,
+  [3610] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(256), 1,
+      ts_builtin_sym_end,
+    ACTIONS(258), 1,
+      anon_sym_type,
+    ACTIONS(261), 1,
+      anon_sym_context,
+    ACTIONS(264), 1,
+      anon_sym_invariant,
+    ACTIONS(267), 1,
+      anon_sym_state,
+    STATE(88), 5,
+      sym_type_decl,
+      sym_context_decl,
+      sym_invariant_decl,
+      sym_state_decl,
+      aux_sym_program_repeat1,
+  [3636] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(244), 10,
+    ACTIONS(270), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4907,10 +5399,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3145] = 2,
+  [3652] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(246), 10,
+    ACTIONS(272), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4921,10 +5413,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3161] = 2,
+  [3668] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(248), 10,
+    ACTIONS(274), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4935,10 +5427,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3177] = 2,
+  [3684] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(250), 10,
+    ACTIONS(276), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4949,10 +5441,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3193] = 2,
+  [3700] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(252), 10,
+    ACTIONS(278), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4963,10 +5455,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3209] = 2,
+  [3716] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(254), 10,
+    ACTIONS(280), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4977,10 +5469,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3225] = 2,
+  [3732] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(256), 10,
+    ACTIONS(282), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -4991,10 +5483,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3241] = 2,
+  [3748] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(258), 10,
+    ACTIONS(284), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5005,10 +5497,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3257] = 2,
+  [3764] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(260), 10,
+    ACTIONS(286), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5019,10 +5511,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3273] = 2,
+  [3780] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(262), 10,
+    ACTIONS(288), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5033,10 +5525,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3289] = 2,
+  [3796] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(264), 10,
+    ACTIONS(290), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5047,10 +5539,29 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3305] = 2,
+  [3812] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_type,
+    ACTIONS(9), 1,
+      anon_sym_context,
+    ACTIONS(11), 1,
+      anon_sym_invariant,
+    ACTIONS(13), 1,
+      anon_sym_state,
+    ACTIONS(292), 1,
+      ts_builtin_sym_end,
+    STATE(88), 5,
+      sym_type_decl,
+      sym_context_decl,
+      sym_invariant_decl,
+      sym_state_decl,
+      aux_sym_program_repeat1,
+  [3838] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(266), 10,
+    ACTIONS(294), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5061,10 +5572,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3321] = 2,
+  [3854] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(268), 10,
+    ACTIONS(296), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5075,10 +5586,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3337] = 2,
+  [3870] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(270), 10,
+    ACTIONS(298), 10,
       anon_sym_RBRACE,
       anon_sym_let,
       anon_sym_select,
@@ -5089,10 +5600,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3353] = 2,
+  [3886] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(272), 9,
+    ACTIONS(300), 9,
       anon_sym_let,
       anon_sym_select,
       anon_sym_foreach,
@@ -5102,1265 +5613,1364 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_assert,
       anon_sym_commit,
       anon_sym_next,
-  [3368] = 5,
+  [3901] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(194), 1,
+    STATE(229), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3388] = 6,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [3921] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(278), 1,
-      ts_builtin_sym_end,
-    ACTIONS(280), 1,
-      anon_sym_type,
-    ACTIONS(283), 1,
-      anon_sym_context,
-    ACTIONS(286), 1,
-      anon_sym_state,
-    STATE(95), 4,
-      sym_type_decl,
-      sym_context_decl,
-      sym_state_decl,
-      aux_sym_program_repeat1,
-  [3410] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(190), 1,
+    STATE(206), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3430] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [3941] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(207), 1,
+    STATE(231), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3450] = 6,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(7), 1,
-      anon_sym_type,
-    ACTIONS(9), 1,
-      anon_sym_context,
-    ACTIONS(11), 1,
-      anon_sym_state,
-    ACTIONS(289), 1,
-      ts_builtin_sym_end,
-    STATE(95), 4,
-      sym_type_decl,
-      sym_context_decl,
-      sym_state_decl,
-      aux_sym_program_repeat1,
-  [3472] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [3961] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(211), 1,
+    STATE(251), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3492] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [3981] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(192), 1,
+    STATE(213), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3512] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [4001] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(255), 1,
+    STATE(280), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3532] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [4021] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(188), 1,
+    STATE(226), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3552] = 5,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [4041] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(274), 1,
+    ACTIONS(302), 1,
       sym_identifier,
-    STATE(209), 1,
+    STATE(211), 1,
       sym_type,
-    STATE(113), 2,
+    STATE(125), 2,
       sym_primitive_type,
       sym_generic_type,
-    ACTIONS(276), 4,
-      anon_sym_Int,
-      anon_sym_Bool,
-      anon_sym_String,
-      anon_sym_Float,
-  [3572] = 3,
+    ACTIONS(304), 4,
+      anon_sym_int,
+      anon_sym_bool,
+      anon_sym_string,
+      anon_sym_float,
+  [4061] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(293), 1,
-      anon_sym_LBRACK,
-    ACTIONS(291), 5,
+    ACTIONS(306), 1,
+      anon_sym_LBRACE,
+    ACTIONS(310), 1,
       anon_sym_SEMI,
-      anon_sym_EQ,
-      anon_sym_from,
-      anon_sym_in,
-      anon_sym_RBRACK,
-  [3586] = 6,
+    STATE(140), 1,
+      sym_edge_export_block,
+    ACTIONS(308), 3,
+      anon_sym_RBRACE,
+      anon_sym_when,
+      anon_sym_otherwise,
+  [4079] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(295), 1,
+    ACTIONS(306), 1,
+      anon_sym_LBRACE,
+    ACTIONS(314), 1,
       anon_sym_SEMI,
-    ACTIONS(297), 1,
-      anon_sym_COMMA,
-    ACTIONS(299), 1,
-      anon_sym_where,
-    STATE(139), 1,
-      aux_sym_update_stmt_repeat1,
-    STATE(200), 1,
-      sym_where_clause,
-  [3605] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(301), 1,
+    STATE(160), 1,
+      sym_edge_export_block,
+    ACTIONS(312), 3,
       anon_sym_RBRACE,
-    ACTIONS(303), 1,
       anon_sym_when,
-    ACTIONS(305), 1,
       anon_sym_otherwise,
-    STATE(111), 2,
-      sym_next_case,
-      aux_sym_next_block_repeat1,
-  [3622] = 2,
+  [4097] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(307), 5,
+    ACTIONS(318), 1,
+      anon_sym_LBRACK,
+    ACTIONS(316), 5,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_from,
       anon_sym_in,
       anon_sym_RBRACK,
-  [3633] = 2,
+  [4111] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(320), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4122] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(322), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4133] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(309), 5,
+    ACTIONS(324), 1,
       anon_sym_SEMI,
-      anon_sym_EQ,
-      anon_sym_from,
-      anon_sym_in,
-      anon_sym_RBRACK,
-  [3644] = 6,
+    ACTIONS(326), 1,
+      anon_sym_COMMA,
+    ACTIONS(328), 1,
+      anon_sym_where,
+    STATE(158), 1,
+      aux_sym_update_stmt_repeat1,
+    STATE(218), 1,
+      sym_where_clause,
+  [4152] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(330), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4163] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(299), 1,
+    ACTIONS(328), 1,
       anon_sym_where,
-    ACTIONS(311), 1,
+    ACTIONS(332), 1,
       anon_sym_SEMI,
-    ACTIONS(313), 1,
+    ACTIONS(334), 1,
       anon_sym_limit,
-    STATE(155), 1,
+    STATE(174), 1,
       sym_where_clause,
-    STATE(215), 1,
+    STATE(236), 1,
       sym_limit_clause,
-  [3663] = 4,
+  [4182] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(315), 1,
-      anon_sym_LBRACE,
-    STATE(157), 1,
-      sym_edge_export_block,
-    ACTIONS(317), 3,
+    ACTIONS(336), 1,
       anon_sym_RBRACE,
+    ACTIONS(338), 1,
       anon_sym_when,
+    ACTIONS(341), 1,
       anon_sym_otherwise,
-  [3678] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(319), 1,
-      anon_sym_RBRACE,
-    ACTIONS(321), 1,
-      anon_sym_when,
-    ACTIONS(324), 1,
-      anon_sym_otherwise,
-    STATE(111), 2,
+    STATE(121), 2,
       sym_next_case,
       aux_sym_next_block_repeat1,
-  [3695] = 4,
+  [4199] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(315), 1,
-      anon_sym_LBRACE,
-    STATE(154), 1,
-      sym_edge_export_block,
-    ACTIONS(327), 3,
-      anon_sym_RBRACE,
-      anon_sym_when,
-      anon_sym_otherwise,
-  [3710] = 2,
+    ACTIONS(344), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4210] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(291), 5,
+    ACTIONS(346), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4221] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(348), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4232] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(316), 5,
       anon_sym_SEMI,
       anon_sym_EQ,
       anon_sym_from,
       anon_sym_in,
       anon_sym_RBRACK,
-  [3721] = 6,
+  [4243] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(297), 1,
-      anon_sym_COMMA,
-    ACTIONS(299), 1,
-      anon_sym_where,
-    ACTIONS(329), 1,
+    ACTIONS(350), 5,
       anon_sym_SEMI,
-    STATE(105), 1,
-      aux_sym_update_stmt_repeat1,
-    STATE(248), 1,
-      sym_where_clause,
-  [3740] = 2,
+      anon_sym_EQ,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_RBRACK,
+  [4254] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(331), 4,
+    ACTIONS(352), 5,
       ts_builtin_sym_end,
       anon_sym_type,
       anon_sym_context,
+      anon_sym_invariant,
       anon_sym_state,
-  [3750] = 2,
+  [4265] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(333), 4,
+    ACTIONS(354), 5,
       ts_builtin_sym_end,
       anon_sym_type,
       anon_sym_context,
+      anon_sym_invariant,
       anon_sym_state,
-  [3760] = 4,
+  [4276] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(335), 1,
-      sym_identifier,
-    ACTIONS(337), 1,
+    ACTIONS(356), 1,
       anon_sym_RBRACE,
-    STATE(126), 2,
-      sym_type_field,
-      aux_sym_type_decl_repeat1,
-  [3774] = 4,
+    ACTIONS(358), 1,
+      anon_sym_when,
+    ACTIONS(360), 1,
+      anon_sym_otherwise,
+    STATE(121), 2,
+      sym_next_case,
+      aux_sym_next_block_repeat1,
+  [4293] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(341), 1,
+    ACTIONS(362), 5,
+      anon_sym_SEMI,
+      anon_sym_EQ,
+      anon_sym_from,
+      anon_sym_in,
+      anon_sym_RBRACK,
+  [4304] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(364), 5,
+      ts_builtin_sym_end,
+      anon_sym_type,
+      anon_sym_context,
+      anon_sym_invariant,
+      anon_sym_state,
+  [4315] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(326), 1,
       anon_sym_COMMA,
+    ACTIONS(328), 1,
+      anon_sym_where,
+    ACTIONS(366), 1,
+      anon_sym_SEMI,
     STATE(118), 1,
-      aux_sym_state_decl_repeat1,
-    ACTIONS(339), 2,
-      anon_sym_LBRACE,
-      anon_sym_RPAREN,
-  [3788] = 2,
+      aux_sym_update_stmt_repeat1,
+    STATE(276), 1,
+      sym_where_clause,
+  [4334] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(368), 1,
+      sym_identifier,
+    ACTIONS(371), 1,
+      anon_sym_RBRACE,
+    STATE(133), 2,
+      sym_edge_field,
+      aux_sym_edge_export_block_repeat1,
+  [4348] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(344), 4,
+    ACTIONS(373), 1,
       anon_sym_SEMI,
+    ACTIONS(375), 1,
       anon_sym_EQ_GT,
+    ACTIONS(377), 1,
       anon_sym_BSLASH_SLASH,
+    ACTIONS(379), 1,
       anon_sym_SLASH_BSLASH,
-  [3798] = 4,
-    ACTIONS(3), 1,
+  [4364] = 4,
+    ACTIONS(381), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(385), 1,
       sym_comment,
-    ACTIONS(346), 1,
-      sym_identifier,
-    ACTIONS(348), 1,
-      anon_sym_RBRACE,
-    STATE(134), 2,
-      sym_context_field,
-      aux_sym_context_decl_repeat1,
-  [3812] = 5,
+    STATE(149), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(383), 2,
+      aux_sym_string_token1,
+      aux_sym_string_token2,
+  [4378] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(57), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(350), 1,
+    ACTIONS(387), 1,
       sym_identifier,
-    STATE(197), 1,
-      sym_qualified_name,
-    STATE(199), 1,
-      sym_string,
-  [3828] = 5,
+    ACTIONS(389), 1,
+      anon_sym_RBRACE,
+    STATE(152), 2,
+      sym_use_field,
+      aux_sym_use_state_stmt_repeat1,
+  [4392] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(57), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(350), 1,
+    ACTIONS(391), 1,
       sym_identifier,
-    STATE(199), 1,
-      sym_string,
-    STATE(205), 1,
-      sym_qualified_name,
-  [3844] = 5,
+    ACTIONS(393), 1,
+      anon_sym_RBRACE,
+    STATE(150), 2,
+      sym_context_field,
+      aux_sym_context_decl_repeat1,
+  [4406] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(57), 1,
+    ACTIONS(91), 1,
       anon_sym_DQUOTE,
-    ACTIONS(350), 1,
+    ACTIONS(395), 1,
       sym_identifier,
-    STATE(199), 1,
+    STATE(238), 1,
       sym_string,
-    STATE(210), 1,
+    STATE(244), 1,
       sym_qualified_name,
-  [3860] = 4,
-    ACTIONS(352), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(357), 1,
-      sym_comment,
-    STATE(124), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(354), 2,
-      aux_sym_string_token1,
-      aux_sym_string_token2,
-  [3874] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(359), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [3884] = 4,
+  [4422] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(361), 1,
+    ACTIONS(397), 1,
       sym_identifier,
-    ACTIONS(364), 1,
+    ACTIONS(399), 1,
       anon_sym_RBRACE,
-    STATE(126), 2,
+    STATE(159), 2,
       sym_type_field,
       aux_sym_type_decl_repeat1,
-  [3898] = 4,
-    ACTIONS(357), 1,
-      sym_comment,
-    ACTIONS(366), 1,
-      anon_sym_DQUOTE,
-    STATE(144), 1,
-      aux_sym_string_repeat1,
-    ACTIONS(368), 2,
-      aux_sym_string_token1,
-      aux_sym_string_token2,
-  [3912] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(370), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [3922] = 2,
+  [4436] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(372), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [3932] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(374), 1,
-      sym_identifier,
-    ACTIONS(376), 1,
+    ACTIONS(403), 1,
+      anon_sym_SEMI,
+    ACTIONS(401), 3,
       anon_sym_RBRACE,
-    STATE(138), 2,
-      sym_use_field,
-      aux_sym_use_state_stmt_repeat1,
-  [3946] = 4,
+      anon_sym_when,
+      anon_sym_otherwise,
+  [4448] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(380), 1,
-      anon_sym_BSLASH_SLASH,
-    ACTIONS(382), 1,
-      anon_sym_SLASH_BSLASH,
-    ACTIONS(378), 2,
+    ACTIONS(405), 4,
       anon_sym_SEMI,
       anon_sym_EQ_GT,
-  [3960] = 3,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [4458] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(382), 1,
-      anon_sym_SLASH_BSLASH,
-    ACTIONS(378), 3,
+    ACTIONS(198), 4,
       anon_sym_SEMI,
       anon_sym_EQ_GT,
       anon_sym_BSLASH_SLASH,
-  [3972] = 2,
+      anon_sym_SLASH_BSLASH,
+  [4468] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(378), 4,
+    ACTIONS(407), 1,
+      sym_identifier,
+    ACTIONS(410), 1,
+      anon_sym_RBRACE,
+    STATE(143), 2,
+      sym_context_field,
+      aux_sym_context_decl_repeat1,
+  [4482] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(379), 1,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(412), 3,
       anon_sym_SEMI,
       anon_sym_EQ_GT,
       anon_sym_BSLASH_SLASH,
-      anon_sym_SLASH_BSLASH,
-  [3982] = 4,
+  [4494] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(416), 1,
+      anon_sym_COMMA,
+    STATE(145), 1,
+      aux_sym_state_decl_repeat1,
+    ACTIONS(414), 2,
+      anon_sym_LBRACE,
+      anon_sym_RPAREN,
+  [4508] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(384), 1,
+    ACTIONS(419), 1,
       sym_identifier,
-    ACTIONS(387), 1,
+    ACTIONS(421), 1,
       anon_sym_RBRACE,
-    STATE(134), 2,
-      sym_context_field,
-      aux_sym_context_decl_repeat1,
-  [3996] = 4,
+    STATE(133), 2,
+      sym_edge_field,
+      aux_sym_edge_export_block_repeat1,
+  [4522] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(303), 1,
+    ACTIONS(375), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(377), 1,
+      anon_sym_BSLASH_SLASH,
+    ACTIONS(379), 1,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(423), 1,
+      anon_sym_SEMI,
+  [4538] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(358), 1,
       anon_sym_when,
-    ACTIONS(305), 1,
+    ACTIONS(360), 1,
       anon_sym_otherwise,
-    STATE(106), 2,
+    STATE(129), 2,
       sym_next_case,
       aux_sym_next_block_repeat1,
-  [4010] = 2,
+  [4552] = 4,
+    ACTIONS(385), 1,
+      sym_comment,
+    ACTIONS(425), 1,
+      anon_sym_DQUOTE,
+    STATE(149), 1,
+      aux_sym_string_repeat1,
+    ACTIONS(427), 2,
+      aux_sym_string_token1,
+      aux_sym_string_token2,
+  [4566] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(389), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [4020] = 2,
+    ACTIONS(391), 1,
+      sym_identifier,
+    ACTIONS(430), 1,
+      anon_sym_RBRACE,
+    STATE(143), 2,
+      sym_context_field,
+      aux_sym_context_decl_repeat1,
+  [4580] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(391), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [4030] = 4,
+    ACTIONS(412), 4,
+      anon_sym_SEMI,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [4590] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(393), 1,
+    ACTIONS(432), 1,
       sym_identifier,
-    ACTIONS(396), 1,
+    ACTIONS(435), 1,
       anon_sym_RBRACE,
-    STATE(138), 2,
+    STATE(152), 2,
       sym_use_field,
       aux_sym_use_state_stmt_repeat1,
-  [4044] = 4,
+  [4604] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(400), 1,
-      anon_sym_COMMA,
-    STATE(139), 1,
-      aux_sym_update_stmt_repeat1,
-    ACTIONS(398), 2,
+    ACTIONS(437), 4,
+      anon_sym_RBRACE,
       anon_sym_SEMI,
-      anon_sym_where,
-  [4058] = 2,
+      anon_sym_when,
+      anon_sym_otherwise,
+  [4614] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(403), 4,
-      ts_builtin_sym_end,
-      anon_sym_type,
-      anon_sym_context,
-      anon_sym_state,
-  [4068] = 5,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(395), 1,
+      sym_identifier,
+    STATE(233), 1,
+      sym_qualified_name,
+    STATE(238), 1,
+      sym_string,
+  [4630] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(380), 1,
+    ACTIONS(377), 1,
       anon_sym_BSLASH_SLASH,
-    ACTIONS(382), 1,
+    ACTIONS(379), 1,
       anon_sym_SLASH_BSLASH,
-    ACTIONS(405), 1,
+    ACTIONS(412), 2,
       anon_sym_SEMI,
-    ACTIONS(407), 1,
       anon_sym_EQ_GT,
-  [4084] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(190), 4,
-      anon_sym_SEMI,
-      anon_sym_EQ_GT,
-      anon_sym_BSLASH_SLASH,
-      anon_sym_SLASH_BSLASH,
-  [4094] = 4,
+  [4644] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(409), 1,
+    ACTIONS(91), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(395), 1,
       sym_identifier,
-    ACTIONS(411), 1,
-      anon_sym_RBRACE,
-    STATE(145), 2,
-      sym_edge_field,
-      aux_sym_edge_export_block_repeat1,
-  [4108] = 4,
-    ACTIONS(357), 1,
+    STATE(202), 1,
+      sym_qualified_name,
+    STATE(238), 1,
+      sym_string,
+  [4660] = 4,
+    ACTIONS(385), 1,
       sym_comment,
-    ACTIONS(413), 1,
+    ACTIONS(439), 1,
       anon_sym_DQUOTE,
-    STATE(124), 1,
+    STATE(135), 1,
       aux_sym_string_repeat1,
-    ACTIONS(415), 2,
+    ACTIONS(441), 2,
       aux_sym_string_token1,
       aux_sym_string_token2,
-  [4122] = 4,
+  [4674] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(417), 1,
-      sym_identifier,
-    ACTIONS(420), 1,
-      anon_sym_RBRACE,
-    STATE(145), 2,
-      sym_edge_field,
-      aux_sym_edge_export_block_repeat1,
-  [4136] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(380), 1,
-      anon_sym_BSLASH_SLASH,
-    ACTIONS(382), 1,
-      anon_sym_SLASH_BSLASH,
-    ACTIONS(407), 1,
-      anon_sym_EQ_GT,
-    ACTIONS(422), 1,
+    ACTIONS(445), 1,
+      anon_sym_COMMA,
+    STATE(158), 1,
+      aux_sym_update_stmt_repeat1,
+    ACTIONS(443), 2,
       anon_sym_SEMI,
-  [4152] = 4,
+      anon_sym_where,
+  [4688] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(346), 1,
+    ACTIONS(448), 1,
       sym_identifier,
-    ACTIONS(424), 1,
+    ACTIONS(451), 1,
       anon_sym_RBRACE,
-    STATE(120), 2,
-      sym_context_field,
-      aux_sym_context_decl_repeat1,
-  [4166] = 4,
+    STATE(159), 2,
+      sym_type_field,
+      aux_sym_type_decl_repeat1,
+  [4702] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(196), 1,
-      anon_sym_COMMA,
-    ACTIONS(200), 1,
-      anon_sym_RPAREN,
-    STATE(167), 1,
-      aux_sym_insert_stmt_repeat1,
-  [4179] = 4,
+    ACTIONS(455), 1,
+      anon_sym_SEMI,
+    ACTIONS(453), 3,
+      anon_sym_RBRACE,
+      anon_sym_when,
+      anon_sym_otherwise,
+  [4714] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(196), 1,
+    ACTIONS(457), 1,
+      anon_sym_LBRACE,
+    ACTIONS(459), 1,
       anon_sym_COMMA,
-    ACTIONS(426), 1,
-      anon_sym_RPAREN,
-    STATE(167), 1,
-      aux_sym_insert_stmt_repeat1,
-  [4192] = 4,
+    STATE(145), 1,
+      aux_sym_state_decl_repeat1,
+  [4727] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(428), 1,
+    ACTIONS(461), 1,
       anon_sym_RBRACE,
-    ACTIONS(430), 1,
+    ACTIONS(463), 1,
       anon_sym_COMMA,
-    STATE(150), 1,
+    STATE(162), 1,
       aux_sym_struct_literal_repeat1,
-  [4205] = 4,
+  [4740] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(433), 1,
-      anon_sym_LBRACE,
-    ACTIONS(435), 1,
+    ACTIONS(397), 1,
+      sym_identifier,
+    STATE(139), 2,
+      sym_type_field,
+      aux_sym_type_decl_repeat1,
+  [4751] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(466), 1,
+      anon_sym_EQ_GT,
+    ACTIONS(468), 1,
+      anon_sym_BSLASH_SLASH,
+    ACTIONS(470), 1,
+      anon_sym_SLASH_BSLASH,
+  [4764] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(208), 3,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [4773] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(459), 1,
       anon_sym_COMMA,
-    STATE(158), 1,
+    ACTIONS(472), 1,
+      anon_sym_RPAREN,
+    STATE(170), 1,
       aux_sym_state_decl_repeat1,
-  [4218] = 2,
+  [4786] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(474), 3,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [4795] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(476), 1,
+      anon_sym_type,
+    ACTIONS(478), 1,
+      anon_sym_context,
+    ACTIONS(480), 1,
+      anon_sym_state,
+  [4808] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(398), 3,
+    ACTIONS(443), 3,
       anon_sym_SEMI,
       anon_sym_COMMA,
       anon_sym_where,
-  [4227] = 4,
+  [4817] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(435), 1,
+    ACTIONS(459), 1,
       anon_sym_COMMA,
-    ACTIONS(437), 1,
+    ACTIONS(482), 1,
       anon_sym_RPAREN,
-    STATE(118), 1,
+    STATE(145), 1,
       aux_sym_state_decl_repeat1,
-  [4240] = 2,
+  [4830] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(470), 1,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(484), 2,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+  [4841] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(484), 3,
+      anon_sym_EQ_GT,
+      anon_sym_BSLASH_SLASH,
+      anon_sym_SLASH_BSLASH,
+  [4850] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(439), 3,
+    ACTIONS(453), 3,
       anon_sym_RBRACE,
       anon_sym_when,
       anon_sym_otherwise,
-  [4249] = 4,
+  [4859] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(313), 1,
+    ACTIONS(334), 1,
       anon_sym_limit,
-    ACTIONS(441), 1,
+    ACTIONS(486), 1,
       anon_sym_SEMI,
-    STATE(227), 1,
+    STATE(250), 1,
       sym_limit_clause,
-  [4262] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(443), 1,
-      anon_sym_RBRACE,
-    ACTIONS(445), 1,
-      anon_sym_COMMA,
-    STATE(150), 1,
-      aux_sym_struct_literal_repeat1,
-  [4275] = 2,
+  [4872] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(447), 3,
+    ACTIONS(401), 3,
       anon_sym_RBRACE,
       anon_sym_when,
       anon_sym_otherwise,
-  [4284] = 4,
+  [4881] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(435), 1,
+    ACTIONS(419), 1,
+      sym_identifier,
+    STATE(146), 2,
+      sym_edge_field,
+      aux_sym_edge_export_block_repeat1,
+  [4892] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(459), 1,
       anon_sym_COMMA,
-    ACTIONS(449), 1,
+    ACTIONS(488), 1,
       anon_sym_LBRACE,
-    STATE(118), 1,
+    STATE(161), 1,
       aux_sym_state_decl_repeat1,
-  [4297] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(451), 1,
-      anon_sym_type,
-    ACTIONS(453), 1,
-      anon_sym_context,
-    ACTIONS(455), 1,
-      anon_sym_state,
-  [4310] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(299), 1,
-      anon_sym_where,
-    ACTIONS(457), 1,
-      anon_sym_SEMI,
-    STATE(232), 1,
-      sym_where_clause,
-  [4323] = 4,
+  [4905] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      anon_sym_COMMA,
-    ACTIONS(459), 1,
+    ACTIONS(490), 1,
       anon_sym_RBRACE,
-    STATE(156), 1,
-      aux_sym_struct_literal_repeat1,
-  [4336] = 4,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(173), 1,
-      anon_sym_RPAREN,
-    ACTIONS(196), 1,
+    ACTIONS(492), 1,
       anon_sym_COMMA,
-    STATE(167), 1,
-      aux_sym_insert_stmt_repeat1,
-  [4349] = 2,
+    STATE(187), 1,
+      aux_sym_struct_literal_repeat1,
+  [4918] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 3,
+    ACTIONS(414), 3,
       anon_sym_LBRACE,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [4358] = 2,
+  [4927] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(461), 3,
+    ACTIONS(494), 3,
       anon_sym_RBRACE,
       anon_sym_when,
       anon_sym_otherwise,
-  [4367] = 3,
+  [4936] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(374), 1,
-      sym_identifier,
-    STATE(130), 2,
-      sym_use_field,
-      aux_sym_use_state_stmt_repeat1,
-  [4378] = 3,
+    ACTIONS(328), 1,
+      anon_sym_where,
+    ACTIONS(496), 1,
+      anon_sym_SEMI,
+    STATE(286), 1,
+      sym_where_clause,
+  [4949] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(335), 1,
-      sym_identifier,
-    STATE(117), 2,
-      sym_type_field,
-      aux_sym_type_decl_repeat1,
-  [4389] = 4,
+    ACTIONS(183), 1,
+      anon_sym_RPAREN,
+    ACTIONS(204), 1,
+      anon_sym_COMMA,
+    STATE(183), 1,
+      aux_sym_insert_stmt_repeat1,
+  [4962] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(208), 1,
+    ACTIONS(218), 1,
       anon_sym_RPAREN,
-    ACTIONS(463), 1,
+    ACTIONS(498), 1,
       anon_sym_COMMA,
-    STATE(167), 1,
+    STATE(183), 1,
       aux_sym_insert_stmt_repeat1,
-  [4402] = 4,
+  [4975] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(435), 1,
+    ACTIONS(204), 1,
       anon_sym_COMMA,
-    ACTIONS(466), 1,
+    ACTIONS(206), 1,
       anon_sym_RPAREN,
-    STATE(153), 1,
-      aux_sym_state_decl_repeat1,
-  [4415] = 3,
+    STATE(183), 1,
+      aux_sym_insert_stmt_repeat1,
+  [4988] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(409), 1,
-      sym_identifier,
-    STATE(143), 2,
-      sym_edge_field,
-      aux_sym_edge_export_block_repeat1,
-  [4426] = 2,
+    ACTIONS(204), 1,
+      anon_sym_COMMA,
+    ACTIONS(501), 1,
+      anon_sym_RPAREN,
+    STATE(183), 1,
+      aux_sym_insert_stmt_repeat1,
+  [5001] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(468), 1,
+      anon_sym_BSLASH_SLASH,
+    ACTIONS(470), 1,
+      anon_sym_SLASH_BSLASH,
+    ACTIONS(503), 1,
+      anon_sym_EQ_GT,
+  [5014] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(468), 2,
+    ACTIONS(492), 1,
+      anon_sym_COMMA,
+    ACTIONS(505), 1,
       anon_sym_RBRACE,
+    STATE(162), 1,
+      aux_sym_struct_literal_repeat1,
+  [5027] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(387), 1,
       sym_identifier,
-  [4434] = 2,
+    STATE(136), 2,
+      sym_use_field,
+      aux_sym_use_state_stmt_repeat1,
+  [5038] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(470), 2,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-  [4442] = 2,
+    ACTIONS(507), 3,
+      anon_sym_RBRACE,
+      anon_sym_when,
+      anon_sym_otherwise,
+  [5047] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(509), 1,
+      sym_identifier,
+    STATE(178), 1,
+      sym_struct_field,
+  [5057] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(472), 2,
+    ACTIONS(511), 2,
       anon_sym_RBRACE,
       sym_identifier,
-  [4450] = 3,
+  [5065] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(474), 1,
+    ACTIONS(513), 2,
+      anon_sym_RBRACE,
       sym_identifier,
-    ACTIONS(476), 1,
-      anon_sym_RPAREN,
-  [4460] = 2,
+  [5073] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 2,
-      anon_sym_LBRACE,
-      anon_sym_SEMI,
-  [4468] = 3,
+    ACTIONS(461), 2,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+  [5081] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(480), 1,
+    ACTIONS(515), 1,
       sym_identifier,
-    STATE(152), 1,
+    STATE(169), 1,
       sym_assignment,
-  [4478] = 2,
+  [5091] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(482), 2,
-      anon_sym_RBRACE,
+    ACTIONS(517), 1,
       sym_identifier,
-  [4486] = 3,
+    ACTIONS(519), 1,
+      anon_sym_RPAREN,
+  [5101] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(484), 1,
+    ACTIONS(509), 1,
       sym_identifier,
-    STATE(180), 1,
+    STATE(193), 1,
       sym_struct_field,
-  [4496] = 3,
+  [5111] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(480), 1,
+    ACTIONS(515), 1,
       sym_identifier,
-    STATE(114), 1,
+    STATE(132), 1,
       sym_assignment,
-  [4506] = 3,
+  [5121] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(484), 1,
-      sym_identifier,
-    STATE(161), 1,
-      sym_struct_field,
-  [4516] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(428), 2,
-      anon_sym_RBRACE,
-      anon_sym_COMMA,
-  [4524] = 2,
+    ACTIONS(521), 2,
+      anon_sym_LBRACE,
+      anon_sym_SEMI,
+  [5129] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(486), 2,
+    ACTIONS(523), 2,
       anon_sym_RBRACE,
       sym_identifier,
-  [4532] = 2,
+  [5137] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-  [4539] = 2,
+    ACTIONS(525), 2,
+      anon_sym_LBRACE,
+      anon_sym_SEMI,
+  [5145] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(490), 1,
+    ACTIONS(527), 2,
+      anon_sym_RBRACE,
       sym_identifier,
-  [4546] = 2,
+  [5153] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(492), 1,
-      anon_sym_LBRACE,
-  [4553] = 2,
+    ACTIONS(529), 1,
+      anon_sym_SEMI,
+  [5160] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(494), 1,
-      ts_builtin_sym_end,
-  [4560] = 2,
+    ACTIONS(531), 1,
+      sym_identifier,
+  [5167] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(496), 1,
+    ACTIONS(533), 1,
       sym_identifier,
-  [4567] = 2,
+  [5174] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(498), 1,
-      anon_sym_uses,
-  [4574] = 2,
+    ACTIONS(535), 1,
+      sym_identifier,
+  [5181] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(500), 1,
-      anon_sym_EQ,
-  [4581] = 2,
+    ACTIONS(537), 1,
+      anon_sym_SEMI,
+  [5188] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(502), 1,
+    ACTIONS(539), 1,
       anon_sym_RBRACE,
-  [4588] = 2,
+  [5195] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(504), 1,
+    ACTIONS(541), 1,
       anon_sym_SEMI,
-  [4595] = 2,
+  [5202] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(506), 1,
-      sym_identifier,
-  [4602] = 2,
+    ACTIONS(543), 1,
+      anon_sym_LBRACE,
+  [5209] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(508), 1,
-      anon_sym_from,
-  [4609] = 2,
+    ACTIONS(545), 1,
+      anon_sym_RBRACE,
+  [5216] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(510), 1,
-      anon_sym_by,
-  [4616] = 2,
+    ACTIONS(547), 1,
+      anon_sym_SEMI,
+  [5223] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(512), 1,
-      anon_sym_RBRACK,
-  [4623] = 2,
+    ACTIONS(549), 1,
+      ts_builtin_sym_end,
+  [5230] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(514), 1,
-      sym_identifier,
-  [4630] = 2,
+    ACTIONS(551), 1,
+      anon_sym_in,
+  [5237] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(516), 1,
+    ACTIONS(553), 1,
       anon_sym_LBRACE,
-  [4637] = 2,
+  [5244] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(518), 1,
-      anon_sym_SEMI,
-  [4644] = 2,
+    ACTIONS(555), 1,
+      anon_sym_LBRACE,
+  [5251] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(520), 1,
-      anon_sym_EQ_GT,
-  [4651] = 2,
+    ACTIONS(557), 1,
+      anon_sym_Relation,
+  [5258] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(522), 1,
-      anon_sym_COLON_COLON,
-  [4658] = 2,
+    ACTIONS(559), 1,
+      anon_sym_RBRACE,
+  [5265] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(524), 1,
+    ACTIONS(561), 1,
       anon_sym_SEMI,
-  [4665] = 2,
+  [5272] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(526), 1,
-      sym_identifier,
-  [4672] = 2,
+    ACTIONS(563), 1,
+      anon_sym_LBRACE,
+  [5279] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(528), 1,
+    ACTIONS(565), 1,
       anon_sym_LPAREN,
-  [4679] = 2,
+  [5286] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(530), 1,
+    ACTIONS(567), 1,
       anon_sym_values,
-  [4686] = 2,
+  [5293] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(532), 1,
-      anon_sym_RBRACE,
-  [4693] = 2,
+    ACTIONS(569), 1,
+      anon_sym_EQ_GT,
+  [5300] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(534), 1,
-      anon_sym_SEMI,
-  [4700] = 2,
+    ACTIONS(571), 1,
+      sym_identifier,
+  [5307] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(536), 1,
-      anon_sym_export,
-  [4707] = 2,
+    ACTIONS(573), 1,
+      anon_sym_LBRACE,
+  [5314] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(538), 1,
-      anon_sym_in,
-  [4714] = 2,
+    ACTIONS(575), 1,
+      anon_sym_EQ,
+  [5321] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(540), 1,
+    ACTIONS(577), 1,
       anon_sym_EQ,
-  [4721] = 2,
+  [5328] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(542), 1,
-      anon_sym_SEMI,
-  [4728] = 2,
+    ACTIONS(579), 1,
+      anon_sym_export,
+  [5335] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(544), 1,
-      anon_sym_LBRACE,
-  [4735] = 2,
+    ACTIONS(581), 1,
+      anon_sym_Relation,
+  [5342] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(546), 1,
+    ACTIONS(583), 1,
+      anon_sym_RBRACK,
+  [5349] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(585), 1,
+      anon_sym_COLON,
+  [5356] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(587), 1,
       anon_sym_SEMI,
-  [4742] = 2,
+  [5363] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(548), 1,
-      anon_sym_Relation,
-  [4749] = 2,
+    ACTIONS(589), 1,
+      sym_identifier,
+  [5370] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(550), 1,
+    ACTIONS(591), 1,
+      anon_sym_SEMI,
+  [5377] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(593), 1,
       sym_number,
-  [4756] = 2,
+  [5384] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(552), 1,
+    ACTIONS(595), 1,
       anon_sym_RBRACE,
-  [4763] = 2,
+  [5391] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(441), 1,
+    ACTIONS(486), 1,
       anon_sym_SEMI,
-  [4770] = 2,
+  [5398] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(554), 1,
-      anon_sym_SEMI,
-  [4777] = 2,
+    ACTIONS(597), 1,
+      anon_sym_COLON,
+  [5405] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(556), 1,
-      anon_sym_COLON,
-  [4784] = 2,
+    ACTIONS(599), 1,
+      anon_sym_COLON_COLON,
+  [5412] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(558), 1,
-      sym_identifier,
-  [4791] = 2,
+    ACTIONS(601), 1,
+      anon_sym_LBRACE,
+  [5419] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(560), 1,
+    ACTIONS(603), 1,
       anon_sym_LPAREN,
-  [4798] = 2,
+  [5426] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(562), 1,
+    ACTIONS(605), 1,
       anon_sym_values,
-  [4805] = 2,
+  [5433] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(564), 1,
-      anon_sym_COLON,
-  [4812] = 2,
+    ACTIONS(607), 1,
+      anon_sym_by,
+  [5440] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(566), 1,
-      anon_sym_COLON,
-  [4819] = 2,
+    ACTIONS(609), 1,
+      anon_sym_into,
+  [5447] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(611), 1,
+      anon_sym_LBRACE,
+  [5454] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(568), 1,
+    ACTIONS(613), 1,
+      anon_sym_from,
+  [5461] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(615), 1,
       sym_identifier,
-  [4826] = 2,
+  [5468] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(570), 1,
-      anon_sym_COLON,
-  [4833] = 2,
+    ACTIONS(617), 1,
+      anon_sym_SEMI,
+  [5475] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(572), 1,
+    ACTIONS(619), 1,
       anon_sym_SEMI,
-  [4840] = 2,
+  [5482] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(574), 1,
-      anon_sym_EQ_GT,
-  [4847] = 2,
+    ACTIONS(621), 1,
+      sym_identifier,
+  [5489] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(576), 1,
+    ACTIONS(623), 1,
       anon_sym_SEMI,
-  [4854] = 2,
+  [5496] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(578), 1,
-      sym_identifier,
-  [4861] = 2,
+    ACTIONS(625), 1,
+      anon_sym_from,
+  [5503] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(580), 1,
-      sym_identifier,
-  [4868] = 2,
+    ACTIONS(627), 1,
+      anon_sym_uses,
+  [5510] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(582), 1,
+    ACTIONS(629), 1,
       anon_sym_SEMI,
-  [4875] = 2,
+  [5517] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(584), 1,
+    ACTIONS(631), 1,
       sym_identifier,
-  [4882] = 2,
+  [5524] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(586), 1,
-      anon_sym_SEMI,
-  [4889] = 2,
+    ACTIONS(633), 1,
+      anon_sym_COLON,
+  [5531] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(588), 1,
+    ACTIONS(635), 1,
       anon_sym_LPAREN,
-  [4896] = 2,
+  [5538] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(590), 1,
+    ACTIONS(637), 1,
       anon_sym_COLON,
-  [4903] = 2,
+  [5545] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(592), 1,
+    ACTIONS(639), 1,
       anon_sym_COLON,
-  [4910] = 2,
+  [5552] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(594), 1,
-      anon_sym_LPAREN,
-  [4917] = 2,
+    ACTIONS(641), 1,
+      anon_sym_COLON,
+  [5559] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(596), 1,
-      anon_sym_set,
-  [4924] = 2,
+    ACTIONS(643), 1,
+      anon_sym_LPAREN,
+  [5566] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(598), 1,
-      anon_sym_COLON,
-  [4931] = 2,
+    ACTIONS(645), 1,
+      sym_identifier,
+  [5573] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(600), 1,
+    ACTIONS(647), 1,
       sym_identifier,
-  [4938] = 2,
+  [5580] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(602), 1,
+    ACTIONS(649), 1,
       sym_identifier,
-  [4945] = 2,
+  [5587] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(651), 1,
+      anon_sym_EQ_GT,
+  [5594] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(604), 1,
+    ACTIONS(653), 1,
       anon_sym_SEMI,
-  [4952] = 2,
+  [5601] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(606), 1,
+    ACTIONS(655), 1,
       sym_identifier,
-  [4959] = 2,
+  [5608] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(608), 1,
-      anon_sym_into,
-  [4966] = 2,
+    ACTIONS(657), 1,
+      sym_identifier,
+  [5615] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(610), 1,
-      anon_sym_from,
-  [4973] = 2,
+    ACTIONS(659), 1,
+      sym_identifier,
+  [5622] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(612), 1,
-      anon_sym_LBRACE,
-  [4980] = 2,
+    ACTIONS(661), 1,
+      anon_sym_COLON,
+  [5629] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(614), 1,
-      sym_identifier,
-  [4987] = 2,
+    ACTIONS(663), 1,
+      anon_sym_COLON,
+  [5636] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(616), 1,
+    ACTIONS(665), 1,
       anon_sym_RBRACE,
-  [4994] = 2,
+  [5643] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(295), 1,
-      anon_sym_SEMI,
-  [5001] = 2,
+    ACTIONS(667), 1,
+      sym_identifier,
+  [5650] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(618), 1,
-      anon_sym_COLON,
-  [5008] = 2,
+    ACTIONS(669), 1,
+      sym_identifier,
+  [5657] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(620), 1,
-      sym_identifier,
-  [5015] = 2,
+    ACTIONS(671), 1,
+      anon_sym_by,
+  [5664] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(622), 1,
+    ACTIONS(673), 1,
       anon_sym_SEMI,
-  [5022] = 2,
+  [5671] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(624), 1,
-      anon_sym_values,
-  [5029] = 2,
+    ACTIONS(324), 1,
+      anon_sym_SEMI,
+  [5678] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(675), 1,
+      anon_sym_set,
+  [5685] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(626), 1,
+    ACTIONS(677), 1,
       sym_identifier,
-  [5036] = 2,
+  [5692] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(628), 1,
-      anon_sym_SEMI,
-  [5043] = 2,
+    ACTIONS(679), 1,
+      anon_sym_values,
+  [5699] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(630), 1,
+    ACTIONS(681), 1,
       anon_sym_SEMI,
-  [5050] = 2,
+  [5706] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(632), 1,
-      anon_sym_LBRACE,
-  [5057] = 2,
+    ACTIONS(683), 1,
+      sym_identifier,
+  [5713] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(634), 1,
+    ACTIONS(685), 1,
       anon_sym_SEMI,
-  [5064] = 2,
+  [5720] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(636), 1,
-      anon_sym_RBRACE,
-  [5071] = 2,
+    ACTIONS(687), 1,
+      sym_identifier,
+  [5727] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(638), 1,
+    ACTIONS(689), 1,
       sym_identifier,
-  [5078] = 2,
+  [5734] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(640), 1,
-      anon_sym_RBRACE,
-  [5085] = 2,
+    ACTIONS(691), 1,
+      sym_identifier,
+  [5741] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(693), 1,
+      anon_sym_SEMI,
+  [5748] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(642), 1,
+    ACTIONS(695), 1,
       anon_sym_SEMI,
-  [5092] = 2,
+  [5755] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(644), 1,
+    ACTIONS(697), 1,
       sym_identifier,
-  [5099] = 2,
+  [5762] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(646), 1,
-      anon_sym_LBRACE,
+    ACTIONS(699), 1,
+      anon_sym_RBRACE,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
@@ -6371,261 +6981,287 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(6)] = 276,
   [SMALL_STATE(7)] = 344,
   [SMALL_STATE(8)] = 412,
-  [SMALL_STATE(9)] = 477,
-  [SMALL_STATE(10)] = 539,
-  [SMALL_STATE(11)] = 598,
-  [SMALL_STATE(12)] = 657,
-  [SMALL_STATE(13)] = 716,
-  [SMALL_STATE(14)] = 775,
-  [SMALL_STATE(15)] = 834,
-  [SMALL_STATE(16)] = 867,
-  [SMALL_STATE(17)] = 898,
-  [SMALL_STATE(18)] = 929,
-  [SMALL_STATE(19)] = 959,
-  [SMALL_STATE(20)] = 989,
-  [SMALL_STATE(21)] = 1019,
-  [SMALL_STATE(22)] = 1049,
-  [SMALL_STATE(23)] = 1079,
-  [SMALL_STATE(24)] = 1109,
-  [SMALL_STATE(25)] = 1139,
-  [SMALL_STATE(26)] = 1179,
-  [SMALL_STATE(27)] = 1215,
-  [SMALL_STATE(28)] = 1249,
-  [SMALL_STATE(29)] = 1279,
-  [SMALL_STATE(30)] = 1309,
-  [SMALL_STATE(31)] = 1357,
-  [SMALL_STATE(32)] = 1405,
-  [SMALL_STATE(33)] = 1453,
-  [SMALL_STATE(34)] = 1499,
-  [SMALL_STATE(35)] = 1547,
-  [SMALL_STATE(36)] = 1595,
-  [SMALL_STATE(37)] = 1643,
-  [SMALL_STATE(38)] = 1691,
-  [SMALL_STATE(39)] = 1738,
-  [SMALL_STATE(40)] = 1783,
-  [SMALL_STATE(41)] = 1830,
-  [SMALL_STATE(42)] = 1875,
-  [SMALL_STATE(43)] = 1922,
-  [SMALL_STATE(44)] = 1966,
-  [SMALL_STATE(45)] = 2010,
-  [SMALL_STATE(46)] = 2054,
-  [SMALL_STATE(47)] = 2098,
-  [SMALL_STATE(48)] = 2142,
-  [SMALL_STATE(49)] = 2186,
-  [SMALL_STATE(50)] = 2230,
-  [SMALL_STATE(51)] = 2274,
-  [SMALL_STATE(52)] = 2318,
-  [SMALL_STATE(53)] = 2362,
-  [SMALL_STATE(54)] = 2406,
-  [SMALL_STATE(55)] = 2450,
-  [SMALL_STATE(56)] = 2494,
-  [SMALL_STATE(57)] = 2525,
-  [SMALL_STATE(58)] = 2561,
-  [SMALL_STATE(59)] = 2600,
-  [SMALL_STATE(60)] = 2639,
-  [SMALL_STATE(61)] = 2674,
-  [SMALL_STATE(62)] = 2713,
-  [SMALL_STATE(63)] = 2735,
-  [SMALL_STATE(64)] = 2769,
-  [SMALL_STATE(65)] = 2803,
-  [SMALL_STATE(66)] = 2837,
-  [SMALL_STATE(67)] = 2870,
-  [SMALL_STATE(68)] = 2903,
-  [SMALL_STATE(69)] = 2936,
-  [SMALL_STATE(70)] = 2969,
-  [SMALL_STATE(71)] = 2989,
-  [SMALL_STATE(72)] = 3009,
-  [SMALL_STATE(73)] = 3029,
-  [SMALL_STATE(74)] = 3049,
-  [SMALL_STATE(75)] = 3065,
-  [SMALL_STATE(76)] = 3081,
-  [SMALL_STATE(77)] = 3097,
-  [SMALL_STATE(78)] = 3113,
-  [SMALL_STATE(79)] = 3129,
-  [SMALL_STATE(80)] = 3145,
-  [SMALL_STATE(81)] = 3161,
-  [SMALL_STATE(82)] = 3177,
-  [SMALL_STATE(83)] = 3193,
-  [SMALL_STATE(84)] = 3209,
-  [SMALL_STATE(85)] = 3225,
-  [SMALL_STATE(86)] = 3241,
-  [SMALL_STATE(87)] = 3257,
-  [SMALL_STATE(88)] = 3273,
-  [SMALL_STATE(89)] = 3289,
-  [SMALL_STATE(90)] = 3305,
-  [SMALL_STATE(91)] = 3321,
-  [SMALL_STATE(92)] = 3337,
-  [SMALL_STATE(93)] = 3353,
-  [SMALL_STATE(94)] = 3368,
-  [SMALL_STATE(95)] = 3388,
-  [SMALL_STATE(96)] = 3410,
-  [SMALL_STATE(97)] = 3430,
-  [SMALL_STATE(98)] = 3450,
-  [SMALL_STATE(99)] = 3472,
-  [SMALL_STATE(100)] = 3492,
-  [SMALL_STATE(101)] = 3512,
-  [SMALL_STATE(102)] = 3532,
-  [SMALL_STATE(103)] = 3552,
-  [SMALL_STATE(104)] = 3572,
-  [SMALL_STATE(105)] = 3586,
-  [SMALL_STATE(106)] = 3605,
-  [SMALL_STATE(107)] = 3622,
-  [SMALL_STATE(108)] = 3633,
-  [SMALL_STATE(109)] = 3644,
-  [SMALL_STATE(110)] = 3663,
-  [SMALL_STATE(111)] = 3678,
-  [SMALL_STATE(112)] = 3695,
-  [SMALL_STATE(113)] = 3710,
-  [SMALL_STATE(114)] = 3721,
-  [SMALL_STATE(115)] = 3740,
-  [SMALL_STATE(116)] = 3750,
-  [SMALL_STATE(117)] = 3760,
-  [SMALL_STATE(118)] = 3774,
-  [SMALL_STATE(119)] = 3788,
-  [SMALL_STATE(120)] = 3798,
-  [SMALL_STATE(121)] = 3812,
-  [SMALL_STATE(122)] = 3828,
-  [SMALL_STATE(123)] = 3844,
-  [SMALL_STATE(124)] = 3860,
-  [SMALL_STATE(125)] = 3874,
-  [SMALL_STATE(126)] = 3884,
-  [SMALL_STATE(127)] = 3898,
-  [SMALL_STATE(128)] = 3912,
-  [SMALL_STATE(129)] = 3922,
-  [SMALL_STATE(130)] = 3932,
-  [SMALL_STATE(131)] = 3946,
-  [SMALL_STATE(132)] = 3960,
-  [SMALL_STATE(133)] = 3972,
-  [SMALL_STATE(134)] = 3982,
-  [SMALL_STATE(135)] = 3996,
-  [SMALL_STATE(136)] = 4010,
-  [SMALL_STATE(137)] = 4020,
-  [SMALL_STATE(138)] = 4030,
-  [SMALL_STATE(139)] = 4044,
-  [SMALL_STATE(140)] = 4058,
-  [SMALL_STATE(141)] = 4068,
-  [SMALL_STATE(142)] = 4084,
-  [SMALL_STATE(143)] = 4094,
-  [SMALL_STATE(144)] = 4108,
-  [SMALL_STATE(145)] = 4122,
-  [SMALL_STATE(146)] = 4136,
-  [SMALL_STATE(147)] = 4152,
-  [SMALL_STATE(148)] = 4166,
-  [SMALL_STATE(149)] = 4179,
-  [SMALL_STATE(150)] = 4192,
-  [SMALL_STATE(151)] = 4205,
-  [SMALL_STATE(152)] = 4218,
-  [SMALL_STATE(153)] = 4227,
-  [SMALL_STATE(154)] = 4240,
-  [SMALL_STATE(155)] = 4249,
-  [SMALL_STATE(156)] = 4262,
-  [SMALL_STATE(157)] = 4275,
-  [SMALL_STATE(158)] = 4284,
-  [SMALL_STATE(159)] = 4297,
-  [SMALL_STATE(160)] = 4310,
-  [SMALL_STATE(161)] = 4323,
-  [SMALL_STATE(162)] = 4336,
-  [SMALL_STATE(163)] = 4349,
-  [SMALL_STATE(164)] = 4358,
-  [SMALL_STATE(165)] = 4367,
-  [SMALL_STATE(166)] = 4378,
-  [SMALL_STATE(167)] = 4389,
-  [SMALL_STATE(168)] = 4402,
-  [SMALL_STATE(169)] = 4415,
-  [SMALL_STATE(170)] = 4426,
-  [SMALL_STATE(171)] = 4434,
-  [SMALL_STATE(172)] = 4442,
-  [SMALL_STATE(173)] = 4450,
-  [SMALL_STATE(174)] = 4460,
-  [SMALL_STATE(175)] = 4468,
-  [SMALL_STATE(176)] = 4478,
-  [SMALL_STATE(177)] = 4486,
-  [SMALL_STATE(178)] = 4496,
-  [SMALL_STATE(179)] = 4506,
-  [SMALL_STATE(180)] = 4516,
-  [SMALL_STATE(181)] = 4524,
-  [SMALL_STATE(182)] = 4532,
-  [SMALL_STATE(183)] = 4539,
-  [SMALL_STATE(184)] = 4546,
-  [SMALL_STATE(185)] = 4553,
-  [SMALL_STATE(186)] = 4560,
-  [SMALL_STATE(187)] = 4567,
-  [SMALL_STATE(188)] = 4574,
-  [SMALL_STATE(189)] = 4581,
-  [SMALL_STATE(190)] = 4588,
-  [SMALL_STATE(191)] = 4595,
-  [SMALL_STATE(192)] = 4602,
-  [SMALL_STATE(193)] = 4609,
-  [SMALL_STATE(194)] = 4616,
-  [SMALL_STATE(195)] = 4623,
-  [SMALL_STATE(196)] = 4630,
-  [SMALL_STATE(197)] = 4637,
-  [SMALL_STATE(198)] = 4644,
-  [SMALL_STATE(199)] = 4651,
-  [SMALL_STATE(200)] = 4658,
-  [SMALL_STATE(201)] = 4665,
-  [SMALL_STATE(202)] = 4672,
-  [SMALL_STATE(203)] = 4679,
-  [SMALL_STATE(204)] = 4686,
-  [SMALL_STATE(205)] = 4693,
-  [SMALL_STATE(206)] = 4700,
-  [SMALL_STATE(207)] = 4707,
-  [SMALL_STATE(208)] = 4714,
-  [SMALL_STATE(209)] = 4721,
-  [SMALL_STATE(210)] = 4728,
-  [SMALL_STATE(211)] = 4735,
-  [SMALL_STATE(212)] = 4742,
-  [SMALL_STATE(213)] = 4749,
-  [SMALL_STATE(214)] = 4756,
-  [SMALL_STATE(215)] = 4763,
-  [SMALL_STATE(216)] = 4770,
-  [SMALL_STATE(217)] = 4777,
-  [SMALL_STATE(218)] = 4784,
-  [SMALL_STATE(219)] = 4791,
-  [SMALL_STATE(220)] = 4798,
-  [SMALL_STATE(221)] = 4805,
-  [SMALL_STATE(222)] = 4812,
-  [SMALL_STATE(223)] = 4819,
-  [SMALL_STATE(224)] = 4826,
-  [SMALL_STATE(225)] = 4833,
-  [SMALL_STATE(226)] = 4840,
-  [SMALL_STATE(227)] = 4847,
-  [SMALL_STATE(228)] = 4854,
-  [SMALL_STATE(229)] = 4861,
-  [SMALL_STATE(230)] = 4868,
-  [SMALL_STATE(231)] = 4875,
-  [SMALL_STATE(232)] = 4882,
-  [SMALL_STATE(233)] = 4889,
-  [SMALL_STATE(234)] = 4896,
-  [SMALL_STATE(235)] = 4903,
-  [SMALL_STATE(236)] = 4910,
-  [SMALL_STATE(237)] = 4917,
-  [SMALL_STATE(238)] = 4924,
-  [SMALL_STATE(239)] = 4931,
-  [SMALL_STATE(240)] = 4938,
-  [SMALL_STATE(241)] = 4945,
-  [SMALL_STATE(242)] = 4952,
-  [SMALL_STATE(243)] = 4959,
-  [SMALL_STATE(244)] = 4966,
-  [SMALL_STATE(245)] = 4973,
-  [SMALL_STATE(246)] = 4980,
-  [SMALL_STATE(247)] = 4987,
-  [SMALL_STATE(248)] = 4994,
-  [SMALL_STATE(249)] = 5001,
-  [SMALL_STATE(250)] = 5008,
-  [SMALL_STATE(251)] = 5015,
-  [SMALL_STATE(252)] = 5022,
-  [SMALL_STATE(253)] = 5029,
-  [SMALL_STATE(254)] = 5036,
-  [SMALL_STATE(255)] = 5043,
-  [SMALL_STATE(256)] = 5050,
-  [SMALL_STATE(257)] = 5057,
-  [SMALL_STATE(258)] = 5064,
-  [SMALL_STATE(259)] = 5071,
-  [SMALL_STATE(260)] = 5078,
-  [SMALL_STATE(261)] = 5085,
-  [SMALL_STATE(262)] = 5092,
-  [SMALL_STATE(263)] = 5099,
+  [SMALL_STATE(9)] = 480,
+  [SMALL_STATE(10)] = 545,
+  [SMALL_STATE(11)] = 610,
+  [SMALL_STATE(12)] = 672,
+  [SMALL_STATE(13)] = 731,
+  [SMALL_STATE(14)] = 790,
+  [SMALL_STATE(15)] = 849,
+  [SMALL_STATE(16)] = 908,
+  [SMALL_STATE(17)] = 967,
+  [SMALL_STATE(18)] = 1026,
+  [SMALL_STATE(19)] = 1085,
+  [SMALL_STATE(20)] = 1144,
+  [SMALL_STATE(21)] = 1203,
+  [SMALL_STATE(22)] = 1236,
+  [SMALL_STATE(23)] = 1267,
+  [SMALL_STATE(24)] = 1298,
+  [SMALL_STATE(25)] = 1328,
+  [SMALL_STATE(26)] = 1358,
+  [SMALL_STATE(27)] = 1388,
+  [SMALL_STATE(28)] = 1418,
+  [SMALL_STATE(29)] = 1458,
+  [SMALL_STATE(30)] = 1494,
+  [SMALL_STATE(31)] = 1528,
+  [SMALL_STATE(32)] = 1558,
+  [SMALL_STATE(33)] = 1588,
+  [SMALL_STATE(34)] = 1618,
+  [SMALL_STATE(35)] = 1648,
+  [SMALL_STATE(36)] = 1678,
+  [SMALL_STATE(37)] = 1726,
+  [SMALL_STATE(38)] = 1772,
+  [SMALL_STATE(39)] = 1820,
+  [SMALL_STATE(40)] = 1868,
+  [SMALL_STATE(41)] = 1916,
+  [SMALL_STATE(42)] = 1964,
+  [SMALL_STATE(43)] = 2012,
+  [SMALL_STATE(44)] = 2060,
+  [SMALL_STATE(45)] = 2107,
+  [SMALL_STATE(46)] = 2154,
+  [SMALL_STATE(47)] = 2199,
+  [SMALL_STATE(48)] = 2244,
+  [SMALL_STATE(49)] = 2291,
+  [SMALL_STATE(50)] = 2335,
+  [SMALL_STATE(51)] = 2379,
+  [SMALL_STATE(52)] = 2423,
+  [SMALL_STATE(53)] = 2467,
+  [SMALL_STATE(54)] = 2511,
+  [SMALL_STATE(55)] = 2555,
+  [SMALL_STATE(56)] = 2599,
+  [SMALL_STATE(57)] = 2643,
+  [SMALL_STATE(58)] = 2687,
+  [SMALL_STATE(59)] = 2731,
+  [SMALL_STATE(60)] = 2775,
+  [SMALL_STATE(61)] = 2819,
+  [SMALL_STATE(62)] = 2863,
+  [SMALL_STATE(63)] = 2907,
+  [SMALL_STATE(64)] = 2938,
+  [SMALL_STATE(65)] = 2974,
+  [SMALL_STATE(66)] = 3013,
+  [SMALL_STATE(67)] = 3052,
+  [SMALL_STATE(68)] = 3087,
+  [SMALL_STATE(69)] = 3122,
+  [SMALL_STATE(70)] = 3161,
+  [SMALL_STATE(71)] = 3195,
+  [SMALL_STATE(72)] = 3229,
+  [SMALL_STATE(73)] = 3251,
+  [SMALL_STATE(74)] = 3285,
+  [SMALL_STATE(75)] = 3318,
+  [SMALL_STATE(76)] = 3351,
+  [SMALL_STATE(77)] = 3384,
+  [SMALL_STATE(78)] = 3417,
+  [SMALL_STATE(79)] = 3450,
+  [SMALL_STATE(80)] = 3470,
+  [SMALL_STATE(81)] = 3490,
+  [SMALL_STATE(82)] = 3510,
+  [SMALL_STATE(83)] = 3530,
+  [SMALL_STATE(84)] = 3546,
+  [SMALL_STATE(85)] = 3562,
+  [SMALL_STATE(86)] = 3578,
+  [SMALL_STATE(87)] = 3594,
+  [SMALL_STATE(88)] = 3610,
+  [SMALL_STATE(89)] = 3636,
+  [SMALL_STATE(90)] = 3652,
+  [SMALL_STATE(91)] = 3668,
+  [SMALL_STATE(92)] = 3684,
+  [SMALL_STATE(93)] = 3700,
+  [SMALL_STATE(94)] = 3716,
+  [SMALL_STATE(95)] = 3732,
+  [SMALL_STATE(96)] = 3748,
+  [SMALL_STATE(97)] = 3764,
+  [SMALL_STATE(98)] = 3780,
+  [SMALL_STATE(99)] = 3796,
+  [SMALL_STATE(100)] = 3812,
+  [SMALL_STATE(101)] = 3838,
+  [SMALL_STATE(102)] = 3854,
+  [SMALL_STATE(103)] = 3870,
+  [SMALL_STATE(104)] = 3886,
+  [SMALL_STATE(105)] = 3901,
+  [SMALL_STATE(106)] = 3921,
+  [SMALL_STATE(107)] = 3941,
+  [SMALL_STATE(108)] = 3961,
+  [SMALL_STATE(109)] = 3981,
+  [SMALL_STATE(110)] = 4001,
+  [SMALL_STATE(111)] = 4021,
+  [SMALL_STATE(112)] = 4041,
+  [SMALL_STATE(113)] = 4061,
+  [SMALL_STATE(114)] = 4079,
+  [SMALL_STATE(115)] = 4097,
+  [SMALL_STATE(116)] = 4111,
+  [SMALL_STATE(117)] = 4122,
+  [SMALL_STATE(118)] = 4133,
+  [SMALL_STATE(119)] = 4152,
+  [SMALL_STATE(120)] = 4163,
+  [SMALL_STATE(121)] = 4182,
+  [SMALL_STATE(122)] = 4199,
+  [SMALL_STATE(123)] = 4210,
+  [SMALL_STATE(124)] = 4221,
+  [SMALL_STATE(125)] = 4232,
+  [SMALL_STATE(126)] = 4243,
+  [SMALL_STATE(127)] = 4254,
+  [SMALL_STATE(128)] = 4265,
+  [SMALL_STATE(129)] = 4276,
+  [SMALL_STATE(130)] = 4293,
+  [SMALL_STATE(131)] = 4304,
+  [SMALL_STATE(132)] = 4315,
+  [SMALL_STATE(133)] = 4334,
+  [SMALL_STATE(134)] = 4348,
+  [SMALL_STATE(135)] = 4364,
+  [SMALL_STATE(136)] = 4378,
+  [SMALL_STATE(137)] = 4392,
+  [SMALL_STATE(138)] = 4406,
+  [SMALL_STATE(139)] = 4422,
+  [SMALL_STATE(140)] = 4436,
+  [SMALL_STATE(141)] = 4448,
+  [SMALL_STATE(142)] = 4458,
+  [SMALL_STATE(143)] = 4468,
+  [SMALL_STATE(144)] = 4482,
+  [SMALL_STATE(145)] = 4494,
+  [SMALL_STATE(146)] = 4508,
+  [SMALL_STATE(147)] = 4522,
+  [SMALL_STATE(148)] = 4538,
+  [SMALL_STATE(149)] = 4552,
+  [SMALL_STATE(150)] = 4566,
+  [SMALL_STATE(151)] = 4580,
+  [SMALL_STATE(152)] = 4590,
+  [SMALL_STATE(153)] = 4604,
+  [SMALL_STATE(154)] = 4614,
+  [SMALL_STATE(155)] = 4630,
+  [SMALL_STATE(156)] = 4644,
+  [SMALL_STATE(157)] = 4660,
+  [SMALL_STATE(158)] = 4674,
+  [SMALL_STATE(159)] = 4688,
+  [SMALL_STATE(160)] = 4702,
+  [SMALL_STATE(161)] = 4714,
+  [SMALL_STATE(162)] = 4727,
+  [SMALL_STATE(163)] = 4740,
+  [SMALL_STATE(164)] = 4751,
+  [SMALL_STATE(165)] = 4764,
+  [SMALL_STATE(166)] = 4773,
+  [SMALL_STATE(167)] = 4786,
+  [SMALL_STATE(168)] = 4795,
+  [SMALL_STATE(169)] = 4808,
+  [SMALL_STATE(170)] = 4817,
+  [SMALL_STATE(171)] = 4830,
+  [SMALL_STATE(172)] = 4841,
+  [SMALL_STATE(173)] = 4850,
+  [SMALL_STATE(174)] = 4859,
+  [SMALL_STATE(175)] = 4872,
+  [SMALL_STATE(176)] = 4881,
+  [SMALL_STATE(177)] = 4892,
+  [SMALL_STATE(178)] = 4905,
+  [SMALL_STATE(179)] = 4918,
+  [SMALL_STATE(180)] = 4927,
+  [SMALL_STATE(181)] = 4936,
+  [SMALL_STATE(182)] = 4949,
+  [SMALL_STATE(183)] = 4962,
+  [SMALL_STATE(184)] = 4975,
+  [SMALL_STATE(185)] = 4988,
+  [SMALL_STATE(186)] = 5001,
+  [SMALL_STATE(187)] = 5014,
+  [SMALL_STATE(188)] = 5027,
+  [SMALL_STATE(189)] = 5038,
+  [SMALL_STATE(190)] = 5047,
+  [SMALL_STATE(191)] = 5057,
+  [SMALL_STATE(192)] = 5065,
+  [SMALL_STATE(193)] = 5073,
+  [SMALL_STATE(194)] = 5081,
+  [SMALL_STATE(195)] = 5091,
+  [SMALL_STATE(196)] = 5101,
+  [SMALL_STATE(197)] = 5111,
+  [SMALL_STATE(198)] = 5121,
+  [SMALL_STATE(199)] = 5129,
+  [SMALL_STATE(200)] = 5137,
+  [SMALL_STATE(201)] = 5145,
+  [SMALL_STATE(202)] = 5153,
+  [SMALL_STATE(203)] = 5160,
+  [SMALL_STATE(204)] = 5167,
+  [SMALL_STATE(205)] = 5174,
+  [SMALL_STATE(206)] = 5181,
+  [SMALL_STATE(207)] = 5188,
+  [SMALL_STATE(208)] = 5195,
+  [SMALL_STATE(209)] = 5202,
+  [SMALL_STATE(210)] = 5209,
+  [SMALL_STATE(211)] = 5216,
+  [SMALL_STATE(212)] = 5223,
+  [SMALL_STATE(213)] = 5230,
+  [SMALL_STATE(214)] = 5237,
+  [SMALL_STATE(215)] = 5244,
+  [SMALL_STATE(216)] = 5251,
+  [SMALL_STATE(217)] = 5258,
+  [SMALL_STATE(218)] = 5265,
+  [SMALL_STATE(219)] = 5272,
+  [SMALL_STATE(220)] = 5279,
+  [SMALL_STATE(221)] = 5286,
+  [SMALL_STATE(222)] = 5293,
+  [SMALL_STATE(223)] = 5300,
+  [SMALL_STATE(224)] = 5307,
+  [SMALL_STATE(225)] = 5314,
+  [SMALL_STATE(226)] = 5321,
+  [SMALL_STATE(227)] = 5328,
+  [SMALL_STATE(228)] = 5335,
+  [SMALL_STATE(229)] = 5342,
+  [SMALL_STATE(230)] = 5349,
+  [SMALL_STATE(231)] = 5356,
+  [SMALL_STATE(232)] = 5363,
+  [SMALL_STATE(233)] = 5370,
+  [SMALL_STATE(234)] = 5377,
+  [SMALL_STATE(235)] = 5384,
+  [SMALL_STATE(236)] = 5391,
+  [SMALL_STATE(237)] = 5398,
+  [SMALL_STATE(238)] = 5405,
+  [SMALL_STATE(239)] = 5412,
+  [SMALL_STATE(240)] = 5419,
+  [SMALL_STATE(241)] = 5426,
+  [SMALL_STATE(242)] = 5433,
+  [SMALL_STATE(243)] = 5440,
+  [SMALL_STATE(244)] = 5447,
+  [SMALL_STATE(245)] = 5454,
+  [SMALL_STATE(246)] = 5461,
+  [SMALL_STATE(247)] = 5468,
+  [SMALL_STATE(248)] = 5475,
+  [SMALL_STATE(249)] = 5482,
+  [SMALL_STATE(250)] = 5489,
+  [SMALL_STATE(251)] = 5496,
+  [SMALL_STATE(252)] = 5503,
+  [SMALL_STATE(253)] = 5510,
+  [SMALL_STATE(254)] = 5517,
+  [SMALL_STATE(255)] = 5524,
+  [SMALL_STATE(256)] = 5531,
+  [SMALL_STATE(257)] = 5538,
+  [SMALL_STATE(258)] = 5545,
+  [SMALL_STATE(259)] = 5552,
+  [SMALL_STATE(260)] = 5559,
+  [SMALL_STATE(261)] = 5566,
+  [SMALL_STATE(262)] = 5573,
+  [SMALL_STATE(263)] = 5580,
+  [SMALL_STATE(264)] = 5587,
+  [SMALL_STATE(265)] = 5594,
+  [SMALL_STATE(266)] = 5601,
+  [SMALL_STATE(267)] = 5608,
+  [SMALL_STATE(268)] = 5615,
+  [SMALL_STATE(269)] = 5622,
+  [SMALL_STATE(270)] = 5629,
+  [SMALL_STATE(271)] = 5636,
+  [SMALL_STATE(272)] = 5643,
+  [SMALL_STATE(273)] = 5650,
+  [SMALL_STATE(274)] = 5657,
+  [SMALL_STATE(275)] = 5664,
+  [SMALL_STATE(276)] = 5671,
+  [SMALL_STATE(277)] = 5678,
+  [SMALL_STATE(278)] = 5685,
+  [SMALL_STATE(279)] = 5692,
+  [SMALL_STATE(280)] = 5699,
+  [SMALL_STATE(281)] = 5706,
+  [SMALL_STATE(282)] = 5713,
+  [SMALL_STATE(283)] = 5720,
+  [SMALL_STATE(284)] = 5727,
+  [SMALL_STATE(285)] = 5734,
+  [SMALL_STATE(286)] = 5741,
+  [SMALL_STATE(287)] = 5748,
+  [SMALL_STATE(288)] = 5755,
+  [SMALL_STATE(289)] = 5762,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -6633,310 +7269,336 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
-  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
-  [59] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(15),
-  [62] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(179),
-  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0),
-  [67] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(55),
-  [70] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(10),
-  [73] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(212),
-  [76] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(54),
-  [79] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(20),
-  [82] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(20),
-  [85] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(18),
-  [88] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0), SHIFT_REPEAT(127),
-  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1, 0, 0),
-  [93] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1, 0, 0),
-  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
-  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
-  [99] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
-  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
-  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
-  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1, 0, 0),
-  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expr, 2, 0, 0),
-  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expr, 2, 0, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(215),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [27] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [29] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [31] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [35] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [39] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(21),
+  [42] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(190),
+  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0),
+  [47] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(50),
+  [50] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(12),
+  [53] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(228),
+  [56] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(60),
+  [59] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(35),
+  [62] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(35),
+  [65] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(26),
+  [68] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(157),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
+  [85] = {.entry = {.count = 1, .reusable = false}}, SHIFT(35),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [91] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [95] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1, 0, 0),
+  [101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1, 0, 0),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 3, 0, 0),
+  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 3, 0, 0),
   [117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 3, 0, 0),
   [119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 3, 0, 0),
-  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 3, 0, 0),
-  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 3, 0, 0),
-  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 7),
-  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 7),
+  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_field_access, 3, 0, 2),
+  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_field_access, 3, 0, 2),
   [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binary_expr, 3, 0, 0),
   [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binary_expr, 3, 0, 0),
-  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
-  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
-  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(44),
-  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 4, 0, 0),
-  [145] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 4, 0, 0),
-  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0),
-  [149] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(228),
-  [152] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(229),
-  [155] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(231),
-  [158] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(240),
-  [161] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(243),
-  [164] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(244),
-  [167] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(45),
-  [170] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(254),
-  [173] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
-  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
-  [183] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0), SHIFT_REPEAT(159),
-  [186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0),
-  [188] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0),
-  [190] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_expr, 1, 0, 0),
-  [192] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
-  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
-  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assignment, 3, 0, 12),
-  [200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [202] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_precondition_block_repeat1, 2, 0, 0),
-  [204] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 2, 0, 0),
-  [206] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 3, 0, 9),
-  [208] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_insert_stmt_repeat1, 2, 0, 0),
-  [210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [212] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_stmt, 1, 0, 0),
-  [220] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_stmt, 1, 0, 0),
-  [222] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_type_stmt, 4, 0, 4),
-  [224] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_type_stmt, 4, 0, 4),
-  [226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_context_stmt, 4, 0, 5),
-  [228] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_context_stmt, 4, 0, 5),
-  [230] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_state_stmt, 6, 0, 11),
-  [232] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_state_stmt, 6, 0, 11),
-  [234] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 9, 0, 6),
-  [236] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 13, 0, 6),
-  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_statement, 1, 0, 0),
-  [240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_stmt, 5, 0, 6),
-  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_stmt, 4, 0, 6),
-  [244] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 6, 0, 8),
-  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_stmt, 7, 0, 14),
-  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 7, 0, 15),
-  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 7, 0, 8),
-  [252] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 8, 0, 15),
-  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_foreach_stmt, 8, 0, 16),
-  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assert_stmt, 3, 0, 0),
-  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 9, 0, 15),
-  [260] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_foreach_stmt, 9, 0, 16),
-  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_commit_stmt, 2, 0, 0),
-  [264] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 10, 0, 6),
-  [266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 11, 0, 6),
-  [268] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 12, 0, 6),
-  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 5, 0, 8),
-  [272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_precondition_block, 4, 0, 0),
-  [274] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
-  [276] = {.entry = {.count = 1, .reusable = false}}, SHIFT(107),
-  [278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0),
-  [280] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(195),
-  [283] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(191),
-  [286] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(250),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1, 0, 0),
-  [291] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1, 0, 0),
-  [293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
-  [303] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_type, 1, 0, 0),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 4, 0, 3),
-  [311] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [313] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [315] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 4, 0, 13),
-  [319] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0),
-  [321] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0), SHIFT_REPEAT(9),
-  [324] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0), SHIFT_REPEAT(226),
-  [327] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 3, 0, 10),
-  [329] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [331] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_decl, 4, 0, 1),
-  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 8, 0, 1),
-  [335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
-  [337] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat1, 2, 0, 0),
-  [341] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(246),
-  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_unary_expr, 2, 0, 0),
-  [346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
-  [348] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [350] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [352] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
-  [354] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(124),
-  [357] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
-  [359] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_decl, 5, 0, 1),
-  [361] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(249),
-  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_decl_repeat1, 2, 0, 0),
-  [366] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
-  [368] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
-  [370] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 9, 0, 1),
-  [372] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_decl, 5, 0, 1),
-  [374] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
-  [376] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [378] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_binary_expr, 3, 0, 0),
-  [380] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [382] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [384] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_context_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(217),
-  [387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_context_decl_repeat1, 2, 0, 0),
-  [389] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 7, 0, 1),
-  [391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 10, 0, 1),
-  [393] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_use_state_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(221),
-  [396] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_use_state_stmt_repeat1, 2, 0, 0),
-  [398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_update_stmt_repeat1, 2, 0, 0),
-  [400] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_update_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(175),
-  [403] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 11, 0, 1),
-  [405] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 1, 0, 0),
-  [407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [413] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [415] = {.entry = {.count = 1, .reusable = false}}, SHIFT(124),
-  [417] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_edge_export_block_repeat1, 2, 0, 0), SHIFT_REPEAT(234),
-  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_edge_export_block_repeat1, 2, 0, 0),
-  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_quantifier, 8, 0, 17),
-  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
-  [428] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 0),
-  [430] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 0), SHIFT_REPEAT(177),
-  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
-  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 4, 0, 10),
-  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 5, 0, 13),
-  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_export_block, 4, 0, 0),
-  [463] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_insert_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(43),
-  [466] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [468] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 4, 0, 2),
-  [470] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 3, 0, 0),
-  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_field, 4, 0, 2),
-  [474] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [478] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 1, 0, 0),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
-  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_field, 4, 0, 2),
-  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
-  [486] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_field, 4, 0, 2),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [494] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [518] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [522] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [526] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [530] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_block, 4, 0, 0),
-  [534] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
-  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
-  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
-  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
-  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
-  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
-  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
-  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
-  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
-  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
-  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
+  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [143] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unary_expr, 2, 0, 0),
+  [145] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unary_expr, 2, 0, 0),
+  [147] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_literal, 4, 0, 0),
+  [149] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_struct_literal, 4, 0, 0),
+  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1, 0, 0),
+  [153] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1, 0, 0),
+  [155] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0),
+  [157] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(254),
+  [160] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(262),
+  [163] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(272),
+  [166] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(283),
+  [169] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(243),
+  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(245),
+  [175] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(55),
+  [178] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat3, 2, 0, 0), SHIFT_REPEAT(208),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [187] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [191] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0), SHIFT_REPEAT(168),
+  [194] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0),
+  [196] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_state_decl_repeat2, 2, 0, 0),
+  [198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_expr, 1, 0, 0),
+  [200] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
+  [206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
+  [208] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_guard_expr, 1, 0, 0),
+  [210] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assignment, 3, 0, 13),
+  [212] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_struct_field, 3, 0, 4),
+  [214] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_where_clause, 2, 0, 0),
+  [216] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_invariant_decl_repeat1, 2, 0, 0),
+  [218] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_insert_stmt_repeat1, 2, 0, 0),
+  [220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [230] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_context_stmt, 4, 0, 7),
+  [232] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_context_stmt, 4, 0, 7),
+  [234] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_stmt, 1, 0, 0),
+  [236] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_stmt, 1, 0, 0),
+  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_type_stmt, 4, 0, 6),
+  [240] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_type_stmt, 4, 0, 6),
+  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_state_stmt, 6, 0, 12),
+  [244] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_use_state_stmt, 6, 0, 12),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 9, 0, 16),
+  [248] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_statement, 1, 0, 0),
+  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_commit_stmt, 2, 0, 0),
+  [252] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_assert_stmt, 3, 0, 0),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_stmt, 4, 0, 8),
+  [256] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0),
+  [258] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(203),
+  [261] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(263),
+  [264] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(278),
+  [267] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_program_repeat1, 2, 0, 0), SHIFT_REPEAT(284),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 5, 0, 9),
+  [272] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delete_stmt, 5, 0, 8),
+  [274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 6, 0, 9),
+  [276] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_stmt, 7, 0, 15),
+  [278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 7, 0, 16),
+  [280] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_update_stmt, 7, 0, 9),
+  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_select_stmt, 8, 0, 16),
+  [284] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_foreach_stmt, 8, 0, 17),
+  [286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_foreach_stmt, 9, 0, 17),
+  [288] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 9, 0, 8),
+  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 13, 0, 8),
+  [292] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_program, 1, 0, 0),
+  [294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 10, 0, 8),
+  [296] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 11, 0, 8),
+  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_insert_stmt, 12, 0, 8),
+  [300] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_precondition_block, 4, 0, 0),
+  [302] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [304] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
+  [306] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [308] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 4, 0, 14),
+  [310] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [312] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 3, 0, 10),
+  [314] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [316] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1, 0, 0),
+  [318] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [320] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 9, 0, 1),
+  [322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_decl, 5, 0, 1),
+  [324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [328] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
+  [330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 10, 0, 1),
+  [332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
+  [336] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0),
+  [338] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0), SHIFT_REPEAT(11),
+  [341] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_next_block_repeat1, 2, 0, 0), SHIFT_REPEAT(264),
+  [344] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 7, 0, 1),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 11, 0, 1),
+  [348] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_decl, 5, 0, 1),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_type, 1, 0, 0),
+  [352] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_decl, 4, 0, 1),
+  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_state_decl, 8, 0, 1),
+  [356] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [362] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generic_type, 4, 0, 5),
+  [364] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invariant_decl, 5, 0, 1),
+  [366] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [368] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_edge_export_block_repeat1, 2, 0, 0), SHIFT_REPEAT(259),
+  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_edge_export_block_repeat1, 2, 0, 0),
+  [373] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_condition, 1, 0, 0),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
+  [377] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [381] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [383] = {.entry = {.count = 1, .reusable = false}}, SHIFT(149),
+  [385] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [387] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [389] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [401] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 5, 0, 14),
+  [403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [405] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_unary_expr, 2, 0, 0),
+  [407] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_context_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(255),
+  [410] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_context_decl_repeat1, 2, 0, 0),
+  [412] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_binary_expr, 3, 0, 0),
+  [414] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat1, 2, 0, 0),
+  [416] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_state_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(268),
+  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tla_quantifier, 8, 0, 11),
+  [425] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0),
+  [427] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_repeat1, 2, 0, 0), SHIFT_REPEAT(149),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [432] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_use_state_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(257),
+  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_use_state_stmt_repeat1, 2, 0, 0),
+  [437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_export_block, 4, 0, 0),
+  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [441] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_update_stmt_repeat1, 2, 0, 0),
+  [445] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_update_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(194),
+  [448] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_type_decl_repeat1, 2, 0, 0), SHIFT_REPEAT(237),
+  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_type_decl_repeat1, 2, 0, 0),
+  [453] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 4, 0, 10),
+  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [457] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 0),
+  [463] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_struct_literal_repeat1, 2, 0, 0), SHIFT_REPEAT(196),
+  [466] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_guard, 1, 0, 0),
+  [468] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [470] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [472] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [474] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_guard_unary_expr, 2, 0, 0),
+  [476] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [484] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_guard_binary_expr, 3, 0, 0),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
+  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [494] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 6, 0, 14),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [498] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_insert_stmt_repeat1, 2, 0, 0), SHIFT_REPEAT(59),
+  [501] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_guard_quantifier, 8, 0, 11),
+  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(34),
+  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_case, 5, 0, 10),
+  [509] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
+  [511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type_field, 4, 0, 3),
+  [513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_context_field, 4, 0, 3),
+  [515] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
+  [517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [519] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 3, 0, 0),
+  [523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_edge_field, 4, 0, 3),
+  [525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_qualified_name, 1, 0, 0),
+  [527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_field, 4, 0, 3),
+  [529] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [531] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [533] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [535] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [537] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
+  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [541] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [543] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [549] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
+  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
+  [563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [573] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_next_block, 4, 0, 0),
+  [597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [599] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_limit_clause, 2, 0, 0),
+  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [629] = {.entry = {.count = 1, .reusable = true}}, SHIFT(98),
+  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [633] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [637] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [647] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [675] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
+  [679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(198),
+  [685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
 };
 
 #ifdef __cplusplus